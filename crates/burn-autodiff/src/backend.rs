@@ -11,6 +11,28 @@ use core::marker::PhantomData;
 ///
 /// This works as a backend decorator, extending the functionality of any backend with
 /// backpropagation.
+///
+/// # Higher-order gradients
+///
+/// There is no `create_graph` flag on the backward pass: nesting the decorator is the API for
+/// higher-order gradients. The backward pass is executed with the operations of the inner backend,
+/// so with `Autodiff<Autodiff<B>>` the gradients are tracked tensors of the inner autodiff backend
+/// that can be differentiated again. Each level of nesting adds one order of differentiation, and
+/// each level can use its own checkpoint strategy.
+///
+/// ```rust, ignore
+/// type Outer = Autodiff<Autodiff<B>>;
+///
+/// let x = Tensor::<Autodiff<B>, 2>::from_data([[1.0, 2.0]], &device).require_grad();
+/// let x_outer = Tensor::<Outer, 2>::from_inner(x.clone()).require_grad();
+///
+/// // First-order gradient, tracked by the inner autodiff backend.
+/// let y = x_outer.clone().powf_scalar(3.0).sum();
+/// let grad = x_outer.grad(&y.backward()).unwrap();
+///
+/// // Second-order gradient.
+/// let grad_grad = x.grad(&grad.sum().backward()).unwrap();
+/// ```
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Autodiff<B, C = NoCheckpointing> {
     _b: PhantomData<B>,
@@ -18,6 +18,11 @@ impl NodeTree {
     pub(crate) fn parents(&self, node_id: &NodeID) -> Option<Vec<NodeID>> {
         self.map.get(node_id).map(|node| node.parents.clone())
     }
+
+    /// Whether the node is part of the tree
+    pub(crate) fn contains(&self, node_id: &NodeID) -> bool {
+        self.map.contains_key(node_id)
+    }
 }
 
 #[derive(new, Debug)]
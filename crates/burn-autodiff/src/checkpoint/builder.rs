@@ -3,7 +3,7 @@ use std::{any::Any, collections::HashMap, sync::Arc};
 use burn_tensor::backend::Backend;
 
 use crate::{
-    graph::{ComputingProperty, NodeID, NodeRef, NodeSteps},
+    graph::{ComputingProperty, NodeID, NodeRef},
     tensor::AutodiffTensor,
 };
 
@@ -54,6 +54,8 @@ impl CheckpointingAction {
 /// and builds a checkpointer right before the backward pass
 pub struct CheckpointerBuilder {
     explicit_actions: Vec<CheckpointingAction>,
+    /// The node of the operation that requested each explicit action, once it is known.
+    requesters: Vec<Option<NodeID>>,
     backup_actions: Vec<CheckpointingAction>,
 }
 
@@ -77,7 +79,10 @@ impl CheckpointerBuilder {
         action_type: ActionType,
     ) {
         let action_list = match action_type {
-            ActionType::Explicit => &mut self.explicit_actions,
+            ActionType::Explicit => {
+                self.requesters.push(None);
+                &mut self.explicit_actions
+            }
             ActionType::Backup => &mut self.backup_actions,
         };
         match &tensor.node.properties {
@@ -96,10 +101,20 @@ impl CheckpointerBuilder {
         }
     }
 
+    /// Marks the explicit actions that are not yet attributed as requested by the given node.
+    pub(crate) fn requested_by(&mut self, node_id: &NodeID) {
+        for requester in self.requesters.iter_mut().filter(|id| id.is_none()) {
+            *requester = Some(node_id.clone());
+        }
+    }
+
     pub(crate) fn extend(&mut self, other: CheckpointerBuilder) {
         for other_action in other.explicit_actions {
             self.explicit_actions.push(other_action)
         }
+        for other_requester in other.requesters {
+            self.requesters.push(other_requester)
+        }
         for other_unsure in other.backup_actions {
             self.backup_actions.push(other_unsure)
         }
@@ -109,8 +124,13 @@ impl CheckpointerBuilder {
         self.explicit_actions.len() + self.backup_actions.len()
     }
 
-    pub(crate) fn build(self, graph: &NodeSteps) -> Checkpointer {
-        let node_tree = self.make_tree(graph);
+    /// Builds the checkpointer for the nodes of the given tree.
+    ///
+    /// Explicit actions requested by operations outside of the tree are discarded, since their
+    /// backward steps are not executed. This happens when the graph has branches that don't lead
+    /// to the root of the backward pass, e.g. the forward pass when differentiating a gradient.
+    pub(crate) fn build(mut self, node_tree: NodeTree) -> Checkpointer {
+        self.discard_unreachable(&node_tree);
         let mut backward_states_map = HashMap::new();
         let mut retro_forwards_map = HashMap::new();
 
@@ -134,6 +154,22 @@ impl CheckpointerBuilder {
         )
     }
 
+    fn discard_unreachable(&mut self, node_tree: &NodeTree) {
+        let actions = core::mem::take(&mut self.explicit_actions);
+        let requesters = core::mem::take(&mut self.requesters);
+
+        for (action, requester) in actions.into_iter().zip(requesters) {
+            if let Some(node_id) = &requester {
+                if !node_tree.contains(node_id) {
+                    continue;
+                }
+            }
+
+            self.explicit_actions.push(action);
+            self.requesters.push(requester);
+        }
+    }
+
     fn find_stop_nodes(&self) -> Vec<NodeID> {
         let mut stop_nodes = Vec::default();
         for action in self
@@ -248,14 +284,6 @@ impl CheckpointerBuilder {
         }
     }
 
-    fn make_tree(&self, graph: &NodeSteps) -> NodeTree {
-        let mut tree = HashMap::default();
-        for (id, step) in graph {
-            tree.insert(id.clone(), step.node());
-        }
-        NodeTree::new(tree)
-    }
-
     fn update_n_required_of_parents(
         id: NodeID,
         n_required_map: &mut HashMap<NodeID, usize>,
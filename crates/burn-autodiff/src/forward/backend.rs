@@ -0,0 +1,47 @@
+use crate::forward::tensor::DualTensor;
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// Enable forward mode auto-differentiation on a backend.
+///
+/// This works as a backend decorator: each float tensor carries a tangent, the directional
+/// derivative of its value, which is propagated alongside the value by every operation. Use
+/// [jvp](crate::forward::jvp) to compute Jacobian-vector products.
+///
+/// The decorator composes with [Autodiff](crate::Autodiff): `Autodiff<ForwardAD<B>>` executes the
+/// backward pass on dual tensors, which computes Hessian-vector products (forward-over-reverse).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ForwardAD<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for ForwardAD<B> {
+    type Device = B::Device;
+
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type FullPrecisionBackend = ForwardAD<B::FullPrecisionBackend>;
+
+    type FloatTensorPrimitive<const D: usize> = DualTensor<B, D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn ad_enabled() -> bool {
+        B::ad_enabled()
+    }
+
+    fn name() -> String {
+        format!("forward_ad<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device) {
+        B::sync(device);
+    }
+}
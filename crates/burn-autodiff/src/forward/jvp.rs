@@ -0,0 +1,64 @@
+use crate::forward::{tensor::DualTensor, ForwardAD};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Create a tensor with the given tangent.
+pub fn dual<B: Backend, const D: usize>(
+    primal: Tensor<B, D>,
+    tangent: Tensor<B, D>,
+) -> Tensor<ForwardAD<B>, D> {
+    Tensor::from_primitive(DualTensor::new(
+        primal.into_primitive(),
+        Some(tangent.into_primitive()),
+    ))
+}
+
+/// Returns the value of a tensor, without its tangent.
+pub fn primal<B: Backend, const D: usize>(tensor: Tensor<ForwardAD<B>, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(tensor.into_primitive().primal)
+}
+
+/// Returns the tangent of a tensor, which is zero if the tensor doesn't depend on a tensor with a
+/// tangent.
+pub fn tangent<B: Backend, const D: usize>(tensor: Tensor<ForwardAD<B>, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(tensor.into_primitive().tangent_or_zeros())
+}
+
+/// Computes the Jacobian-vector product of a function.
+///
+/// The function is evaluated once on the primals, while the tangents are propagated through each
+/// operation. This is cheaper than reverse mode when the function has fewer inputs than outputs.
+///
+/// # Arguments
+///
+/// * `func` - The function to differentiate.
+/// * `primals` - The inputs of the function.
+/// * `tangents` - The direction of the derivative for each input.
+///
+/// # Returns
+///
+/// The output of the function and its directional derivative.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // The derivative of `sin(x)` in the direction `v` is `cos(x) * v`.
+/// let (output, tangent) = jvp(|[x]| x.sin(), [x], [v]);
+/// ```
+pub fn jvp<B, F, const D: usize, const D2: usize, const N: usize>(
+    func: F,
+    primals: [Tensor<B, D>; N],
+    tangents: [Tensor<B, D>; N],
+) -> (Tensor<B, D2>, Tensor<B, D2>)
+where
+    B: Backend,
+    F: FnOnce([Tensor<ForwardAD<B>, D>; N]) -> Tensor<ForwardAD<B>, D2>,
+{
+    let mut tangents = tangents.into_iter();
+    let inputs = primals.map(|primal| dual(primal, tangents.next().unwrap()));
+    let output = func(inputs).into_primitive();
+
+    (
+        Tensor::from_primitive(output.primal.clone()),
+        Tensor::from_primitive(output.tangent_or_zeros()),
+    )
+}
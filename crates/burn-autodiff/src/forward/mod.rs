@@ -0,0 +1,7 @@
+mod backend;
+mod jvp;
+mod ops;
+mod tensor;
+
+pub use backend::*;
+pub use jvp::*;
@@ -0,0 +1,5 @@
+use crate::forward::ForwardAD;
+use burn_tensor::{backend::Backend, ops::ActivationOps};
+
+// The default activations are composed of float tensor operations, which propagate the tangents.
+impl<B: Backend> ActivationOps<ForwardAD<B>> for ForwardAD<B> {}
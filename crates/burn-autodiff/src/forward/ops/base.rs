@@ -0,0 +1,12 @@
+use burn_tensor::backend::Backend;
+
+/// Adds two tangents, where `None` is a zero tangent.
+pub(crate) fn add_tangents<B: Backend, const D: usize>(
+    lhs: Option<B::FloatTensorPrimitive<D>>,
+    rhs: Option<B::FloatTensorPrimitive<D>>,
+) -> Option<B::FloatTensorPrimitive<D>> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(B::float_add(lhs, rhs)),
+        (lhs, rhs) => lhs.or(rhs),
+    }
+}
@@ -0,0 +1,230 @@
+use crate::forward::tensor::DualTensor;
+use burn_tensor::{backend::Backend, linalg, Tensor};
+
+type Primitive<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D>;
+
+pub(crate) fn solve<B: Backend, const D: usize>(
+    a: DualTensor<B, D>,
+    b: DualTensor<B, D>,
+) -> DualTensor<B, D> {
+    let output = B::float_solve(a.primal.clone(), b.primal);
+    if a.tangent.is_none() && b.tangent.is_none() {
+        return DualTensor::constant(output);
+    }
+
+    // With `x = a^-1 b`, the tangent is `a^-1 (tb - ta x)`.
+    let x = Tensor::<B, D>::from_primitive(output.clone());
+    let mut rhs = tensor(b.tangent).unwrap_or_else(|| x.zeros_like());
+    if let Some(tangent_a) = tensor(a.tangent) {
+        rhs = rhs - tangent_a.matmul(x);
+    }
+    let tangent = linalg::solve(Tensor::from_primitive(a.primal), rhs);
+
+    DualTensor::new(output, Some(tangent.into_primitive()))
+}
+
+pub(crate) fn slogdet<B: Backend, const D: usize>(
+    tensor: DualTensor<B, D>,
+) -> (DualTensor<B, D>, DualTensor<B, D>) {
+    let (sign, logabsdet) = B::float_slogdet(tensor.primal.clone());
+
+    // The tangent of the logarithm of the absolute determinant is `trace(a^-1 ta)`.
+    let tangent = tensor.tangent.map(|tangent| {
+        let a = Tensor::<B, D>::from_primitive(tensor.primal);
+        let product = linalg::solve(a, Tensor::from_primitive(tangent));
+        let identity = identity_like(&product);
+
+        (product * identity)
+            .sum_dim(D - 1)
+            .sum_dim(D - 2)
+            .into_primitive()
+    });
+
+    (
+        DualTensor::constant(sign),
+        DualTensor::new(logabsdet, tangent),
+    )
+}
+
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: DualTensor<B, D>) -> DualTensor<B, D> {
+    let output = B::float_cholesky(tensor.primal);
+
+    // `l phi(l^-1 ta l^-T)`, where `phi` takes the lower triangle with a halved diagonal, with the
+    // tangent symmetrized since the input is symmetric.
+    let tangent = tensor.tangent.map(|tangent| {
+        let l = Tensor::<B, D>::from_primitive(output.clone());
+        let tangent = symmetrize(Tensor::from_primitive(tangent));
+        let x = linalg::solve(l.clone(), tangent);
+        let x = linalg::solve(l.clone(), x.transpose()).transpose();
+        let phi = x.clone().tril(0) - x * identity_like(&l) * 0.5;
+
+        l.matmul(phi).into_primitive()
+    });
+
+    DualTensor::new(output, tangent)
+}
+
+pub(crate) fn qr<B: Backend, const D: usize>(
+    tensor: DualTensor<B, D>,
+) -> (DualTensor<B, D>, DualTensor<B, D>) {
+    let (q, r) = B::float_qr(tensor.primal.clone());
+    let Some(tangent) = tensor.tangent else {
+        return (DualTensor::constant(q), DualTensor::constant(r));
+    };
+
+    let input = Tensor::<B, D>::from_primitive(tensor.primal);
+    let tangent = Tensor::<B, D>::from_primitive(tangent);
+    let q_tensor = Tensor::<B, D>::from_primitive(q.clone());
+    let r_tensor = Tensor::<B, D>::from_primitive(r.clone());
+    let [m, n] = matrix_dims(&input);
+
+    let (tangent_q, tangent_r) = match m >= n {
+        true => qr_tangent(q_tensor, r_tensor, tangent),
+        false => {
+            // With `a = [x, y]` where `x` is square, `r = [q^T x, q^T y]`.
+            let y = input.narrow(D - 1, m, n - m);
+            let tangent_y = tangent.clone().narrow(D - 1, m, n - m);
+            let (tangent_q, tangent_r_x) = qr_tangent(
+                q_tensor.clone(),
+                r_tensor.narrow(D - 1, 0, m),
+                tangent.narrow(D - 1, 0, m),
+            );
+            let tangent_r_y =
+                tangent_q.clone().transpose().matmul(y) + q_tensor.transpose().matmul(tangent_y);
+
+            (
+                tangent_q,
+                Tensor::cat(vec![tangent_r_x, tangent_r_y], D - 1),
+            )
+        }
+    };
+
+    (
+        DualTensor::new(q, Some(tangent_q.into_primitive())),
+        DualTensor::new(r, Some(tangent_r.into_primitive())),
+    )
+}
+
+/// Tangents of the QR decomposition of matrices with at least as many rows as columns.
+fn qr_tangent<B: Backend, const D: usize>(
+    q: Tensor<B, D>,
+    r: Tensor<B, D>,
+    tangent: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    // `z = ta r^-1`, computed by solving `r^T z^T = ta^T`.
+    let z = linalg::solve(r.clone().transpose(), tangent.transpose()).transpose();
+    let x = q.clone().transpose().matmul(z.clone());
+    let lower = x.clone().tril(-1);
+    let omega = lower.clone() - lower.transpose();
+
+    let tangent_r = (x - omega.clone()).matmul(r);
+    let tangent_q = q.clone().matmul(omega) + z.clone() - q.clone().matmul(q.transpose().matmul(z));
+
+    (tangent_q, tangent_r)
+}
+
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: DualTensor<B, D>,
+) -> (DualTensor<B, D>, DualTensor<B, D>, DualTensor<B, D>) {
+    let (u, s, vh) = B::float_svd(tensor.primal);
+    let Some(tangent) = tensor.tangent else {
+        return (
+            DualTensor::constant(u),
+            DualTensor::constant(s),
+            DualTensor::constant(vh),
+        );
+    };
+
+    let tangent = Tensor::<B, D>::from_primitive(tangent);
+    let u_tensor = Tensor::<B, D>::from_primitive(u.clone());
+    let s_tensor = Tensor::<B, D>::from_primitive(s.clone());
+    let v = Tensor::<B, D>::from_primitive(vh.clone()).transpose();
+    let identity = identity_like(&s_tensor);
+
+    // Singular values are stored as rows, so `s_row[j, k] = s_k` and `s_col[j, k] = s_j`.
+    let s_col = s_tensor.clone().transpose();
+    let s_squared = s_tensor.clone().powf_scalar(2.0);
+    let f = (identity.ones_like() - identity.clone())
+        / (s_squared.clone() - s_squared.transpose() + identity.clone());
+
+    let p = u_tensor
+        .clone()
+        .transpose()
+        .matmul(tangent.clone())
+        .matmul(v.clone());
+    let tangent_s = (p.clone() * identity).sum_dim(D - 2);
+
+    let p_s = p.clone() * s_tensor.clone();
+    let s_p = p * s_col;
+    let av = tangent.clone().matmul(v.clone()) / s_tensor.clone();
+    let au = tangent.transpose().matmul(u_tensor.clone()) / s_tensor;
+
+    let tangent_u = u_tensor
+        .clone()
+        .matmul(f.clone() * (p_s.clone() + p_s.transpose()))
+        + av.clone()
+        - u_tensor
+            .clone()
+            .matmul(u_tensor.clone().transpose().matmul(av));
+    let tangent_v = v.clone().matmul(f * (s_p.clone() + s_p.transpose())) + au.clone()
+        - v.clone().matmul(v.transpose().matmul(au));
+
+    (
+        DualTensor::new(u, Some(tangent_u.into_primitive())),
+        DualTensor::new(s, Some(tangent_s.into_primitive())),
+        DualTensor::new(vh, Some(tangent_v.transpose().into_primitive())),
+    )
+}
+
+pub(crate) fn eigh<B: Backend, const D: usize>(
+    tensor: DualTensor<B, D>,
+) -> (DualTensor<B, D>, DualTensor<B, D>) {
+    let (values, vectors) = B::float_eigh(tensor.primal);
+    let Some(tangent) = tensor.tangent else {
+        return (DualTensor::constant(values), DualTensor::constant(vectors));
+    };
+
+    // `tl = diag(v^T ta v)` and `tv = v (f * v^T ta v)` where `f[i, j] = 1 / (l_j - l_i)`.
+    let tangent = symmetrize(Tensor::<B, D>::from_primitive(tangent));
+    let values_tensor = Tensor::<B, D>::from_primitive(values.clone());
+    let vectors_tensor = Tensor::<B, D>::from_primitive(vectors.clone());
+    let identity = identity_like(&vectors_tensor);
+
+    let p = vectors_tensor
+        .clone()
+        .transpose()
+        .matmul(tangent)
+        .matmul(vectors_tensor.clone());
+    let f = (identity.ones_like() - identity.clone())
+        / (values_tensor.clone() - values_tensor.transpose() + identity.clone());
+    let tangent_values = (p.clone() * identity).sum_dim(D - 2);
+    let tangent_vectors = vectors_tensor.matmul(f * p);
+
+    (
+        DualTensor::new(values, Some(tangent_values.into_primitive())),
+        DualTensor::new(vectors, Some(tangent_vectors.into_primitive())),
+    )
+}
+
+fn tensor<B: Backend, const D: usize>(tangent: Option<Primitive<B, D>>) -> Option<Tensor<B, D>> {
+    tangent.map(Tensor::from_primitive)
+}
+
+/// Returns `(x + x^T) / 2`.
+fn symmetrize<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    (tensor.clone() + tensor.transpose()) * 0.5
+}
+
+/// Returns identity matrices broadcastable to the square matrices of the given tensor.
+fn identity_like<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+    let n = tensor.dims()[D - 1];
+    let mut dims = [1; D];
+    dims[D - 2] = n;
+    dims[D - 1] = n;
+
+    Tensor::<B, 2>::diagonal(n, &tensor.device()).reshape(dims)
+}
+
+fn matrix_dims<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> [usize; 2] {
+    [tensor.dims()[D - 2], tensor.dims()[D - 1]]
+}
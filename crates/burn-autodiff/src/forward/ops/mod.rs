@@ -0,0 +1,7 @@
+mod activation;
+mod base;
+mod bool_tensor;
+mod int_tensor;
+mod linalg;
+mod module;
+mod tensor;
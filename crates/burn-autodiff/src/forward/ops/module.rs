@@ -0,0 +1,293 @@
+use super::base::add_tangents;
+use crate::forward::{tensor::DualTensor, ForwardAD};
+use burn_tensor::{backend::Backend, ops::*, Shape};
+
+impl<B: Backend> ModuleOps<ForwardAD<B>> for ForwardAD<B> {
+    fn embedding(weights: DualTensor<B, 2>, indices: IntTensor<B, 2>) -> DualTensor<B, 3> {
+        weights.linear(|weights| B::embedding(weights, indices.clone()))
+    }
+
+    fn embedding_backward(
+        weights: DualTensor<B, 2>,
+        output_grad: DualTensor<B, 3>,
+        indices: IntTensor<B, 2>,
+    ) -> DualTensor<B, 2> {
+        let tangent = output_grad
+            .tangent
+            .map(|tangent| B::embedding_backward(weights.primal.clone(), tangent, indices.clone()));
+
+        DualTensor::new(
+            B::embedding_backward(weights.primal, output_grad.primal, indices),
+            tangent,
+        )
+    }
+
+    fn int_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::int_conv2d(x, weight, bias, options)
+    }
+
+    fn int8_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::int8_conv2d(x, weight, bias, options)
+    }
+
+    fn conv2d(
+        x: DualTensor<B, 4>,
+        weight: DualTensor<B, 4>,
+        bias: Option<DualTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> DualTensor<B, 4> {
+        let (bias, tangent_bias) = split_bias(bias);
+        let tangent = add_tangents::<B, 4>(
+            x.tangent
+                .map(|tangent| B::conv2d(tangent, weight.primal.clone(), None, options.clone())),
+            weight
+                .tangent
+                .map(|tangent| B::conv2d(x.primal.clone(), tangent, None, options.clone())),
+        );
+        let tangent = add_tangents::<B, 4>(tangent, tangent_bias);
+
+        DualTensor::output(B::conv2d(x.primal, weight.primal, bias, options), tangent)
+    }
+
+    fn conv_transpose2d(
+        x: DualTensor<B, 4>,
+        weight: DualTensor<B, 4>,
+        bias: Option<DualTensor<B, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> DualTensor<B, 4> {
+        let (bias, tangent_bias) = split_bias(bias);
+        let tangent = add_tangents::<B, 4>(
+            x.tangent.map(|tangent| {
+                B::conv_transpose2d(tangent, weight.primal.clone(), None, options.clone())
+            }),
+            weight.tangent.map(|tangent| {
+                B::conv_transpose2d(x.primal.clone(), tangent, None, options.clone())
+            }),
+        );
+        let tangent = add_tangents::<B, 4>(tangent, tangent_bias);
+
+        DualTensor::output(
+            B::conv_transpose2d(x.primal, weight.primal, bias, options),
+            tangent,
+        )
+    }
+
+    fn avg_pool2d(
+        x: DualTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> DualTensor<B, 4> {
+        x.linear(|x| B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad))
+    }
+
+    fn avg_pool2d_backward(
+        x: DualTensor<B, 4>,
+        grad: DualTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> DualTensor<B, 4> {
+        let x = x.primal;
+        grad.linear(|grad| {
+            B::avg_pool2d_backward(
+                x.clone(),
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )
+        })
+    }
+
+    fn adaptive_avg_pool2d(x: DualTensor<B, 4>, output_size: [usize; 2]) -> DualTensor<B, 4> {
+        x.linear(|x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: DualTensor<B, 4>,
+        grad: DualTensor<B, 4>,
+    ) -> DualTensor<B, 4> {
+        let x = x.primal;
+        grad.linear(|grad| B::adaptive_avg_pool2d_backward(x.clone(), grad))
+    }
+
+    fn max_pool2d(
+        x: DualTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> DualTensor<B, 4> {
+        match x.tangent {
+            Some(_) => {
+                Self::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation).output
+            }
+            None => DualTensor::constant(B::max_pool2d(
+                x.primal,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool2d_with_indices(
+        x: DualTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<ForwardAD<B>> {
+        let output = B::max_pool2d_with_indices(x.primal, kernel_size, stride, padding, dilation);
+
+        // The indices point to the flattened spatial dimensions of the input.
+        let tangent = x.tangent.map(|tangent| {
+            let [batch_size, channels, height, width] = B::float_shape(&tangent).dims;
+            let shape = B::float_shape(&output.output);
+            let [_, _, height_out, width_out] = shape.dims;
+
+            let tangent =
+                B::float_reshape(tangent, Shape::new([batch_size, channels, height * width]));
+            let indices = B::int_reshape(
+                output.indices.clone(),
+                Shape::new([batch_size, channels, height_out * width_out]),
+            );
+
+            B::float_reshape(B::float_gather(2, tangent, indices), shape)
+        });
+
+        MaxPool2dWithIndices::new(DualTensor::new(output.output, tangent), output.indices)
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: DualTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: DualTensor<B, 4>,
+        indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<ForwardAD<B>> {
+        let x = x.primal;
+        let x_grad = output_grad.linear(|grad| {
+            B::max_pool2d_with_indices_backward(
+                x.clone(),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices.clone(),
+            )
+            .x_grad
+        });
+
+        MaxPool2dBackward::new(x_grad)
+    }
+
+    fn interpolate(
+        x: DualTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> DualTensor<B, 4> {
+        x.linear(|x| B::interpolate(x, output_size, options.clone()))
+    }
+
+    fn interpolate_backward(
+        x: DualTensor<B, 4>,
+        grad: DualTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> DualTensor<B, 4> {
+        let x = x.primal;
+        grad.linear(|grad| B::interpolate_backward(x.clone(), grad, output_size, options.clone()))
+    }
+
+    fn grid_sample_2d(
+        x: DualTensor<B, 4>,
+        grid: DualTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> DualTensor<B, 4> {
+        let tangent = add_tangents::<B, 4>(
+            x.tangent
+                .map(|tangent| B::grid_sample_2d(tangent, grid.primal.clone(), options.clone())),
+            grid.tangent.map(|tangent| {
+                grid_sample::grid_sample_2d_grid_jvp::<B>(
+                    x.primal.clone(),
+                    grid.primal.clone(),
+                    tangent,
+                    options.clone(),
+                )
+            }),
+        );
+
+        DualTensor::output(B::grid_sample_2d(x.primal, grid.primal, options), tangent)
+    }
+
+    fn grid_sample_2d_backward(
+        x: DualTensor<B, 4>,
+        grid: DualTensor<B, 4>,
+        grad: DualTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<ForwardAD<B>> {
+        // The gradients are linear in the output gradient and the grid gradient is linear in the
+        // input, while the tangent of the grid isn't propagated.
+        let backward =
+            |x, grad| B::grid_sample_2d_backward(x, grid.primal.clone(), grad, options.clone());
+
+        let tangent_grad = grad
+            .tangent
+            .map(|tangent| backward(x.primal.clone(), tangent));
+        let tangent_x = x
+            .tangent
+            .map(|tangent| backward(tangent, grad.primal.clone()).grid_grad);
+        let (tangent_x_grad, tangent_grid_grad) = match tangent_grad {
+            Some(tangent) => (Some(tangent.x_grad), Some(tangent.grid_grad)),
+            None => (None, None),
+        };
+
+        let output = backward(x.primal, grad.primal);
+
+        GridSampleBackward::new(
+            DualTensor::output(output.x_grad, tangent_x_grad),
+            DualTensor::output(
+                output.grid_grad,
+                add_tangents::<B, 4>(tangent_grid_grad, tangent_x),
+            ),
+        )
+    }
+}
+
+/// Splits the bias of a convolution into its primal and its tangent, reshaped to be broadcastable
+/// to the output.
+fn split_bias<B: Backend>(
+    bias: Option<DualTensor<B, 1>>,
+) -> (
+    Option<B::FloatTensorPrimitive<1>>,
+    Option<B::FloatTensorPrimitive<4>>,
+) {
+    let Some(bias) = bias else {
+        return (None, None);
+    };
+    let tangent = bias.tangent.map(|tangent| {
+        let [channels] = B::float_shape(&tangent).dims;
+        B::float_reshape(tangent, Shape::new([1, channels, 1, 1]))
+    });
+
+    (Some(bias.primal), tangent)
+}
@@ -0,0 +1,1008 @@
+use super::base::add_tangents;
+use super::linalg;
+use crate::forward::{tensor::DualTensor, ForwardAD};
+use crate::ops::cumulative::first_zero_masks;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
+    trigamma, Data, Device, ElementConversion, PadMode, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for ForwardAD<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<B>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: burn_tensor::Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_random(shape, distribution, device))
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_zeros(shape, device))
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_ones(shape, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::float_shape(&tensor.primal)
+    }
+
+    fn float_to_data<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<B>, D>> {
+        B::float_to_data(&tensor.primal)
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<B>, D>> {
+        B::float_into_data(tensor.primal)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primal)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        B::float_into_int(tensor.primal)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_empty(shape, device))
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = add_tangents::<B, D>(lhs.tangent, rhs.tangent);
+
+        DualTensor::output(B::float_add(lhs.primal, rhs.primal), tangent)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::new(B::float_add_scalar(lhs.primal, rhs), lhs.tangent)
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = add_tangents::<B, D>(lhs.tangent, rhs.tangent.map(B::float_neg));
+
+        DualTensor::output(B::float_sub(lhs.primal, rhs.primal), tangent)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::new(B::float_sub_scalar(lhs.primal, rhs), lhs.tangent)
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent
+                .map(|tangent| B::float_mul(tangent, rhs.primal.clone())),
+            rhs.tangent
+                .map(|tangent| B::float_mul(lhs.primal.clone(), tangent)),
+        );
+
+        DualTensor::output(B::float_mul(lhs.primal, rhs.primal), tangent)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.linear(|tensor| B::float_mul_scalar(tensor, rhs))
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let output = B::float_div(lhs.primal, rhs.primal.clone());
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent,
+            rhs.tangent
+                .map(|tangent| B::float_neg(B::float_mul(tangent, output.clone()))),
+        )
+        .map(|tangent| B::float_div(tangent, rhs.primal));
+
+        DualTensor::output(output, tangent)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.linear(|tensor| B::float_div_scalar(tensor, rhs))
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent
+                .map(|tangent| B::float_matmul(tangent, rhs.primal.clone())),
+            rhs.tangent
+                .map(|tangent| B::float_matmul(lhs.primal.clone(), tangent)),
+        );
+
+        DualTensor::output(B::float_matmul(lhs.primal, rhs.primal), tangent)
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.linear(B::float_neg)
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_recip(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(output.clone(), output.clone());
+            B::float_neg(B::float_mul(tangent, squared))
+        });
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_swap_dims(tensor, dim1, dim2))
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        tensor.linear(|tensor| B::float_reshape(tensor, shape.clone()))
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_gather(dim, tensor, indices.clone()))
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_scatter(
+                dim,
+                tensor.tangent_or_zeros(),
+                indices.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor::new(
+            B::float_scatter(dim, tensor.primal, indices, value.primal),
+            tangent,
+        )
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_select(tensor, dim, indices.clone()))
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_select_assign(
+                tensor.tangent_or_zeros(),
+                dim,
+                indices.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor::new(
+            B::float_select_assign(tensor.primal, dim, indices, value.primal),
+            tangent,
+        )
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        tensor.linear(|tensor| B::float_slice(tensor, ranges.clone()))
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_slice_assign(
+                tensor.tangent_or_zeros(),
+                ranges.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor::new(
+            B::float_slice_assign(tensor.primal, ranges, value.primal),
+            tangent,
+        )
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_mask_where(
+                tensor.tangent_or_zeros(),
+                mask.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor::output(
+            B::float_mask_where(tensor.primal, mask, value.primal),
+            tangent,
+        )
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mask_fill(tangent, mask.clone(), 0.elem()));
+
+        DualTensor::new(B::float_mask_fill(tensor.primal, mask, value), tangent)
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        B::float_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        B::float_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        B::float_greater(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        B::float_greater_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        B::float_greater_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        B::float_greater_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        B::float_lower(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        B::float_lower_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        B::float_lower_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        B::float_lower_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_detach(tensor.primal))
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::new(
+            B::float_set_require_grad(tensor.primal, require_grad),
+            tensor.tangent,
+        )
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
+        B::float_is_require_grad(&tensor.primal)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        tensor.linear(B::float_sum)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_sum_dim(tensor, dim))
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        tensor.linear(B::float_mean)
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_mean_dim(tensor, dim))
+    }
+
+    fn float_to_full_precision<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
+        DualTensor::new(
+            B::float_to_full_precision(&tensor.primal),
+            tensor.tangent.as_ref().map(B::float_to_full_precision),
+        )
+    }
+
+    fn float_from_full_precision<const D: usize>(
+        tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::new(
+            B::float_from_full_precision(tensor.primal),
+            tensor.tangent.map(B::float_from_full_precision),
+        )
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_exp(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, output.clone()));
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_div(tangent, tensor.primal.clone()));
+
+        DualTensor::new(B::float_log(tensor.primal), tangent)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let input = B::float_add_scalar(tensor.primal.clone(), 1.elem());
+            B::float_div(tangent, input)
+        });
+
+        DualTensor::new(B::float_log1p(tensor.primal), tangent)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let derivative = B::float_powf_scalar(tensor.primal.clone(), value - 1.0);
+            let derivative = B::float_mul_scalar(derivative, value.elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::new(B::float_powf_scalar(tensor.primal, value), tangent)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let output = B::float_powf(lhs.primal.clone(), rhs.primal.clone());
+        let tangent_lhs = lhs.tangent.map(|tangent| {
+            let exponent = B::float_sub_scalar(rhs.primal.clone(), 1.elem());
+            let derivative = B::float_powf(lhs.primal.clone(), exponent);
+            let derivative = B::float_mul(derivative, rhs.primal.clone());
+            B::float_mul(tangent, derivative)
+        });
+        let tangent_rhs = rhs.tangent.map(|tangent| {
+            let derivative = B::float_mul(output.clone(), B::float_log(lhs.primal));
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::output(output, add_tangents::<B, D>(tangent_lhs, tangent_rhs))
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_sqrt(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_div(tangent, B::float_mul_scalar(output.clone(), 2.elem())));
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let negative = B::float_lower_elem(tensor.primal.clone(), 0.elem());
+            B::float_mask_where(tangent.clone(), negative, B::float_neg(tangent))
+        });
+
+        DualTensor::new(B::float_abs(tensor.primal), tangent)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let derivative = B::float_neg(B::float_sin(tensor.primal.clone()));
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::new(B::float_cos(tensor.primal), tangent)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_cos(tensor.primal.clone())));
+
+        DualTensor::new(B::float_sin(tensor.primal), tangent)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_tanh(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(output.clone(), output.clone());
+            let derivative = B::float_add_scalar(B::float_neg(squared), 1.elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(tensor.primal.clone(), tensor.primal.clone());
+            let derivative = B::float_exp(B::float_neg(squared));
+            let derivative =
+                B::float_mul_scalar(derivative, core::f64::consts::FRAC_2_SQRT_PI.elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::new(B::float_erf(tensor.primal), tangent)
+    }
+
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_floor(tensor.primal))
+    }
+
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_ceil(tensor.primal))
+    }
+
+    fn float_round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_round(tensor.primal))
+    }
+
+    fn float_trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_trunc(tensor.primal))
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_sign(tensor.primal))
+    }
+
+    fn float_tan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_tan(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(output.clone(), output.clone());
+            B::float_mul(tangent, B::float_add_scalar(squared, 1.elem()))
+        });
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_asin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(tensor.primal.clone(), tensor.primal.clone());
+            let derivative = B::float_sqrt(B::float_add_scalar(B::float_neg(squared), 1.elem()));
+            B::float_div(tangent, derivative)
+        });
+
+        DualTensor::new(B::float_asin(tensor.primal), tangent)
+    }
+
+    fn float_acos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(tensor.primal.clone(), tensor.primal.clone());
+            let derivative = B::float_sqrt(B::float_add_scalar(B::float_neg(squared), 1.elem()));
+            B::float_neg(B::float_div(tangent, derivative))
+        });
+
+        DualTensor::new(B::float_acos(tensor.primal), tangent)
+    }
+
+    fn float_atan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let squared = B::float_mul(tensor.primal.clone(), tensor.primal.clone());
+            B::float_div(tangent, B::float_add_scalar(squared, 1.elem()))
+        });
+
+        DualTensor::new(B::float_atan(tensor.primal), tangent)
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let norm = B::float_add(
+            B::float_mul(lhs.primal.clone(), lhs.primal.clone()),
+            B::float_mul(rhs.primal.clone(), rhs.primal.clone()),
+        );
+        let tangent_lhs = lhs.tangent.map(|tangent| {
+            let derivative = B::float_div(rhs.primal.clone(), norm.clone());
+            B::float_mul(tangent, derivative)
+        });
+        let tangent_rhs = rhs.tangent.map(|tangent| {
+            let derivative = B::float_div(B::float_neg(lhs.primal.clone()), norm);
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::output(
+            B::float_atan2(lhs.primal, rhs.primal),
+            add_tangents::<B, D>(tangent_lhs, tangent_rhs),
+        )
+    }
+
+    fn float_sinh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_cosh(tensor.primal.clone())));
+
+        DualTensor::new(B::float_sinh(tensor.primal), tangent)
+    }
+
+    fn float_cosh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_sinh(tensor.primal.clone())));
+
+        DualTensor::new(B::float_cosh(tensor.primal), tangent)
+    }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_digamma(tensor.primal.clone())));
+
+        DualTensor::new(B::float_lgamma(tensor.primal), tangent)
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, trigamma::<B, D>(tensor.primal.clone())));
+
+        DualTensor::new(B::float_digamma(tensor.primal), tangent)
+    }
+
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_erfinv(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let derivative = B::float_exp(B::float_mul(output.clone(), output.clone()));
+            let derivative =
+                B::float_mul_scalar(derivative, (core::f64::consts::PI.sqrt() / 2.0).elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_expm1<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_exp(tensor.primal.clone())));
+
+        DualTensor::new(B::float_expm1(tensor.primal), tangent)
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensors.iter().any(|tensor| tensor.tangent.is_some()) {
+            true => Some(B::float_cat(
+                tensors.iter().map(DualTensor::tangent_or_zeros).collect(),
+                dim,
+            )),
+            false => None,
+        };
+        let primals = tensors.into_iter().map(|tensor| tensor.primal).collect();
+
+        DualTensor::new(B::float_cat(primals, dim), tangent)
+    }
+
+    fn float_argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmax(tensor.primal, dim)
+    }
+
+    fn float_argmin<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmin(tensor.primal, dim)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_max_dim_with_indices(tensor, dim).0
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (output, indices) = B::float_max_dim_with_indices(tensor.primal, dim);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor::new(output, tangent), indices)
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_min_dim_with_indices(tensor, dim).0
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (output, indices) = B::float_min_dim_with_indices(tensor.primal, dim);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor::new(output, tangent), indices)
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        match tensor.tangent {
+            Some(_) => Self::float_sort_with_indices(tensor, dim, descending).0,
+            None => DualTensor::constant(B::float_sort(tensor.primal, dim, descending)),
+        }
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (output, indices) = B::float_sort_with_indices(tensor.primal, dim, descending);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor::new(output, tangent), indices)
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::float_argsort(tensor.primal, dim, descending)
+    }
+
+    fn float_searchsorted<const D: usize>(
+        sorted: FloatTensor<Self, 1>,
+        values: FloatTensor<Self, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        B::float_searchsorted(sorted.primal, values.primal, right)
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self, 1>,
+        bins: usize,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> FloatTensor<Self, 1> {
+        DualTensor::constant(B::float_histc(tensor.primal, bins, min, max))
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.linear(|tensor| B::float_cumsum(tensor, dim))
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let output = B::float_cumprod(tensor.primal.clone(), dim);
+
+        let tangent = tensor.tangent.map(|tangent| {
+            let input = tensor.primal;
+            let (before, first) = first_zero_masks::<B, D>(input.clone(), dim);
+            let after = B::bool_not(before.clone());
+
+            // Before the first zero, the input can be divided out of the products.
+            let divisor = B::float_mask_fill(input.clone(), after.clone(), 1.elem());
+            let tangent_before = B::float_mul(
+                B::float_cumsum(B::float_div(tangent.clone(), divisor), dim),
+                output.clone(),
+            );
+            let tangent_before = B::float_mask_fill(tangent_before, after, 0.elem());
+
+            // The products from the first zero on only change with it, by the products where it
+            // is replaced by one.
+            let products = B::float_cumprod(B::float_add(input, first.clone()), dim);
+            let tangent_first = B::float_sum_dim(B::float_mul(tangent, first), dim);
+            let tangent_after =
+                B::float_mask_fill(B::float_mul(products, tangent_first), before, 0.elem());
+
+            B::float_add(tangent_before, tangent_after)
+        });
+
+        DualTensor::new(output, tangent)
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_cummax_with_indices(tensor, dim).0
+    }
+
+    fn float_cummax_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (output, indices) = B::float_cummax_with_indices(tensor.primal, dim);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor::new(output, tangent), indices)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_cummin_with_indices(tensor, dim).0
+    }
+
+    fn float_cummin_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (output, indices) = B::float_cummin_with_indices(tensor.primal, dim);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor::new(output, tangent), indices)
+    }
+
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> FloatTensor<Self, D> {
+        // The padding values don't depend on the input, so their tangent is zero.
+        let mode_tangent = match mode {
+            PadMode::Constant(_) => PadMode::Constant(0.0),
+            mode => mode,
+        };
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_pad(tangent, pads, mode_tangent));
+
+        DualTensor::new(B::float_pad(tensor.primal, pads, mode), tangent)
+    }
+
+    fn float_fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let tangent = match real.tangent.is_some() || imag.tangent.is_some() {
+            true => Some(B::float_fft(
+                real.tangent_or_zeros(),
+                imag.tangent_or_zeros(),
+                dim,
+            )),
+            false => None,
+        };
+        let (real, imag) = B::float_fft(real.primal, imag.primal, dim);
+
+        split_complex(real, imag, tangent)
+    }
+
+    fn float_ifft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let tangent = match real.tangent.is_some() || imag.tangent.is_some() {
+            true => Some(B::float_ifft(
+                real.tangent_or_zeros(),
+                imag.tangent_or_zeros(),
+                dim,
+            )),
+            false => None,
+        };
+        let (real, imag) = B::float_ifft(real.primal, imag.primal, dim);
+
+        split_complex(real, imag, tangent)
+    }
+
+    fn float_rfft<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let tangent = tensor.tangent.map(|tangent| B::float_rfft(tangent, dim));
+        let (real, imag) = B::float_rfft(tensor.primal, dim);
+
+        split_complex(real, imag, tangent)
+    }
+
+    fn float_irfft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        n: usize,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match real.tangent.is_some() || imag.tangent.is_some() {
+            true => Some(B::float_irfft(
+                real.tangent_or_zeros(),
+                imag.tangent_or_zeros(),
+                dim,
+                n,
+            )),
+            false => None,
+        };
+
+        DualTensor::new(B::float_irfft(real.primal, imag.primal, dim, n), tangent)
+    }
+
+    fn float_solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        linalg::solve(a, b)
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        linalg::slogdet(tensor)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        linalg::cholesky(tensor)
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        linalg::qr(tensor)
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        linalg::svd(tensor)
+    }
+
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        linalg::eigh(tensor)
+    }
+}
+
+/// Splits the tangent of a complex output between its real and imaginary parts.
+fn split_complex<B: Backend, const D: usize>(
+    real: B::FloatTensorPrimitive<D>,
+    imag: B::FloatTensorPrimitive<D>,
+    tangent: Option<(B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>)>,
+) -> (DualTensor<B, D>, DualTensor<B, D>) {
+    let (tangent_real, tangent_imag) = tangent.unzip();
+
+    (
+        DualTensor::new(real, tangent_real),
+        DualTensor::new(imag, tangent_imag),
+    )
+}
@@ -0,0 +1,51 @@
+use burn_tensor::backend::Backend;
+
+/// A float tensor along with its tangent.
+#[derive(new, Debug, Clone)]
+pub struct DualTensor<B: Backend, const D: usize> {
+    /// The value of the tensor.
+    pub primal: B::FloatTensorPrimitive<D>,
+    /// The tangent of the tensor, `None` when it is zero.
+    pub tangent: Option<B::FloatTensorPrimitive<D>>,
+}
+
+impl<B: Backend, const D: usize> DualTensor<B, D> {
+    /// Create a tensor with a zero tangent.
+    pub fn constant(primal: B::FloatTensorPrimitive<D>) -> Self {
+        Self::new(primal, None)
+    }
+
+    /// Create the output of an operation, expanding the tangent to the shape of the primal when
+    /// it was computed from broadcasted inputs.
+    pub fn output(
+        primal: B::FloatTensorPrimitive<D>,
+        tangent: Option<B::FloatTensorPrimitive<D>>,
+    ) -> Self {
+        let shape = B::float_shape(&primal);
+        let tangent = tangent.map(|tangent| match B::float_shape(&tangent) == shape {
+            true => tangent,
+            false => {
+                let zeros = B::float_zeros(shape, &B::float_device(&primal));
+                B::float_add(zeros, tangent)
+            }
+        });
+
+        Self::new(primal, tangent)
+    }
+
+    /// Returns the tangent, with zeros when it isn't tracked.
+    pub fn tangent_or_zeros(&self) -> B::FloatTensorPrimitive<D> {
+        match &self.tangent {
+            Some(tangent) => tangent.clone(),
+            None => B::float_zeros(B::float_shape(&self.primal), &B::float_device(&self.primal)),
+        }
+    }
+
+    /// Applies a linear operation to both the primal and the tangent.
+    pub fn linear<const D2: usize, F>(self, func: F) -> DualTensor<B, D2>
+    where
+        F: Fn(B::FloatTensorPrimitive<D>) -> B::FloatTensorPrimitive<D2>,
+    {
+        DualTensor::new(func(self.primal), self.tangent.map(&func))
+    }
+}
@@ -1,23 +1,32 @@
+use std::collections::HashMap;
+
 use burn_tensor::backend::Backend;
 
-use crate::{checkpoint::base::Checkpointer, grads::Gradients, tensor::AutodiffTensor};
+use crate::{
+    checkpoint::base::{Checkpointer, NodeTree},
+    grads::Gradients,
+    tensor::AutodiffTensor,
+};
 
 use super::{traversal::BreadthFirstSearch, Graph, NodeRef, StepBoxed};
 
 pub fn backward<B: Backend, const D: usize>(root: AutodiffTensor<B, D>) -> Gradients {
     let grads = Gradients::new::<B, D>(root.node.clone(), root.primitive);
-    let checkpointer = root.graph.build_checkpointer();
-    let tape = build_tape(root.node, root.graph);
+    let (tape, node_tree) = build_tape(root.node, root.graph.clone());
+    let checkpointer = root.graph.build_checkpointer(node_tree);
 
     execute_steps(tape, grads, checkpointer)
 }
 
-fn build_tape(root: NodeRef, graph: Graph) -> Vec<Vec<StepBoxed>> {
+fn build_tape(root: NodeRef, graph: Graph) -> (Vec<Vec<StepBoxed>>, NodeTree) {
     let mut tape = (0..root.order)
         .map(|_| Vec::with_capacity(1))
         .collect::<Vec<_>>();
+    let mut nodes = HashMap::new();
 
     BreadthFirstSearch.traverse(root, graph, |node, step| {
+        nodes.insert(node.id.clone(), node.clone());
+
         if node.order == 0 {
             return;
         }
@@ -27,7 +36,7 @@ fn build_tape(root: NodeRef, graph: Graph) -> Vec<Vec<StepBoxed>> {
         };
     });
 
-    tape
+    (tape, NodeTree::new(nodes))
 }
 
 fn execute_steps(
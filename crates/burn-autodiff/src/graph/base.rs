@@ -2,11 +2,14 @@ use spin::Mutex;
 use std::{collections::HashMap, sync::Arc};
 
 use crate::{
-    checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
+    checkpoint::{
+        base::{Checkpointer, NodeTree},
+        builder::CheckpointerBuilder,
+    },
     grads::Gradients,
 };
 
-use super::{NodeID, NodeRef};
+use super::{NodeID, NodeRef, Requirement};
 
 /// Backward step for reverse mode autodiff.
 pub trait Step: Send + Sync + std::fmt::Debug {
@@ -26,6 +29,16 @@ pub type NodeSteps = HashMap<NodeID, StepBoxed>;
 pub struct Graph {
     steps: Arc<Mutex<NodeSteps>>,
     checkpointing_actions: Arc<Mutex<CheckpointerBuilder>>,
+    link: Arc<Mutex<GraphLink>>,
+}
+
+/// How a graph relates to the graphs merged with it.
+#[derive(Default, Debug)]
+struct GraphLink {
+    /// Whether the graph holds the steps of tracked operations.
+    tracked: bool,
+    /// The graph holding the steps once this graph is merged into it.
+    merged_into: Option<Graph>,
 }
 
 impl Graph {
@@ -45,9 +58,11 @@ impl Graph {
     /// keeping all the tensors alive for multiple backward call is a heavy waste of resources.
     pub fn steps(self) -> NodeSteps {
         let mut map_drain = HashMap::new();
-        self.execute_mut_steps(|map| {
+        let graph = self.root().execute_mut_steps(|map| {
             std::mem::swap(&mut *map, &mut map_drain);
         });
+        graph.link.lock().tracked = false;
+
         map_drain
     }
 
@@ -56,26 +71,72 @@ impl Graph {
     /// This is a owned method, so the current checkpointing actions will be freed.
     pub fn take_checkpointing_actions(self) -> CheckpointerBuilder {
         let mut actions = CheckpointerBuilder::default();
-        self.execute_mut_checkpointing_actions(|checkpointing_actions| {
-            std::mem::swap(&mut *checkpointing_actions, &mut actions);
-        });
+        self.root()
+            .execute_mut_checkpointing_actions(|checkpointing_actions| {
+                std::mem::swap(&mut *checkpointing_actions, &mut actions);
+            });
         actions
     }
 
     /// Register a new step into the graph.
     pub fn register(self, id: &NodeID, ops: StepBoxed) -> Self {
-        self.execute_mut_steps(|map| {
+        let tracked = !matches!(ops.node().requirement, Requirement::None);
+        let graph = self.root().execute_mut_steps(|map| {
             map.insert(id.clone(), ops);
-        })
+        });
+        graph.link.lock().tracked |= tracked;
+
+        graph
     }
 
     /// Merge two graphs.
+    ///
+    /// When the other graph holds tracked steps, it keeps referring to the merged graph, so that
+    /// the tensors sharing it can still be differentiated. The graphs of leaves must be merged
+    /// with [merge_leaf](Graph::merge_leaf) instead, since they would keep the merged graph alive.
     pub fn merge(self, other: Self) -> Self {
-        if Arc::ptr_eq(&self.steps, &other.steps) {
-            return self;
+        self.merge_with(other, true)
+    }
+
+    /// Merge the graph of a leaf tensor, which only holds the step of the leaf itself.
+    pub fn merge_leaf(self, leaf: Self) -> Self {
+        self.merge_with(leaf, false)
+    }
+
+    fn merge_with(self, other: Self, link: bool) -> Self {
+        let graph = self.root();
+        let other = other.root();
+
+        if Arc::ptr_eq(&graph.steps, &other.steps) {
+            return graph;
+        }
+
+        let tracked = other.link.lock().tracked;
+        let graph = graph.merge_different(other.clone());
+
+        if tracked {
+            graph.link.lock().tracked = true;
+
+            if link {
+                other.link.lock().merged_into = Some(graph.clone());
+            }
         }
 
-        self.merge_different(other)
+        graph
+    }
+
+    /// Follows the merges of the graph up to the graph holding its steps.
+    fn root(self) -> Self {
+        let mut graph = self;
+
+        loop {
+            let merged_into = graph.link.lock().merged_into.clone();
+
+            match merged_into {
+                Some(merged_into) => graph = merged_into,
+                None => return graph,
+            }
+        }
     }
 
     fn execute_mut_steps<F: FnOnce(&mut NodeSteps)>(mut self, func: F) -> Self {
@@ -139,14 +200,17 @@ impl Graph {
         })
     }
 
-    pub(crate) fn build_checkpointer(&self) -> Checkpointer {
-        let mut guard = self.checkpointing_actions.lock();
+    pub(crate) fn build_checkpointer(&self, node_tree: NodeTree) -> Checkpointer {
+        let graph = self.clone().root();
+        let mut guard = graph.checkpointing_actions.lock();
         let builder: CheckpointerBuilder = std::mem::take(&mut *guard);
-        builder.build(&self.steps.lock())
+        builder.build(node_tree)
     }
 
     pub(crate) fn extend_checkpointer_builder(&self, checkpointing_actions: CheckpointerBuilder) {
-        self.checkpointing_actions
+        self.clone()
+            .root()
+            .checkpointing_actions
             .lock()
             .extend(checkpointing_actions);
     }
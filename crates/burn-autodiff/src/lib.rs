@@ -14,6 +14,8 @@ extern crate alloc;
 
 /// Checkpoint module.
 pub mod checkpoint;
+/// Forward mode automatic differentiation module.
+pub mod forward;
 /// Gradients module.
 pub mod grads;
 /// Operation module.
@@ -27,6 +29,7 @@ pub(crate) mod utils;
 
 mod backend;
 pub use backend::*;
+pub use forward::ForwardAD;
 
 #[cfg(feature = "export_tests")]
 mod tests;
@@ -79,6 +79,18 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_not(tensor)
     }
 
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_and(lhs, rhs)
+    }
+
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_or(lhs, rhs)
+    }
+
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_xor(lhs, rhs)
+    }
+
     fn bool_into_float<const D: usize>(
         tensor: BoolTensor<B, D>,
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<D> {
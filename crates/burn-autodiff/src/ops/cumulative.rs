@@ -0,0 +1,52 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, ElementConversion, Shape};
+
+#[derive(Debug)]
+pub(crate) struct CumMaxMin;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumMaxMin {
+    type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::float_device(&grad);
+            let zeros = B::float_zeros(shape, &device);
+
+            B::float_scatter(dim, zeros, indices, grad)
+        });
+    }
+}
+
+/// Cumulative sum of `tensor` along `dim`, starting from the last element.
+pub(crate) fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> B::FloatTensorPrimitive<D> {
+    let sum = B::float_sum_dim(tensor.clone(), dim);
+    let cumsum = B::float_cumsum(tensor.clone(), dim);
+
+    B::float_add(B::float_sub(sum, cumsum), tensor)
+}
+
+/// Masks of the elements of `tensor` before its first zero along `dim`, and of that first zero.
+pub(crate) fn first_zero_masks<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> (B::BoolTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+    let zeros = B::bool_into_float(B::float_equal_elem(tensor, 0.elem()));
+    let count = B::float_cumsum(zeros.clone(), dim);
+    let before = B::float_equal_elem(count.clone(), 0.elem());
+    let first = B::float_mul(
+        zeros,
+        B::bool_into_float(B::float_equal_elem(count, 1.elem())),
+    );
+
+    (before, first)
+}
@@ -0,0 +1,166 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, Data, ElementConversion, Shape};
+
+/// The part of a complex output tracked by an operation.
+///
+/// Complex outputs are registered as two nodes, one for each part, sharing the same parents.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ComplexPart {
+    Real,
+    Imag,
+}
+
+impl ComplexPart {
+    /// Places the gradient of the tracked part next to a zero gradient for the other part.
+    fn split<B: Backend, const D: usize>(
+        self,
+        grad: B::FloatTensorPrimitive<D>,
+    ) -> (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+        let zeros = B::float_zeros(B::float_shape(&grad), &B::float_device(&grad));
+
+        match self {
+            ComplexPart::Real => (grad, zeros),
+            ComplexPart::Imag => (zeros, grad),
+        }
+    }
+}
+
+/// Backward of [fft](burn_tensor::ops::FloatTensorOps::float_fft) and
+/// [ifft](burn_tensor::ops::FloatTensorOps::float_ifft).
+#[derive(new, Debug)]
+pub(crate) struct Fft {
+    part: ComplexPart,
+    inverse: bool,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Fft {
+    type State = usize;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let dim = ops.state;
+        let [node_real, node_imag] = ops.parents;
+        let (grad_real, grad_imag) = self.part.split::<B, D>(grads.consume::<B, D>(&ops.node));
+        let n = B::float_shape(&grad_real).dims[dim] as f32;
+
+        // The adjoint of the unnormalized transform is the inverse transform scaled by `n`, and
+        // the adjoint of the inverse transform is the transform scaled by `1 / n`.
+        let (grad_real, grad_imag) = match self.inverse {
+            false => {
+                let (real, imag) = B::float_ifft(grad_real, grad_imag, dim);
+                (
+                    B::float_mul_scalar(real, n.elem()),
+                    B::float_mul_scalar(imag, n.elem()),
+                )
+            }
+            true => {
+                let (real, imag) = B::float_fft(grad_real, grad_imag, dim);
+                (
+                    B::float_div_scalar(real, n.elem()),
+                    B::float_div_scalar(imag, n.elem()),
+                )
+            }
+        };
+
+        if let Some(node) = node_real {
+            grads.register::<B, D>(node, grad_real);
+        }
+
+        if let Some(node) = node_imag {
+            grads.register::<B, D>(node, grad_imag);
+        }
+    }
+}
+
+/// Backward of [rfft](burn_tensor::ops::FloatTensorOps::float_rfft).
+#[derive(new, Debug)]
+pub(crate) struct Rfft {
+    part: ComplexPart,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Rfft {
+    type State = (usize, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (dim, n) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            // Frequencies that aren't returned receive no gradient, and the gradient of a real
+            // input is the real part of the adjoint of the full transform.
+            let (grad_real, grad_imag) = self.part.split::<B, D>(grad);
+            let grad_real = pad_along::<B, D>(grad_real, dim, n);
+            let grad_imag = pad_along::<B, D>(grad_imag, dim, n);
+            let (grad, _) = B::float_ifft(grad_real, grad_imag, dim);
+
+            B::float_mul_scalar(grad, (n as f32).elem())
+        });
+    }
+}
+
+/// Backward of [irfft](burn_tensor::ops::FloatTensorOps::float_irfft).
+#[derive(Debug)]
+pub(crate) struct Irfft;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Irfft {
+    type State = usize;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let dim = ops.state;
+        let [node_real, node_imag] = ops.parents;
+        let grad = grads.consume::<B, D>(&ops.node);
+        let device = B::float_device(&grad);
+        let n = B::float_shape(&grad).dims[dim];
+        let (grad_real, grad_imag) = B::float_rfft(grad, dim);
+
+        // Each frequency other than the zero and Nyquist ones also stands for its conjugate pair.
+        let size = n / 2 + 1;
+        let weights: Vec<f32> = (0..size)
+            .map(|j| match j == 0 || 2 * j == n {
+                true => 1.0 / n as f32,
+                false => 2.0 / n as f32,
+            })
+            .collect();
+        let mut dims = [1; D];
+        dims[dim] = size;
+        let weights = B::float_from_data(Data::new(weights, Shape::new(dims)).convert(), &device);
+
+        if let Some(node) = node_real {
+            grads.register::<B, D>(node, B::float_mul(grad_real, weights.clone()));
+        }
+
+        if let Some(node) = node_imag {
+            grads.register::<B, D>(node, B::float_mul(grad_imag, weights));
+        }
+    }
+}
+
+/// Pads the tensor with zeros at the end of the given dimension up to `size` elements.
+fn pad_along<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+    size: usize,
+) -> B::FloatTensorPrimitive<D> {
+    let shape = B::float_shape(&tensor);
+    let mut shape_padded = shape.clone();
+    shape_padded.dims[dim] = size;
+
+    let zeros = B::float_zeros(shape_padded, &B::float_device(&tensor));
+    let ranges = shape.dims.map(|size| 0..size);
+
+    B::float_slice_assign(zeros, ranges, tensor)
+}
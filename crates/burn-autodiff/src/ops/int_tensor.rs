@@ -3,7 +3,7 @@ use crate::{checkpoint::strategy::CheckpointStrategy, tensor::AutodiffTensor, Au
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, IntTensor, IntTensorOps},
-    Data, Device, Distribution, Reader, Shape,
+    Data, Device, Distribution, PadMode, Reader, Shape,
 };
 
 impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
@@ -345,4 +345,130 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     fn int_arange(range: std::ops::Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
         B::int_arange(range, device)
     }
+
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        B::int_cummin(tensor, dim)
+    }
+
+    fn int_cummin_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        B::int_cummin_with_indices(tensor, dim)
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<Self, D> {
+        B::int_pad(tensor, pads, mode)
+    }
+
+    fn int_matmul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_matmul(lhs, rhs)
+    }
+
+    fn int8_matmul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int8_matmul(lhs, rhs)
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_and_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_or_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_xor_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        B::int_bitwise_not(tensor)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_left_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_left_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_right_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<Self, D> {
+        B::int_bitwise_right_shift_scalar(lhs, rhs)
+    }
 }
@@ -0,0 +1,317 @@
+use super::{Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, linalg, Tensor};
+
+type Primitive<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D>;
+
+/// Backward of [solve](burn_tensor::ops::FloatTensorOps::float_solve).
+#[derive(Debug)]
+pub(crate) struct Solve;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+    type State = (Primitive<B, D>, Primitive<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node_a, node_b] = ops.parents;
+        let grad = Tensor::<B, D>::from_primitive(grads.consume::<B, D>(&ops.node));
+        let a = Tensor::<B, D>::from_primitive(ops.state.0);
+        let x = Tensor::<B, D>::from_primitive(ops.state.1);
+
+        // With `x = a^-1 b`, the gradient of `b` is `a^-T g` and the gradient of `a` is
+        // `-a^-T g x^T`.
+        let grad_b = linalg::solve(a.transpose(), grad);
+
+        if let Some(node) = node_a {
+            let grad_a = grad_b.clone().matmul(x.transpose()).neg();
+            grads.register::<B, D>(node, grad_a.into_primitive());
+        }
+
+        if let Some(node) = node_b {
+            grads.register::<B, D>(node, grad_b.into_primitive());
+        }
+    }
+}
+
+/// Backward of the logarithm of the absolute determinant computed by
+/// [slogdet](burn_tensor::ops::FloatTensorOps::float_slogdet).
+#[derive(Debug)]
+pub(crate) struct LogAbsDet;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for LogAbsDet {
+    type State = Primitive<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node] = ops.parents;
+        let grad = Tensor::<B, D>::from_primitive(grads.consume::<B, D>(&ops.node));
+        let tensor = Tensor::<B, D>::from_primitive(ops.state);
+
+        if let Some(node) = node {
+            let grad = linalg::inv(tensor).transpose() * grad;
+            grads.register::<B, D>(node, grad.into_primitive());
+        }
+    }
+}
+
+/// Backward of [cholesky](burn_tensor::ops::FloatTensorOps::float_cholesky).
+#[derive(Debug)]
+pub(crate) struct Cholesky;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+    type State = Primitive<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node] = ops.parents;
+        let grad = Tensor::<B, D>::from_primitive(grads.consume::<B, D>(&ops.node));
+        let l = Tensor::<B, D>::from_primitive(ops.state);
+
+        if let Some(node) = node {
+            // `l^-T phi(l^T g) l^-1`, where `phi` takes the lower triangle with a halved diagonal,
+            // symmetrized since the input is symmetric.
+            let phi = l.clone().transpose().matmul(grad);
+            let phi = phi.clone().tril(0) - phi * identity_like(&l) * 0.5;
+            let grad = linalg::solve(l.clone().transpose(), phi);
+            let grad = linalg::solve(l.transpose(), grad.transpose()).transpose();
+            let grad = (grad.clone() + grad.transpose()) * 0.5;
+
+            grads.register::<B, D>(node, grad.into_primitive());
+        }
+    }
+}
+
+/// The output of a decomposition tracked by an operation.
+///
+/// Decompositions are registered as one node for each output, sharing the same parent.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Factor {
+    First,
+    Second,
+    Third,
+}
+
+impl Factor {
+    /// Places the gradient of the tracked output next to zero gradients for the other outputs.
+    fn split<B: Backend, const D: usize, const N: usize>(
+        self,
+        grad: Primitive<B, D>,
+        factors: &[Tensor<B, D>; N],
+    ) -> [Tensor<B, D>; N] {
+        let grad = Tensor::<B, D>::from_primitive(grad);
+        let index = match self {
+            Factor::First => 0,
+            Factor::Second => 1,
+            Factor::Third => 2,
+        };
+
+        core::array::from_fn(|i| match i == index {
+            true => grad.clone(),
+            false => factors[i].zeros_like(),
+        })
+    }
+}
+
+/// Backward of [qr](burn_tensor::ops::FloatTensorOps::float_qr).
+#[derive(new, Debug)]
+pub(crate) struct Qr {
+    factor: Factor,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Qr {
+    type State = (Primitive<B, D>, Primitive<B, D>, Primitive<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node] = ops.parents;
+        let (tensor, q, r) = ops.state;
+        let factors = [
+            Tensor::<B, D>::from_primitive(q),
+            Tensor::<B, D>::from_primitive(r),
+        ];
+        let [grad_q, grad_r] = self
+            .factor
+            .split::<B, D, 2>(grads.consume::<B, D>(&ops.node), &factors);
+        let [q, r] = factors;
+
+        let Some(node) = node else {
+            return;
+        };
+
+        let [m, n] = matrix_dims(&q, &r);
+        let grad = match m >= n {
+            true => qr_backward(q, r, grad_q, grad_r),
+            false => {
+                // With `a = [x, y]` where `x` is square, `r = [q^T x, q^T y]` and the gradient of
+                // `y` flows through the last columns of `r`.
+                let tensor = Tensor::<B, D>::from_primitive(tensor);
+                let y = tensor.narrow(D - 1, m, n - m);
+                let grad_r_y = grad_r.clone().narrow(D - 1, m, n - m);
+                let grad_y = q.clone().matmul(grad_r_y.clone());
+                let grad_q = grad_q + y.matmul(grad_r_y.transpose());
+                let grad_x =
+                    qr_backward(q, r.narrow(D - 1, 0, m), grad_q, grad_r.narrow(D - 1, 0, m));
+
+                Tensor::cat(vec![grad_x, grad_y], D - 1)
+            }
+        };
+
+        grads.register::<B, D>(node, grad.into_primitive());
+    }
+}
+
+/// Gradient of the QR decomposition of matrices with at least as many rows as columns.
+fn qr_backward<B: Backend, const D: usize>(
+    q: Tensor<B, D>,
+    r: Tensor<B, D>,
+    grad_q: Tensor<B, D>,
+    grad_r: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let m = grad_r.matmul(r.clone().transpose()) - q.clone().transpose().matmul(grad_q.clone());
+    let m = m.triu(0);
+    let m = m.clone() + m.clone().transpose() - m.clone() * identity_like(&m);
+    let grad = q.matmul(m) + grad_q;
+
+    // Solves `x r^T = grad` for `x`.
+    linalg::solve(r, grad.transpose()).transpose()
+}
+
+/// Backward of [svd](burn_tensor::ops::FloatTensorOps::float_svd).
+#[derive(new, Debug)]
+pub(crate) struct Svd {
+    factor: Factor,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Svd {
+    type State = (Primitive<B, D>, Primitive<B, D>, Primitive<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node] = ops.parents;
+        let (u, s, vh) = ops.state;
+        let factors = [
+            Tensor::<B, D>::from_primitive(u),
+            Tensor::<B, D>::from_primitive(s),
+            Tensor::<B, D>::from_primitive(vh),
+        ];
+        let [grad_u, grad_s, grad_vh] = self
+            .factor
+            .split::<B, D, 3>(grads.consume::<B, D>(&ops.node), &factors);
+        let [u, s, vh] = factors;
+
+        let Some(node) = node else {
+            return;
+        };
+
+        let [m, n] = matrix_dims(&u, &vh);
+        let k = usize::min(m, n);
+        let v = vh.clone().transpose();
+        let grad_v = grad_vh.transpose();
+        let identity = identity_like(&s);
+
+        // Singular values are stored as rows, so `s_row[j, k] = s_k` and `s_col[j, k] = s_j`.
+        let s_col = s.clone().transpose();
+        let s_squared = s.clone().powf_scalar(2.0);
+        let e = s_squared.clone() - s_squared.transpose() + identity.clone();
+
+        let uhgu = skew(u.clone().transpose().matmul(grad_u.clone()));
+        let vhgv = skew(v.clone().transpose().matmul(grad_v.clone()));
+        let inner = (uhgu * s.clone() + s_col * vhgv) / e + identity * grad_s;
+        let mut grad = u.clone().matmul(inner).matmul(vh.clone());
+
+        // The components of the gradients outside of the spans of the singular vectors.
+        if m > k {
+            let grad_u = grad_u / s.clone();
+            let projected = grad_u.clone() - u.clone().matmul(u.clone().transpose().matmul(grad_u));
+            grad = grad + projected.matmul(vh.clone());
+        }
+        if n > k {
+            let grad_v = grad_v / s;
+            let projected = grad_v.clone() - v.matmul(vh.matmul(grad_v));
+            grad = grad + u.matmul(projected.transpose());
+        }
+
+        grads.register::<B, D>(node, grad.into_primitive());
+    }
+}
+
+/// Backward of [eigh](burn_tensor::ops::FloatTensorOps::float_eigh).
+#[derive(new, Debug)]
+pub(crate) struct Eigh {
+    factor: Factor,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Eigh {
+    type State = (Primitive<B, D>, Primitive<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node] = ops.parents;
+        let (values, vectors) = ops.state;
+        let factors = [
+            Tensor::<B, D>::from_primitive(values),
+            Tensor::<B, D>::from_primitive(vectors),
+        ];
+        let [grad_values, grad_vectors] = self
+            .factor
+            .split::<B, D, 2>(grads.consume::<B, D>(&ops.node), &factors);
+        let [values, vectors] = factors;
+
+        let Some(node) = node else {
+            return;
+        };
+
+        // `v (skew(v^T gv) / e + diag(gl)) v^T` where `e[i, j] = l_j - l_i`.
+        let identity = identity_like(&vectors);
+        let e = values.clone() - values.transpose() + identity.clone();
+        let vhgv = skew(vectors.clone().transpose().matmul(grad_vectors)) * 0.5;
+        let inner = vhgv / e + identity * grad_values;
+        let grad = vectors.clone().matmul(inner).matmul(vectors.transpose());
+
+        grads.register::<B, D>(node, grad.into_primitive());
+    }
+}
+
+/// Returns `x - x^T`.
+fn skew<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone() - tensor.transpose()
+}
+
+/// Returns identity matrices broadcastable to the square matrices of the given tensor.
+fn identity_like<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+    let n = tensor.dims()[D - 1];
+    let mut dims = [1; D];
+    dims[D - 2] = n;
+    dims[D - 1] = n;
+
+    Tensor::<B, 2>::diagonal(n, &tensor.device()).reshape(dims)
+}
+
+/// Returns the number of rows of `lhs` and the number of columns of `rhs`.
+fn matrix_dims<B: Backend, const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> [usize; 2] {
+    [lhs.dims()[D - 2], rhs.dims()[D - 1]]
+}
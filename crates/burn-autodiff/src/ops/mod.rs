@@ -6,7 +6,12 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod cumulative;
+pub(crate) mod fft;
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
+pub(crate) mod pad;
+pub(crate) mod sort;
 
 pub use backward::*;
 pub use base::*;
@@ -54,6 +54,24 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate embedding backward.");
     }
 
+    fn int_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::int_conv2d(x, weight, bias, options)
+    }
+
+    fn int8_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::int8_conv2d(x, weight, bias, options)
+    }
+
     fn conv2d(
         x: AutodiffTensor<B, 4>,
         weight: AutodiffTensor<B, 4>,
@@ -299,6 +317,251 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         }
     }
 
+    fn conv3d(
+        x: AutodiffTensor<B, 5>,
+        weight: AutodiffTensor<B, 5>,
+        bias: Option<AutodiffTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct Conv3DWithBias;
+        #[derive(Debug)]
+        struct Conv3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for Conv3DWithBias {
+            type State = (NodeID, NodeID, NodeID, ConvOptions<3>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x_state, weight_state, bias_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let weight = checkpointer.retrieve_node_output(weight_state);
+                let bias = Some(checkpointer.retrieve_node_output(bias_state));
+
+                let backward = B::conv3d_backward(x, weight, bias, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for Conv3DNoBias {
+            type State = (NodeID, NodeID, ConvOptions<3>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x_state, weight_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let weight = checkpointer.retrieve_node_output(weight_state);
+
+                let backward = B::conv3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => match Conv3DWithBias
+                .prepare::<C>(
+                    [x.node.clone(), weight.node.clone(), bias.node.clone()],
+                    [x.graph.clone(), weight.graph.clone(), bias.graph.clone()],
+                )
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let weight_state = prep.checkpoint(&weight);
+                    let bias_state = prep.checkpoint(&bias);
+                    prep.finish(
+                        (x_state, weight_state, bias_state, options.clone()),
+                        B::conv3d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::conv3d(
+                    x.primitive,
+                    weight.primitive,
+                    Some(bias.primitive),
+                    options,
+                )),
+            },
+            None => match Conv3DNoBias
+                .prepare::<C>(
+                    [x.node.clone(), weight.node.clone()],
+                    [x.graph.clone(), weight.graph.clone()],
+                )
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let weight_state = prep.checkpoint(&weight);
+                    prep.finish(
+                        (x_state, weight_state, options.clone()),
+                        B::conv3d(x.primitive, weight.primitive, None, options),
+                    )
+                }
+
+                OpsKind::UnTracked(prep) => {
+                    prep.finish(B::conv3d(x.primitive, weight.primitive, None, options))
+                }
+            },
+        }
+    }
+
+    fn conv_transpose3d(
+        x: AutodiffTensor<B, 5>,
+        weight: AutodiffTensor<B, 5>,
+        bias: Option<AutodiffTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct ConvTranspose3DWithBias;
+        #[derive(Debug)]
+        struct ConvTranspose3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for ConvTranspose3DWithBias {
+            type State = (NodeID, NodeID, NodeID, ConvTransposeOptions<3>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x_state, weight_state, bias_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let weight = checkpointer.retrieve_node_output(weight_state);
+                let bias = Some(checkpointer.retrieve_node_output(bias_state));
+
+                let backward = B::conv_transpose3d_backward(x, weight, bias, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for ConvTranspose3DNoBias {
+            type State = (NodeID, NodeID, ConvTransposeOptions<3>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x_state, weight_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let weight = checkpointer.retrieve_node_output(weight_state);
+
+                let backward = B::conv_transpose3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => match ConvTranspose3DWithBias
+                .prepare::<C>(
+                    [x.node.clone(), weight.node.clone(), bias.node.clone()],
+                    [x.graph.clone(), weight.graph.clone(), bias.graph.clone()],
+                )
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let weight_state = prep.checkpoint(&weight);
+                    let bias_state = prep.checkpoint(&bias);
+
+                    prep.finish(
+                        (x_state, weight_state, bias_state, options.clone()),
+                        B::conv_transpose3d(
+                            x.primitive,
+                            weight.primitive,
+                            Some(bias.primitive),
+                            options,
+                        ),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                    x.primitive,
+                    weight.primitive,
+                    Some(bias.primitive),
+                    options,
+                )),
+            },
+            None => match ConvTranspose3DNoBias
+                .prepare::<C>(
+                    [x.node.clone(), weight.node.clone()],
+                    [x.graph.clone(), weight.graph.clone()],
+                )
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let weight_state = prep.checkpoint(&weight);
+
+                    prep.finish(
+                        (x_state, weight_state, options.clone()),
+                        B::conv_transpose3d(x.primitive, weight.primitive, None, options),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                    x.primitive,
+                    weight.primitive,
+                    None,
+                    options,
+                )),
+            },
+        }
+    }
+
     fn conv1d(
         x: AutodiffTensor<B, 3>,
         weight: AutodiffTensor<B, 3>,
@@ -698,6 +961,83 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate avg pool 2d backward.");
     }
 
+    fn avg_pool3d(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AvgPool3D {
+            type State = (NodeID, [usize; 3], [usize; 3], [usize; 3], bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    );
+                    grads.register::<B, 5>(node, grad);
+                }
+            }
+        }
+
+        match AvgPool3D
+            .prepare::<C>([x.node.clone()], [x.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(
+                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    B::avg_pool3d(
+                        x.primitive.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        _x: AutodiffTensor<B, 5>,
+        _grad: AutodiffTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+    ) -> AutodiffTensor<B, 5> {
+        panic!("Can't differentiate avg pool 3d backward.");
+    }
+
     fn max_pool1d(
         x: AutodiffTensor<B, 3>,
         kernel_size: usize,
@@ -889,6 +1229,98 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> MaxPool2dBackward<Self> {
         panic!("Can't differentiate max pool2d with indices backward.");
     }
+
+    fn max_pool3d(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> AutodiffTensor<B, 5> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()], [x.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                prep.finish(
+                    (
+                        x_state,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()], [x.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+                let output_tensor = prep.finish(
+                    (
+                        x_state,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _output_grad: AutodiffTensor<B, 5>,
+        _indices: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
     fn adaptive_avg_pool1d(x: AutodiffTensor<B, 3>, output_size: usize) -> AutodiffTensor<B, 3> {
         #[derive(Debug)]
         struct AdaptiveAvgPool1D;
@@ -977,12 +1409,275 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
 
+    fn adaptive_avg_pool3d(
+        x: AutodiffTensor<B, 5>,
+        output_size: [usize; 3],
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AdaptiveAvgPool3D {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let state = checkpointer.retrieve_node_output(ops.state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool3d_backward(state, grad);
+                    grads.register::<B, 5>(node, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool3D
+            .prepare::<C>([x.node.clone()], [x.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(x_state, B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        _x: AutodiffTensor<B, 5>,
+        _grad: AutodiffTensor<B, 5>,
+    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<5> {
+        panic!("Can't differentiate adaptive avg pool3d backward.");
+    }
+
     fn interpolate(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct Interpolate;
+
+        impl<B: Backend> Backward<B, 4, 1> for Interpolate {
+            type State = (NodeID, [usize; 2], InterpolateOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+                let (x_state, output_size, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate_backward(x, grad, output_size, options);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match Interpolate
+            .prepare::<C>([x.node.clone()], [x.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(
+                    (x_state, output_size, options.clone()),
+                    B::interpolate(x.primitive, output_size, options),
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate(x.primitive, output_size, options))
+            }
+        }
+    }
+
+    fn interpolate_backward(
         _x: AutodiffTensor<B, 4>,
+        _grad: AutodiffTensor<B, 4>,
         _output_size: [usize; 2],
         _options: InterpolateOptions,
     ) -> AutodiffTensor<B, 4> {
-        unimplemented!()
+        panic!("Can't differentiate interpolate backward.");
+    }
+
+    fn grid_sample_2d(
+        x: AutodiffTensor<B, 4>,
+        grid: AutodiffTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct GridSample2D;
+
+        impl<B: Backend> Backward<B, 4, 2> for GridSample2D {
+            type State = (NodeID, NodeID, GridSampleOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_grid] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (x_state, grid_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let grid = checkpointer.retrieve_node_output(grid_state);
+
+                let backward = B::grid_sample_2d_backward(x, grid, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 4>(node, backward.x_grad)
+                }
+                if let Some(node) = node_grid {
+                    grads.register::<B, 4>(node, backward.grid_grad)
+                }
+            }
+        }
+
+        match GridSample2D
+            .prepare::<C>(
+                [x.node.clone(), grid.node.clone()],
+                [x.graph.clone(), grid.graph.clone()],
+            )
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let grid_state = prep.checkpoint(&grid);
+                prep.finish(
+                    (x_state, grid_state, options.clone()),
+                    B::grid_sample_2d(x.primitive, grid.primitive, options),
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grid_sample_2d(x.primitive, grid.primitive, options))
+            }
+        }
+    }
+
+    fn grid_sample_2d_backward(
+        _x: AutodiffTensor<B, 4>,
+        _grid: AutodiffTensor<B, 4>,
+        _grad: AutodiffTensor<B, 4>,
+        _options: GridSampleOptions,
+    ) -> GridSampleBackward<Self> {
+        panic!("Can't differentiate grid sample 2d backward.");
+    }
+
+    fn scaled_dot_product_attention(
+        query: AutodiffTensor<B, 4>,
+        key: AutodiffTensor<B, 4>,
+        value: AutodiffTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        options: AttentionOptions,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct ScaledDotProductAttention;
+
+        impl<B: Backend> Backward<B, 4, 3> for ScaledDotProductAttention {
+            type State = (
+                NodeID,
+                NodeID,
+                NodeID,
+                Option<BoolTensor<B, 4>>,
+                AttentionOptions,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query_state, key_state, value_state, mask, options) = ops.state;
+                let query = checkpointer.retrieve_node_output(query_state);
+                let key = checkpointer.retrieve_node_output(key_state);
+                let value = checkpointer.retrieve_node_output(value_state);
+
+                // The attention weights are computed again instead of being stored.
+                let backward = B::scaled_dot_product_attention_backward(
+                    query, key, value, mask, grad, options,
+                );
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        match ScaledDotProductAttention
+            .prepare::<C>(
+                [query.node.clone(), key.node.clone(), value.node.clone()],
+                [query.graph.clone(), key.graph.clone(), value.graph.clone()],
+            )
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let query_state = prep.checkpoint(&query);
+                let key_state = prep.checkpoint(&key);
+                let value_state = prep.checkpoint(&value);
+                prep.finish(
+                    (
+                        query_state,
+                        key_state,
+                        value_state,
+                        mask.clone(),
+                        options.clone(),
+                    ),
+                    B::scaled_dot_product_attention(
+                        query.primitive,
+                        key.primitive,
+                        value.primitive,
+                        mask,
+                        options,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::scaled_dot_product_attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                mask,
+                options,
+            )),
+        }
+    }
+
+    fn scaled_dot_product_attention_backward(
+        _query: AutodiffTensor<B, 4>,
+        _key: AutodiffTensor<B, 4>,
+        _value: AutodiffTensor<B, 4>,
+        _mask: Option<BoolTensor<B, 4>>,
+        _grad: AutodiffTensor<B, 4>,
+        _options: AttentionOptions,
+    ) -> AttentionBackward<Self> {
+        panic!("Can't differentiate scaled dot-product attention backward.");
     }
 }
 
@@ -1058,3 +1753,43 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 5, 1> for MaxPool3D {
+    type State = (
+        NodeID,
+        IntTensor<B, 5>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 5>(&ops.node);
+        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices,
+            );
+
+            grads.register::<B, 5>(node, grad.x_grad);
+        }
+    }
+}
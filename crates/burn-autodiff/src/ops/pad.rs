@@ -0,0 +1,49 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, pad_indices, Data, PadMode, Shape};
+
+#[derive(Debug)]
+pub(crate) struct Pad;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Pad {
+    type State = ([(usize, usize); D], PadMode, Shape<D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (pads, mode, shape) = ops.state;
+            let device = B::float_device(&grad);
+            let mut grad = grad;
+
+            // The dimensions are padded in order, so the padding is removed in reverse order.
+            for dim in (0..D).rev() {
+                let (before, after) = pads[dim];
+                if before == 0 && after == 0 {
+                    continue;
+                }
+                let size = shape.dims[dim];
+
+                grad = match mode {
+                    PadMode::Constant(_) => B::float_narrow(grad, dim, before, size),
+                    _ => {
+                        let indices = pad_indices(size, before, after, mode);
+                        let indices = Data::new(indices, Shape::new([size + before + after]));
+                        let indices = B::int_from_data(indices.convert(), &device);
+                        let mut shape_input = B::float_shape(&grad);
+                        shape_input.dims[dim] = size;
+
+                        // Elements copied more than once accumulate the gradients of their copies.
+                        let zeros = B::float_zeros(shape_input, &device);
+                        B::float_select_assign(zeros, dim, indices, grad)
+                    }
+                };
+            }
+
+            grad
+        });
+    }
+}
@@ -17,10 +17,15 @@ use crate::{
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
-    Data, Device, ElementConversion, Reader, Shape, Tensor,
+    trigamma, Data, Device, ElementConversion, PadMode, Reader, Shape, Tensor,
 };
 
+use super::cumulative::{first_zero_masks, reverse_cumsum, CumMaxMin};
+use super::fft::{ComplexPart, Fft, Irfft, Rfft};
+use super::linalg::{Cholesky, Eigh, Factor, LogAbsDet, Qr, Solve, Svd};
 use super::maxmin::MaxMinDim;
+use super::pad::Pad;
+use super::sort::SortDim;
 
 impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C> {
     fn float_from_data<const D: usize>(
@@ -949,7 +954,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>);
 
             fn backward(
                 self,
@@ -957,21 +962,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 grads: &mut Gradients,
                 _checkpointer: &mut Checkpointer,
             ) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
+                // The values are summed into the selected elements, so their gradient is the
+                // gradient of the selected elements, even when indices are repeated.
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::float_zeros(shape_lhs, &device);
-                        B::float_select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::float_zeros(shape_rhs, &device);
-                        B::float_select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::float_select(grad, dim, indices),
                 );
             }
         }
@@ -992,13 +992,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
             .stateful()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::float_shape(&tensor.primitive),
-                    B::float_shape(&value.primitive),
-                    B::float_device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::float_select_assign(tensor.primitive, dim, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::float_select_assign(
@@ -2031,235 +2025,400 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
-    fn float_cat<const D: usize>(
-        tensors: Vec<FloatTensor<Self, D>>,
-        dim: usize,
-    ) -> FloatTensor<Self, D> {
-        #[derive(new, Debug)]
-        struct CatStep<B: Backend, const D: usize> {
-            nodes: Vec<Option<NodeRef>>,
-            // The dimension of each tensor along the dim dimension.
-            // This indicates the number of dimension concatenated for each tensor.
-            dim_sizes: Vec<usize>,
-            output: NodeRef,
-            phantom: PhantomData<B>,
-            dim: usize,
-        }
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Floor;
 
-        impl<B: Backend, const D: usize> Step for CatStep<B, D> {
-            fn step(self: Box<Self>, grads: &mut Gradients, _checkpointer: &mut Checkpointer) {
-                let grad = grads.consume::<B, D>(&self.output);
-                let ranges: Vec<_> = B::float_shape(&grad).dims.iter().map(|v| 0..*v).collect();
-                let ranges: [std::ops::Range<usize>; D] = ranges.try_into().unwrap();
+        retro_unary!(RetroFloor, B::float_floor);
 
-                let mut current_index = 0;
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Floor {
+            type State = (Shape<D>, B::Device);
 
-                self.nodes
-                    .into_iter()
-                    .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
-                    .for_each(|(node, dim_size)| {
-                        let mut ranges = ranges.clone();
-                        ranges[self.dim] = current_index..dim_size + current_index;
-                        current_index += dim_size;
-                        grads.register::<B, D>(node, B::float_slice(grad.clone(), ranges));
-                    });
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                // The output is piecewise constant, so its gradient is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                });
             }
+        }
 
-            fn node(&self) -> NodeRef {
-                self.output.clone()
+        match Floor
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroFloor::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let device = B::float_device(&tensor.primitive);
+                prep.finish((shape, device), B::float_floor(tensor.primitive))
             }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_floor(tensor.primitive)),
         }
+    }
 
-        let mut nodes = Vec::with_capacity(tensors.len());
-        let mut graphs = Vec::with_capacity(tensors.len());
-        let mut primitives = Vec::with_capacity(tensors.len());
-        let mut dim_sizes = Vec::with_capacity(tensors.len());
-
-        tensors.into_iter().for_each(|tensor| {
-            dim_sizes.push(B::float_shape(&tensor.primitive).dims[dim]);
-            nodes.push(tensor.node);
-            primitives.push(tensor.primitive);
-            graphs.push(tensor.graph);
-        });
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Ceil;
 
-        let requirement = Requirement::from_nodes(&nodes);
+        retro_unary!(RetroCeil, B::float_ceil);
 
-        // For simplicity, this operation does not checkpoint anything
-        let cat_computing_property = ComputingProperty::Ambiguous;
-        let checkpointer_builder = CheckpointerBuilder::default();
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Ceil {
+            type State = (Shape<D>, B::Device);
 
-        let output = B::float_cat(primitives, dim);
-        if requirement.is_none() {
-            return AutodiffTensor::from_parents(
-                output,
-                &nodes,
-                graphs.into_iter(),
-                requirement,
-                cat_computing_property,
-                checkpointer_builder,
-            );
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                // The output is piecewise constant, so its gradient is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                });
+            }
         }
 
-        let output = AutodiffTensor::from_parents(
-            output,
-            &nodes,
-            graphs.into_iter(),
-            requirement,
-            cat_computing_property,
-            checkpointer_builder,
-        );
-        let nodes = nodes
-            .into_iter()
-            .map(|node| node.clone_if_require_grad())
-            .collect::<Vec<_>>();
-
-        let ops = CatStep::<B, D>::new(nodes, dim_sizes, output.node.clone(), dim);
-        output.register_step(ops)
-    }
-
-    fn float_max_dim<const D: usize>(
-        tensor: FloatTensor<Self, D>,
-        dim: usize,
-    ) -> FloatTensor<Self, D> {
-        match MaxMinDim
-            .prepare::<C>([tensor.node], [tensor.graph])
-            .compute_bound()
+        match Ceil
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroCeil::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
             .stateful()
         {
             OpsKind::Tracked(prep) => {
                 let shape = B::float_shape(&tensor.primitive);
-                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                let device = B::float_device(&tensor.primitive);
+                prep.finish((shape, device), B::float_ceil(tensor.primitive))
             }
-            OpsKind::UnTracked(prep) => prep.finish(B::float_max_dim(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_ceil(tensor.primitive)),
         }
     }
-    fn float_max_dim_with_indices<const D: usize>(
-        tensor: FloatTensor<Self, D>,
-        dim: usize,
-    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
-        match MaxMinDim
-            .prepare::<C>([tensor.node], [tensor.graph])
-            .compute_bound()
+
+    fn float_round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Round;
+
+        retro_unary!(RetroRound, B::float_round);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Round {
+            type State = (Shape<D>, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                // The output is piecewise constant, so its gradient is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                });
+            }
+        }
+
+        match Round
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroRound::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
             .stateful()
         {
             OpsKind::Tracked(prep) => {
                 let shape = B::float_shape(&tensor.primitive);
-                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
-
-                (tensor, index)
+                let device = B::float_device(&tensor.primitive);
+                prep.finish((shape, device), B::float_round(tensor.primitive))
             }
-            OpsKind::UnTracked(prep) => {
-                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish(tensor);
+            OpsKind::UnTracked(prep) => prep.finish(B::float_round(tensor.primitive)),
+        }
+    }
 
-                (tensor, index)
+    fn float_trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Trunc;
+
+        retro_unary!(RetroTrunc, B::float_trunc);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Trunc {
+            type State = (Shape<D>, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                // The output is piecewise constant, so its gradient is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                });
             }
         }
-    }
-    fn float_min_dim<const D: usize>(
-        tensor: FloatTensor<Self, D>,
-        dim: usize,
-    ) -> FloatTensor<Self, D> {
-        match MaxMinDim
-            .prepare::<C>([tensor.node], [tensor.graph])
-            .compute_bound()
+
+        match Trunc
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroTrunc::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
             .stateful()
         {
             OpsKind::Tracked(prep) => {
                 let shape = B::float_shape(&tensor.primitive);
-                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                let device = B::float_device(&tensor.primitive);
+                prep.finish((shape, device), B::float_trunc(tensor.primitive))
             }
-            OpsKind::UnTracked(prep) => prep.finish(B::float_min_dim(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_trunc(tensor.primitive)),
         }
     }
-    fn float_min_dim_with_indices<const D: usize>(
-        tensor: FloatTensor<Self, D>,
-        dim: usize,
-    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
-        match MaxMinDim
-            .prepare::<C>([tensor.node], [tensor.graph])
-            .compute_bound()
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Sign;
+
+        retro_unary!(RetroSign, B::float_sign);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sign {
+            type State = (Shape<D>, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                // The output is piecewise constant, so its gradient is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                });
+            }
+        }
+
+        match Sign
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroSign::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
             .stateful()
         {
             OpsKind::Tracked(prep) => {
                 let shape = B::float_shape(&tensor.primitive);
-                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
-
-                (tensor, index)
-            }
-            OpsKind::UnTracked(prep) => {
-                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish(tensor);
-
-                (tensor, index)
+                let device = B::float_device(&tensor.primitive);
+                prep.finish((shape, device), B::float_sign(tensor.primitive))
             }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_sign(tensor.primitive)),
         }
     }
 
-    fn float_into_int<const D: usize>(
-        tensor: FloatTensor<Self, D>,
-    ) -> <Autodiff<B> as Backend>::IntTensorPrimitive<D> {
-        B::float_into_int(tensor.primitive)
-    }
-
-    fn float_powf<const D: usize>(
-        lhs: FloatTensor<Self, D>,
-        rhs: FloatTensor<Self, D>,
-    ) -> FloatTensor<Self, D> {
+    fn float_tan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         #[derive(Debug)]
-        struct PowF;
+        struct Tan;
 
-        retro_binary!(RetroPowf, B::float_powf);
+        retro_unary!(RetroTan, B::float_tan);
 
-        impl<B: Backend, const D: usize> Backward<B, D, 2> for PowF {
-            type State = (NodeID, NodeID, BinaryOpsBroadcast<D>);
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Tan {
+            type State = NodeID;
 
             fn backward(
                 self,
-                ops: Ops<Self::State, 2>,
+                ops: Ops<Self::State, 1>,
                 grads: &mut Gradients,
                 checkpointer: &mut Checkpointer,
             ) {
-                let (lhs_id, rhs_id, broadcast) = ops.state;
-                let lhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(lhs_id);
-                let rhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(rhs_id);
-
-                // Both lhs and rhs are needed for both lhs and rhs gradients, but we clone them
-                // the number of times required by the parents specification.
-                let [rhs_4lhs, rhs_4rhs] = duplicate(&ops.parents, Some(rhs));
-                let [lhs_4lhs, lhs_4rhs] = duplicate(&ops.parents, Some(lhs));
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::float_tan(state);
+                    let value = B::float_add_scalar(B::float_mul(value.clone(), value), 1.elem());
+                    B::float_mul(grad, value)
+                });
+            }
+        }
 
-                binary::<B, D, D, D, _, _>(
+        match Tan
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroTan::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_tan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_tan(tensor.primitive)),
+        }
+    }
+
+    fn float_asin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Asin;
+
+        retro_unary!(RetroAsin, B::float_asin);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Asin {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let squared = B::float_mul(state.clone(), state);
+                    let value = B::float_sqrt(B::float_add_scalar(B::float_neg(squared), 1.elem()));
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Asin
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroAsin::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_asin(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_asin(tensor.primitive)),
+        }
+    }
+
+    fn float_acos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Acos;
+
+        retro_unary!(RetroAcos, B::float_acos);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Acos {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let squared = B::float_mul(state.clone(), state);
+                    let value = B::float_sqrt(B::float_add_scalar(B::float_neg(squared), 1.elem()));
+                    B::float_neg(B::float_div(grad, value))
+                });
+            }
+        }
+
+        match Acos
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroAcos::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_acos(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_acos(tensor.primitive)),
+        }
+    }
+
+    fn float_atan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Atan;
+
+        retro_unary!(RetroAtan, B::float_atan);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Atan {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let squared = B::float_mul(state.clone(), state);
+                    B::float_div(grad, B::float_add_scalar(squared, 1.elem()))
+                });
+            }
+        }
+
+        match Atan
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_atan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan(tensor.primitive)),
+        }
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        retro_binary!(RetroAtan2, B::float_atan2);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Atan2 {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast<D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs_id, rhs_id, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(lhs_id);
+                let rhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(rhs_id);
+
+                // d/dy atan2(y, x) = x / (x² + y²) and d/dx atan2(y, x) = -y / (x² + y²)
+                let norm = B::float_add(
+                    B::float_mul(lhs.clone(), lhs.clone()),
+                    B::float_mul(rhs.clone(), rhs.clone()),
+                );
+                let [norm_4lhs, norm_4rhs] = duplicate(&ops.parents, Some(norm));
+
+                binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
                     |grad| {
-                        //rhs*(lhs.val**(rhs-1))*grad
-                        let rhs1 = rhs_4lhs.unwrap();
-                        let rhs2 = rhs1.clone();
-                        let lhs = lhs_4lhs.unwrap();
-
-                        let tmp = B::float_powf(
-                            lhs,
-                            B::float_sub_scalar(rhs1, B::FloatElem::from_elem(1.0)),
-                        );
-                        let value = B::float_mul(tmp, rhs2);
+                        let value = B::float_div(rhs, norm_4lhs.unwrap());
                         let grad = B::float_mul(grad, value);
 
                         broadcast.backward_lhs::<B>(grad)
                     },
                     |grad| {
-                        //lhs**rhs * ln(lhs) * grad
-                        let rhs = rhs_4rhs.unwrap();
-                        let lhs1 = lhs_4rhs.unwrap();
-                        let lhs2 = lhs1.clone();
-                        let tmp = B::float_powf(lhs1, rhs);
-                        let value = B::float_mul(tmp, B::float_log(lhs2));
+                        let value = B::float_div(B::float_neg(lhs), norm_4rhs.unwrap());
                         let grad = B::float_mul(grad, value);
 
                         broadcast.backward_rhs::<B>(grad)
@@ -2270,13 +2429,13 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
 
         let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
 
-        match PowF
+        match Atan2
             .prepare::<C>(
                 [lhs.node.clone(), rhs.node.clone()],
                 [lhs.graph.clone(), rhs.graph.clone()],
             )
             .memory_bound()
-            .retro_forward(RetroPowf::<B, D>::new(
+            .retro_forward(RetroAtan2::<B, D>::new(
                 lhs.node.id.clone(),
                 rhs.node.id.clone(),
             ))
@@ -2288,12 +2447,986 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 let rhs_state = prep.checkpoint(&rhs);
                 prep.finish(
                     (lhs_state, rhs_state, broadcast),
-                    B::float_powf(lhs.primitive, rhs.primitive),
+                    B::float_atan2(lhs.primitive, rhs.primitive),
                 )
             }
-            OpsKind::UnTracked(prep) => prep.finish(B::float_powf(lhs.primitive, rhs.primitive)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_sinh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Sinh;
+
+        retro_unary!(RetroSinh, B::float_sinh);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sinh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_cosh(state))
+                });
+            }
+        }
+
+        match Sinh
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroSinh::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_sinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_sinh(tensor.primitive)),
+        }
+    }
+
+    fn float_cosh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Cosh;
+
+        retro_unary!(RetroCosh, B::float_cosh);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cosh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_sinh(state))
+                });
+            }
+        }
+
+        match Cosh
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroCosh::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_cosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cosh(tensor.primitive)),
         }
     }
+
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        retro_unary!(RetroLgamma, B::float_lgamma);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Lgamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_digamma(state))
+                });
+            }
+        }
+
+        match Lgamma
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroLgamma::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lgamma(tensor.primitive)),
+        }
+    }
+
+    fn float_digamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Digamma;
+
+        retro_unary!(RetroDigamma, B::float_digamma);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Digamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, trigamma::<B, D>(state))
+                });
+            }
+        }
+
+        match Digamma
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroDigamma::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_digamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_digamma(tensor.primitive)),
+        }
+    }
+
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Erfinv;
+
+        retro_unary!(RetroErfinv, B::float_erfinv);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Erfinv {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::float_erfinv(state);
+                    let value = B::float_exp(B::float_mul(value.clone(), value));
+                    let value =
+                        B::float_mul_scalar(value, (core::f64::consts::PI.sqrt() / 2.0).elem());
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfinv::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
+    fn float_expm1<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Expm1;
+
+        retro_unary!(RetroExpm1, B::float_expm1);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Expm1 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let state = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_exp(state))
+                });
+            }
+        }
+
+        match Expm1
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .memory_bound()
+            .retro_forward(RetroExpm1::<B, D>::new(tensor.node.id.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_expm1(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_expm1(tensor.primitive)),
+        }
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(new, Debug)]
+        struct CatStep<B: Backend, const D: usize> {
+            nodes: Vec<Option<NodeRef>>,
+            // The dimension of each tensor along the dim dimension.
+            // This indicates the number of dimension concatenated for each tensor.
+            dim_sizes: Vec<usize>,
+            output: NodeRef,
+            phantom: PhantomData<B>,
+            dim: usize,
+        }
+
+        impl<B: Backend, const D: usize> Step for CatStep<B, D> {
+            fn step(self: Box<Self>, grads: &mut Gradients, _checkpointer: &mut Checkpointer) {
+                let grad = grads.consume::<B, D>(&self.output);
+                let ranges: Vec<_> = B::float_shape(&grad).dims.iter().map(|v| 0..*v).collect();
+                let ranges: [std::ops::Range<usize>; D] = ranges.try_into().unwrap();
+
+                let mut current_index = 0;
+
+                self.nodes
+                    .into_iter()
+                    .zip(self.dim_sizes)
+                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
+                    .for_each(|(node, dim_size)| {
+                        let mut ranges = ranges.clone();
+                        ranges[self.dim] = current_index..dim_size + current_index;
+                        current_index += dim_size;
+                        grads.register::<B, D>(node, B::float_slice(grad.clone(), ranges));
+                    });
+            }
+
+            fn node(&self) -> NodeRef {
+                self.output.clone()
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(tensors.len());
+        let mut graphs = Vec::with_capacity(tensors.len());
+        let mut primitives = Vec::with_capacity(tensors.len());
+        let mut dim_sizes = Vec::with_capacity(tensors.len());
+
+        tensors.into_iter().for_each(|tensor| {
+            dim_sizes.push(B::float_shape(&tensor.primitive).dims[dim]);
+            nodes.push(tensor.node);
+            primitives.push(tensor.primitive);
+            graphs.push(tensor.graph);
+        });
+
+        let requirement = Requirement::from_nodes(&nodes);
+
+        // For simplicity, this operation does not checkpoint anything
+        let cat_computing_property = ComputingProperty::Ambiguous;
+        let checkpointer_builder = CheckpointerBuilder::default();
+
+        let output = B::float_cat(primitives, dim);
+        if requirement.is_none() {
+            return AutodiffTensor::from_parents(
+                output,
+                &nodes,
+                graphs.into_iter(),
+                requirement,
+                cat_computing_property,
+                checkpointer_builder,
+            );
+        }
+
+        let output = AutodiffTensor::from_parents(
+            output,
+            &nodes,
+            graphs.into_iter(),
+            requirement,
+            cat_computing_property,
+            checkpointer_builder,
+        );
+        let nodes = nodes
+            .into_iter()
+            .map(|node| node.clone_if_require_grad())
+            .collect::<Vec<_>>();
+
+        let ops = CatStep::<B, D>::new(nodes, dim_sizes, output.node.clone(), dim);
+        output.register_step(ops)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match MaxMinDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
+                prep.finish((index, shape), tensor)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_max_dim(tensor.primitive, dim)),
+        }
+    }
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match MaxMinDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish((index.clone(), shape), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::float_max_dim_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match MaxMinDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
+                prep.finish((index, shape), tensor)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_min_dim(tensor.primitive, dim)),
+        }
+    }
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match MaxMinDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish((index.clone(), shape), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::float_min_dim_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
+
+    fn float_into_int<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> <Autodiff<B> as Backend>::IntTensorPrimitive<D> {
+        B::float_into_int(tensor.primitive)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct PowF;
+
+        retro_binary!(RetroPowf, B::float_powf);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for PowF {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast<D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs_id, rhs_id, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(lhs_id);
+                let rhs: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(rhs_id);
+
+                // Both lhs and rhs are needed for both lhs and rhs gradients, but we clone them
+                // the number of times required by the parents specification.
+                let [rhs_4lhs, rhs_4rhs] = duplicate(&ops.parents, Some(rhs));
+                let [lhs_4lhs, lhs_4rhs] = duplicate(&ops.parents, Some(lhs));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        //rhs*(lhs.val**(rhs-1))*grad
+                        let rhs1 = rhs_4lhs.unwrap();
+                        let rhs2 = rhs1.clone();
+                        let lhs = lhs_4lhs.unwrap();
+
+                        let tmp = B::float_powf(
+                            lhs,
+                            B::float_sub_scalar(rhs1, B::FloatElem::from_elem(1.0)),
+                        );
+                        let value = B::float_mul(tmp, rhs2);
+                        let grad = B::float_mul(grad, value);
+
+                        broadcast.backward_lhs::<B>(grad)
+                    },
+                    |grad| {
+                        //lhs**rhs * ln(lhs) * grad
+                        let rhs = rhs_4rhs.unwrap();
+                        let lhs1 = lhs_4rhs.unwrap();
+                        let lhs2 = lhs1.clone();
+                        let tmp = B::float_powf(lhs1, rhs);
+                        let value = B::float_mul(tmp, B::float_log(lhs2));
+                        let grad = B::float_mul(grad, value);
+
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match PowF
+            .prepare::<C>(
+                [lhs.node.clone(), rhs.node.clone()],
+                [lhs.graph.clone(), rhs.graph.clone()],
+            )
+            .memory_bound()
+            .retro_forward(RetroPowf::<B, D>::new(
+                lhs.node.id.clone(),
+                rhs.node.id.clone(),
+            ))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_powf(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_powf(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        match SortDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+                prep.finish((indices, shape, dim), tensor)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_sort(tensor.primitive, dim, descending))
+            }
+        }
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match SortDim
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish((indices.clone(), shape, dim), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::float_argsort(tensor.primitive, dim, descending)
+    }
+
+    fn float_searchsorted<const D: usize>(
+        sorted: FloatTensor<Self, 1>,
+        values: FloatTensor<Self, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        B::float_searchsorted(sorted.primitive, values.primitive, right)
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self, 1>,
+        bins: usize,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> FloatTensor<Self, 1> {
+        // The counts are piecewise constant, so they don't have a gradient.
+        AutodiffTensor::new(B::float_histc(tensor.primitive, bins, min, max))
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match CumSum
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::float_cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumProd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+            type State = (
+                B::FloatTensorPrimitive<D>,
+                B::FloatTensorPrimitive<D>,
+                usize,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let (before, first) = first_zero_masks::<B, D>(input.clone(), dim);
+                    let after = B::bool_not(before.clone());
+
+                    // Before the first zero, the input can be divided out of the products.
+                    let divisor = B::float_mask_fill(input.clone(), after.clone(), 1.elem());
+                    let grad_before = B::float_div(
+                        reverse_cumsum::<B, D>(B::float_mul(grad.clone(), output), dim),
+                        divisor,
+                    );
+                    let grad_before = B::float_mask_fill(grad_before, after, 0.elem());
+
+                    // The products from the first zero on only depend on it, so its gradient
+                    // comes from the products where it is replaced by one, and the elements
+                    // after it don't have any.
+                    let output = B::float_cumprod(B::float_add(input, first.clone()), dim);
+                    let products = B::float_mask_fill(B::float_mul(grad, output), before, 0.elem());
+                    let grad_first = B::float_mul(first, B::float_sum_dim(products, dim));
+
+                    B::float_add(grad_before, grad_first)
+                });
+            }
+        }
+
+        match CumProd
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cumprod(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) = B::float_cummax_with_indices(tensor.primitive, dim);
+                prep.finish((indices, shape, dim), tensor)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cummax(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummax_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match CumMaxMin
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) = B::float_cummax_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish((indices.clone(), shape, dim), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::float_cummax_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) = B::float_cummin_with_indices(tensor.primitive, dim);
+                prep.finish((indices, shape, dim), tensor)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cummin(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummin_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        match CumMaxMin
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let (tensor, indices) = B::float_cummin_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish((indices.clone(), shape, dim), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::float_cummin_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> FloatTensor<Self, D> {
+        match Pad
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                prep.finish(
+                    (pads, mode, shape),
+                    B::float_pad(tensor.primitive, pads, mode),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_pad(tensor.primitive, pads, mode)),
+        }
+    }
+
+    fn float_fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (output_real, output_imag) =
+            B::float_fft(real.primitive.clone(), imag.primitive.clone(), dim);
+
+        let register = |part, output| match Fft::new(part, false)
+            .prepare::<C>(
+                [real.node.clone(), imag.node.clone()],
+                [real.graph.clone(), imag.graph.clone()],
+            )
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(dim, output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            register(ComplexPart::Real, output_real),
+            register(ComplexPart::Imag, output_imag),
+        )
+    }
+
+    fn float_ifft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (output_real, output_imag) =
+            B::float_ifft(real.primitive.clone(), imag.primitive.clone(), dim);
+
+        let register = |part, output| match Fft::new(part, true)
+            .prepare::<C>(
+                [real.node.clone(), imag.node.clone()],
+                [real.graph.clone(), imag.graph.clone()],
+            )
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(dim, output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            register(ComplexPart::Real, output_real),
+            register(ComplexPart::Imag, output_imag),
+        )
+    }
+
+    fn float_rfft<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let n = B::float_shape(&tensor.primitive).dims[dim];
+        let (output_real, output_imag) = B::float_rfft(tensor.primitive.clone(), dim);
+
+        let register = |part, output| match Rfft::new(part)
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((dim, n), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            register(ComplexPart::Real, output_real),
+            register(ComplexPart::Imag, output_imag),
+        )
+    }
+
+    fn float_irfft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        n: usize,
+    ) -> FloatTensor<Self, D> {
+        match Irfft
+            .prepare::<C>([real.node, imag.node], [real.graph, imag.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish(dim, B::float_irfft(real.primitive, imag.primitive, dim, n))
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_irfft(real.primitive, imag.primitive, dim, n))
+            }
+        }
+    }
+
+    fn float_solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        match Solve
+            .prepare::<C>([a.node, b.node], [a.graph, b.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_solve(a.primitive.clone(), b.primitive);
+                prep.finish((a.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_solve(a.primitive, b.primitive)),
+        }
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (sign, logabsdet) = B::float_slogdet(tensor.primitive.clone());
+
+        // The sign is piecewise constant, so only the logarithm of the determinant is tracked.
+        let logabsdet = match LogAbsDet
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(tensor.primitive, logabsdet),
+            OpsKind::UnTracked(prep) => prep.finish(logabsdet),
+        };
+
+        (AutodiffTensor::new(sign), logabsdet)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        match Cholesky
+            .prepare::<C>([tensor.node], [tensor.graph])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cholesky(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cholesky(tensor.primitive)),
+        }
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (q, r) = B::float_qr(tensor.primitive.clone());
+        let state = (tensor.primitive.clone(), q.clone(), r.clone());
+
+        let register = |factor, output| match Qr::new(factor)
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (register(Factor::First, q), register(Factor::Second, r))
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vh) = B::float_svd(tensor.primitive);
+        let state = (u.clone(), s.clone(), vh.clone());
+
+        let register = |factor, output| match Svd::new(factor)
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            register(Factor::First, u),
+            register(Factor::Second, s),
+            register(Factor::Third, vh),
+        )
+    }
+
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (values, vectors) = B::float_eigh(tensor.primitive);
+        let state = (values.clone(), vectors.clone());
+
+        let register = |factor, output| match Eigh::new(factor)
+            .prepare::<C>([tensor.node.clone()], [tensor.graph.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            register(Factor::First, values),
+            register(Factor::Second, vectors),
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
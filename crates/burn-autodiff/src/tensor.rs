@@ -86,13 +86,20 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
         parent_graphs: I,
         requirement: Requirement,
         computing_properties: ComputingProperty,
-        checkpointer_builder: CheckpointerBuilder,
+        mut checkpointer_builder: CheckpointerBuilder,
     ) -> Self {
-        let graph = parent_graphs
-            .reduce(|acc, graph| acc.merge(graph))
-            .unwrap_or_else(Graph::new);
-
-        graph.extend_checkpointer_builder(checkpointer_builder);
+        // The steps are never registered in the graph of a leaf, which often lives much longer
+        // than the operations using it, e.g. a parameter.
+        let graph = parent_nodes.iter().zip(parent_graphs).fold(
+            None,
+            |acc: Option<Graph>, (node, graph)| match (acc, node.order) {
+                (Some(acc), 0) => Some(acc.merge_leaf(graph)),
+                (Some(acc), _) => Some(acc.merge(graph)),
+                (None, 0) => Some(Graph::new().merge_leaf(graph)),
+                (None, _) => Some(graph),
+            },
+        );
+        let graph = graph.unwrap_or_else(Graph::new);
 
         let order = parent_nodes
             .iter()
@@ -110,6 +117,9 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
         )
         .into();
 
+        checkpointer_builder.requested_by(&node.id);
+        graph.extend_checkpointer_builder(checkpointer_builder);
+
         Self {
             primitive,
             node,
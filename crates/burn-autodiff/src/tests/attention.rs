@@ -0,0 +1,175 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation::softmax;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::{attention::AttentionDropout, AttentionOptions};
+    use burn_tensor::{Bool, Data, Shape, Tensor};
+
+    #[test]
+    fn test_attention_grads() {
+        let test = AttentionTestCase::new([2, 2, 3, 4], 5);
+
+        test.assert_grads(None, AttentionOptions::new(), None);
+    }
+
+    #[test]
+    fn test_attention_causal_mask_grads() {
+        let test = AttentionTestCase::new([1, 2, 3, 4], 4);
+        let device = Default::default();
+        let mask = Tensor::<TestAutodiffBackend, 4, Bool>::from_bool(
+            Data::from([[[
+                [false, true, false, false],
+                [false, false, false, true],
+                [true, true, true, true],
+            ]]]),
+            &device,
+        );
+        // The causal mask aligned with the last key and the mask, with a fully masked query.
+        let reference_mask = Tensor::<TestAutodiffBackend, 4, Bool>::from_bool(
+            Data::from([[[
+                [false, true, true, true],
+                [false, false, false, true],
+                [true, true, true, true],
+            ]]]),
+            &device,
+        );
+
+        test.assert_grads(
+            Some(mask),
+            AttentionOptions::new().with_causal(true).with_scale(0.7),
+            Some(reference_mask),
+        );
+    }
+
+    #[test]
+    fn test_attention_dropout_grads() {
+        let test = AttentionTestCase::new([2, 1, 3, 2], 3);
+
+        test.assert_grads(
+            None,
+            AttentionOptions::new().with_dropout(0.4).with_seed(42),
+            None,
+        );
+    }
+
+    struct AttentionTestCase {
+        query: TestAutodiffTensor<4>,
+        key: TestAutodiffTensor<4>,
+        value: TestAutodiffTensor<4>,
+        weights: TestAutodiffTensor<4>,
+    }
+
+    impl AttentionTestCase {
+        fn new(shape_query: [usize; 4], seq_length_2: usize) -> Self {
+            let [batch_size, n_heads, seq_length_1, d_k] = shape_query;
+            let shape_key = [batch_size, n_heads, seq_length_2, d_k];
+            let shape_value = [batch_size, n_heads, seq_length_2, 3];
+            let shape_output = [batch_size, n_heads, seq_length_1, 3];
+
+            Self {
+                query: tensor(shape_query, 3),
+                key: tensor(shape_key, 5),
+                value: tensor(shape_value, 7),
+                weights: tensor(shape_output, 2),
+            }
+        }
+
+        /// Asserts that the gradients are the same as the gradients of the attention composed of
+        /// tensor operations, the reference mask combining the mask and the causal mask.
+        fn assert_grads(
+            self,
+            mask: Option<Tensor<TestAutodiffBackend, 4, Bool>>,
+            options: AttentionOptions,
+            reference_mask: Option<Tensor<TestAutodiffBackend, 4, Bool>>,
+        ) {
+            let output = scaled_dot_product_attention(
+                self.query.clone(),
+                self.key.clone(),
+                self.value.clone(),
+                mask,
+                options.clone(),
+            );
+            let grads = (output * self.weights.clone()).sum().backward();
+
+            let [query, key, value] = [&self.query, &self.key, &self.value].map(|tensor| {
+                TestAutodiffTensor::from_data(tensor.to_data(), &Default::default()).require_grad()
+            });
+            let reference = reference_attention(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                reference_mask,
+                &options,
+            );
+            let grads_reference = (reference * self.weights).sum().backward();
+
+            for (tensor, reference) in [(self.query, query), (self.key, key), (self.value, value)] {
+                tensor
+                    .grad(&grads)
+                    .unwrap()
+                    .into_data()
+                    .assert_approx_eq(&reference.grad(&grads_reference).unwrap().into_data(), 3);
+            }
+        }
+    }
+
+    fn reference_attention(
+        query: TestAutodiffTensor<4>,
+        key: TestAutodiffTensor<4>,
+        value: TestAutodiffTensor<4>,
+        mask: Option<Tensor<TestAutodiffBackend, 4, Bool>>,
+        options: &AttentionOptions,
+    ) -> TestAutodiffTensor<4> {
+        let [_, _, _, d_k] = query.dims();
+        let scale = options.scale.unwrap_or(1.0 / (d_k as f64).sqrt());
+        let scores = query
+            .clone()
+            .matmul(key.clone().transpose())
+            .mul_scalar(scale);
+        let shape = scores.shape();
+
+        let weights = match mask {
+            Some(mask) => {
+                let mask = mask.repeat(0, shape.dims[0]).repeat(1, shape.dims[1]);
+                let keep = mask.clone().bool_not().float();
+                softmax(scores.mask_fill(mask, -1.0e4), 3) * keep
+            }
+            None => softmax(scores, 3),
+        };
+
+        let weights = match options.dropout > 0.0 {
+            true => {
+                let dropout = AttentionDropout::new(options.seed.unwrap(), options.dropout);
+                let [batch_size, n_heads, seq_length_1, seq_length_2] = shape.dims;
+                let factors = (0..batch_size)
+                    .flat_map(|b| (0..n_heads).map(move |h| (b, h)))
+                    .flat_map(|(b, h)| (0..seq_length_1).map(move |i| (b, h, i)))
+                    .flat_map(|(b, h, i)| (0..seq_length_2).map(move |j| [b, h, i, j]))
+                    .map(|position| match dropout.keep(position) {
+                        true => dropout.factor() as f32,
+                        false => 0.0,
+                    })
+                    .collect::<Vec<_>>();
+                weights
+                    * TestAutodiffTensor::from_data(
+                        Data::new(factors, shape).convert(),
+                        &Default::default(),
+                    )
+            }
+            false => weights,
+        };
+
+        weights.matmul(value)
+    }
+
+    fn tensor(shape: [usize; 4], step: usize) -> TestAutodiffTensor<4> {
+        let shape = Shape::new(shape);
+        let values = (0..shape.num_elements())
+            .map(|i| ((i * step) % 11) as f32 / 5.0 - 1.0)
+            .collect::<Vec<_>>();
+
+        TestAutodiffTensor::from_data(Data::new(values, shape).convert(), &Default::default())
+            .require_grad()
+    }
+}
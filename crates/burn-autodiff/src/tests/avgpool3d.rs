@@ -0,0 +1,118 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [2, 1, 2],
+            size: [3, 3, 4],
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                    [0.2500, 0.2500, 0.2500, 0.2500],
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                ],
+                [
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                    [0.2500, 0.2500, 0.2500, 0.2500],
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                ],
+                [
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                    [0.2500, 0.2500, 0.2500, 0.2500],
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [2, 1, 2],
+            size: [3, 3, 4],
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.4167, 0.4167, 0.4167, 0.4167],
+                    [0.6667, 0.6667, 0.6667, 0.6667],
+                    [0.4167, 0.4167, 0.4167, 0.4167],
+                ],
+                [
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.3333, 0.3333, 0.3333, 0.3333],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                ],
+                [
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.3333, 0.3333, 0.3333, 0.3333],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        size: [usize; 3],
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+                &device,
+            )
+            .require_grad();
+            let output = avg_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
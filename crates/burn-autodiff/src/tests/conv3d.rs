@@ -0,0 +1,170 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Data, Shape};
+
+    #[test]
+    fn test_conv3d_basic() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels: [2, 2],
+            kernel_size: [2, 2, 2],
+            padding: [1, 1, 1],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 2, 2],
+        };
+        let device = Default::default();
+        let grads = Grads {
+            x: TestTensor::from_floats(
+                [[
+                    [[[184., 184.], [184., 184.]], [[184., 184.], [184., 184.]]],
+                    [[[312., 312.], [312., 312.]], [[312., 312.], [312., 312.]]],
+                ]],
+                &device,
+            ),
+            weight: TestTensor::from_floats(
+                [
+                    [
+                        [[[28., 28.], [28., 28.]], [[28., 28.], [28., 28.]]],
+                        [[[92., 92.], [92., 92.]], [[92., 92.], [92., 92.]]],
+                    ],
+                    [
+                        [[[28., 28.], [28., 28.]], [[28., 28.], [28., 28.]]],
+                        [[[92., 92.], [92., 92.]], [[92., 92.], [92., 92.]]],
+                    ],
+                ],
+                &device,
+            ),
+            bias: TestTensor::from_floats([27., 27.], &device),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels: [2, 2],
+            kernel_size: [2, 2, 1],
+            padding: [1, 0, 0],
+            stride: [2, 1, 1],
+            dilation: [1, 1, 2],
+            groups: 2,
+            size: [3, 2, 3],
+        };
+        let device = Default::default();
+        let grads = Grads {
+            x: TestTensor::from_floats(
+                [[
+                    [
+                        [[2., 2., 2.], [3., 3., 3.]],
+                        [[0., 0., 0.], [1., 1., 1.]],
+                        [[2., 2., 2.], [3., 3., 3.]],
+                    ],
+                    [
+                        [[6., 6., 6.], [7., 7., 7.]],
+                        [[4., 4., 4.], [5., 5., 5.]],
+                        [[6., 6., 6.], [7., 7., 7.]],
+                    ],
+                ]],
+                &device,
+            ),
+            weight: TestTensor::from_floats(
+                [
+                    [[[[21.], [30.]], [[42.], [60.]]]],
+                    [[[[75.], [84.]], [[150.], [168.]]]],
+                ],
+                &device,
+            ),
+            bias: TestTensor::from_floats([6., 6.], &device),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels: [usize; 2],
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels[0],
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels[1],
+                self.channels[0] / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let device = Default::default();
+            let weight = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements() as i64, &device)
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+                &device,
+            )
+            .require_grad();
+            let bias = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..self.channels[1] as i64, &device)
+                    .into_data()
+                    .convert(),
+                &device,
+            )
+            .require_grad();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+                &device,
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(self.stride, self.padding, self.dilation, self.groups),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
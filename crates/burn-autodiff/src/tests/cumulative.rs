@@ -0,0 +1,108 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, -2.0], [3.0, 0.0, 5.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cumsum(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 5.0, 3.0], [15.0, 11.0, 6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]], &device)
+                .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d/dx0 (x0 + x0*x1 + x0*x1*x2) = 1 + x1 + x1*x2, and so on.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[9.0, 4.0, 2.0], [3.5, -5.0, -0.5]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats(
+            [
+                [2.0, 0.0, 3.0, 4.0],
+                [0.0, 2.0, 0.0, 5.0],
+                [1.0, 2.0, 3.0, 0.0],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Only the products up to the first zero and the ones that contain it once depend on
+        // each element.
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 32.0, 0.0, 0.0],
+                [3.0, 0.0, 0.0, 0.0],
+                [9.0, 4.0, 2.0, 6.0],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 3.0, 2.0, 4.0], [5.0, 1.0, 5.0, 0.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cummax(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[1.0, 5.0, 0.0, 4.0], [11.0, 0.0, 15.0, 0.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_diff_cummin_with_indices() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[2.0, 3.0, 1.0, 4.0]], &device).require_grad();
+
+        let (tensor_2, indices) = tensor_1.clone().cummin_with_indices(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(indices.into_data(), Data::from([[0, 0, 2, 2]]));
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0, 2.0, 0.0]]), 5);
+    }
+}
@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(ad_einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, Data, Tensor};
+
+    #[test]
+    fn should_diff_einsum_batched_matrix_vector() {
+        let device = Default::default();
+        let matrices = Tensor::<TestAutodiffBackend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[0.0, 1.0, 0.0], [-1.0, 2.0, 1.0]],
+            ],
+            &device,
+        )
+        .require_grad();
+        let vectors = TestAutodiffTensor::from_floats([[1.0, 0.0, 2.0], [1.0, 1.0, -1.0]], &device)
+            .require_grad();
+
+        let output = einsum::<TestAutodiffBackend, 2>(
+            "bij,bj->bi",
+            vec![matrices.clone().into(), vectors.clone().into()],
+        );
+        let grads = output.backward();
+
+        matrices.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [[1.0, 0.0, 2.0], [1.0, 0.0, 2.0]],
+                [[1.0, 1.0, -1.0], [1.0, 1.0, -1.0]],
+            ]),
+            4,
+        );
+        vectors
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[5.0, 7.0, 9.0], [-1.0, 3.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_einsum_trace() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let output = einsum::<TestAutodiffBackend, 1>("ii->", vec![tensor.clone().into()]);
+        let grads = output.backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 4);
+    }
+}
@@ -0,0 +1,110 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ComplexTensor, Data};
+
+    #[test]
+    fn should_diff_fft() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::from_floats([1.0, 2.0, 0.0, -1.0], &device).require_grad();
+        let imag = TestAutodiffTensor::from_floats([0.0, -1.0, 3.0, 1.0], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+        let weights_imag = TestAutodiffTensor::from_floats([-1.0, 0.5, 2.0, 0.0], &device);
+
+        let (output_real, output_imag) = ComplexTensor::new(real.clone(), imag.clone())
+            .fft(0)
+            .into_parts();
+        let output = output_real.mul(weights_real) + output_imag.mul(weights_imag);
+        let grads = output.backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .to_data()
+            .assert_approx_eq(&Data::from([10.0, -2.5, -2.0, -1.5]), 4);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&Data::from([1.5, -5.0, 0.5, -1.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_ifft() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::from_floats([[1.0], [2.0], [0.0]], &device).require_grad();
+        let imag = TestAutodiffTensor::from_floats([[0.0], [-1.0], [3.0]], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_floats([[1.0], [2.0], [3.0]], &device);
+        let weights_imag = TestAutodiffTensor::from_floats([[-1.0], [0.5], [2.0]], &device);
+
+        let (output_real, output_imag) = ComplexTensor::new(real.clone(), imag.clone())
+            .ifft(0)
+            .into_parts();
+        let output = output_real.mul(weights_real) + output_imag.mul(weights_imag);
+        let grads = output.backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0], [-0.93301], [-0.06699]]), 4);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5], [-0.46132], [-1.03868]]), 4);
+    }
+
+    #[test]
+    fn should_diff_rfft() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([1.0, -2.0, 0.5, 4.0, 3.0], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0], &device);
+        let weights_imag = TestAutodiffTensor::from_floats([-1.0, 0.5, 2.0], &device);
+
+        let (output_real, output_imag) = tensor.clone().rfft(0).into_parts();
+        let output = output_real.mul(weights_real) + output_imag.mul(weights_imag);
+        let grads = output.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([6.0, -2.46012, 1.91724, -1.2992, 0.84208]), 4);
+    }
+
+    #[test]
+    fn should_diff_irfft() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::from_floats([1.0, 2.0, -1.0], &device).require_grad();
+        let imag = TestAutodiffTensor::from_floats([0.5, 1.0, 2.0], &device).require_grad();
+
+        let weights = TestAutodiffTensor::from_floats([1.0, -2.0, 3.0, 0.5], &device);
+        let output = ComplexTensor::new(real.clone(), imag.clone())
+            .irfft(0, 4)
+            .mul(weights);
+        let grads = output.backward();
+
+        real.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.625, -1.0, 1.375]), 4);
+        imag.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.0, 1.25, 0.0]), 4);
+
+        let weights = TestAutodiffTensor::from_floats([1.0, -2.0, 3.0, 0.5, 2.0], &device);
+        let output = ComplexTensor::new(real.clone(), imag.clone())
+            .irfft(0, 5)
+            .mul(weights);
+        let grads = output.backward();
+
+        real.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.9, -0.73262, 0.83262]), 4);
+        imag.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.0, 0.93391, 1.89151]), 4);
+    }
+}
@@ -0,0 +1,258 @@
+#[burn_tensor_testgen::testgen(ad_forward)]
+mod tests {
+    use super::*;
+    use burn_autodiff::forward::{dual, jvp, primal, tangent};
+    use burn_tensor::module::{adaptive_avg_pool2d, avg_pool2d, conv2d, max_pool2d};
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    type TestForwardBackend = burn_autodiff::ForwardAD<TestBackend>;
+
+    #[test]
+    fn should_compute_jvp_elementwise() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [-1.0, 0.5]], &device);
+        let v = TestTensor::from_data([[0.5, -1.0], [2.0, 1.0]], &device);
+
+        let (output, tangent) = jvp(|[x]| x.clone().sin() * x, [x], [v]);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.84147, 1.81859], [0.84147, 0.23971]]), 3);
+        tangent
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.69089, -0.07700], [-2.76355, 0.91822]]), 3);
+    }
+
+    #[test]
+    fn should_compute_jvp_matmul() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let w = TestTensor::from_data([[2.0, 0.0], [1.0, 3.0]], &device);
+        let tangent_x = TestTensor::from_data([[1.0, 0.0], [0.0, 1.0]], &device);
+        let tangent_w = TestTensor::from_data([[0.0, 1.0], [1.0, 0.0]], &device);
+
+        let (output, tangent) = jvp(|[x, w]| x.matmul(w), [x, w], [tangent_x, tangent_w]);
+
+        assert_eq!(output.into_data(), Data::from([[4.0, 6.0], [10.0, 12.0]]));
+        assert_eq!(tangent.into_data(), Data::from([[4.0, 1.0], [5.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_have_zero_tangent_for_constants() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let constant = Tensor::<TestForwardBackend, 2>::ones([2, 2], &device);
+
+        let (_, tangent) = jvp(|[x]| x.detach() + constant, [x.clone()], [x]);
+
+        assert_eq!(tangent.into_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_compute_hessian_vector_product() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [-1.0, 0.5]], &device);
+        let v = TestTensor::from_data([[0.5, -1.0], [2.0, 1.0]], &device);
+
+        // Reverse mode over forward mode: the tangent of the gradient is the product of the
+        // Hessian with the tangent of the input.
+        let x = Tensor::<burn_autodiff::Autodiff<TestForwardBackend>, 2>::from_inner(dual(x, v))
+            .require_grad();
+        let grads = x.clone().powf_scalar(3.0).sum().backward();
+        let grad = x.grad(&grads).unwrap();
+
+        tangent(grad)
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, -12.0], [-12.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn should_match_finite_difference_sum_dim() {
+        assert_jvp_finite_difference(|[x]| x.sum_dim(1), [sample([3, 4], 0)], [sample([3, 4], 1)]);
+    }
+
+    #[test]
+    fn should_match_finite_difference_mean_dim() {
+        assert_jvp_finite_difference(
+            |[x]| x.mean_dim(0).exp(),
+            [sample([3, 4], 0)],
+            [sample([3, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_max_dim_with_indices() {
+        assert_jvp_finite_difference(
+            |[x]| {
+                let (values, indices) = x.max_dim_with_indices(1);
+                values + indices.float()
+            },
+            [sample([3, 4], 0)],
+            [sample([3, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_min_dim_with_indices() {
+        assert_jvp_finite_difference(
+            |[x]| {
+                let (values, indices) = x.min_dim_with_indices(0);
+                values * indices.float().add_scalar(1.0)
+            },
+            [sample([3, 4], 0)],
+            [sample([3, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_sort() {
+        assert_jvp_finite_difference(
+            |[x]| {
+                x.sort(1).mul(
+                    Tensor::arange(1..5, &Default::default())
+                        .float()
+                        .unsqueeze(),
+                )
+            },
+            [sample([3, 4], 0)],
+            [sample([3, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_sort_descending_with_indices() {
+        assert_jvp_finite_difference(
+            |[x]| {
+                let (values, indices) = x.sort_descending_with_indices(0);
+                values.powf_scalar(2.0) + indices.float()
+            },
+            [sample([3, 4], 0)],
+            [sample([3, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_cumprod() {
+        assert_jvp_finite_difference(|[x]| x.cumprod(1), [sample([3, 4], 0)], [sample([3, 4], 1)]);
+    }
+
+    #[test]
+    fn should_compute_jvp_cumprod_with_zeros() {
+        let device = Default::default();
+        let x = TestTensor::from_data(
+            [
+                [2.0, 0.0, 3.0, 4.0],
+                [0.0, 2.0, 0.0, 5.0],
+                [1.0, 2.0, 3.0, 0.0],
+            ],
+            &device,
+        );
+        let v = TestTensor::ones([3, 4], &device);
+
+        let (_, tangent) = jvp(|[x]| x.cumprod(1), [x], [v]);
+
+        tangent.to_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 2.0, 6.0, 24.0],
+                [1.0, 2.0, 0.0, 0.0],
+                [1.0, 3.0, 11.0, 6.0],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_conv2d() {
+        assert_jvp_finite_difference(
+            |[x, weight]| {
+                let weight = weight.slice([0..3, 0..2, 0..2, 0..2]);
+                conv2d(x, weight, None, ConvOptions::new([1, 2], [1, 0], [1, 1], 1))
+            },
+            [sample([1, 2, 4, 4], 0), sample([3, 2, 4, 4], 2)],
+            [sample([1, 2, 4, 4], 1), sample([3, 2, 4, 4], 3)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_grouped_conv2d() {
+        assert_jvp_finite_difference(
+            |[x, weight]| {
+                let weight = weight.slice([0..4, 0..1, 0..3, 0..3]);
+                conv2d(x, weight, None, ConvOptions::new([1, 1], [1, 1], [1, 1], 2))
+            },
+            [sample([1, 2, 4, 4], 0), sample([4, 2, 4, 4], 2)],
+            [sample([1, 2, 4, 4], 1), sample([4, 2, 4, 4], 3)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_max_pool2d() {
+        assert_jvp_finite_difference(
+            |[x]| max_pool2d(x, [2, 2], [1, 1], [1, 1], [1, 1]),
+            [sample([1, 2, 4, 4], 0)],
+            [sample([1, 2, 4, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_avg_pool2d() {
+        assert_jvp_finite_difference(
+            |[x]| {
+                avg_pool2d(x.clone(), [3, 3], [2, 1], [1, 1], false)
+                    + adaptive_avg_pool2d(x, [2, 4])
+            },
+            [sample([1, 2, 4, 4], 0)],
+            [sample([1, 2, 4, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_match_finite_difference_powf() {
+        assert_jvp_finite_difference(
+            |[x, exponent]| x.exp().powf(exponent),
+            [sample([3, 4], 0), sample([3, 4], 2).div_scalar(2.0)],
+            [sample([3, 4], 1), sample([3, 4], 3)],
+        );
+    }
+
+    /// Distinct multiples of `1/16` in `[-1.875, 1.875]`, so that the perturbations of the finite
+    /// differences never change the order of the values.
+    fn sample<const D: usize>(shape: [usize; D], seed: usize) -> TestTensor<D> {
+        let shape = Shape::new(shape);
+        let values = (0..shape.num_elements())
+            .map(|i| ((i * 37 + seed * 23 + 11) % 61) as f32 / 16.0 - 1.875)
+            .collect::<Vec<_>>();
+        let data = Data::new(values, Shape::new([shape.num_elements()]));
+
+        TestTensor::<1>::from_data(data.convert(), &Default::default()).reshape(shape)
+    }
+
+    /// Asserts that the Jacobian-vector product of the function matches its central finite
+    /// difference in the direction of the tangents.
+    fn assert_jvp_finite_difference<F, const D: usize, const D2: usize, const N: usize>(
+        func: F,
+        primals: [TestTensor<D>; N],
+        tangents: [TestTensor<D>; N],
+    ) where
+        F: Fn([Tensor<TestForwardBackend, D>; N]) -> Tensor<TestForwardBackend, D2>,
+    {
+        const STEP: f32 = 1e-2;
+
+        let (_, jvp_tangent) = jvp(&func, primals.clone(), tangents.clone());
+        let evaluate = |step: f32| {
+            let mut tangents = tangents.clone().into_iter();
+            let inputs = primals.clone().map(|primal| {
+                let shifted = primal + tangents.next().unwrap().mul_scalar(step);
+                let zeros = shifted.zeros_like();
+                dual(shifted, zeros)
+            });
+            primal(func(inputs))
+        };
+        let finite_difference = (evaluate(STEP) - evaluate(-STEP)).div_scalar(2.0 * STEP);
+
+        jvp_tangent
+            .into_data()
+            .assert_approx_eq_diff(&finite_difference.into_data(), 1e-2);
+    }
+}
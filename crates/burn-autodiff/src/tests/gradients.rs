@@ -24,4 +24,25 @@ mod tests {
         assert_ne!(grad_1_new.to_data(), grad_1.into_data());
         assert_eq!(grad_1_new.into_data(), grad_1_updated.into_data());
     }
+
+    #[test]
+    fn should_diff_tensor_merged_into_another_graph() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, -2.0], [0.5, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[2.0, 1.0], [-1.0, 4.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().exp();
+        // The graph of the third tensor is merged into the graph of the product.
+        let _tensor_4 = tensor_2.clone().exp().mul(tensor_3.clone());
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&tensor_1.exp().into_data(), 3);
+        assert!(tensor_2.grad(&grads).is_none());
+    }
 }
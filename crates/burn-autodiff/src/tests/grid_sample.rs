@@ -0,0 +1,139 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear),
+        };
+
+        test.assert_grads(
+            TestTensor::from_floats(
+                [[[
+                    [0.56, 0.0, 0.81, 1.665],
+                    [1.82, 1.98, 0.39, 1.635],
+                    [2.58, 1.62, 0.0, 3.42],
+                ]]],
+                &Default::default(),
+            ),
+            TestTensor::from_floats(
+                [[
+                    [[0.0, 0.0], [4.0, 12.0], [6.0, 18.0]],
+                    [[-83.6, -59.4], [-58.0, 9.0], [12.0, 36.0]],
+                ]],
+                &Default::default(),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear)
+                .with_padding_mode(GridSamplePaddingMode::Reflection)
+                .with_align_corners(true),
+        };
+
+        test.assert_grads(
+            TestTensor::from_floats(
+                [[[
+                    [0.68, 0.12, 1.0613, 1.2887],
+                    [1.61, 3.71, 2.1538, 4.7762],
+                    [0.96, 1.82, 0.86, 1.96],
+                ]]],
+                &Default::default(),
+            ),
+            TestTensor::from_floats(
+                [[
+                    [[1.5, 4.0], [3.0, 8.0], [4.5, 12.0]],
+                    [[6.0, 16.0], [-7.5, 20.0], [9.0, 24.0]],
+                ]],
+                &Default::default(),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_affine_grid() {
+        let device = Default::default();
+        let x = input();
+        let theta = TestAutodiffTensor::from_data(
+            Data::from([[[0.5, -0.2, 0.1], [0.3, 0.9, -0.15]]]),
+            &device,
+        )
+        .require_grad();
+
+        let grid = affine_grid_2d(theta.clone(), [1, 1, 2, 3], false);
+        let output = grid_sample_2d(x, grid, GridSampleOptions::new(GridSampleMode::Bilinear));
+        let grads = output.mul(weights()).backward();
+
+        let theta_grad = theta.grad(&grads).unwrap();
+        let theta_grad_expected = TestTensor::from_floats(
+            [[[5.6, 9.2, 41.6], [18.7667, 29.075, 121.85]]],
+            &Default::default(),
+        );
+        theta_grad
+            .to_data()
+            .assert_approx_eq(&theta_grad_expected.into_data(), 2);
+    }
+
+    struct GridSampleTestCase {
+        options: GridSampleOptions,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_grads(self, x_grad_expected: TestTensor<4>, grid_grad_expected: TestTensor<4>) {
+            let device = Default::default();
+            let x = input().require_grad();
+            let grid = TestAutodiffTensor::from_data(
+                Data::from([[
+                    [[-0.9, -0.8], [-0.3, 0.2], [0.55, -0.45]],
+                    [[0.8, 0.7], [1.1, -0.2], [-0.6, 0.4]],
+                ]]),
+                &device,
+            )
+            .require_grad();
+
+            let output = grid_sample_2d(x.clone(), grid.clone(), self.options);
+            let grads = output.mul(weights()).backward();
+
+            let x_grad = x.grad(&grads).unwrap();
+            let grid_grad = grid.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_expected.into_data(), 3);
+            grid_grad
+                .to_data()
+                .assert_approx_eq(&grid_grad_expected.into_data(), 3);
+        }
+    }
+
+    fn input() -> TestAutodiffTensor<4> {
+        let device = Default::default();
+        let shape = Shape::new([1, 1, 3, 4]);
+        TestAutodiffTensor::from_data(
+            TestTensorInt::arange(0..shape.num_elements() as i64, &device)
+                .reshape(shape)
+                .into_data()
+                .convert(),
+            &device,
+        )
+    }
+
+    fn weights() -> TestAutodiffTensor<4> {
+        let device = Default::default();
+        let shape = Shape::new([1, 1, 2, 3]);
+        TestAutodiffTensor::from_data(
+            TestTensorInt::arange(1..shape.num_elements() as i64 + 1, &device)
+                .reshape(shape)
+                .into_data()
+                .convert(),
+            &device,
+        )
+    }
+}
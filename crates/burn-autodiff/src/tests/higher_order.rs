@@ -0,0 +1,114 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint::strategy::{
+        BalancedCheckpointing, CheckpointStrategy, NoCheckpointing,
+    };
+    use burn_autodiff::Autodiff;
+    use burn_tensor::{Data, Tensor};
+
+    type TestHigherOrderBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+    #[test]
+    fn should_diff_twice_cube() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data([[1.0, 2.0], [-1.0, 0.5]], &device).require_grad();
+        let x_outer = Tensor::<TestHigherOrderBackend, 2>::from_inner(x.clone()).require_grad();
+
+        let y = x_outer.clone().powf_scalar(3.0).sum();
+        let grads = y.backward();
+        let grad = x_outer.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[3.0, 12.0], [3.0, 0.75]]), 3);
+
+        let grads = grad.sum().backward();
+        let grad = x.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[6.0, 12.0], [-6.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty() {
+        gradient_penalty(false);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty_tracked_weight() {
+        gradient_penalty(true);
+    }
+
+    #[test]
+    fn should_diff_hessian_vector_product() {
+        hessian_vector_product::<NoCheckpointing>();
+    }
+
+    #[test]
+    fn should_diff_hessian_vector_product_balanced_checkpointing() {
+        hessian_vector_product::<BalancedCheckpointing>();
+    }
+
+    /// Computes `H v` for `f(x) = sum(exp(x W) / (x^2 + 1))` by differentiating `<grad f(x), v>`,
+    /// with the given checkpoint strategy on both levels.
+    fn hessian_vector_product<C: CheckpointStrategy>() {
+        type Inner<C> = Autodiff<TestBackend, C>;
+        type Outer<C> = Autodiff<Inner<C>, C>;
+
+        let device = Default::default();
+        let x = Tensor::<Inner<C>, 2>::from_data([[0.5, -0.3], [0.2, 0.8]], &device).require_grad();
+        let weight = Tensor::<Inner<C>, 2>::from_data([[0.4, -0.6], [0.9, 0.1]], &device);
+        let v = Tensor::<Inner<C>, 2>::from_data([[1.0, -2.0], [0.5, 1.5]], &device);
+        let x_outer = Tensor::<Outer<C>, 2>::from_inner(x.clone()).require_grad();
+        let weight_outer = Tensor::<Outer<C>, 2>::from_inner(weight);
+
+        let numerator = x_outer.clone().matmul(weight_outer).exp();
+        let denominator = x_outer.clone().powf_scalar(2.0).add_scalar(1.0);
+        let y = numerator.div(denominator).sum();
+        let grads = y.backward();
+        let grad = x_outer.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[-0.69410, 1.10034], [-0.31859, 1.41298]]), 3);
+
+        let grads = grad.mul(v).sum().backward();
+        let hvp = x.grad(&grads).unwrap();
+
+        hvp.to_data()
+            .assert_approx_eq(&Data::from([[0.69259, -0.27173], [-1.28753, 3.20724]]), 3);
+    }
+
+    /// Differentiates the squared norm of the gradient of `sum(sin(x W))` with respect to `x`,
+    /// with the weight tracked or not on the outer level.
+    fn gradient_penalty(track_weight: bool) {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data([[1.0, -0.5], [0.3, 2.0]], &device).require_grad();
+        let weight = TestAutodiffTensor::from_data([[0.5, -1.0, 0.2], [1.5, 0.3, -0.7]], &device)
+            .require_grad();
+        let x_outer = Tensor::<TestHigherOrderBackend, 2>::from_inner(x.clone()).require_grad();
+        let mut weight_outer = Tensor::<TestHigherOrderBackend, 2>::from_inner(weight.clone());
+        if track_weight {
+            weight_outer = weight_outer.require_grad();
+        }
+
+        let y = x_outer.clone().matmul(weight_outer).sin().sum();
+        let grads = y.backward();
+        let grad = x_outer.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.24647, 0.97915], [-1.40957, -1.37347]]), 3);
+
+        let penalty = grad.powf_scalar(2.0).sum();
+        let grads = penalty.backward();
+        let grad_x = x.grad(&grads).unwrap();
+        let grad_weight = weight.grad(&grads).unwrap();
+
+        grad_x
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.4406, 0.742], [0.8309, -1.1727]]), 3);
+        grad_weight.to_data().assert_approx_eq(
+            &Data::from([[4.0704, -2.5824, 0.8372], [4.1574, -3.0466, 3.3546]]),
+            3,
+        );
+    }
+}
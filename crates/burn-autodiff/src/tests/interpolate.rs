@@ -0,0 +1,161 @@
+#[burn_tensor_testgen::testgen(ad_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{interpolate, interpolate1d, interpolate3d};
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_bilinear_interpolate_without_align_corners() {
+        let test = InterpolateTestCase {
+            shape: [1, 1, 3, 4],
+            output_size: [5, 6],
+            options: InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+        };
+
+        test.assert_grad_2d(TestTensor::from_floats(
+            [[[
+                [6.2000, 9.6667, 13.1333, 16.6000],
+                [33.3000, 37.2000, 41.1000, 45.0000],
+                [53.0000, 56.4667, 59.9333, 63.4000],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_bicubic_interpolate() {
+        let test = InterpolateTestCase {
+            shape: [1, 1, 3, 3],
+            output_size: [5, 4],
+            options: InterpolateOptions::new(InterpolateMode::Bicubic),
+        };
+
+        test.assert_grad_2d(TestTensor::from_floats(
+            [[[
+                [1.1834, 4.7529, 6.0012],
+                [21.2674, 33.0961, 28.7616],
+                [26.1603, 37.7992, 30.9780],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_bilinear_interpolate_antialias() {
+        let test = InterpolateTestCase {
+            shape: [1, 1, 6, 5],
+            output_size: [3, 2],
+            options: InterpolateOptions::new(InterpolateMode::Bilinear)
+                .with_align_corners(false)
+                .with_antialias(true),
+        };
+
+        test.assert_grad_2d(TestTensor::from_floats(
+            [[[
+                [0.0000, 0.0195, 0.0974, 0.1753, 0.1364],
+                [0.0795, 0.1388, 0.2394, 0.3401, 0.2557],
+                [0.2386, 0.3644, 0.4586, 0.5528, 0.4034],
+                [0.4205, 0.6242, 0.7183, 0.8125, 0.5852],
+                [0.6250, 0.9180, 1.0187, 1.1193, 0.8011],
+                [0.5455, 0.7987, 0.8766, 0.9545, 0.6818],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_linear_interpolate() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::zeros([1, 1, 4], &device).require_grad();
+        let options = InterpolateOptions::new(InterpolateMode::Linear).with_align_corners(false);
+
+        let output = interpolate1d(x.clone(), 7, options);
+        let weights = TestAutodiffTensor::from_data(
+            TestTensorInt::arange(0..7, &device)
+                .reshape([1, 1, 7])
+                .into_data()
+                .convert(),
+            &device,
+        );
+        let grads = output.mul(weights).sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad.to_data().assert_approx_eq(
+            &TestTensor::from_floats([[[0.7857, 3.7143, 7.0000, 9.5000]]], &device).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_trilinear_interpolate() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::zeros([1, 1, 2, 2, 3], &device).require_grad();
+        let options = InterpolateOptions::new(InterpolateMode::Trilinear).with_align_corners(false);
+
+        let output = interpolate3d(x.clone(), [3, 3, 2], options);
+        let weights = TestAutodiffTensor::from_data(
+            TestTensorInt::arange(0..18, &device)
+                .reshape([1, 1, 3, 3, 2])
+                .into_data()
+                .convert(),
+            &device,
+        );
+        let grads = output.mul(weights).sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad.to_data().assert_approx_eq(
+            &TestTensor::from_floats(
+                [[[
+                    [[4.5000, 3.5625, 6.1875], [9.0000, 6.5625, 10.6875]],
+                    [[18.0000, 12.5625, 19.6875], [22.5000, 15.5625, 24.1875]],
+                ]]],
+                &device,
+            )
+            .into_data(),
+            3,
+        );
+    }
+
+    struct InterpolateTestCase {
+        shape: [usize; 4],
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_grad_2d(self, x_grad: TestTensor<4>) {
+            let device = Default::default();
+            let shape_x = Shape::new(self.shape);
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+                &device,
+            )
+            .require_grad();
+            let [batch_size, channels, _, _] = self.shape;
+            let [height_out, width_out] = self.output_size;
+
+            // Weighting the output makes each output position contribute differently.
+            let weights = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(
+                    0..(batch_size * channels * height_out * width_out) as i64,
+                    &device,
+                )
+                .reshape([batch_size, channels, height_out, width_out])
+                .into_data()
+                .convert(),
+                &device,
+            );
+            let output = interpolate(x.clone(), self.output_size, self.options);
+            let grads = output.mul(weights).sum().backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let a = TestAutodiffTensor::from_floats(
+            [[[4.0, 1.0], [2.0, 3.0]], [[2.0, -1.0], [1.0, 1.0]]],
+            &device,
+        )
+        .require_grad();
+        let b = TestAutodiffTensor::from_floats(
+            [[[1.0, 2.0], [0.0, -1.0]], [[3.0, 1.0], [2.0, 0.5]]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[[1.0, -1.0], [2.0, 0.5]], [[0.0, 1.0], [-2.0, 1.0]]],
+            &device,
+        );
+
+        let output = linalg::solve(a.clone(), b.clone()).mul(weights);
+        let grads = output.backward();
+
+        a.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [[0.31, -0.34], [-0.42, 0.38]],
+                [[-1.11111, -0.22222], [1.72222, 0.44444]],
+            ]),
+            3,
+        );
+        b.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [[-0.1, -0.4], [0.7, 0.3]],
+                [[0.66667, 0.0], [-1.33333, 1.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats(
+            [[2.0, 1.0, 0.0], [1.0, -3.0, 1.0], [0.0, -1.0, 2.0]],
+            &device,
+        )
+        .require_grad();
+
+        let grads = linalg::det(tensor.clone()).backward();
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([[-5.0, -2.0, -1.0], [-2.0, 4.0, 2.0], [1.0, -2.0, -7.0]]),
+            3,
+        );
+
+        let (_sign, logabsdet) = linalg::slogdet(tensor.clone());
+        let grads = logabsdet.backward();
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [0.41667, 0.16667, 0.08333],
+                [0.16667, -0.33333, -0.16667],
+                [-0.08333, 0.16667, 0.58333],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats(
+            [[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 3.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[1.0, 2.0, 3.0], [-1.0, 0.5, 2.0], [1.0, -2.0, 1.5]],
+            &device,
+        );
+
+        let output = linalg::cholesky(tensor.clone()).mul(weights);
+        let grads = output.backward();
+
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [0.44457, -0.38913, 0.55653],
+                [-0.38913, 0.27827, -0.61307],
+                [0.55653, -0.61307, 0.45227],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_qr() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, -1.0], [0.5, 2.0]], &device)
+                .require_grad();
+        let weights_q =
+            TestAutodiffTensor::from_floats([[1.0, -1.0], [2.0, 0.5], [0.0, 1.0]], &device);
+        let weights_r = TestAutodiffTensor::from_floats([[1.0, 2.0], [0.0, -1.0]], &device);
+
+        let (q, r) = linalg::qr(tensor.clone());
+        let output = q.mul(weights_q).sum() + r.mul(weights_r).sum();
+        let grads = output.backward();
+
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([[1.59573, -0.37079], [0.32963, 2.25801], [1.23387, -0.00021]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_svd() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [-1.0, 0.5, 2.0]], &device)
+            .require_grad();
+        let weights_u = TestAutodiffTensor::from_floats([[-1.0, -0.5], [0.0, 0.5]], &device);
+        let weights_s = TestAutodiffTensor::from_floats([[2.0, -1.0]], &device);
+        let weights_vh =
+            TestAutodiffTensor::from_floats([[1.0, 0.75, 0.5], [0.25, 0.0, -0.25]], &device);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let output =
+            u.mul(weights_u).sum() + s.mul(weights_s.clone()).sum() + vh.mul(weights_vh).sum();
+        let grads = output.backward();
+
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([[0.02872, 0.82348, 1.61824], [1.02539, 0.73805, 0.45071]]),
+            3,
+        );
+
+        let tensor =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, -1.0], [0.5, 2.0]], &device)
+                .require_grad();
+        let weights_u =
+            TestAutodiffTensor::from_floats([[-1.0, -0.5], [0.0, 0.5], [1.0, 1.5]], &device);
+        let weights_vh = TestAutodiffTensor::from_floats([[1.0, 0.75], [0.5, 0.25]], &device);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let output = u.mul(weights_u).sum() + s.mul(weights_s).sum() + vh.mul(weights_vh).sum();
+        let grads = output.backward();
+
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [-0.20575, -1.21111],
+                [2.18646, -0.24444],
+                [0.09898, -0.41111],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats(
+            [[2.0, 1.0, 0.5], [1.0, 3.0, -1.0], [0.5, -1.0, 5.0]],
+            &device,
+        )
+        .require_grad();
+        let weights_values = TestAutodiffTensor::from_floats([[1.0, -1.0, 2.0]], &device);
+        let weights_vectors = TestAutodiffTensor::from_floats(
+            [[1.0, 2.0, 3.0], [-1.0, 0.5, 2.0], [1.0, -2.0, 1.5]],
+            &device,
+        );
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let output = values.mul(weights_values).sum() + vectors.mul(weights_vectors).sum();
+        let grads = output.backward();
+
+        tensor.grad(&grads).unwrap().to_data().assert_approx_eq(
+            &Data::from([
+                [0.64705, -1.11146, 0.50251],
+                [-1.11146, -1.0787, -0.0155],
+                [0.50251, -0.0155, 2.43165],
+            ]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::max_pool3d, Data};
+
+    fn input() -> TestAutodiffTensor<5> {
+        TestAutodiffTensor::from_floats(
+            [[[
+                [
+                    [0.0, 1.7, 3.4, 1.4],
+                    [3.1, 1.1, 2.8, 0.8],
+                    [2.5, 0.5, 2.2, 0.2],
+                ],
+                [
+                    [1.9, 3.6, 1.6, 3.3],
+                    [1.3, 3.0, 1.0, 2.7],
+                    [0.7, 2.4, 0.4, 2.1],
+                ],
+                [
+                    [0.1, 1.8, 3.5, 1.5],
+                    [3.2, 1.2, 2.9, 0.9],
+                    [2.6, 0.6, 2.3, 0.3],
+                ],
+            ]]],
+            &Default::default(),
+        )
+        .require_grad()
+    }
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let x = input();
+        let x_grad_expected = TestAutodiffTensor::from_floats(
+            [[[
+                [
+                    [0.0, 0.0, 1.0, 0.0],
+                    [1.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 4.0, 0.0, 0.0],
+                    [0.0, 2.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 0.0, 1.0, 0.0],
+                    [1.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+            ]]],
+            &Default::default(),
+        );
+
+        let output = max_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let x = input();
+        let x_grad_expected = TestAutodiffTensor::from_floats(
+            [[[
+                [
+                    [0.0, 1.0, 2.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 2.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 1.0, 2.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+            ]]],
+            &Default::default(),
+        );
+
+        let output = max_pool3d(x.clone(), [2, 2, 3], [1, 2, 1], [1, 0, 1], [1, 1, 2]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
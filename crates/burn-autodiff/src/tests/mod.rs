@@ -3,10 +3,13 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
 mod add;
 mod aggregation;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod broadcast;
 mod cat;
@@ -14,16 +17,26 @@ mod checkpoint;
 mod complex;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod fft;
+mod forward;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod higher_order;
+mod interpolate;
+mod linalg;
 mod log;
 mod log1p;
 mod mask;
@@ -31,10 +44,12 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod mul;
 mod multithread;
 mod neg;
 mod nonzero;
+mod pad;
 mod pow;
 mod recip;
 mod relu;
@@ -44,10 +59,14 @@ mod sigmoid;
 mod sin;
 mod slice;
 mod softmax;
+mod sort;
+mod sparse;
+mod special;
 mod sqrt;
 mod sub;
 mod tanh;
 mod transpose;
+mod trig;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -59,6 +78,8 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_broadcast!();
         burn_autodiff::testgen_gradients!();
         burn_autodiff::testgen_checkpoint!();
+        burn_autodiff::testgen_ad_higher_order!();
+        burn_autodiff::testgen_ad_forward!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -67,14 +88,22 @@ macro_rules! testgen_all {
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv3d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
+        burn_autodiff::testgen_ad_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_attention!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
@@ -86,9 +115,16 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
+        burn_autodiff::testgen_ad_special!();
+        burn_autodiff::testgen_ad_trig!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_einsum!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_sparse!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
@@ -98,11 +134,13 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
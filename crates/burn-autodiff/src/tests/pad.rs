@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode};
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad([(1, 0), (0, 1)], PadMode::Constant(5.0))
+            .mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[4.0, 5.0], [7.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                [1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            ],
+            &device,
+        );
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad([(0, 0), (2, 1)], PadMode::Reflect)
+            .mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Elements copied by the padding receive the gradients of all their copies.
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[3.0, 12.0, 6.0], [1.0, 3.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_pad_replicate_and_circular() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad([(1, 2), (0, 0)], PadMode::Replicate)
+            .pad([(0, 0), (1, 2)], PadMode::Circular);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[4.0, 6.0], [6.0, 9.0]]));
+    }
+}
@@ -54,4 +54,66 @@ mod tests {
             Data::from([[64., 64., 64.], [19., 19., 19.]])
         );
     }
+
+    #[test]
+    fn test_select_assign_grad_repeated_indices() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(
+            Data::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]),
+            &device,
+        )
+        .require_grad();
+        let values = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_data(
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        );
+        let indices = Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([2, 2]), &device);
+
+        let tensor_2 = tensor_1.clone().select_assign(0, indices, values.clone());
+        let tensor_3 = tensor_2.mul(weights).sum();
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+        );
+        assert_eq!(grad_2.into_data(), Data::from([[5.0, 6.0], [5.0, 6.0]]));
+    }
+
+    #[test]
+    fn test_select_assign_grad_repeated_indices_dim_1() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), &device)
+                .require_grad();
+        let values =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), &device)
+                .require_grad();
+        let indices =
+            Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([0, 2, 0]), &device);
+
+        let tensor_2 = tensor_1.clone().select_assign(1, indices, values.clone());
+        let tensor_3 = tensor_2.clone().mul(tensor_2).sum();
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        // The first column receives the first and the last values, the last column the second.
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[8.0, 2.0, 8.0], [26.0, 8.0, 20.0]])
+        );
+        assert_eq!(
+            grad_2.into_data(),
+            Data::from([[8.0, 8.0, 8.0], [26.0, 20.0, 26.0]])
+        );
+    }
 }
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, -2.0], [3.0, 0.0, 5.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().sort(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 3.0, 1.0], [5.0, 4.0, 6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_sort_descending_with_indices() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, -2.0], [3.0, 0.0, 5.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let (tensor_2, indices) = tensor_1.clone().sort_descending_with_indices(0);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(indices.into_data(), Data::from([[1, 0, 1], [0, 1, 0]]));
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[4.0, 2.0, 6.0], [1.0, 5.0, 3.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_topk() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, -2.0], [3.0, 0.0, 5.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let tensor_2 = tensor_1.clone().topk(2, 1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 1.0, 0.0], [4.0, 0.0, 3.0]]), 5);
+    }
+}
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Shape, Sparse, Tensor};
+
+    #[test]
+    fn should_diff_spmm() {
+        let device = Default::default();
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 1], [1, 0], [1, 2]], &device);
+        let values = TestAutodiffTensor::from_floats([2.0, 3.0, -1.0], &device).require_grad();
+        let rhs = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, -1.0], [2.0, 0.5]], &device);
+
+        let lhs = Tensor::<TestAutodiffBackend, 2, Sparse>::from_coo(
+            indices,
+            values.clone(),
+            Shape::new([2, 3]),
+        );
+        let output = lhs.into_csr().spmm(rhs.clone()).mul(weights);
+        let grads = output.backward();
+
+        rhs.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 1.5], [2.0, -2.0], [-2.0, -0.5]]), 4);
+        values
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-1.0, 3.0, 13.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_mul_dense() {
+        let device = Default::default();
+        let lhs = TestAutodiffTensor::from_floats([[0.0, 2.0], [-3.0, 0.0]], &device);
+        let rhs = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let output = lhs.to_sparse_coo().mul_dense(rhs.clone()).to_dense();
+        let grads = output.backward();
+
+        rhs.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0], [-3.0, 0.0]]), 4);
+    }
+}
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(ad_special)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    fn assert_grad<const N: usize>(
+        input: [f32; N],
+        func: impl Fn(TestAutodiffTensor<1>) -> TestAutodiffTensor<1>,
+        expected: [f32; N],
+    ) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(input, &device).require_grad();
+
+        let grads = func(tensor.clone()).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from(expected), 3);
+    }
+
+    #[test]
+    fn should_diff_lgamma() {
+        assert_grad([0.5, 2.0], |tensor| tensor.lgamma(), [-1.96351, 0.42278]);
+    }
+
+    #[test]
+    fn should_diff_digamma() {
+        assert_grad(
+            [0.5, 2.0, -0.5],
+            |tensor| tensor.digamma(),
+            [4.93480, 0.64493, 8.93480],
+        );
+    }
+
+    #[test]
+    fn should_diff_erfinv() {
+        assert_grad(
+            [-0.5, 0.0, 0.3],
+            |tensor| tensor.erfinv(),
+            [1.11258, 0.88623, 0.95452],
+        );
+    }
+
+    #[test]
+    fn should_diff_expm1() {
+        assert_grad([0.2, 0.5], |tensor| tensor.expm1(), [1.22140, 1.64872]);
+    }
+
+    #[test]
+    fn should_have_zero_grad_for_rounding() {
+        let input = [-1.5, 0.3, 2.5];
+        let zeros = [0.0, 0.0, 0.0];
+
+        assert_grad(input, |tensor| tensor.floor(), zeros);
+        assert_grad(input, |tensor| tensor.ceil(), zeros);
+        assert_grad(input, |tensor| tensor.round(), zeros);
+        assert_grad(input, |tensor| tensor.trunc(), zeros);
+        assert_grad(input, |tensor| tensor.sign(), zeros);
+    }
+}
@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(ad_trig)]
+mod tests {
+    use super::*;
+    use burn_autodiff::forward::jvp;
+    use burn_tensor::Data;
+
+    fn assert_grad(
+        func: impl Fn(TestAutodiffTensor<1>) -> TestAutodiffTensor<1>,
+        expected: [f32; 2],
+    ) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data([0.2, 0.5], &device).require_grad();
+
+        let grads = func(tensor.clone()).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from(expected), 3);
+    }
+
+    #[test]
+    fn should_diff_tan() {
+        assert_grad(|tensor| tensor.tan(), [1.04109, 1.29845]);
+    }
+
+    #[test]
+    fn should_diff_asin() {
+        assert_grad(|tensor| tensor.asin(), [1.02062, 1.15470]);
+    }
+
+    #[test]
+    fn should_diff_acos() {
+        assert_grad(|tensor| tensor.acos(), [-1.02062, -1.15470]);
+    }
+
+    #[test]
+    fn should_diff_atan() {
+        assert_grad(|tensor| tensor.atan(), [0.96154, 0.8]);
+    }
+
+    #[test]
+    fn should_diff_sinh() {
+        assert_grad(|tensor| tensor.sinh(), [1.02007, 1.12763]);
+    }
+
+    #[test]
+    fn should_diff_cosh() {
+        assert_grad(|tensor| tensor.cosh(), [0.20134, 0.52110]);
+    }
+
+    #[test]
+    fn should_diff_atan2() {
+        let device = Default::default();
+        let y = TestAutodiffTensor::from_data([[1.0, -2.0]], &device).require_grad();
+        let x = TestAutodiffTensor::from_data([[1.0], [2.0]], &device).require_grad();
+
+        let grads = y.clone().atan2(x.clone()).sum().backward();
+
+        y.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.9, 0.45]]), 3);
+        x.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.1], [0.05]]), 3);
+    }
+
+    #[test]
+    fn should_compute_jvp_of_atan2() {
+        let device = Default::default();
+        let y = TestTensor::from_data([1.0, -1.0], &device);
+        let x = TestTensor::from_data([1.0, 2.0], &device);
+        let tangent_y = TestTensor::from_data([1.0, 1.0], &device);
+        let tangent_x = TestTensor::from_data([0.0, 1.0], &device);
+
+        let (_, tangent) = jvp(|[y, x]| y.atan2(x), [y, x], [tangent_y, tangent_x]);
+
+        tangent
+            .to_data()
+            .assert_approx_eq(&Data::from([0.5, 0.6]), 3);
+    }
+}
@@ -102,6 +102,28 @@ impl<F: FloatCandleElement, I: IntCandleElement> BoolTensorOps<Self> for Candle<
         CandleTensor::new(tensor.tensor.eq(&x).unwrap())
     }
 
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        // The booleans are stored as zeros and ones.
+        CandleTensor::new(lhs.tensor.broadcast_mul(&rhs.tensor).unwrap())
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        CandleTensor::new(lhs.tensor.broadcast_maximum(&rhs.tensor).unwrap())
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        CandleTensor::new(lhs.tensor.broadcast_ne(&rhs.tensor).unwrap())
+    }
+
     fn bool_swap_dims<const D: usize>(
         tensor: <Candle<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D>,
         dim1: usize,
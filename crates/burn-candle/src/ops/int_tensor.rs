@@ -1,3 +1,4 @@
+use burn_common::rand::get_seeded_rng;
 use burn_tensor::{
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
     Bool, Data, Device, Distribution, ElementConversion, Reader, Shape,
@@ -408,6 +409,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            _ => {
+                let data =
+                    Data::<I, D>::random(Shape::new(*shape), distribution, &mut get_seeded_rng());
+                CandleTensor::new(candle_core::Tensor::from_vec(data.value, shape, device).unwrap())
+            }
         }
     }
 }
@@ -1,6 +1,6 @@
 use burn_tensor::{
     ops::{
-        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateMode,
+        interpolate, ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateMode,
         InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
     },
     Shape,
@@ -242,19 +242,13 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self, 4> {
-        let tensor = match options.mode {
-            InterpolateMode::Nearest => x
-                .tensor
-                .upsample_nearest2d(output_size[0], output_size[1])
-                .unwrap(),
-            InterpolateMode::Bilinear => {
-                panic!("bilinear interpolation is not supported by Candle")
-            }
-            InterpolateMode::Bicubic => {
-                panic!("bicubic interpolation is not supported by Candle")
-            }
-        };
-
-        CandleTensor::new(tensor)
+        match options.mode {
+            InterpolateMode::Nearest => CandleTensor::new(
+                x.tensor
+                    .upsample_nearest2d(output_size[0], output_size[1])
+                    .unwrap(),
+            ),
+            _ => interpolate::interpolate_from_matmul::<Self>(x, output_size, options),
+        }
     }
 }
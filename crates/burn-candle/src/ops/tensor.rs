@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 
+use burn_common::rand::get_seeded_rng;
 use burn_tensor::{
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
     Data, Device, Distribution, ElementConversion, Reader, Shape,
@@ -50,6 +51,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            _ => {
+                let data =
+                    Data::<F, D>::random(Shape::new(*shape), distribution, &mut get_seeded_rng());
+                CandleTensor::new(candle_core::Tensor::from_vec(data.value, shape, device).unwrap())
+            }
         }
     }
 
@@ -421,6 +427,14 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         CandleTensor::new(tensor.tensor.erf().unwrap())
     }
 
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.floor().unwrap())
+    }
+
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.ceil().unwrap())
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
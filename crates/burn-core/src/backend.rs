@@ -10,6 +10,15 @@ pub use burn_autodiff as autodiff;
 #[cfg(feature = "autodiff")]
 pub use burn_autodiff::Autodiff;
 
+#[cfg(feature = "autodiff")]
+pub use burn_autodiff::ForwardAD;
+
+#[cfg(feature = "vmap")]
+pub use burn_vmap as vmap;
+
+#[cfg(feature = "vmap")]
+pub use burn_vmap::Vmap;
+
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu as wgpu;
 
@@ -244,4 +244,12 @@ pub trait AutodiffModule<B: AutodiffBackend>: Module<B> + Send + Sync + core::fm
 
     /// Get the same module, but on the inner backend without auto-differentiation.
     fn valid(&self) -> Self::InnerModule;
+
+    /// Get the same module on the auto-differentiation backend from the module on the inner
+    /// backend, with its float parameters requiring gradients.
+    ///
+    /// When the inner backend is itself an auto-differentiation backend, the parameters stay in
+    /// its graph, so the gradients computed with the returned module can be differentiated again
+    /// with respect to the parameters of the given module.
+    fn from_inner(module: Self::InnerModule) -> Self;
 }
@@ -86,6 +86,10 @@ macro_rules! constant {
         fn valid(&self) -> Self::InnerModule {
             self.clone()
         }
+
+        fn from_inner(module: Self::InnerModule) -> Self {
+            module
+        }
     };
 
     ($type:ty) => {
@@ -166,6 +170,10 @@ impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> AutodiffModule<
     fn valid(&self) -> Self::InnerModule {
         self.clone().inner()
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Tensor::from_inner(module)
+    }
 }
 
 impl<B: Backend> Module<B> for PhantomData<B> {
@@ -206,6 +214,10 @@ impl<B: AutodiffBackend> AutodiffModule<B> for PhantomData<B> {
     fn valid(&self) -> Self::InnerModule {
         PhantomData
     }
+
+    fn from_inner(_module: Self::InnerModule) -> Self {
+        PhantomData
+    }
 }
 
 #[cfg(all(test, feature = "std"))]
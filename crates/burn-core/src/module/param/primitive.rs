@@ -56,6 +56,10 @@ where
     fn valid(&self) -> Self::InnerModule {
         self.as_ref().map(|module| module.valid())
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        module.map(T::from_inner)
+    }
 }
 
 impl<T, B> Module<B> for Vec<T>
@@ -124,6 +128,10 @@ where
     fn valid(&self) -> Self::InnerModule {
         self.iter().map(|module| module.valid()).collect()
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        module.into_iter().map(T::from_inner).collect()
+    }
 }
 
 impl<const N: usize, T, B> Module<B> for [T; N]
@@ -196,6 +204,10 @@ where
     fn valid(&self) -> Self::InnerModule {
         self.map(|module| module.valid())
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        module.map(T::from_inner)
+    }
 }
 
 /// A macro for generating implementations for tuple modules of different sizes.
@@ -254,6 +266,10 @@ macro_rules! impl_module_tuple {
             fn valid(&self) -> Self::InnerModule {
                 ($(self.$i.valid(),)*)
             }
+
+            fn from_inner(module: Self::InnerModule) -> Self {
+                ($($l::from_inner(module.$i),)*)
+            }
         }
     };
 }
@@ -5,7 +5,7 @@ use crate::tensor::{
     Tensor,
 };
 use alloc::vec::Vec;
-use burn_tensor::{Bool, Int};
+use burn_tensor::{Bool, Int, Quantized};
 
 impl<B: Backend, const D: usize> From<Tensor<B, D>> for Param<Tensor<B, D>> {
     fn from(value: Tensor<B, D>) -> Self {
@@ -27,6 +27,12 @@ impl<B: Backend, const D: usize> From<Tensor<B, D, Bool>> for Param<Tensor<B, D,
     }
 }
 
+impl<B: Backend, const D: usize> From<Tensor<B, D, Quantized>> for Param<Tensor<B, D, Quantized>> {
+    fn from(value: Tensor<B, D, Quantized>) -> Self {
+        Param::new(ParamId::new(), value)
+    }
+}
+
 impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     type Record = Param<Tensor<B, D>>;
 
@@ -189,6 +195,70 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Bool>> {
     }
 }
 
+impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Quantized>> {
+    type Record = Param<Tensor<B, D, Quantized>>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        visitor.visit_int(&self.id, &self.value.values());
+        visitor.visit_float(&self.id, &self.value.scale());
+        visitor.visit_int(&self.id, &self.value.zero_point());
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        let value = Tensor::from_parts(
+            mapper.map_int(&self.id, self.value.values()),
+            mapper.map_float(&self.id, self.value.scale()),
+            mapper.map_int(&self.id, self.value.zero_point()),
+            self.value.scheme(),
+        );
+        Self::new(self.id, value)
+    }
+
+    fn into_record(self) -> Self::Record {
+        self
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let tensor = record.value;
+        let device = self.device();
+
+        // Make sure we load the record into the same module device.
+        if tensor.device() != device {
+            return Self::new(record.id, tensor).to_device(&device);
+        }
+
+        Self::new(record.id, tensor)
+    }
+
+    fn to_device(self, device: &<B as Backend>::Device) -> Self {
+        self.map(|tensor| {
+            Tensor::from_parts(
+                tensor.values().to_device(device),
+                tensor.scale().to_device(device),
+                tensor.zero_point().to_device(device),
+                tensor.scheme(),
+            )
+        })
+    }
+
+    fn fork(self, device: &<B as Backend>::Device) -> Self {
+        self.to_device(device) // Don't support autodiff.
+    }
+
+    fn collect_devices(
+        &self,
+        mut devices: Vec<<B as Backend>::Device>,
+    ) -> Vec<<B as Backend>::Device> {
+        let device = self.device();
+
+        if !devices.contains(&device) {
+            devices.push(device)
+        }
+
+        devices
+    }
+}
+
 impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D>> {
     type InnerModule = Param<Tensor<B::InnerBackend, D>>;
 
@@ -198,6 +268,10 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D
             self.value.clone().inner().set_require_grad(false),
         )
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Param::new(module.id, Tensor::from_inner(module.value).require_grad())
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D, Int>> {
@@ -206,6 +280,10 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D
     fn valid(&self) -> Self::InnerModule {
         Param::new(self.id.clone(), self.value.clone().inner())
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Param::new(module.id, Tensor::from_inner(module.value))
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D, Bool>> {
@@ -214,6 +292,38 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D
     fn valid(&self) -> Self::InnerModule {
         Param::new(self.id.clone(), self.value.clone().inner())
     }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Param::new(module.id, Tensor::from_inner(module.value))
+    }
+}
+
+impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D, Quantized>> {
+    type InnerModule = Param<Tensor<B::InnerBackend, D, Quantized>>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Param::new(
+            self.id.clone(),
+            Tensor::from_parts(
+                self.value.values().inner(),
+                self.value.scale().inner(),
+                self.value.zero_point().inner(),
+                self.value.scheme(),
+            ),
+        )
+    }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Param::new(
+            module.id,
+            Tensor::from_parts(
+                Tensor::from_inner(module.value.values()),
+                Tensor::from_inner(module.value.scale()),
+                Tensor::from_inner(module.value.zero_point()),
+                module.value.scheme(),
+            ),
+        )
+    }
 }
 
 #[cfg(all(test, feature = "std"))]
@@ -221,7 +331,7 @@ mod tests {
     use super::*;
     use crate::{
         module::Module,
-        nn::LinearConfig,
+        nn::{Linear, LinearConfig},
         record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
         TestAutodiffBackend,
     };
@@ -265,4 +375,32 @@ mod tests {
             module_init_with.weight.is_require_grad()
         );
     }
+
+    #[test]
+    fn test_from_inner_should_differentiate_gradients_with_respect_to_params() {
+        type HigherOrderBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>(&device);
+        let linear_outer = Linear::<HigherOrderBackend>::from_inner(linear.clone());
+        let x = Tensor::<HigherOrderBackend, 2>::ones([1, 3], &device).require_grad();
+
+        let grads = linear_outer.forward(x.clone()).sum().backward();
+        let grad = x.grad(&grads).unwrap();
+        let grads = grad.powf_scalar(2.0).sum().backward();
+        let grad_weight = linear.weight.grad(&grads).unwrap();
+
+        // The gradient of each input feature is the sum of its weights, so each weight gets twice
+        // that sum from the squared norm of the gradient.
+        let expected = linear
+            .weight
+            .val()
+            .inner()
+            .sum_dim(1)
+            .mul_scalar(2.0)
+            .repeat(1, 2);
+        grad_weight
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
 }
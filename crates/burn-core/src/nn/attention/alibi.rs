@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Int, Tensor};
+use libm::{floorf, log2f, powf};
+
+/// Generate the slopes of the attention linear biases (ALiBi) of each head, as described in the
+/// paper [Train Short, Test Long](https://arxiv.org/abs/2108.12409).
+///
+/// The slopes form a geometric sequence starting at `2^(-8 / n_heads)` when the number of heads
+/// is a power of two. Otherwise, the slopes of the closest smaller power of two are completed by
+/// every other slope of the next power of two.
+pub fn generate_alibi_slopes(n_heads: usize) -> Vec<f32> {
+    let slopes = |n: usize| {
+        let base = powf(2.0, -8.0 / n as f32);
+        (1..=n).map(move |i| powf(base, i as f32))
+    };
+
+    let closest = powf(2.0, floorf(log2f(n_heads as f32))) as usize;
+    let mut result = slopes(closest).collect::<Vec<_>>();
+
+    if closest < n_heads {
+        result.extend(slopes(2 * closest).step_by(2).take(n_heads - closest));
+    }
+
+    result
+}
+
+/// Generate the attention linear biases (ALiBi) added to the attention scores, from the
+/// [slopes](generate_alibi_slopes) of each head.
+///
+/// The bias of each head is its slope multiplied by the negative distance between the query and
+/// the key positions. When there are fewer queries than keys, the queries are aligned with the
+/// last keys, as when the previous keys are cached.
+///
+/// # Shapes
+///
+/// - slopes: `[n_heads]`
+/// - output: `[n_heads, seq_length_1, seq_length_2]`
+pub fn generate_alibi_bias<B: Backend>(
+    slopes: Tensor<B, 1>,
+    seq_length_1: usize,
+    seq_length_2: usize,
+) -> Tensor<B, 3> {
+    let device = slopes.device();
+    let [n_heads] = slopes.dims();
+    let offset = seq_length_2.saturating_sub(seq_length_1) as i64;
+
+    let queries = Tensor::<B, 1, Int>::arange(offset..offset + seq_length_1 as i64, &device)
+        .float()
+        .reshape([seq_length_1, 1]);
+    let keys = Tensor::<B, 1, Int>::arange(0..seq_length_2 as i64, &device)
+        .float()
+        .reshape([1, seq_length_2]);
+    let distance = (queries - keys).abs();
+
+    slopes.neg().reshape([n_heads, 1, 1]) * distance.unsqueeze::<3>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_generate_alibi_slopes() {
+        Data::from(generate_alibi_slopes(8).as_slice()).assert_approx_eq(
+            &Data::from([
+                0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625, 0.0078125, 0.00390625,
+            ]),
+            6,
+        );
+        Data::from(generate_alibi_slopes(6).as_slice()).assert_approx_eq(
+            &Data::from([0.25, 0.0625, 0.015625, 0.00390625, 0.5, 0.125]),
+            6,
+        );
+    }
+
+    #[test]
+    fn test_generate_alibi_bias() {
+        let device = Default::default();
+        let slopes =
+            Tensor::<TestBackend, 1>::from_floats(generate_alibi_slopes(2).as_slice(), &device);
+        let bias = generate_alibi_bias(slopes, 2, 3);
+
+        bias.into_data().assert_approx_eq(
+            &Data::from([
+                [[-0.0625, 0.0, -0.0625], [-0.125, -0.0625, 0.0]],
+                [
+                    [-0.00390625, 0.0, -0.00390625],
+                    [-0.0078125, -0.00390625, 0.0],
+                ],
+            ]),
+            6,
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use crate as burn;
 
+use crate::nn::attention::{generate_alibi_bias, generate_alibi_slopes};
 use crate::nn::cache::TensorCache;
-use crate::nn::Initializer;
+use crate::nn::{Initializer, RotaryEncoding, RotaryEncodingConfig};
 use crate::{
     config::Config,
-    module::Module,
+    module::{AutodiffModule, ConstantRecord, Devices, Module, ModuleMapper, ModuleVisitor},
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{
+        activation,
+        backend::{AutodiffBackend, Backend},
+        Bool, Tensor,
+    },
 };
 use libm::sqrtf;
 
@@ -17,6 +22,10 @@ pub struct MultiHeadAttentionConfig {
     d_model: usize,
     /// The number of heads.
     n_heads: usize,
+    /// The number of heads of the keys and values, each of them being shared by a group of
+    /// query heads (grouped-query attention). A single head is shared by all the query heads
+    /// when it is one (multi-query attention). Default: n_heads
+    n_kv_heads: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     dropout: f64,
@@ -38,6 +47,99 @@ pub struct MultiHeadAttentionConfig {
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// The relative position information applied inside the attention. Default: None
+    pub position_encoding: Option<AttentionPositionConfig>,
+}
+
+/// Relative position information applied inside the [attention](MultiHeadAttention), instead
+/// of being added to its inputs.
+#[derive(Config, Debug)]
+pub enum AttentionPositionConfig {
+    /// Rotate the queries and keys of each head with a [rotary encoding](RotaryEncoding), whose
+    /// `d_model` is the size of each head.
+    Rotary(RotaryEncodingConfig),
+
+    /// Add the [attention linear biases](generate_alibi_bias) to the attention scores.
+    Alibi,
+}
+
+/// The relative position information applied inside the [attention](MultiHeadAttention), built
+/// from its [configuration](AttentionPositionConfig).
+///
+/// It only depends on the configuration, so it isn't saved in the record of the attention, and the
+/// records without it, such as the imported ones, load the same attention.
+#[derive(Clone, Debug)]
+pub enum AttentionPosition<B: Backend> {
+    /// No position information.
+    None,
+    /// The rotary encoding of the queries and keys of each head.
+    Rotary(RotaryEncoding<B>),
+    /// The slopes of the attention linear biases of each head.
+    Alibi(Tensor<B, 1>),
+}
+
+impl<B: Backend> Module<B> for AttentionPosition<B> {
+    type Record = ConstantRecord;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {
+        // Nothing to do
+    }
+
+    fn map<M: ModuleMapper<B>>(self, _mapper: &mut M) -> Self {
+        self
+    }
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        ConstantRecord::new()
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Rotary(rotary) => Self::Rotary(rotary.to_device(device)),
+            Self::Alibi(slopes) => Self::Alibi(slopes.to_device(device)),
+        }
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Rotary(rotary) => Self::Rotary(rotary.fork(device)),
+            Self::Alibi(slopes) => Self::Alibi(Module::fork(slopes, device)),
+        }
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        match self {
+            Self::None => devices,
+            Self::Rotary(rotary) => rotary.collect_devices(devices),
+            Self::Alibi(slopes) => slopes.collect_devices(devices),
+        }
+    }
+}
+
+impl<B: AutodiffBackend> AutodiffModule<B> for AttentionPosition<B> {
+    type InnerModule = AttentionPosition<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        match self {
+            Self::None => AttentionPosition::None,
+            Self::Rotary(rotary) => AttentionPosition::Rotary(rotary.valid()),
+            Self::Alibi(slopes) => AttentionPosition::Alibi(slopes.valid()),
+        }
+    }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        match module {
+            AttentionPosition::None => Self::None,
+            AttentionPosition::Rotary(rotary) => Self::Rotary(RotaryEncoding::from_inner(rotary)),
+            AttentionPosition::Alibi(slopes) => Self::Alibi(Tensor::from_inner(slopes)),
+        }
+    }
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -45,8 +147,8 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - query: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input features and `n_kv_heads * d_model / n_heads` output features.
+/// - value: [Linear](nn::Linear) layer with `d_model` input features and `n_kv_heads * d_model / n_heads` output features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
 #[derive(Module, Debug)]
 pub struct MultiHeadAttention<B: Backend> {
@@ -57,9 +159,11 @@ pub struct MultiHeadAttention<B: Backend> {
     dropout: nn::Dropout,
     activation: nn::Gelu,
     n_heads: usize,
+    n_kv_heads: usize,
     d_k: usize,
     min_float: f64,
     quiet_softmax: bool,
+    position: AttentionPosition<B>,
 }
 
 /// [Multihead attention](MultiHeadAttention) forward pass input argument.
@@ -75,23 +179,27 @@ pub struct MhaInput<B: Backend> {
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> MultiHeadAttention<B> {
-        let linear = |config: &Self| {
-            nn::LinearConfig::new(config.d_model, config.d_model)
+        let n_kv_heads = self.n_kv_heads();
+        let linear = |config: &Self, d_output| {
+            nn::LinearConfig::new(config.d_model, d_output)
                 .with_initializer(self.initializer.clone())
                 .init(device)
         };
+        let d_kv = n_kv_heads * self.d_model / self.n_heads;
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self, self.d_model),
+            key: linear(self, d_kv),
+            value: linear(self, d_kv),
+            output: linear(self, self.d_model),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::Gelu::new(),
             n_heads: self.n_heads,
+            n_kv_heads,
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            position: self.init_position(device),
         }
     }
 
@@ -101,21 +209,60 @@ impl MultiHeadAttentionConfig {
         &self,
         record: MultiHeadAttentionRecord<B>,
     ) -> MultiHeadAttention<B> {
-        let linear = |config: &Self, record| {
-            nn::LinearConfig::new(config.d_model, config.d_model).init_with(record)
+        let n_kv_heads = self.n_kv_heads();
+        let linear = |config: &Self, d_output, record| {
+            nn::LinearConfig::new(config.d_model, d_output).init_with(record)
         };
+        let d_kv = n_kv_heads * self.d_model / self.n_heads;
+
+        let device = record.query.weight.device();
 
         MultiHeadAttention {
-            query: linear(self, record.query),
-            key: linear(self, record.key),
-            value: linear(self, record.value),
-            output: linear(self, record.output),
+            query: linear(self, self.d_model, record.query),
+            key: linear(self, d_kv, record.key),
+            value: linear(self, d_kv, record.value),
+            output: linear(self, self.d_model, record.output),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::Gelu::new(),
             n_heads: self.n_heads,
+            n_kv_heads,
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            position: self.init_position(&device),
+        }
+    }
+
+    fn n_kv_heads(&self) -> usize {
+        let n_kv_heads = self.n_kv_heads.unwrap_or(self.n_heads);
+        assert!(
+            n_kv_heads > 0 && self.n_heads / n_kv_heads * n_kv_heads == self.n_heads,
+            "n_heads({}) must be a multiple of n_kv_heads({})",
+            self.n_heads,
+            n_kv_heads,
+        );
+
+        n_kv_heads
+    }
+
+    fn init_position<B: Backend>(&self, device: &B::Device) -> AttentionPosition<B> {
+        match &self.position_encoding {
+            Some(AttentionPositionConfig::Rotary(config)) => {
+                let d_k = self.d_model / self.n_heads;
+                assert!(
+                    config.d_model == d_k,
+                    "d_model({}) of the rotary encoding must be equal to the size of each head({})",
+                    config.d_model,
+                    d_k,
+                );
+
+                AttentionPosition::Rotary(config.init(device))
+            }
+            Some(AttentionPositionConfig::Alibi) => AttentionPosition::Alibi(Tensor::from_floats(
+                generate_alibi_slopes(self.n_heads).as_slice(),
+                device,
+            )),
+            None => AttentionPosition::None,
         }
     }
 }
@@ -178,9 +325,13 @@ impl<B: Backend> MultiHeadAttention<B> {
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
+
+        let query = self.rotate(query, 0);
+        let key = self.repeat_kv(self.rotate(key, 0));
+        let value = self.repeat_kv(value);
 
         let attn_scores = self.attn_scores(query, key);
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
@@ -204,16 +355,27 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
-
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
+        let [_, seq_length_2, _] = input.key.dims();
+
+        // Only the last positions are computed when the previous ones are cached, so they are
+        // rotated with the offset of the first position that isn't cached.
+        let query = cache.query.forward(input.query, |t| {
+            let [_, seq_length, _] = t.dims();
+            let query = self.attention_linear(t, &self.query, self.n_heads);
+            self.rotate(query, seq_length_1 - seq_length)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            let [_, seq_length, _] = t.dims();
+            let key = self.attention_linear(t, &self.key, self.n_kv_heads);
+            self.rotate(key, seq_length_2 - seq_length)
+        });
+        let value = cache.value.forward(input.value, |t| {
+            self.attention_linear(t, &self.value, self.n_kv_heads)
+        });
+
+        // The keys and values are cached before being shared by the query heads.
+        let key = self.repeat_kv(key);
+        let value = self.repeat_kv(value);
 
         let attn_scores = self.attn_scores(query, key);
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
@@ -229,10 +391,17 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
+        let mut attn_scores = query
             .matmul(key.transpose())
             .div_scalar(sqrtf(self.d_k as f32));
 
+        if let AttentionPosition::Alibi(slopes) = &self.position {
+            let [_, _, seq_length_1, seq_length_2] = attn_scores.dims();
+            let bias = generate_alibi_bias(slopes.clone(), seq_length_1, seq_length_2);
+
+            attn_scores = attn_scores + bias.unsqueeze();
+        }
+
         self.dropout.forward(attn_scores)
     }
 
@@ -267,11 +436,35 @@ impl<B: Backend> MultiHeadAttention<B> {
         }
     }
 
-    fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+    fn rotate(&self, tensor: Tensor<B, 4>, offset: usize) -> Tensor<B, 4> {
+        match &self.position {
+            AttentionPosition::Rotary(rotary) => rotary.forward_offset(tensor, offset),
+            _ => tensor,
+        }
+    }
+
+    fn repeat_kv(&self, tensor: Tensor<B, 4>) -> Tensor<B, 4> {
+        if self.n_kv_heads == self.n_heads {
+            return tensor;
+        }
+
+        let [batch_size, n_kv_heads, seq_length, d_k] = tensor.dims();
+        tensor
+            .reshape([batch_size, n_kv_heads, 1, seq_length, d_k])
+            .repeat(2, self.n_heads / n_kv_heads)
+            .reshape([batch_size, self.n_heads, seq_length, d_k])
+    }
+
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
 }
@@ -332,7 +525,11 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{
+        module::Param,
+        nn::{attention::generate_autoregressive_mask, cache::CacheState, LinearRecord},
+        TestBackend,
+    };
     use alloc::vec::Vec;
     use burn::tensor::{Distribution, Shape};
     use burn_tensor::Int;
@@ -456,9 +653,216 @@ mod tests {
 
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
-        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
+        let [d_model, n_heads] = [12, 2];
+        test_autoregressive(MultiHeadAttentionConfig::new(d_model, n_heads));
+    }
+
+    #[test]
+    fn test_autoregressive_rotary_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [12, 2];
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_position_encoding(Some(
+                AttentionPositionConfig::Rotary(RotaryEncodingConfig::new(16, d_model / n_heads)),
+            )),
+        );
+    }
+
+    #[test]
+    fn test_autoregressive_alibi_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [12, 2];
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_position_encoding(Some(AttentionPositionConfig::Alibi)),
+        );
+    }
+
+    #[test]
+    fn test_autoregressive_grouped_query_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads, n_kv_heads] = [12, 4, 2];
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_n_kv_heads(Some(n_kv_heads)),
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_match_repeated_heads() {
+        test_shared_kv_heads(2);
+    }
+
+    #[test]
+    fn test_multi_query_attention_should_match_repeated_heads() {
+        test_shared_kv_heads(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kv_heads_should_divide_heads() {
+        MultiHeadAttentionConfig::new(12, 4)
+            .with_n_kv_heads(Some(3))
+            .init::<TestBackend>(&Default::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_alibi_should_be_kept_when_saved_and_loaded() {
+        test_record_round_trip(AttentionPositionConfig::Alibi);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rotary_should_be_kept_when_saved_and_loaded() {
+        test_record_round_trip(AttentionPositionConfig::Rotary(RotaryEncodingConfig::new(
+            16, 6,
+        )));
+    }
+
+    #[test]
+    fn test_position_should_be_kept_when_loading_a_record_without_it() {
         let device = Default::default();
-        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let config = MultiHeadAttentionConfig::new(12, 2);
+        let alibi_config = config
+            .clone()
+            .with_position_encoding(Some(AttentionPositionConfig::Alibi));
+        let plain = config.init::<TestBackend>(&device);
+        let input = MhaInput::self_attn(Tensor::random([2, 4, 12], Distribution::Default, &device));
+
+        let loaded = alibi_config
+            .init::<TestBackend>(&device)
+            .load_record(plain.clone().into_record());
+        let expected = alibi_config.init_with::<TestBackend>(plain.clone().into_record());
+
+        let output = loaded.forward(input.clone()).context.into_data();
+        assert_eq!(output, expected.forward(input.clone()).context.into_data());
+        assert_ne!(output, plain.forward(input).context.into_data());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_record_round_trip(position: AttentionPositionConfig) {
+        use crate::record::{FullPrecisionSettings, NamedMpkBytesRecorder, Recorder};
+
+        let device = Default::default();
+        let config = MultiHeadAttentionConfig::new(12, 2).with_position_encoding(Some(position));
+        let mha = config.init::<TestBackend>(&device);
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(mha.clone().into_record(), ()).unwrap();
+        let loaded = config
+            .init::<TestBackend>(&device)
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        let input = MhaInput::self_attn(Tensor::random([2, 4, 12], Distribution::Default, &device));
+        assert_eq!(
+            loaded.forward(input.clone()).context.into_data(),
+            mha.forward(input).context.into_data()
+        );
+    }
+
+    /// Checks that sharing the key and value heads is the same as a regular multihead attention
+    /// whose key and value heads are repeated.
+    fn test_shared_kv_heads(n_kv_heads: usize) {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [2, 3, 5, 16, 4];
+        let d_k = d_model / n_heads;
+        let device = Default::default();
+        let gqa = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_n_kv_heads(Some(n_kv_heads))
+            .init::<TestBackend>(&device);
+
+        let repeat_heads = |record: LinearRecord<TestBackend>| {
+            let weight = record
+                .weight
+                .val()
+                .reshape([d_model, n_kv_heads, 1, d_k])
+                .repeat(2, n_heads / n_kv_heads)
+                .reshape([d_model, d_model]);
+            let bias = record.bias.unwrap().val();
+            let bias = bias
+                .reshape([n_kv_heads, 1, d_k])
+                .repeat(1, n_heads / n_kv_heads)
+                .reshape([d_model]);
+
+            LinearRecord {
+                weight: Param::from(weight),
+                bias: Some(Param::from(bias)),
+            }
+        };
+        let mut record = gqa.clone().into_record();
+        record.key = repeat_heads(record.key);
+        record.value = repeat_heads(record.value);
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init_with(record);
+
+        let input = MhaInput::new(
+            Tensor::random(
+                [batch_size, seq_length_1, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+        );
+
+        let output_1 = gqa.forward(input.clone());
+        let output_2 = mha.forward(input);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+        output_1
+            .weights
+            .into_data()
+            .assert_approx_eq(&output_2.weights.into_data(), 3);
+    }
+
+    #[test]
+    fn test_rotary_self_attention_is_shift_invariant() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 12, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_dropout(0.0)
+            .with_position_encoding(Some(AttentionPositionConfig::Rotary(
+                RotaryEncodingConfig::new(16, d_model / n_heads),
+            )))
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let query = mha.attention_linear(tensor.clone(), &mha.query, n_heads);
+        let key = mha.attention_linear(tensor, &mha.key, n_heads);
+
+        // Moving the whole sequence doesn't change the relative positions.
+        let scores_1 = mha.attn_scores(mha.rotate(query.clone(), 0), mha.rotate(key.clone(), 0));
+        let scores_2 = mha.attn_scores(mha.rotate(query, 7), mha.rotate(key, 7));
+
+        scores_1
+            .into_data()
+            .assert_approx_eq(&scores_2.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_should_match_head_size() {
+        MultiHeadAttentionConfig::new(12, 2)
+            .with_position_encoding(Some(AttentionPositionConfig::Rotary(
+                RotaryEncodingConfig::new(16, 12),
+            )))
+            .init::<TestBackend>(&Default::default());
+    }
+
+    fn test_autoregressive(config: MultiHeadAttentionConfig) {
+        let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
+        let device = Default::default();
+        let mha = config.init::<TestBackend>(&device);
 
         let tensor = Tensor::<TestBackend, 3>::random(
             [batch_size, seq_length, d_model],
@@ -489,5 +893,21 @@ mod tests {
             .context
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
+
+        // The keys and values are cached before being shared by the query heads.
+        for linear_cache in [&cache.key, &cache.value] {
+            match linear_cache {
+                MhaLinearCache::Autoregressive(
+                    TensorCache {
+                        state: CacheState::Value(tensor),
+                    },
+                    _,
+                ) => assert_eq!(
+                    tensor.dims(),
+                    [batch_size, mha.n_kv_heads, seq_length, mha.d_k]
+                ),
+                _ => panic!("The keys and values should be cached"),
+            }
+        }
     }
 }
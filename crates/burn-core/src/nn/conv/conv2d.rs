@@ -53,11 +53,11 @@ pub struct Conv2d<B: Backend> {
     pub weight: Param<Tensor<B, 4>>,
     /// Tensor of shape `[channels_out]`
     pub bias: Option<Param<Tensor<B, 1>>>,
-    stride: [usize; 2],
-    kernel_size: [usize; 2],
-    dilation: [usize; 2],
-    groups: usize,
-    padding: PaddingConfig2d,
+    pub(crate) stride: [usize; 2],
+    pub(crate) kernel_size: [usize; 2],
+    pub(crate) dilation: [usize; 2],
+    pub(crate) groups: usize,
+    pub(crate) padding: PaddingConfig2d,
 }
 
 impl Conv2dConfig {
@@ -0,0 +1,128 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [Cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The margin under which the cosine similarity of the dissimilar pairs isn't penalized.
+    /// Default: 0.0
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [Cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init(&self) -> CosineEmbeddingLoss {
+        self.assertions();
+        CosineEmbeddingLoss {
+            margin: self.margin,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            (-1.0..=1.).contains(&self.margin),
+            "Margin of Cosine embedding loss should be in interval [-1, 1]. Got {}",
+            self.margin
+        );
+    }
+}
+
+/// Calculate the cosine embedding loss, which measures whether two inputs are similar or not
+/// using their cosine similarity.
+///
+/// - `1 - cos(x1, x2)` if the target is `1`
+/// - `max(0, cos(x1, x2) - margin)` if the target is `-1`
+#[derive(Module, Clone, Debug)]
+pub struct CosineEmbeddingLoss {
+    margin: f32,
+}
+
+impl CosineEmbeddingLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, embedding_size]`
+    /// - input2: `[batch_size, embedding_size]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, embedding_size]`
+    /// - input2: `[batch_size, embedding_size]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = input1.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of inputs ({}).",
+            targets_size,
+            batch_size
+        );
+
+        // A small epsilon is added to the squared norms to avoid a division by zero.
+        let dot = (input1.clone() * input2.clone()).sum_dim(1);
+        let norms = input1.powf_scalar(2.0).sum_dim(1).add_scalar(1e-12)
+            * input2.powf_scalar(2.0).sum_dim(1).add_scalar(1e-12);
+        let cosine = (dot / norms.sqrt()).reshape([batch_size]);
+
+        let similar = cosine.clone().neg().add_scalar(1.0);
+        let dissimilar = cosine.sub_scalar(self.margin).clamp_min(0.0);
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let device = Default::default();
+        let input1 =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [1.0, 1.0], [0.0, 1.0]], &device);
+        let input2 =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0], [-1.0, 1.0], [0.0, 2.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, -1, -1], &device);
+
+        let cosine_embedding = CosineEmbeddingLossConfig::new().with_margin(0.1).init();
+        let loss_no_reduction =
+            cosine_embedding.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss = cosine_embedding.forward(input1, input2, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.29289, 0.0, 0.9]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.39763]), 3);
+    }
+}
@@ -0,0 +1,322 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// The log-probability used for the impossible alignments, instead of negative infinity which
+/// would produce NaN values in the computations.
+const NEG_INF: f32 = -1e30;
+
+/// Configuration to create a [Connectionist temporal classification loss](CtcLoss).
+#[derive(Config, Debug)]
+pub struct CtcLossConfig {
+    /// The index of the blank label. Default: 0
+    #[config(default = 0)]
+    pub blank: usize,
+    /// Replace the infinite losses of the impossible alignments by zero. Default: false
+    #[config(default = false)]
+    pub zero_infinity: bool,
+}
+
+impl CtcLossConfig {
+    /// Initialize [Connectionist temporal classification loss](CtcLoss).
+    pub fn init(&self) -> CtcLoss {
+        CtcLoss {
+            blank: self.blank,
+            zero_infinity: self.zero_infinity,
+        }
+    }
+}
+
+/// Calculate the connectionist temporal classification loss, as described in the paper
+/// [Connectionist Temporal Classification: Labelling Unsegmented Sequence Data with Recurrent Neural Networks](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+///
+/// The loss is the negative log-likelihood of the targets, summed over all of their alignments
+/// with the inputs.
+#[derive(Module, Clone, Debug)]
+pub struct CtcLoss {
+    blank: usize,
+    zero_infinity: bool,
+}
+
+impl CtcLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// The mean reduction divides the loss of each sample by its target length before
+    /// averaging over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[input_length, batch_size, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                (loss / target_lengths.float().clamp_min(1.0)).mean()
+            }
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[input_length, batch_size, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        Self::assertions(&log_probs, &targets, &input_lengths, &target_lengths);
+        let [max_input_length, batch_size, _] = log_probs.dims();
+        let [_, max_target_length] = targets.dims();
+        let device = log_probs.device();
+        let length = 2 * max_target_length + 1;
+
+        // The targets are extended with a blank label before, between and after their labels.
+        let blanks =
+            Tensor::<B, 2, Int>::full([batch_size, max_target_length], self.blank as i64, &device);
+        let extended = Tensor::cat(
+            vec![
+                Tensor::stack::<3>(vec![blanks, targets], 2)
+                    .reshape([batch_size, 2 * max_target_length]),
+                Tensor::full([batch_size, 1], self.blank as i64, &device),
+            ],
+            1,
+        );
+
+        // Log-probabilities of the extended labels at each time step: [batch_size, time, length].
+        let emissions = log_probs.swap_dims(0, 1).gather(
+            2,
+            extended
+                .clone()
+                .reshape([batch_size, 1, length])
+                .repeat(1, max_input_length),
+        );
+        let emission = |t: usize| {
+            emissions
+                .clone()
+                .narrow(1, t, 1)
+                .reshape([batch_size, length])
+        };
+
+        // A label can be skipped when it is a blank between two different labels.
+        let skip = match length > 2 {
+            true => Some(
+                extended
+                    .clone()
+                    .narrow(1, 2, length - 2)
+                    .not_equal(extended.narrow(1, 0, length - 2)),
+            ),
+            false => None,
+        };
+        let impossible = |size: usize| Tensor::<B, 2>::full([batch_size, size], NEG_INF, &device);
+
+        // The alignments start with the first blank or the first label.
+        let start = Tensor::<B, 1, Int>::arange(0..length as i64, &device)
+            .greater_elem(1)
+            .float()
+            .mul_scalar(NEG_INF)
+            .reshape([1, length]);
+        let mut alpha = emission(0) + start;
+
+        for t in 1..max_input_length {
+            let shift_one = match length > 1 {
+                true => Tensor::cat(
+                    vec![impossible(1), alpha.clone().narrow(1, 0, length - 1)],
+                    1,
+                ),
+                false => impossible(length),
+            };
+            let shift_two = match &skip {
+                Some(skip) => Tensor::cat(
+                    vec![
+                        impossible(2),
+                        alpha
+                            .clone()
+                            .narrow(1, 0, length - 2)
+                            .mask_fill(skip.clone().bool_not(), NEG_INF),
+                    ],
+                    1,
+                ),
+                None => impossible(length),
+            };
+            let next = log_sum_exp(vec![alpha.clone(), shift_one, shift_two]) + emission(t);
+
+            // The samples whose inputs already ended keep their last values.
+            let active = input_lengths
+                .clone()
+                .greater_elem(t as i64)
+                .reshape([batch_size, 1])
+                .repeat(1, length);
+            alpha = alpha.mask_where(active, next);
+        }
+
+        // The alignments end with the last label or the last blank.
+        let last = target_lengths
+            .clone()
+            .mul_scalar(2)
+            .reshape([batch_size, 1]);
+        let before_last = last.clone().sub_scalar(1).clamp_min(0);
+        let empty = target_lengths.equal_elem(0).reshape([batch_size, 1]);
+        let loss = log_sum_exp(vec![
+            alpha.clone().gather(1, last),
+            alpha.gather(1, before_last).mask_fill(empty, NEG_INF),
+        ])
+        .neg()
+        .reshape([batch_size]);
+
+        let infinite = loss.clone().greater_elem(-NEG_INF / 10.0);
+        match self.zero_infinity {
+            true => loss.mask_fill(infinite, 0.0),
+            false => loss.mask_fill(infinite, f32::INFINITY),
+        }
+    }
+
+    fn assertions<B: Backend>(
+        log_probs: &Tensor<B, 3>,
+        targets: &Tensor<B, 2, Int>,
+        input_lengths: &Tensor<B, 1, Int>,
+        target_lengths: &Tensor<B, 1, Int>,
+    ) {
+        let [_, batch_size, _] = log_probs.dims();
+        let [targets_size, _] = targets.dims();
+        let [input_lengths_size] = input_lengths.dims();
+        let [target_lengths_size] = target_lengths.dims();
+        assert!(
+            batch_size == targets_size
+                && batch_size == input_lengths_size
+                && batch_size == target_lengths_size,
+            "Batch size of log_probs ({}), targets ({}), input_lengths ({}) and target_lengths ({}) should be equal.",
+            batch_size,
+            targets_size,
+            input_lengths_size,
+            target_lengths_size
+        );
+    }
+}
+
+/// Computes the logarithm of the sum of the exponentials of the tensors, element-wise.
+fn log_sum_exp<B: Backend>(tensors: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+    let max = tensors
+        .iter()
+        .skip(1)
+        .fold(tensors[0].clone(), |max, tensor| {
+            max.max_pair(tensor.clone())
+        });
+    let sum = tensors
+        .into_iter()
+        .map(|tensor| (tensor - max.clone()).exp())
+        .reduce(|lhs, rhs| lhs + rhs)
+        .unwrap();
+
+    max + sum.log()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::{activation::log_softmax, Data};
+
+    fn log_probs() -> Tensor<TestBackend, 3> {
+        let logits = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[0.1, 0.5, -0.3], [0.2, -0.1, 0.4]],
+                [[0.7, 0.2, 0.1], [-0.5, 0.3, 0.9]],
+                [[0.3, -0.2, 0.6], [0.1, 0.1, 0.1]],
+                [[0.0, 0.8, -0.4], [0.5, 0.2, -0.3]],
+            ],
+            &Default::default(),
+        );
+
+        log_softmax(logits, 2)
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let device = Default::default();
+        let targets = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [1, 1]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_ints([4, 3], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_ints([2, 2], &device);
+
+        let ctc = CtcLossConfig::new().init();
+        let loss_no_reduction = ctc.forward_no_reduction(
+            log_probs(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss = ctc.forward(
+            log_probs(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+            Reduction::Auto,
+        );
+        let loss_sum = ctc.forward(
+            log_probs(),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.839_745, 4.469_784]), 4);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.577_382]), 4);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([6.309_53]), 4);
+    }
+
+    #[test]
+    fn test_ctc_loss_impossible_alignment() {
+        let device = Default::default();
+        let targets = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [1, 1]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_ints([4, 2], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_ints([2, 2], &device);
+
+        let loss = CtcLossConfig::new().init().forward_no_reduction(
+            log_probs(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_zero_infinity = CtcLossConfig::new()
+            .with_zero_infinity(true)
+            .init()
+            .forward_no_reduction(log_probs(), targets, input_lengths, target_lengths);
+
+        let loss = loss.into_data().value;
+        assert!((loss[0] - 1.839_745).abs() < 1e-4);
+        assert!(loss[1].is_infinite());
+        loss_zero_infinity
+            .into_data()
+            .assert_approx_eq(&Data::from([1.839_745, 0.0]), 4);
+    }
+}
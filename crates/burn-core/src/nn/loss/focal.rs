@@ -0,0 +1,159 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::activation::sigmoid;
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [Focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, which reduces the loss of the well-classified samples. Default: 2.0
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// The weight of the positive samples, the negative samples being weighted by `1 - alpha`.
+    ///
+    /// The samples are not weighted when it is `None`.
+    pub alpha: Option<f32>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [Focal loss](FocalLoss).
+    pub fn init(&self) -> FocalLoss {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self.alpha,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.,
+            "Gamma of Focal loss should be non-negative. Got {}",
+            self.gamma
+        );
+        if let Some(alpha) = self.alpha {
+            assert!(
+                (0.0..=1.).contains(&alpha),
+                "Alpha of Focal loss should be in interval [0, 1]. Got {}",
+                alpha
+            );
+        }
+    }
+}
+
+/// Calculate the binary focal loss from the input logits and the targets, as described in the
+/// paper [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+///
+/// The binary cross-entropy of each element is multiplied by `(1 - p_t)^gamma`, where `p_t` is
+/// the probability of the target class.
+#[derive(Module, Clone, Debug)]
+pub struct FocalLoss {
+    gamma: f32,
+    alpha: Option<f32>,
+}
+
+impl FocalLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        // Binary cross-entropy with logits, in its numerically stable form.
+        let cross_entropy = logits.clone().clamp_min(0.0) - logits.clone() * targets.clone()
+            + logits.clone().abs().neg().exp().log1p();
+
+        let probs = sigmoid(logits);
+        let probs_target = probs.clone() * targets.clone()
+            + (probs.neg().add_scalar(1.0)) * (targets.clone().neg().add_scalar(1.0));
+        let loss = cross_entropy * probs_target.neg().add_scalar(1.0).powf_scalar(self.gamma);
+
+        match self.alpha {
+            Some(alpha) => {
+                let weights = targets
+                    .mul_scalar(2.0 * alpha - 1.0)
+                    .add_scalar(1.0 - alpha);
+                loss * weights
+            }
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_focal_loss() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.5, 2.0], &device);
+        let targets = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 1.0], &device);
+
+        let focal = FocalLossConfig::new().init();
+        let loss_no_reduction = focal.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_sum = focal.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.022658, 0.067573, 0.001804]), 4);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([0.092035]), 4);
+    }
+
+    #[test]
+    fn test_focal_loss_with_alpha() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.5, 2.0], &device);
+        let targets = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 1.0], &device);
+
+        let loss = FocalLossConfig::new()
+            .with_alpha(Some(0.25))
+            .init()
+            .forward_no_reduction(logits, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.016994, 0.016893, 0.000451]), 4);
+    }
+
+    #[test]
+    fn test_focal_loss_without_focusing_is_binary_cross_entropy() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.5, 2.0], &device);
+        let targets = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 1.0], &device);
+
+        let loss = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .init()
+            .forward_no_reduction(logits, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.313262, 0.474077, 0.126928]), 4);
+    }
+}
@@ -0,0 +1,186 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [Huber loss](HuberLoss).
+#[derive(Config, Debug)]
+pub struct HuberLossConfig {
+    /// The bound where the loss changes from quadratic to linear. Default: 1.0
+    #[config(default = 1.0)]
+    pub delta: f32,
+}
+
+impl HuberLossConfig {
+    /// Initialize [Huber loss](HuberLoss).
+    pub fn init(&self) -> HuberLoss {
+        self.assertions();
+        HuberLoss { delta: self.delta }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.delta > 0.,
+            "Delta of Huber loss should be positive. Got {}",
+            self.delta
+        );
+    }
+}
+
+/// Calculate the Huber loss between the predictions and the targets.
+///
+/// The loss is quadratic for the errors smaller than `delta`, and linear for the larger ones:
+///
+/// - `0.5 * error^2` if `|error| <= delta`
+/// - `delta * (|error| - 0.5 * delta)` otherwise
+#[derive(Module, Clone, Debug)]
+pub struct HuberLoss {
+    delta: f32,
+}
+
+impl HuberLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = predictions.sub(targets).abs();
+        // The error is split into the part below `delta` and the part above it, which is zero
+        // when the error is small.
+        let quadratic = error.clone().clamp_max(self.delta);
+        let linear = error.sub(quadratic.clone());
+
+        quadratic.powf_scalar(2.0).mul_scalar(0.5) + linear.mul_scalar(self.delta)
+    }
+}
+
+/// Configuration to create a [Smooth L1 loss](SmoothL1Loss).
+#[derive(Config, Debug)]
+pub struct SmoothL1LossConfig {
+    /// The bound where the loss changes from quadratic to linear. Default: 1.0
+    #[config(default = 1.0)]
+    pub beta: f32,
+}
+
+impl SmoothL1LossConfig {
+    /// Initialize [Smooth L1 loss](SmoothL1Loss).
+    pub fn init(&self) -> SmoothL1Loss {
+        assert!(
+            self.beta > 0.,
+            "Beta of Smooth L1 loss should be positive. Got {}",
+            self.beta
+        );
+        SmoothL1Loss {
+            huber: HuberLoss { delta: self.beta },
+        }
+    }
+}
+
+/// Calculate the Smooth L1 loss between the predictions and the targets, which is the
+/// [Huber loss](HuberLoss) divided by `beta`:
+///
+/// - `0.5 * error^2 / beta` if `|error| < beta`
+/// - `|error| - 0.5 * beta` otherwise
+#[derive(Module, Clone, Debug)]
+pub struct SmoothL1Loss {
+    huber: HuberLoss,
+}
+
+impl SmoothL1Loss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        self.huber
+            .forward_no_reduction(predictions, targets)
+            .div_scalar(self.huber.delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_huber_loss() {
+        let device = Default::default();
+        let predictions =
+            Tensor::<TestBackend, 1>::from_floats([-2.0, -0.5, 0.0, 0.3, 2.0], &device);
+        let targets = Tensor::<TestBackend, 1>::from_floats([0.0, 0.0, 0.0, 0.0, 0.5], &device);
+
+        let huber = HuberLossConfig::new().with_delta(0.5).init();
+        let loss_no_reduction = huber.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = huber.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = huber.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.875, 0.125, 0.0, 0.045, 0.625]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.334]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([1.67]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss() {
+        let device = Default::default();
+        let predictions =
+            Tensor::<TestBackend, 1>::from_floats([-2.0, -0.5, 0.0, 0.3, 2.0], &device);
+        let targets = Tensor::<TestBackend, 1>::from_floats([0.0, 0.0, 0.0, 0.0, 0.5], &device);
+
+        let loss = SmoothL1LossConfig::new()
+            .with_beta(0.5)
+            .init()
+            .forward_no_reduction(predictions, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.75, 0.25, 0.0, 0.09, 1.25]), 3);
+    }
+}
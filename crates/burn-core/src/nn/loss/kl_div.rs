@@ -0,0 +1,122 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [Kullback-Leibler divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// Whether the targets are given as log-probabilities instead of probabilities.
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [Kullback-Leibler divergence loss](KlDivLoss).
+    pub fn init(&self) -> KlDivLoss {
+        KlDivLoss {
+            log_target: self.log_target,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence between the targets and the predictions.
+///
+/// The predictions are log-probabilities, and the loss of each element is
+/// `target * (log(target) - prediction)`, which is zero where the target is zero.
+#[derive(Module, Clone, Debug)]
+pub struct KlDivLoss {
+    log_target: bool,
+}
+
+impl KlDivLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp() * targets.sub(predictions);
+        }
+
+        // The zero targets are replaced by one before the logarithm, so they don't have a loss.
+        let zeros = targets.clone().equal_elem(0.0);
+        let log_targets = targets.clone().mask_fill(zeros, 1.0).log();
+
+        targets * log_targets.sub(predictions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let predictions =
+            Tensor::<TestBackend, 2>::from_floats([[0.2, 0.3, 0.5], [0.25, 0.25, 0.5]], &device)
+                .log();
+        let targets =
+            Tensor::<TestBackend, 2>::from_floats([[0.1, 0.6, 0.3], [0.0, 0.5, 0.5]], &device);
+
+        let kl_div = KlDivLossConfig::new().init();
+        let loss_no_reduction = kl_div.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = kl_div.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = kl_div.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[-0.06931, 0.41589, -0.15325], [0.0, 0.34657, 0.0]]),
+            3,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([0.08998]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5399]), 3);
+    }
+
+    #[test]
+    fn test_kl_div_loss_with_log_target() {
+        let device = Default::default();
+        let predictions =
+            Tensor::<TestBackend, 2>::from_floats([[0.2, 0.3, 0.5], [0.25, 0.25, 0.5]], &device)
+                .log();
+        let targets =
+            Tensor::<TestBackend, 2>::from_floats([[0.1, 0.6, 0.3], [0.2, 0.3, 0.5]], &device)
+                .log();
+
+        let loss = KlDivLossConfig::new()
+            .with_log_target(true)
+            .init()
+            .forward_no_reduction(predictions, targets);
+
+        loss.into_data().assert_approx_eq(
+            &Data::from([[-0.06931, 0.41589, -0.15325], [-0.04463, 0.0547, 0.0]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [L1 loss](L1Loss).
+#[derive(Config, Debug)]
+pub struct L1LossConfig {}
+
+impl L1LossConfig {
+    /// Initialize [L1 loss](L1Loss).
+    pub fn init(&self) -> L1Loss {
+        L1Loss {}
+    }
+}
+
+/// Calculate the mean absolute error loss between the predictions and the targets.
+#[derive(Module, Clone, Debug, Default)]
+pub struct L1Loss {}
+
+impl L1Loss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        predictions.sub(targets).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_l1_loss() {
+        let device = Default::default();
+        let predictions =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [3.0, 1.5]]), &device);
+
+        let l1 = L1LossConfig::new().init();
+        let loss_no_reduction = l1.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = l1.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = l1.forward(predictions, targets, Reduction::Sum);
+
+        assert_eq!(
+            loss_no_reduction.into_data(),
+            Data::from([[1.0, 1.0], [0.0, 2.5]])
+        );
+        assert_eq!(loss.into_data(), Data::from([1.125]));
+        assert_eq!(loss_sum.into_data(), Data::from([4.5]));
+    }
+}
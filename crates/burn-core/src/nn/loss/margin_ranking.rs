@@ -0,0 +1,92 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [Margin ranking loss](MarginRankingLoss).
+#[derive(Config, Debug)]
+pub struct MarginRankingLossConfig {
+    /// The margin by which the inputs should be ranked. Default: 0.0
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl MarginRankingLossConfig {
+    /// Initialize [Margin ranking loss](MarginRankingLoss).
+    pub fn init(&self) -> MarginRankingLoss {
+        MarginRankingLoss {
+            margin: self.margin,
+        }
+    }
+}
+
+/// Calculate the margin ranking loss, which measures whether the first input should be ranked
+/// higher (target `1`) or lower (target `-1`) than the second input:
+///
+/// `max(0, -target * (x1 - x2) + margin)`
+#[derive(Module, Clone, Debug)]
+pub struct MarginRankingLoss {
+    margin: f32,
+}
+
+impl MarginRankingLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[...dims]`
+    /// - input2: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<B: Backend, const D: usize>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+    ) -> Tensor<B, D> {
+        (targets.float().neg() * input1.sub(input2))
+            .add_scalar(self.margin)
+            .clamp_min(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_margin_ranking_loss() {
+        let device = Default::default();
+        let input1 = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0], &device);
+        let input2 = Tensor::<TestBackend, 1>::from_floats([2.0, 2.0, 1.0], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, -1, 1], &device);
+
+        let margin_ranking = MarginRankingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction =
+            margin_ranking.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_sum = margin_ranking.forward(input1, input2, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, 0.5, 0.0]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([2.0]), 3);
+    }
+}
@@ -0,0 +1,157 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [Negative log likelihood loss](NllLoss).
+#[derive(Config, Debug)]
+pub struct NllLossConfig {
+    /// Create weighted negative log likelihood, with one weight per class.
+    ///
+    /// The loss of a sample is multiplied by the weight of its target, and the mean reduction
+    /// divides by the sum of the weights of the targets.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl NllLossConfig {
+    /// Initialize [Negative log likelihood loss](NllLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> NllLoss<B> {
+        self.assertions();
+        NllLoss {
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<B, 1>::from_floats(e.as_slice(), device)),
+        }
+    }
+
+    fn assertions(&self) {
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of negative log likelihood have to be positive."
+            );
+        }
+    }
+}
+
+/// Calculate the negative log likelihood loss from the input log-probabilities and the targets.
+#[derive(Module, Debug)]
+pub struct NllLoss<B: Backend> {
+    /// Weights for negative log likelihood.
+    pub weights: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> NllLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(log_probs, targets.clone());
+        match reduction {
+            Reduction::Mean | Reduction::Auto => match &self.weights {
+                Some(weights) => loss.sum() / weights.clone().gather(0, targets).sum(),
+                None => loss.mean(),
+            },
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        Self::assertions(&log_probs, &targets);
+        let [batch_size] = targets.dims();
+        let loss = log_probs
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .neg();
+
+        match &self.weights {
+            Some(weights) => loss * weights.clone().gather(0, targets),
+            None => loss,
+        }
+    }
+
+    fn assertions(log_probs: &Tensor<B, 2>, targets: &Tensor<B, 1, Int>) {
+        let [log_probs_height, _] = log_probs.dims();
+        let [targets_height] = targets.dims();
+        assert!(
+            log_probs_height == targets_height,
+            "Shape of targets ({}) should correspond to outer shape of log_probs ({}).",
+            targets_height,
+            log_probs_height
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec;
+    use burn_tensor::Data;
+
+    fn inputs() -> (Tensor<TestBackend, 2>, Tensor<TestBackend, 1, Int>) {
+        let device = Default::default();
+        let log_probs = Tensor::from_floats(
+            [[-0.5, -1.2, -2.0], [-1.5, -0.3, -2.5], [-0.9, -0.7, -1.9]],
+            &device,
+        );
+        let targets = Tensor::from_ints([0, 2, 1], &device);
+
+        (log_probs, targets)
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        let (log_probs, targets) = inputs();
+
+        let nll = NllLossConfig::new().init(&Default::default());
+        let loss_no_reduction = nll.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss = nll.forward(log_probs.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = nll.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 2.5, 0.7]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.23333]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([3.7]), 3);
+    }
+
+    #[test]
+    fn test_nll_loss_with_weights() {
+        let (log_probs, targets) = inputs();
+
+        let nll = NllLossConfig::new()
+            .with_weights(Some(vec![1.0, 2.0, 3.0]))
+            .init(&Default::default());
+        let loss_no_reduction = nll.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss = nll.forward(log_probs, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 7.5, 1.4]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.56667]), 3);
+    }
+}
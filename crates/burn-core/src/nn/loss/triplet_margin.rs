@@ -0,0 +1,184 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [Triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin between the positive and the negative distances. Default: 1.0
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The norm degree of the pairwise distance. Default: 2.0
+    #[config(default = 2.0)]
+    pub p: f32,
+    /// A small value added to the differences to avoid a zero distance. Default: 1e-6
+    #[config(default = 1e-6)]
+    pub epsilon: f32,
+    /// Use the distance between the positive and the negative samples when it is smaller than
+    /// the anchor one, as described in the paper
+    /// [Learning shallow convolutional feature descriptors with triplet losses](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+    /// Default: false
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [Triplet margin loss](TripletMarginLoss).
+    pub fn init(&self) -> TripletMarginLoss {
+        self.assertions();
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            epsilon: self.epsilon,
+            swap: self.swap,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.p > 0.,
+            "Norm degree of Triplet margin loss should be positive. Got {}",
+            self.p
+        );
+    }
+}
+
+/// Calculate the triplet margin loss, which measures whether the anchors are closer to the
+/// positive samples than to the negative samples by a margin:
+///
+/// `max(0, d(anchor, positive) - d(anchor, negative) + margin)`
+#[derive(Module, Clone, Debug)]
+pub struct TripletMarginLoss {
+    margin: f32,
+    p: f32,
+    epsilon: f32,
+    swap: bool,
+}
+
+impl TripletMarginLoss {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, embedding_size]`
+    /// - positives: `[batch_size, embedding_size]`
+    /// - negatives: `[batch_size, embedding_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(anchors, positives, negatives);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, embedding_size]`
+    /// - positives: `[batch_size, embedding_size]`
+    /// - negatives: `[batch_size, embedding_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let distance_positive = self.distance(anchors.clone(), positives.clone());
+        let mut distance_negative = self.distance(anchors, negatives.clone());
+
+        if self.swap {
+            distance_negative = distance_negative.min_pair(self.distance(positives, negatives));
+        }
+
+        (distance_positive - distance_negative)
+            .add_scalar(self.margin)
+            .clamp_min(0.0)
+    }
+
+    fn distance<B: Backend>(&self, lhs: Tensor<B, 2>, rhs: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = lhs.dims();
+
+        lhs.sub(rhs)
+            .add_scalar(self.epsilon)
+            .abs()
+            .powf_scalar(self.p)
+            .sum_dim(1)
+            .powf_scalar(1.0 / self.p)
+            .reshape([batch_size])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    type Triplet = (
+        Tensor<TestBackend, 2>,
+        Tensor<TestBackend, 2>,
+        Tensor<TestBackend, 2>,
+    );
+
+    fn inputs() -> Triplet {
+        let device = Default::default();
+        let anchors = Tensor::from_floats([[0.0, 0.0], [1.0, 1.0]], &device);
+        let positives = Tensor::from_floats([[2.0, 0.0], [1.0, 3.0]], &device);
+        let negatives = Tensor::from_floats([[2.0, 1.0], [3.0, 1.0]], &device);
+
+        (anchors, positives, negatives)
+    }
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let (anchors, positives, negatives) = inputs();
+
+        let triplet = TripletMarginLossConfig::new().init();
+        let loss_no_reduction =
+            triplet.forward_no_reduction(anchors.clone(), positives.clone(), negatives.clone());
+        let loss = triplet.forward(anchors, positives, negatives, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.76393, 1.0]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.88197]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_with_swap() {
+        let (anchors, positives, negatives) = inputs();
+
+        let loss = TripletMarginLossConfig::new()
+            .with_swap(true)
+            .init()
+            .forward_no_reduction(anchors, positives, negatives);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([2.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_with_l1_distance() {
+        let (anchors, positives, negatives) = inputs();
+
+        let loss = TripletMarginLossConfig::new()
+            .with_p(1.0)
+            .with_margin(0.5)
+            .init()
+            .forward_no_reduction(anchors, positives, negatives);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.5]), 3);
+    }
+}
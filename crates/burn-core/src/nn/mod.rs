@@ -13,6 +13,9 @@ pub mod loss;
 /// Pooling module
 pub mod pool;
 
+/// Quantization module
+pub mod quantization;
+
 /// Transformer module
 pub mod transformer;
 
@@ -27,6 +30,7 @@ mod pos_encoding;
 mod prelu;
 mod relu;
 mod rnn;
+mod rotary_encoding;
 mod unfold;
 
 pub use dropout::*;
@@ -40,4 +44,5 @@ pub use pos_encoding::*;
 pub use prelu::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rotary_encoding::*;
 pub use unfold::*;
@@ -14,15 +14,18 @@ pub struct LayerNormConfig {
     /// A value required for numerical stability. Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
+    /// If a learnable bias should be applied. Default: true
+    #[config(default = true)]
+    pub bias: bool,
 }
 
 /// Applies Layer Normalization over an input tensor as described in the paper [Layer Normalization](https://arxiv.org/abs/1607.06450).
 ///
-/// `Y = norm(X) * γ + β`
+/// `Y = norm(X) * γ + β`, where `β` is omitted when the layer has no bias.
 #[derive(Module, Debug)]
 pub struct LayerNorm<B: Backend> {
     gamma: Param<Tensor<B, 1>>,
-    beta: Param<Tensor<B, 1>>,
+    beta: Option<Param<Tensor<B, 1>>>,
     epsilon: f64,
 }
 
@@ -30,11 +33,14 @@ impl LayerNormConfig {
     /// Initialize a new [layer norm](LayerNorm) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> LayerNorm<B> {
         let gamma = Tensor::ones([self.d_model], device);
-        let beta = Tensor::zeros([self.d_model], device);
+        let beta = match self.bias {
+            true => Some(Param::from(Tensor::zeros([self.d_model], device))),
+            false => None,
+        };
 
         LayerNorm {
             gamma: Param::from(gamma),
-            beta: Param::from(beta),
+            beta,
             epsilon: self.epsilon,
         }
     }
@@ -61,9 +67,12 @@ impl<B: Backend> LayerNorm<B> {
 
         let input_normalized = input.sub(mean).div(var.sqrt().add_scalar(self.epsilon));
 
-        input_normalized
-            .mul(self.gamma.val().unsqueeze())
-            .add(self.beta.val().unsqueeze())
+        let output = input_normalized.mul(self.gamma.val().unsqueeze());
+
+        match &self.beta {
+            Some(beta) => output.add(beta.val().unsqueeze()),
+            None => output,
+        }
     }
 }
 
@@ -99,6 +108,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn layer_norm_forward_no_bias() {
+        let device = Default::default();
+        let module = LayerNormConfig::new(4)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::from_data(Data::from([[1.0, 2.0, 3.0, 6.0]]), &device);
+
+        let output = module.forward(input);
+
+        assert!(module.beta.is_none());
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.069_039, -0.534_52, 0.0, 1.603_559]]), 3);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn layer_norm_backward() {
@@ -123,7 +148,7 @@ mod tests {
         let tensor_1_grad = tensor_1.grad(&grads).unwrap();
         let tensor_2_grad = tensor_2.grad(&grads).unwrap();
         let gamma_grad = module.gamma.grad(&grads).unwrap();
-        let beta_grad = module.beta.grad(&grads).unwrap();
+        let beta_grad = module.beta.as_ref().unwrap().grad(&grads).unwrap();
 
         gamma_grad
             .to_data()
@@ -0,0 +1,145 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::PadMode;
+
+/// Configuration to create a [LocalResponseNorm](LocalResponseNorm) layer.
+#[derive(Config, Debug)]
+pub struct LocalResponseNormConfig {
+    /// The number of neighbouring channels used for the normalization.
+    pub size: usize,
+    /// The scaling factor of the sum of squares. Default: 1e-4
+    #[config(default = 1e-4)]
+    pub alpha: f64,
+    /// The exponent of the normalization. Default: 0.75
+    #[config(default = 0.75)]
+    pub beta: f64,
+    /// The constant added to the scaled sum of squares. Default: 1.0
+    #[config(default = 1.0)]
+    pub k: f64,
+}
+
+impl LocalResponseNormConfig {
+    /// Initialize a new [local response norm](LocalResponseNorm) module.
+    pub fn init(&self) -> LocalResponseNorm {
+        assert!(
+            self.size > 0,
+            "The size of local response norm should be positive. Got {}",
+            self.size
+        );
+
+        LocalResponseNorm {
+            size: self.size,
+            alpha: self.alpha,
+            beta: self.beta,
+            k: self.k,
+        }
+    }
+}
+
+/// Applies Local Response Normalization over an input tensor as described in the paper
+/// [ImageNet Classification with Deep Convolutional Neural Networks](https://papers.nips.cc/paper/4824-imagenet-classification-with-deep-convolutional-neural-networks).
+///
+/// Each channel is normalized by the sum of squares of the `size` channels around it:
+///
+/// `Y[c] = X[c] / (k + alpha / size * sum(X[c']^2))^beta`
+///
+/// The channels summed go from `c - floor((size - 1) / 2)` to `c + ceil((size - 1) / 2)`, as in
+/// the ONNX `LRN` operator.
+#[derive(Module, Clone, Debug)]
+pub struct LocalResponseNorm {
+    size: usize,
+    alpha: f64,
+    beta: f64,
+    k: f64,
+}
+
+impl LocalResponseNorm {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let channels = input.dims()[1];
+        let before = (self.size - 1) / 2;
+        let after = self.size - 1 - before;
+
+        let mut pads = [(0, 0); D];
+        pads[1] = (before, after);
+        let squares = input
+            .clone()
+            .powf_scalar(2.0)
+            .pad(pads, PadMode::Constant(0.0));
+
+        let sum = (1..self.size).fold(squares.clone().narrow(1, 0, channels), |sum, offset| {
+            sum.add(squares.clone().narrow(1, offset, channels))
+        });
+
+        let scale = sum
+            .mul_scalar(self.alpha / self.size as f64)
+            .add_scalar(self.k)
+            .powf_scalar(self.beta);
+
+        input.div(scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn local_response_norm_forward() {
+        let device = Default::default();
+        let module = LocalResponseNormConfig::new(3).with_alpha(1.0).init();
+        let input = Tensor::<TestBackend, 3>::from_data(
+            Data::from([[[1.0, -2.0], [0.5, 3.0], [-1.5, 0.0], [2.0, 1.0]]]),
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [0.7701, -0.56988],
+                [0.27998, 0.85482],
+                [-0.63189, 0.0],
+                [0.85954, 0.80593],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn local_response_norm_forward_even_size() {
+        let device = Default::default();
+        let module = LocalResponseNormConfig::new(2)
+            .with_alpha(0.5)
+            .with_beta(0.5)
+            .with_k(2.0)
+            .init();
+        let input = Tensor::<TestBackend, 3>::from_data(
+            Data::from([[[1.0, -2.0], [0.5, 3.0], [-1.5, 0.0], [2.0, 1.0]]]),
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [0.6576, -0.87287],
+                [0.30861, 1.45521],
+                [-0.79472, 0.0],
+                [1.1547, 0.66667],
+            ]]),
+            3,
+        );
+    }
+}
@@ -2,8 +2,16 @@ mod batch;
 mod group;
 mod instance;
 mod layer;
+mod local_response;
+mod rms;
+mod spectral;
+mod weight;
 
 pub use batch::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
+pub use local_response::*;
+pub use rms::*;
+pub use spectral::*;
+pub use weight::*;
@@ -0,0 +1,95 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [RmsNorm](RmsNorm) layer.
+#[derive(Config)]
+pub struct RmsNormConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+}
+
+/// Applies Root Mean Square Layer Normalization over an input tensor as described in the paper
+/// [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// `Y = X / sqrt(mean(X^2) + ε) * γ`
+///
+/// Unlike [LayerNorm](crate::nn::LayerNorm), the input is neither centered nor shifted by a bias.
+#[derive(Module, Debug)]
+pub struct RmsNorm<B: Backend> {
+    gamma: Param<Tensor<B, 1>>,
+    epsilon: f64,
+}
+
+impl RmsNormConfig {
+    /// Initialize a new [RMS norm](RmsNorm) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RmsNorm<B> {
+        let gamma = Tensor::ones([self.d_model], device);
+
+        RmsNorm {
+            gamma: Param::from(gamma),
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [RMS norm](RmsNorm) module with a [record](RmsNormRecord).
+    pub fn init_with<B: Backend>(&self, record: RmsNormRecord<B>) -> RmsNorm<B> {
+        RmsNorm {
+            gamma: record.gamma,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> RmsNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let rms = input
+            .clone()
+            .powf_scalar(2.0)
+            .mean_dim(D - 1)
+            .add_scalar(self.epsilon)
+            .sqrt();
+
+        input.div(rms).mul(self.gamma.val().unsqueeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn rms_norm_forward() {
+        let device = Default::default();
+        let module = RmsNormConfig::new(4).init::<TestBackend>(&device);
+        let input = Tensor::from_data(
+            Data::from([[[1.0, 2.0, 3.0, 6.0], [-0.5, 0.0, 1.5, 2.0]]]),
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [0.282_842, 0.565_685, 0.848_528, 1.697_055],
+                [-0.392_231, 0.0, 1.176_693, 1.568_924],
+            ]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate as burn;
+
+use super::weight::WeightedModule;
+use crate::config::Config;
+use crate::module::{Module, RunningState};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [SpectralNorm](SpectralNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The dimension of the weight corresponding to the rows of the matrix whose spectral norm
+    /// is computed.
+    ///
+    /// The dimension of the output features is used when it is `None`.
+    pub dim: Option<usize>,
+    /// The number of power iterations done on each training forward pass. Default: 1
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Divides the weight of a module by its spectral norm, as described in the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957).
+///
+/// `W = V / σ(V)`
+///
+/// The spectral norm `σ` of the weight of the wrapped module is estimated by power iteration, the
+/// estimate of its first left singular vector being kept as a running state, which is refined
+/// during training and used as is during inference.
+#[derive(Module, Debug)]
+pub struct SpectralNorm<B: Backend, M> {
+    module: M,
+    u: RunningState<Tensor<B, 1>>,
+    dim: usize,
+    n_power_iterations: usize,
+    epsilon: f64,
+}
+
+impl SpectralNormConfig {
+    /// Initialize a new [spectral norm](SpectralNorm) wrapper around the module.
+    pub fn init<B: Backend, M: WeightedModule<B>>(&self, module: M) -> SpectralNorm<B, M> {
+        let dim = self.dim.unwrap_or(M::OUTPUT_DIM);
+        let weight = module.weight_matrix(dim);
+        let [rows, _] = weight.dims();
+
+        let u = Tensor::random([rows], Distribution::Normal(0.0, 1.0), &weight.device());
+        let u = normalize(u.unsqueeze_dim(1), self.epsilon).reshape([rows]);
+
+        SpectralNorm {
+            module,
+            u: RunningState::new(u.detach()),
+            dim,
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, M: WeightedModule<B>> SpectralNorm<B, M> {
+    /// The wrapped module with its weight divided by its spectral norm, whose forward pass is
+    /// applied by the caller.
+    ///
+    /// When autodiff is enabled, the estimate of the spectral norm is refined by power iteration.
+    pub fn module(&self) -> M {
+        let weight = self.module.weight_matrix(self.dim);
+        let [rows, _] = weight.dims();
+        let matrix = weight.clone().detach();
+
+        let u = match B::ad_enabled() {
+            true => {
+                let mut u = self
+                    .u
+                    .value_sync()
+                    .to_device(&matrix.device())
+                    .unsqueeze_dim(1);
+
+                for _ in 0..self.n_power_iterations {
+                    let v = normalize(matrix.clone().transpose().matmul(u), self.epsilon);
+                    u = normalize(matrix.clone().matmul(v), self.epsilon);
+                }
+
+                self.u.update(u.clone().reshape([rows]).detach());
+                u
+            }
+            false => self.u.value().to_device(&matrix.device()).unsqueeze_dim(1),
+        };
+        let v = normalize(matrix.transpose().matmul(u.clone()), self.epsilon);
+
+        // The singular vectors are constants, the gradient only flowing through the weight.
+        let sigma = u.transpose().matmul(weight.clone().matmul(v));
+
+        self.module
+            .clone()
+            .with_weight_matrix(weight.div(sigma), self.dim)
+    }
+}
+
+/// Normalizes a column vector.
+fn normalize<B: Backend>(vector: Tensor<B, 2>, epsilon: f64) -> Tensor<B, 2> {
+    let norm = vector
+        .clone()
+        .powf_scalar(2.0)
+        .sum()
+        .sqrt()
+        .clamp_min(epsilon)
+        .unsqueeze();
+
+    vector.div(norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::LinearConfig;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn spectral_norm_linear() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from(Tensor::from_floats([[3.0, 0.0], [0.0, 1.0]], &device));
+        let spectral_norm = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init(linear);
+
+        let weight = spectral_norm.module().weight.val();
+
+        weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 0.333_333]]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn spectral_norm_keeps_estimate_for_inference() {
+        use crate::module::AutodiffModule;
+
+        let device = Default::default();
+        let mut linear = LinearConfig::new(3, 2)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from(Tensor::from_floats(
+            [[1.0, 2.0], [0.0, -1.0], [2.0, 0.5]],
+            &device,
+        ));
+        let spectral_norm = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init(linear);
+
+        let weight = spectral_norm.module().weight.val().inner();
+        let weight_inference = spectral_norm.valid().module().weight.val();
+
+        weight_inference
+            .to_data()
+            .assert_approx_eq(&weight.to_data(), 3);
+    }
+
+    #[test]
+    fn spectral_norm_inference() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        linear.weight = Param::from(Tensor::from_floats([[3.0, 0.0], [0.0, 1.0]], &device));
+        let mut spectral_norm = SpectralNormConfig::new().init(linear);
+        spectral_norm.u = RunningState::new(Tensor::from_floats([1.0, 0.0], &device));
+
+        let weight = spectral_norm.module().weight.val();
+
+        weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 0.333_333]]), 3);
+    }
+}
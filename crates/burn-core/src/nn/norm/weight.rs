@@ -0,0 +1,225 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::conv::{Conv1d, Conv2d, Conv3d, ConvTranspose1d, ConvTranspose2d, ConvTranspose3d};
+use crate::nn::Linear;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// A module with a weight that can be reparametrized by [WeightNorm](WeightNorm) and
+/// [SpectralNorm](crate::nn::SpectralNorm).
+///
+/// The weight is seen as a matrix whose rows are its slices along a dimension, usually the one of
+/// the output features.
+pub trait WeightedModule<B: Backend>: Module<B> {
+    /// The dimension of the weight corresponding to the output features.
+    const OUTPUT_DIM: usize;
+
+    /// The weight as a matrix with one row for each of its slices along `dim`.
+    fn weight_matrix(&self, dim: usize) -> Tensor<B, 2>;
+
+    /// Replaces the weight by a matrix with one row for each of its slices along `dim`.
+    fn with_weight_matrix(self, weight: Tensor<B, 2>, dim: usize) -> Self;
+}
+
+macro_rules! weighted_module {
+    ($module:ident, $output_dim:expr) => {
+        impl<B: Backend> WeightedModule<B> for $module<B> {
+            const OUTPUT_DIM: usize = $output_dim;
+
+            fn weight_matrix(&self, dim: usize) -> Tensor<B, 2> {
+                to_matrix(self.weight.val(), dim)
+            }
+
+            fn with_weight_matrix(mut self, weight: Tensor<B, 2>, dim: usize) -> Self {
+                // The parameter keeps its id, its value being computed from the parameters of the
+                // reparametrization.
+                self.weight = self
+                    .weight
+                    .map(|tensor| from_matrix(weight, dim, tensor.dims()));
+                self
+            }
+        }
+    };
+}
+
+weighted_module!(Linear, 1);
+weighted_module!(Conv1d, 0);
+weighted_module!(Conv2d, 0);
+weighted_module!(Conv3d, 0);
+weighted_module!(ConvTranspose1d, 1);
+weighted_module!(ConvTranspose2d, 1);
+weighted_module!(ConvTranspose3d, 1);
+
+fn to_matrix<B: Backend, const D: usize>(weight: Tensor<B, D>, dim: usize) -> Tensor<B, 2> {
+    let dims = weight.dims();
+    let rows = dims[dim];
+    let columns = dims.iter().product::<usize>() / rows;
+
+    weight.swap_dims(0, dim).reshape([rows, columns])
+}
+
+fn from_matrix<B: Backend, const D: usize>(
+    matrix: Tensor<B, 2>,
+    dim: usize,
+    mut dims: [usize; D],
+) -> Tensor<B, D> {
+    dims.swap(0, dim);
+
+    matrix.reshape(dims).swap_dims(0, dim)
+}
+
+/// Configuration to create a [WeightNorm](WeightNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct WeightNormConfig {
+    /// The dimension of the weight whose slices are normalized separately.
+    ///
+    /// The dimension of the output features is used when it is `None`.
+    pub dim: Option<usize>,
+}
+
+/// Reparametrizes the weight of a module by its magnitude and its direction, as described in the
+/// paper [Weight Normalization](https://arxiv.org/abs/1602.07868).
+///
+/// `W = g * V / ||V||`
+///
+/// The weight of the wrapped module is the direction `V`, and the magnitude `g` of each slice of
+/// the weight along `dim` is initialized to the norm of the slice, so that the weight is
+/// unchanged at initialization.
+#[derive(Module, Debug)]
+pub struct WeightNorm<B: Backend, M> {
+    module: M,
+    magnitude: Param<Tensor<B, 1>>,
+    dim: usize,
+}
+
+impl WeightNormConfig {
+    /// Initialize a new [weight norm](WeightNorm) wrapper around the module.
+    pub fn init<B: Backend, M: WeightedModule<B>>(&self, module: M) -> WeightNorm<B, M> {
+        let dim = self.dim.unwrap_or(M::OUTPUT_DIM);
+        let magnitude = row_norm(module.weight_matrix(dim));
+
+        WeightNorm {
+            module,
+            magnitude: Param::from(magnitude.detach()),
+            dim,
+        }
+    }
+}
+
+impl<B: Backend, M: WeightedModule<B>> WeightNorm<B, M> {
+    /// The wrapped module with the weight computed from its magnitude and its direction, whose
+    /// forward pass is applied by the caller.
+    pub fn module(&self) -> M {
+        let direction = self.module.weight_matrix(self.dim);
+        let scale = self
+            .magnitude
+            .val()
+            .div(row_norm(direction.clone()))
+            .unsqueeze_dim(1);
+
+        self.module
+            .clone()
+            .with_weight_matrix(direction.mul(scale), self.dim)
+    }
+}
+
+/// The L2 norm of each row of the matrix.
+pub(crate) fn row_norm<B: Backend>(matrix: Tensor<B, 2>) -> Tensor<B, 1> {
+    let [rows, _] = matrix.dims();
+
+    matrix.powf_scalar(2.0).sum_dim(1).sqrt().reshape([rows])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{conv::Conv2dConfig, LinearConfig};
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn weight_norm_preserves_weight_at_initialization() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init(linear.clone());
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 0.5]], &device);
+
+        weight_norm
+            .module()
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&linear.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn weight_norm_linear() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        linear.weight = Param::from(Tensor::from_floats([[3.0, 1.0], [4.0, 0.0]], &device));
+        let mut weight_norm = WeightNormConfig::new().init(linear);
+
+        // The magnitude of each output feature is the norm of its weights.
+        weight_norm
+            .magnitude
+            .to_data()
+            .assert_approx_eq(&Data::from([5.0, 1.0]), 3);
+
+        weight_norm.magnitude = Param::from(Tensor::from_floats([1.0, 2.0], &device));
+
+        weight_norm
+            .module()
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.6, 2.0], [0.8, 0.0]]), 3);
+    }
+
+    #[test]
+    fn weight_norm_conv2d_output_channels() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [2, 2]).init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init(conv);
+
+        assert_eq!(weight_norm.dim, 0);
+        assert_eq!(weight_norm.magnitude.dims(), [3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weight_norm_backward() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from(Tensor::from_floats([[3.0, 1.0], [4.0, 0.0]], &device));
+        let weight_norm = WeightNormConfig::new().init(linear);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]], &device);
+
+        let grads = weight_norm.module().forward(input).sum().backward();
+
+        // The output is `g * (v · x) / ||v||`, so the gradient of the magnitude is the output of
+        // the normalized weight, and the direction gets no gradient along itself.
+        weight_norm
+            .magnitude
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([2.2, 1.0]), 3);
+        weight_norm
+            .module
+            .weight
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.32, 0.0], [0.24, 2.0]]), 3);
+    }
+}
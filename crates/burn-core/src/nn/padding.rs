@@ -67,3 +67,40 @@ impl PaddingConfig2d {
         }
     }
 }
+
+/// Padding configuration for 3D operators.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum PaddingConfig3d {
+    /// Dynamically calculate the amount of padding necessary to ensure that the output size will be
+    /// the same as the input.
+    Same,
+    /// Same as no padding.
+    Valid,
+    /// Applies the specified amount of padding to all inputs.
+    Explicit(usize, usize, usize),
+}
+
+impl PaddingConfig3d {
+    pub(crate) fn calculate_padding_3d(
+        &self,
+        depth: usize,
+        height: usize,
+        width: usize,
+        kernel_size: &[usize; 3],
+        stride: &[usize; 3],
+    ) -> [usize; 3] {
+        let same_padding = || {
+            let p1 = calculate_conv_padding(kernel_size[0], stride[0], depth, depth);
+            let p2 = calculate_conv_padding(kernel_size[1], stride[1], height, height);
+            let p3 = calculate_conv_padding(kernel_size[2], stride[2], width, width);
+
+            [p1, p2, p3]
+        };
+
+        match self {
+            Self::Same => same_padding(),
+            Self::Valid => [0, 0, 0],
+            Self::Explicit(v1, v2, v3) => [*v1, *v2, *v3],
+        }
+    }
+}
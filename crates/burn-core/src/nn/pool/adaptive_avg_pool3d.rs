@@ -0,0 +1,41 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::adaptive_avg_pool3d;
+
+/// Configuration to create a [3D adaptive avg pooling](AdaptiveAvgPool3d) layer.
+#[derive(Config)]
+pub struct AdaptiveAvgPool3dConfig {
+    /// The size of the output.
+    pub output_size: [usize; 3],
+}
+
+/// Applies a 3D adaptive avg pooling over input tensors.
+#[derive(Module, Clone, Debug)]
+pub struct AdaptiveAvgPool3d {
+    output_size: [usize; 3],
+}
+
+impl AdaptiveAvgPool3dConfig {
+    /// Initialize a new [adaptive avg pool 3d](AdaptiveAvgPool3d) module.
+    pub fn init(&self) -> AdaptiveAvgPool3d {
+        AdaptiveAvgPool3d {
+            output_size: self.output_size,
+        }
+    }
+}
+
+impl AdaptiveAvgPool3d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels, depth_out, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        adaptive_avg_pool3d(input, self.output_size)
+    }
+}
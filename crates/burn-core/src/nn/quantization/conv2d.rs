@@ -0,0 +1,125 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::nn::PaddingConfig2d;
+use crate::tensor::{backend::Backend, Int, QuantizationScheme, Quantized, Tensor};
+use burn_tensor::module::quantized_conv2d;
+use burn_tensor::ops::ConvOptions;
+
+/// Conv2d layer whose weight is quantized to 8-bit integers with one scale per output channel,
+/// created by a [post-training quantizer](super::PostTrainingQuantizer).
+///
+/// The inputs are quantized per tensor, so the convolution is accumulated in integers.
+#[derive(Module, Debug)]
+pub struct QuantizedConv2d<B: Backend> {
+    /// Quantized tensor of shape `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`
+    pub weight: Param<Tensor<B, 4, Quantized>>,
+    /// Tensor of shape `[channels_out]`
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// The scale of the inputs, computed from the range of each input when not calibrated.
+    pub input_scale: Option<Param<Tensor<B, 1>>>,
+    /// The zero point of the inputs, computed from the range of each input when not calibrated.
+    pub input_zero_point: Option<Param<Tensor<B, 1, Int>>>,
+    pub(super) stride: [usize; 2],
+    pub(super) kernel_size: [usize; 2],
+    pub(super) dilation: [usize; 2],
+    pub(super) groups: usize,
+    pub(super) padding: PaddingConfig2d,
+}
+
+impl<B: Backend> QuantizedConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+
+        let input = match (&self.input_scale, &self.input_zero_point) {
+            (Some(scale), Some(zero_point)) => {
+                input.quantize(scale.val(), zero_point.val(), QuantizationScheme::PerTensor)
+            }
+            _ => input.quantize_dynamic(QuantizationScheme::PerTensor),
+        };
+
+        quantized_conv2d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::quantization::{MinMaxObserver, PostTrainingQuantizer};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    fn config() -> Conv2dConfig {
+        Conv2dConfig::new([4, 6], [3, 2])
+            .with_stride([2, 1])
+            .with_groups(2)
+            .with_padding(PaddingConfig2d::Explicit(1, 0))
+    }
+
+    fn quantized_conv(device: &<TestBackend as Backend>::Device) -> QuantizedConv2d<TestBackend> {
+        let conv = config().init(device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let input = Tensor::random([2, 4, 5, 5], Distribution::Uniform(-1.0, 1.0), device);
+
+        quantizer.observe_conv2d(conv.clone()).forward(input);
+        quantizer.quantize_conv2d(&conv)
+    }
+
+    #[test]
+    fn quantized_conv2d_should_take_the_options_of_the_layer() {
+        let device = Default::default();
+        let conv = quantized_conv(&device);
+
+        assert_eq!(conv.stride, [2, 1]);
+        assert_eq!(conv.kernel_size, [3, 2]);
+        assert_eq!(conv.dilation, [1, 1]);
+        assert_eq!(conv.groups, 2);
+        assert_eq!(conv.padding, PaddingConfig2d::Explicit(1, 0));
+        let input = Tensor::<TestBackend, 4>::random([1, 4, 6, 5], Distribution::Default, &device);
+        assert_eq!(conv.forward(input).dims(), [1, 6, 3, 4]);
+    }
+
+    #[test]
+    fn quantized_conv2d_should_be_recorded_and_loaded() {
+        let device = Default::default();
+        let conv = quantized_conv(&device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(conv.clone().into_record(), ()).unwrap();
+        let loaded = quantized_conv(&device).load_record(recorder.load(bytes, &device).unwrap());
+
+        assert_eq!(
+            loaded.weight.val().dequantize().into_data(),
+            conv.weight.val().dequantize().into_data()
+        );
+        assert_eq!(
+            loaded.input_scale.clone().unwrap().val().into_data(),
+            conv.input_scale.clone().unwrap().val().into_data()
+        );
+        assert_eq!(
+            loaded.input_zero_point.clone().unwrap().val().into_data(),
+            conv.input_zero_point.clone().unwrap().val().into_data()
+        );
+        let input = Tensor::<TestBackend, 4>::random([1, 4, 6, 5], Distribution::Default, &device);
+        assert_eq!(
+            loaded.forward(input.clone()).into_data(),
+            conv.forward(input).into_data()
+        );
+    }
+}
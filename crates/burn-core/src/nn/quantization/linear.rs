@@ -0,0 +1,96 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::tensor::{backend::Backend, Int, QuantizationScheme, Quantized, Tensor};
+
+/// Linear layer whose weight is quantized to 8-bit integers with one scale per output feature,
+/// created by a [post-training quantizer](super::PostTrainingQuantizer).
+///
+/// The inputs are quantized per tensor, so the product with the weight is accumulated in integers.
+#[derive(Module, Debug)]
+pub struct QuantizedLinear<B: Backend> {
+    /// Quantized matrix of shape `[d_input, d_output]`.
+    pub weight: Param<Tensor<B, 2, Quantized>>,
+    /// Vector of size `d_output`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// The scale of the inputs, computed from the range of each input when not calibrated.
+    pub input_scale: Option<Param<Tensor<B, 1>>>,
+    /// The zero point of the inputs, computed from the range of each input when not calibrated.
+    pub input_zero_point: Option<Param<Tensor<B, 1, Int>>>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let mut shape = input.dims();
+        let [d_input, d_output] = self.weight.dims();
+
+        let input = input.reshape([-1, d_input as i32]);
+        let input = match (&self.input_scale, &self.input_zero_point) {
+            (Some(scale), Some(zero_point)) => {
+                input.quantize(scale.val(), zero_point.val(), QuantizationScheme::PerTensor)
+            }
+            _ => input.quantize_dynamic(QuantizationScheme::PerTensor),
+        };
+
+        shape[D - 1] = d_output;
+        let output = input.matmul(self.weight.val()).reshape(shape);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::quantization::{MinMaxObserver, PostTrainingQuantizer};
+    use crate::nn::LinearConfig;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    fn quantized_linear(device: &<TestBackend as Backend>::Device) -> QuantizedLinear<TestBackend> {
+        let linear = LinearConfig::new(6, 4).init(device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let input = Tensor::random([8, 6], Distribution::Uniform(-1.0, 1.0), device);
+
+        quantizer.observe_linear(linear.clone()).forward(input);
+        quantizer.quantize_linear(&linear)
+    }
+
+    #[test]
+    fn quantized_linear_should_be_recorded_and_loaded() {
+        let device = Default::default();
+        let linear = quantized_linear(&device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(linear.clone().into_record(), ()).unwrap();
+        let loaded = quantized_linear(&device).load_record(recorder.load(bytes, &device).unwrap());
+
+        assert_eq!(
+            loaded.weight.val().dequantize().into_data(),
+            linear.weight.val().dequantize().into_data()
+        );
+        assert_eq!(
+            loaded.input_scale.clone().unwrap().val().into_data(),
+            linear.input_scale.clone().unwrap().val().into_data()
+        );
+        assert_eq!(
+            loaded.input_zero_point.clone().unwrap().val().into_data(),
+            linear.input_zero_point.clone().unwrap().val().into_data()
+        );
+        let input = Tensor::<TestBackend, 2>::random([3, 6], Distribution::Default, &device);
+        assert_eq!(
+            loaded.forward(input.clone()).into_data(),
+            linear.forward(input).into_data()
+        );
+    }
+}
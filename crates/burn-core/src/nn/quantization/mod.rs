@@ -0,0 +1,11 @@
+mod conv2d;
+mod linear;
+mod observed;
+mod observer;
+mod quantizer;
+
+pub use conv2d::*;
+pub use linear::*;
+pub use observed::*;
+pub use observer::*;
+pub use quantizer::*;
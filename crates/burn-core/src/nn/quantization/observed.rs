@@ -0,0 +1,120 @@
+use alloc::sync::Arc;
+
+use burn_common::stub::Mutex;
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::Tensor;
+
+use super::Observer;
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::nn::{conv::Conv2d, Linear};
+
+/// A layer whose inputs are collected by an observer during calibration, created by a
+/// [post-training quantizer](super::PostTrainingQuantizer).
+///
+/// The observer is shared by the clones of the layer and by the quantizer that created it, which
+/// reads the observed range when quantizing the layer. The record is the one of the wrapped layer,
+/// so a module using observed layers loads the records of the module using the plain layers.
+///
+/// Observing an input reads its values, so the forward pass isn't available on wasm without the
+/// `wasm-sync` feature.
+#[derive(Clone, Debug)]
+pub struct Observed<M, O> {
+    /// The observed layer.
+    pub layer: M,
+    observer: Arc<Mutex<O>>,
+}
+
+impl<M, O: Observer> Observed<M, O> {
+    pub(super) fn new(layer: M, observer: Arc<Mutex<O>>) -> Self {
+        Self { layer, observer }
+    }
+
+    /// Returns the range of the inputs observed so far, or `None` if the layer didn't run.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.observer.lock().unwrap().range()
+    }
+
+    /// Returns the wrapped layer.
+    pub fn into_layer(self) -> M {
+        self.layer
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn observe<B: Backend, const D: usize>(&self, input: &Tensor<B, D>) {
+        let values = input.to_data().convert::<f32>().value;
+
+        self.observer.lock().unwrap().observe(&values);
+    }
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<B: Backend, O: Observer> Observed<Linear<B>, O> {
+    /// Observes the input, then applies the forward pass of the linear layer.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.observe(&input);
+        self.layer.forward(input)
+    }
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<B: Backend, O: Observer> Observed<Conv2d<B>, O> {
+    /// Observes the input, then applies the forward pass of the conv2d layer.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.observe(&input);
+        self.layer.forward(input)
+    }
+}
+
+impl<B: Backend, M: Module<B>, O: Observer> Module<B> for Observed<M, O> {
+    type Record = M::Record;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.layer.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        Self::new(self.layer.map(mapper), self.observer)
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self::new(self.layer.load_record(record), self.observer)
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.layer.into_record()
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self::new(self.layer.to_device(device), self.observer)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self::new(self.layer.fork(device), self.observer)
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.layer.collect_devices(devices)
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>, O: Observer> AutodiffModule<B> for Observed<M, O> {
+    type InnerModule = Observed<M::InnerModule, O>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Observed::new(self.layer.valid(), self.observer.clone())
+    }
+
+    fn from_inner(module: Self::InnerModule) -> Self {
+        Observed::new(M::from_inner(module.layer), module.observer)
+    }
+}
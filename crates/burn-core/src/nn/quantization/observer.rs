@@ -0,0 +1,404 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Collects statistics of the values flowing through a layer during calibration to choose the
+/// range of real values mapped to the quantized values.
+pub trait Observer: Clone + Send + Sync + core::fmt::Debug {
+    /// Updates the statistics with new values.
+    fn observe(&mut self, values: &[f32]);
+
+    /// Returns the range of values to quantize, or `None` if no values were observed.
+    fn range(&self) -> Option<(f32, f32)>;
+}
+
+/// Observer keeping the smallest and largest values, so that no value is clipped.
+#[derive(Clone, Debug, Default)]
+pub struct MinMaxObserver {
+    range: Option<(f32, f32)>,
+}
+
+impl MinMaxObserver {
+    /// Creates a new min-max observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for MinMaxObserver {
+    fn observe(&mut self, values: &[f32]) {
+        for value in values.iter().copied() {
+            self.range = match self.range {
+                Some((min, max)) => Some((f32::min(min, value), f32::max(max, value))),
+                None => Some((value, value)),
+            };
+        }
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        self.range
+    }
+}
+
+/// Observer clipping the given percentage of the most extreme values on each side, which makes
+/// the range robust to outliers.
+#[derive(Clone, Debug)]
+pub struct PercentileObserver {
+    percentile: f32,
+    histogram: Histogram,
+}
+
+impl PercentileObserver {
+    /// Creates a new percentile observer keeping the values between the `100 - percentile` and the
+    /// `percentile` percentiles.
+    ///
+    /// # Panics
+    ///
+    /// If the percentile isn't in `[50, 100]`.
+    pub fn new(percentile: f32) -> Self {
+        if !(50.0..=100.0).contains(&percentile) {
+            panic!("The percentile must be between 50 and 100, got {percentile}");
+        }
+
+        Self {
+            percentile,
+            histogram: Histogram::new(HISTOGRAM_BINS),
+        }
+    }
+}
+
+impl Observer for PercentileObserver {
+    fn observe(&mut self, values: &[f32]) {
+        self.histogram.add(values.iter().copied());
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        if self.histogram.total == 0.0 {
+            return None;
+        }
+
+        let fraction = self.percentile / 100.0;
+
+        Some((
+            self.histogram.quantile(1.0 - fraction),
+            self.histogram.quantile(fraction),
+        ))
+    }
+}
+
+/// Observer choosing the range that minimizes the information lost by quantization, measured by
+/// the Kullback-Leibler divergence between the histograms of the values before and after
+/// quantization.
+///
+/// The range is symmetric, unless no negative value was observed, in which case it starts at zero.
+#[derive(Clone, Debug)]
+pub struct HistogramObserver {
+    histogram: Histogram,
+    signed: bool,
+}
+
+impl Default for HistogramObserver {
+    fn default() -> Self {
+        Self {
+            histogram: Histogram::new(HISTOGRAM_BINS),
+            signed: false,
+        }
+    }
+}
+
+impl HistogramObserver {
+    /// Creates a new histogram observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for HistogramObserver {
+    fn observe(&mut self, values: &[f32]) {
+        self.signed |= values.iter().any(|value| *value < 0.0);
+        self.histogram.add(values.iter().map(|value| value.abs()));
+    }
+
+    fn range(&self) -> Option<(f32, f32)> {
+        if self.histogram.total == 0.0 {
+            return None;
+        }
+
+        let threshold = self.histogram.width() * entropy_bins(&self.histogram.counts) as f32;
+
+        match self.signed {
+            true => Some((-threshold, threshold)),
+            false => Some((0.0, threshold)),
+        }
+    }
+}
+
+const HISTOGRAM_BINS: usize = 2048;
+
+/// The number of levels quantizing the absolute values, one sign bit being used for the others.
+const ENTROPY_LEVELS: usize = 128;
+
+/// Histogram whose range grows to hold every added value, and always includes zero.
+#[derive(Clone, Debug)]
+struct Histogram {
+    min: f32,
+    max: f32,
+    counts: Vec<f64>,
+    total: f64,
+}
+
+impl Histogram {
+    fn new(num_bins: usize) -> Self {
+        Self {
+            min: 0.0,
+            max: 0.0,
+            counts: vec![0.0; num_bins],
+            total: 0.0,
+        }
+    }
+
+    fn width(&self) -> f32 {
+        (self.max - self.min) / self.counts.len() as f32
+    }
+
+    fn add<I: Iterator<Item = f32> + Clone>(&mut self, values: I) {
+        let (min, max) = values
+            .clone()
+            .fold((self.min, self.max), |(min, max), value| {
+                (f32::min(min, value), f32::max(max, value))
+            });
+
+        if min < self.min || max > self.max {
+            self.rescale(min, max);
+        }
+
+        let width = self.width();
+        let last = self.counts.len() - 1;
+
+        for value in values {
+            let index = match width > 0.0 {
+                true => usize::min(((value - self.min) / width) as usize, last),
+                false => 0,
+            };
+
+            self.counts[index] += 1.0;
+            self.total += 1.0;
+        }
+    }
+
+    /// Extends the range, moving the counts of each bin to the new bin holding its center.
+    fn rescale(&mut self, min: f32, max: f32) {
+        let width = self.width();
+        let mut histogram = Self {
+            min,
+            max,
+            counts: vec![0.0; self.counts.len()],
+            total: 0.0,
+        };
+        let new_width = histogram.width();
+        let last = self.counts.len() - 1;
+
+        for (index, count) in self.counts.iter().enumerate() {
+            if *count == 0.0 {
+                continue;
+            }
+
+            let center = self.min + (index as f32 + 0.5) * width;
+            let index = usize::min(((center - min) / new_width) as usize, last);
+
+            histogram.counts[index] += count;
+            histogram.total += count;
+        }
+
+        *self = histogram;
+    }
+
+    /// The value below which the given fraction of the values lies.
+    fn quantile(&self, fraction: f32) -> f32 {
+        let target = fraction as f64 * self.total;
+        let mut cumulative = 0.0;
+
+        for (index, count) in self.counts.iter().enumerate() {
+            if *count > 0.0 && cumulative + count >= target {
+                let offset = ((target - cumulative) / count) as f32;
+                return self.min + (index as f32 + offset) * self.width();
+            }
+
+            cumulative += count;
+        }
+
+        self.max
+    }
+}
+
+/// Returns the number of bins of the histogram of absolute values to quantize, chosen to minimize
+/// the Kullback-Leibler divergence between the clipped histogram and its quantized version.
+fn entropy_bins(counts: &[f64]) -> usize {
+    let mut best = (f64::INFINITY, counts.len());
+
+    // Going from the widest range, the divergence must decrease to clip more values.
+    for num_bins in (ENTROPY_LEVELS..=counts.len()).rev() {
+        // The clipped values are accumulated in the last bin.
+        let mut reference = counts[..num_bins].to_vec();
+        reference[num_bins - 1] += counts[num_bins..].iter().sum::<f64>();
+
+        // Each quantization level spreads its values uniformly over its non-empty bins.
+        let mut quantized = vec![0.0; num_bins];
+        for level in 0..ENTROPY_LEVELS {
+            let start = level * num_bins / ENTROPY_LEVELS;
+            let end = (level + 1) * num_bins / ENTROPY_LEVELS;
+            let bins = &reference[start..end];
+            let non_empty = bins.iter().filter(|count| **count > 0.0).count();
+
+            if non_empty == 0 {
+                continue;
+            }
+
+            let count = bins.iter().sum::<f64>() / non_empty as f64;
+            for index in start..end {
+                if reference[index] > 0.0 {
+                    quantized[index] = count;
+                }
+            }
+        }
+
+        let divergence = kl_divergence(&reference, &quantized);
+        if divergence < best.0 {
+            best = (divergence, num_bins);
+        }
+    }
+
+    best.1
+}
+
+fn kl_divergence(p: &[f64], q: &[f64]) -> f64 {
+    let total_p = p.iter().sum::<f64>();
+    let total_q = q.iter().sum::<f64>();
+    let mut divergence = 0.0;
+
+    for (p, q) in p.iter().zip(q) {
+        if *p == 0.0 {
+            continue;
+        }
+        if *q == 0.0 {
+            return f64::INFINITY;
+        }
+
+        let p = p / total_p;
+        let q = q / total_q;
+        divergence += p * libm::log(p / q);
+    }
+
+    divergence
+}
+
+/// Computes the scale and the zero point mapping the range, extended to include zero, to the
+/// signed 8-bit values.
+pub(crate) fn quantization_parameters(range: (f32, f32)) -> (f32, i32) {
+    let min = f32::min(range.0, 0.0);
+    let max = f32::max(range.1, 0.0);
+
+    let scale = match (max - min) / 255.0 {
+        scale if scale > 0.0 => scale,
+        _ => 1.0,
+    };
+    let zero_point = libm::rintf(-128.0 - min / scale).clamp(-128.0, 127.0);
+
+    (scale, zero_point as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_max_observer_should_keep_the_extreme_values() {
+        let mut observer = MinMaxObserver::new();
+
+        observer.observe(&[0.5, -1.0, 2.0]);
+        observer.observe(&[3.0, 0.0]);
+
+        assert_eq!(observer.range(), Some((-1.0, 3.0)));
+    }
+
+    #[test]
+    fn percentile_observer_should_clip_outliers() {
+        let mut observer = PercentileObserver::new(99.0);
+        let values: Vec<f32> = (0..1000).map(|i| i as f32 / 1000.0).collect();
+
+        observer.observe(&values);
+        observer.observe(&[100.0]);
+
+        let (min, max) = observer.range().unwrap();
+        assert!(min.abs() < 0.05, "{min}");
+        assert!((max - 0.99).abs() < 0.1, "{max}");
+    }
+
+    #[test]
+    fn percentile_observer_should_interpolate_the_quantiles() {
+        let mut observer = PercentileObserver::new(99.0);
+        // The range [-1024, 1024] gives bins of width 1, each holding one value, except the last
+        // one which also holds 1024.
+        let values: Vec<f32> = (-1024..=1024).map(|i| i as f32).collect();
+
+        observer.observe(&values);
+
+        // 1% of the 2049 values is 20.49 values, reached 0.49 into the bin 20, and 99% is 2028.51
+        // values, reached 0.51 into the bin 2028.
+        let (min, max) = observer.range().unwrap();
+        assert!((min - (-1024.0 + 20.49)).abs() < 1e-3, "{min}");
+        assert!((max - (-1024.0 + 2028.51)).abs() < 1e-3, "{max}");
+    }
+
+    #[test]
+    fn histogram_observer_should_clip_outliers() {
+        let mut observer = HistogramObserver::new();
+        let values: Vec<f32> = (0..10000)
+            .map(|i| libm::sinf(i as f32) * (i % 7) as f32 / 7.0)
+            .collect();
+
+        observer.observe(&values);
+        observer.observe(&[50.0]);
+
+        let (min, max) = observer.range().unwrap();
+        assert_eq!(min, -max);
+        assert!(0.5 < max && max < 5.0, "{max}");
+    }
+
+    #[test]
+    fn histogram_observer_should_minimize_the_divergence() {
+        let mut observer = HistogramObserver::new();
+        // The absolute values fill the bins of width 1 up to 128 with the counts 1, 2, ..., 128,
+        // and an outlier lies in the last bin.
+        let mut values: Vec<f32> = (0..128)
+            .flat_map(|i| {
+                let value = i as f32 + 0.5;
+                (0..=i).map(move |j| if j % 2 == 0 { value } else { -value })
+            })
+            .collect();
+        values.push(2048.0);
+
+        observer.observe(&values);
+
+        // Clipping at 128 bins gives one bin per quantization level, so the quantized histogram is
+        // exact, while every wider range merges bins with different counts.
+        assert_eq!(observer.range(), Some((-128.0, 128.0)));
+    }
+
+    #[test]
+    fn histogram_observer_should_start_at_zero_without_negative_values() {
+        let mut observer = HistogramObserver::new();
+
+        observer.observe(&[0.0, 1.0, 2.0, 3.0]);
+
+        let (min, max) = observer.range().unwrap();
+        assert_eq!(min, 0.0);
+        assert!(max > 2.0, "{max}");
+    }
+
+    #[test]
+    fn quantization_parameters_should_map_the_range() {
+        assert_eq!(quantization_parameters((-128.0, 127.0)), (1.0, 0));
+        assert_eq!(quantization_parameters((2.0, 510.0)), (2.0, -128));
+        assert_eq!(quantization_parameters((0.0, 0.0)), (1.0, -128));
+    }
+}
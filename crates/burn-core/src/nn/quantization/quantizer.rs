@@ -0,0 +1,386 @@
+use alloc::sync::Arc;
+
+use burn_common::stub::Mutex;
+use burn_tensor::{backend::Backend, Int, QuantizationScheme, Tensor};
+use hashbrown::HashMap;
+
+use super::{quantization_parameters, Observed, Observer, QuantizedConv2d, QuantizedLinear};
+use crate::module::{ModuleMapper, Param, ParamId};
+use crate::nn::{conv::Conv2d, Linear};
+
+/// Post-training quantization of the [linear](Linear) and [conv2d](Conv2d) layers of a module.
+///
+/// The layers to quantize are first wrapped in [observed](Observed) layers, with
+/// [observe_linear](PostTrainingQuantizer::observe_linear) and
+/// [observe_conv2d](PostTrainingQuantizer::observe_conv2d), and representative batches are run
+/// through the module using them, while an [observer](Observer) collects the range of the inputs
+/// of each layer. The layers can then be converted to their quantized version with
+/// [quantize_linear](PostTrainingQuantizer::quantize_linear) and
+/// [quantize_conv2d](PostTrainingQuantizer::quantize_conv2d), whose weights are stored as 8-bit
+/// integers with one scale per output channel.
+///
+/// The quantizer is also a [module mapper](ModuleMapper), which fake-quantizes the weights of the
+/// calibrated layers: they are quantized and dequantized back to floats, so the module keeps its
+/// type and simulates the quantization error with the existing float operations.
+#[derive(Clone, Debug)]
+pub struct PostTrainingQuantizer<O: Observer> {
+    observer: O,
+    observers: HashMap<ParamId, Arc<Mutex<O>>>,
+}
+
+impl<O: Observer> PostTrainingQuantizer<O> {
+    /// Creates a new quantizer, using a copy of the given observer for each layer.
+    pub fn new(observer: O) -> Self {
+        Self {
+            observer,
+            observers: HashMap::new(),
+        }
+    }
+
+    /// Wraps a linear layer so that its inputs are observed when it runs.
+    ///
+    /// Observing the same layer again shares the observer, so its range covers both inputs.
+    pub fn observe_linear<B: Backend>(&mut self, linear: Linear<B>) -> Observed<Linear<B>, O> {
+        let observer = self.observer_of(&linear.weight.id);
+
+        Observed::new(linear, observer)
+    }
+
+    /// Wraps a conv2d layer so that its inputs are observed when it runs.
+    ///
+    /// Observing the same layer again shares the observer, so its range covers both inputs.
+    pub fn observe_conv2d<B: Backend>(&mut self, conv: Conv2d<B>) -> Observed<Conv2d<B>, O> {
+        let observer = self.observer_of(&conv.weight.id);
+
+        Observed::new(conv, observer)
+    }
+
+    /// Returns the observed range of the inputs of the layer with the given weight, if it ran
+    /// during calibration.
+    pub fn input_range(&self, weight_id: &ParamId) -> Option<(f32, f32)> {
+        self.observers.get(weight_id)?.lock().unwrap().range()
+    }
+
+    /// Converts a linear layer to a quantized linear layer.
+    ///
+    /// The inputs are quantized with the range observed during calibration, or with the range of
+    /// each input when the layer didn't run during calibration.
+    pub fn quantize_linear<B: Backend>(&self, linear: &Linear<B>) -> QuantizedLinear<B> {
+        let (input_scale, input_zero_point) = self.input_quantization(&linear.weight);
+
+        QuantizedLinear {
+            weight: Param::from(
+                linear
+                    .weight
+                    .val()
+                    .quantize_dynamic(QuantizationScheme::PerChannel(1)),
+            ),
+            bias: linear.bias.clone(),
+            input_scale,
+            input_zero_point,
+        }
+    }
+
+    /// Converts a conv2d layer to a quantized conv2d layer with the same options.
+    ///
+    /// The inputs are quantized with the range observed during calibration, or with the range of
+    /// each input when the layer didn't run during calibration.
+    pub fn quantize_conv2d<B: Backend>(&self, conv: &Conv2d<B>) -> QuantizedConv2d<B> {
+        let (input_scale, input_zero_point) = self.input_quantization(&conv.weight);
+
+        QuantizedConv2d {
+            weight: Param::from(
+                conv.weight
+                    .val()
+                    .quantize_dynamic(QuantizationScheme::PerChannel(0)),
+            ),
+            bias: conv.bias.clone(),
+            input_scale,
+            input_zero_point,
+            stride: conv.stride,
+            kernel_size: conv.kernel_size,
+            dilation: conv.dilation,
+            groups: conv.groups,
+            padding: conv.padding.clone(),
+        }
+    }
+
+    fn observer_of(&mut self, weight_id: &ParamId) -> Arc<Mutex<O>> {
+        self.observers
+            .entry(weight_id.clone())
+            .or_insert_with(|| Arc::new(Mutex::new(self.observer.clone())))
+            .clone()
+    }
+
+    /// The scale and zero point of the inputs of the layer with the given weight.
+    #[allow(clippy::type_complexity)]
+    fn input_quantization<B: Backend, const D: usize>(
+        &self,
+        weight: &Param<Tensor<B, D>>,
+    ) -> (
+        Option<Param<Tensor<B, 1>>>,
+        Option<Param<Tensor<B, 1, Int>>>,
+    ) {
+        match self.input_range(&weight.id) {
+            Some(range) => {
+                let device = weight.device();
+                let (scale, zero_point) = quantization_parameters(range);
+
+                (
+                    Some(Param::from(Tensor::from_floats([scale], &device))),
+                    Some(Param::from(Tensor::from_ints([zero_point], &device))),
+                )
+            }
+            None => (None, None),
+        }
+    }
+}
+
+impl<B: Backend, O: Observer> ModuleMapper<B> for PostTrainingQuantizer<O> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if self.input_range(id).is_none() {
+            return tensor;
+        }
+
+        // Linear weights have their output features last, while convolution weights have their
+        // output channels first.
+        let dim = match D {
+            2 => 1,
+            _ => 0,
+        };
+        let is_require_grad = tensor.is_require_grad();
+
+        tensor
+            .quantize_dynamic(QuantizationScheme::PerChannel(dim))
+            .dequantize()
+            .set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{conv::Conv2dConfig, LinearConfig, PaddingConfig2d};
+    use crate::{module::Module, nn::quantization::MinMaxObserver, TestBackend};
+    use alloc::vec::Vec;
+    use burn_tensor::Distribution;
+
+    use crate as burn;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        linear: Linear<B>,
+        unused: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct ObservedModel<B: Backend> {
+        conv: Observed<Conv2d<B>, MinMaxObserver>,
+        linear: Observed<Linear<B>, MinMaxObserver>,
+    }
+
+    fn conv_config() -> Conv2dConfig {
+        Conv2dConfig::new([2, 3], [3, 3]).with_padding(PaddingConfig2d::Same)
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: conv_config().init(device),
+                linear: LinearConfig::new(48, 5).init(device),
+                unused: LinearConfig::new(5, 5).init(device),
+            }
+        }
+
+        fn observe(
+            &self,
+            quantizer: &mut PostTrainingQuantizer<MinMaxObserver>,
+        ) -> ObservedModel<B> {
+            ObservedModel {
+                conv: quantizer.observe_conv2d(self.conv.clone()),
+                linear: quantizer.observe_linear(self.linear.clone()),
+            }
+        }
+    }
+
+    impl<B: Backend> ObservedModel<B> {
+        fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+            let x = self.conv.forward(input);
+            self.linear.forward(x.flatten(1, 3))
+        }
+    }
+
+    fn batches(device: &<TestBackend as Backend>::Device) -> Vec<Tensor<TestBackend, 4>> {
+        (0..4)
+            .map(|_| Tensor::random([2, 2, 4, 4], Distribution::Uniform(-2.0, 2.0), device))
+            .collect()
+    }
+
+    fn calibrate(
+        model: &Model<TestBackend>,
+        quantizer: &mut PostTrainingQuantizer<MinMaxObserver>,
+        batches: Vec<Tensor<TestBackend, 4>>,
+    ) {
+        let observed = model.observe(quantizer);
+
+        for batch in batches {
+            observed.forward(batch);
+        }
+    }
+
+    /// Bound of the error of a sum of `terms` products between an input and a weight both
+    /// quantized to 8 bits, where each value is off by at most the scale of its tensor.
+    fn product_error_bound<const D: usize, const D2: usize>(
+        input: &Tensor<TestBackend, D>,
+        weight: &Tensor<TestBackend, D2>,
+        terms: usize,
+    ) -> f32 {
+        fn scale_and_max<const D: usize>(tensor: &Tensor<TestBackend, D>) -> (f32, f32) {
+            let min = f32::min(tensor.clone().min().into_scalar(), 0.0);
+            let max = f32::max(tensor.clone().max().into_scalar(), 0.0);
+            ((max - min) / 255.0, f32::max(-min, max))
+        }
+
+        let (input_scale, input_max) = scale_and_max(input);
+        let (weight_scale, weight_max) = scale_and_max(weight);
+
+        terms as f32 * (input_scale * (weight_max + weight_scale) + input_max * weight_scale)
+    }
+
+    #[test]
+    fn observed_layers_should_observe_their_inputs() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let batches = batches(&device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+
+        calibrate(&model, &mut quantizer, batches.clone());
+
+        let input = Tensor::cat(batches, 0);
+        let (min, max) = quantizer.input_range(&model.conv.weight.id).unwrap();
+        assert_eq!(min, input.clone().min().into_scalar());
+        assert_eq!(max, input.max().into_scalar());
+
+        let (min, max) = quantizer.input_range(&model.linear.weight.id).unwrap();
+        assert!(min < max);
+        assert_eq!(quantizer.input_range(&model.unused.weight.id), None);
+    }
+
+    #[test]
+    fn quantizers_should_not_share_observers() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let mut other_quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let input = Tensor::<TestBackend, 2>::from_floats([[-1.0, 2.0, 0.5, 0.0, 1.0]], &device);
+
+        let observed = other_quantizer.observe_linear(model.unused.clone());
+        quantizer
+            .observe_linear(model.unused.clone())
+            .clone()
+            .forward(input);
+
+        assert_eq!(
+            quantizer.input_range(&model.unused.weight.id),
+            Some((-1.0, 2.0))
+        );
+        assert_eq!(observed.range(), None);
+        assert_eq!(other_quantizer.input_range(&model.unused.weight.id), None);
+    }
+
+    #[test]
+    fn observed_layer_should_load_the_record_of_the_layer() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let other = Model::<TestBackend>::new(&device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let input =
+            Tensor::<TestBackend, 2>::random([3, 5], Distribution::Uniform(-1.0, 1.0), &device);
+
+        let observed = quantizer
+            .observe_linear(model.unused)
+            .load_record(other.unused.clone().into_record());
+
+        assert_eq!(
+            observed.forward(input.clone()).into_data(),
+            other.unused.forward(input).into_data()
+        );
+    }
+
+    #[test]
+    fn quantized_layers_should_approximate_the_float_layers() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let batches = batches(&device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        calibrate(&model, &mut quantizer, batches.clone());
+
+        let conv = quantizer.quantize_conv2d(&model.conv);
+        let linear = quantizer.quantize_linear(&model.linear);
+
+        for input in batches {
+            // Each output of the convolution sums 2 input channels times 3x3 kernel values.
+            let tolerance = product_error_bound(&input, &model.conv.weight.val(), 18);
+            let expected = model.conv.forward(input.clone());
+            let output = conv.forward(input);
+            output
+                .into_data()
+                .assert_approx_eq_diff(&expected.clone().into_data(), tolerance as f64);
+
+            let input = expected.flatten::<2>(1, 3);
+            let tolerance = product_error_bound(&input, &model.linear.weight.val(), 48);
+            let expected = model.linear.forward(input.clone());
+            let output = linear.forward(input);
+            output
+                .into_data()
+                .assert_approx_eq_diff(&expected.into_data(), tolerance as f64);
+        }
+    }
+
+    #[test]
+    fn quantized_linear_should_quantize_uncalibrated_inputs_dynamically() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        let input =
+            Tensor::<TestBackend, 3>::random([2, 3, 5], Distribution::Uniform(-1.0, 1.0), &device);
+
+        let linear = quantizer.quantize_linear(&model.unused);
+
+        assert!(linear.input_scale.is_none());
+        let tolerance = product_error_bound(&input, &model.unused.weight.val(), 5);
+        linear
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq_diff(&model.unused.forward(input).into_data(), tolerance as f64);
+    }
+
+    #[test]
+    fn mapper_should_fake_quantize_the_calibrated_weights() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let mut quantizer = PostTrainingQuantizer::new(MinMaxObserver::new());
+        calibrate(&model, &mut quantizer, batches(&device));
+
+        let quantized = model.clone().map(&mut quantizer);
+
+        let weight = model.linear.weight.val();
+        let fake_quantized = quantized.linear.weight.val();
+        // Each output feature is quantized with 255 steps over its range.
+        let tolerance = (weight.clone().abs().max_dim(0) / 127.0).reshape([5]);
+        let error = (fake_quantized.clone() - weight.clone()).abs().max_dim(0);
+        let within_tolerance = error.reshape([5]).lower_equal(tolerance);
+        assert!(within_tolerance.all().into_scalar());
+        assert_ne!(fake_quantized.into_data(), weight.into_data());
+
+        assert_eq!(
+            quantized.linear.bias.unwrap().val().into_data(),
+            model.linear.bias.unwrap().val().into_data()
+        );
+        assert_eq!(
+            quantized.unused.weight.val().into_data(),
+            model.unused.weight.val().into_data()
+        );
+    }
+}
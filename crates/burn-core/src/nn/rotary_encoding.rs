@@ -0,0 +1,267 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::Data;
+
+use libm::{cosf, powf, sinf};
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config, Debug)]
+pub struct RotaryEncodingConfig {
+    /// Maximum sequence length to use.
+    pub max_sequence_length: usize,
+
+    /// The size of each vector, usually the size of each attention head.
+    pub d_model: usize,
+
+    /// The base of the rotation frequencies. Default: 10000.0
+    #[config(default = "10_000.0")]
+    pub theta: f32,
+
+    /// The number of leading features that are rotated, the remaining ones being left unchanged.
+    ///
+    /// All the features are rotated when it is `None`.
+    pub rotary_dims: Option<usize>,
+}
+
+/// Rotary positional encoding layer, as described in the paper
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// Instead of being added to the inputs, the position is encoded by rotating the pairs of
+/// features of the queries and keys by an angle proportional to their position, so that their
+/// dot product only depends on their relative position.
+///
+/// The features are paired with the one half of the rotary dimensions away from them, which is
+/// the layout used by most of the published checkpoints (GPT-NeoX, LLaMA).
+#[derive(Module, Debug)]
+pub struct RotaryEncoding<B: Backend> {
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+    d_model: usize,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [RotaryEncoding](RotaryEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RotaryEncoding<B> {
+        let rotary_dims = self.rotary_dims.unwrap_or(self.d_model);
+        let half = rotary_dims / 2;
+        assert!(
+            2 * half == rotary_dims && rotary_dims <= self.d_model,
+            "rotary_dims({}) must be even and smaller or equal than d_model({})",
+            rotary_dims,
+            self.d_model,
+        );
+
+        let mut cos = Vec::with_capacity(self.max_sequence_length * half);
+        let mut sin = Vec::with_capacity(self.max_sequence_length * half);
+
+        for position in 0..self.max_sequence_length {
+            for i in 0..half {
+                let frequency = powf(self.theta, -2.0 * i as f32 / rotary_dims as f32);
+                let angle = position as f32 * frequency;
+                cos.push(cosf(angle));
+                sin.push(sinf(angle));
+            }
+        }
+
+        let shape = [self.max_sequence_length, half];
+
+        RotaryEncoding {
+            cos: Tensor::from_data(Data::new(cos, shape.into()).convert(), device),
+            sin: Tensor::from_data(Data::new(sin, shape.into()).convert(), device),
+            d_model: self.d_model,
+        }
+    }
+}
+
+impl<B: Backend> RotaryEncoding<B> {
+    /// Applies the forward pass on the input tensor by rotating its features, starting at the
+    /// first position.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [..., seq_length, d_model]
+    /// * output: [..., seq_length, d_model]
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.forward_offset(input, 0)
+    }
+
+    /// Applies the forward pass on the input tensor by rotating its features, the first element
+    /// of the sequence being at the position `offset`.
+    ///
+    /// This is used when the previous positions of the sequence are cached.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [..., seq_length, d_model]
+    /// * output: [..., seq_length, d_model]
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the last position is greater than the maximum sequence length.
+    /// * Panics if the input d_model is not equal to the d_model of the encoding.
+    pub fn forward_offset<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        offset: usize,
+    ) -> Tensor<B, D> {
+        let dims = input.dims();
+        let seq_length = dims[D - 2];
+        let d_model = dims[D - 1];
+        let [max_sequence_length, half] = self.cos.dims();
+
+        assert!(
+            offset + seq_length <= max_sequence_length,
+            "max_sequence_length({}) must be greater or equal than offset({offset}) + length({seq_length})",
+            max_sequence_length,
+        );
+
+        assert!(
+            d_model == self.d_model,
+            "d_model({}) of the input must be equal to d_model of encoding({})",
+            d_model,
+            self.d_model,
+        );
+
+        let mut shape = [1; D];
+        shape[D - 2] = seq_length;
+        shape[D - 1] = half;
+
+        let cos = self
+            .cos
+            .clone()
+            .narrow(0, offset, seq_length)
+            .reshape(shape);
+        let sin = self
+            .sin
+            .clone()
+            .narrow(0, offset, seq_length)
+            .reshape(shape);
+
+        let x1 = input.clone().narrow(D - 1, 0, half);
+        let x2 = input.clone().narrow(D - 1, half, half);
+
+        let mut features = vec![
+            x1.clone() * cos.clone() - x2.clone() * sin.clone(),
+            x2 * cos + x1 * sin,
+        ];
+
+        if d_model > 2 * half {
+            features.push(input.narrow(D - 1, 2 * half, d_model - 2 * half));
+        }
+
+        Tensor::cat(features, D - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_rotary_encoding() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(8, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+            ]],
+            &device,
+        );
+
+        let output = rope.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[
+                [1.0, 2.0, 3.0, 4.0],
+                [-1.98411, 1.9599, 2.46238, 4.0198],
+                [-3.14404, 1.91961, -0.33914, 4.0392],
+            ]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_rotary_encoding_theta_and_offset() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(8, 4)
+            .with_theta(100.0)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 4.0]],
+            &device,
+        );
+
+        let output = rope.forward_offset(input, 1);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [-1.98411, 1.59067, 2.46238, 4.17968],
+                [-3.14404, 1.16546, -0.33914, 4.3176],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_partial_rotary_encoding() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(8, 4)
+            .with_rotary_dims(Some(2))
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+            ],
+            &device,
+        );
+
+        let output = rope.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 2.0, 3.0, 4.0],
+                [-1.14264, 1.92208, 3.0, 4.0],
+                [-2.23474, 0.077, 3.0, 4.0],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_rotary_encoding_dot_product_is_relative() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(16, 6).init::<TestBackend>(&device);
+        let query =
+            Tensor::<TestBackend, 2>::from_floats([[0.3, -1.2, 0.5, 2.0, -0.7, 1.1]], &device);
+        let key =
+            Tensor::<TestBackend, 2>::from_floats([[1.4, 0.2, -0.9, 0.6, 0.8, -1.5]], &device);
+
+        let dot = |query_position: usize, key_position: usize| {
+            let query = rope.forward_offset(query.clone(), query_position);
+            let key = rope.forward_offset(key.clone(), key_position);
+            query.matmul(key.transpose())
+        };
+
+        dot(5, 3)
+            .into_data()
+            .assert_approx_eq(&dot(9, 7).into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_encoding_odd_rotary_dims() {
+        let device = Default::default();
+        RotaryEncodingConfig::new(8, 5).init::<TestBackend>(&device);
+    }
+}
@@ -11,7 +11,9 @@ use crate::{
     config::Config,
     module::Module,
     nn::{
-        attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+        attention::{
+            AttentionPositionConfig, MhaInput, MultiHeadAttention, MultiHeadAttentionConfig,
+        },
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
     tensor::{backend::Backend, Tensor},
@@ -26,6 +28,9 @@ pub struct TransformerDecoderConfig {
     pub d_ff: usize,
     /// The number of attention heads.
     pub n_heads: usize,
+    /// The number of heads of the keys and values, which are shared by groups of attention
+    /// heads. Default: n_heads
+    pub n_kv_heads: Option<usize>,
     /// The number of layers.
     pub n_layers: usize,
     /// The dropout rate. Default: 0.1
@@ -47,6 +52,8 @@ pub struct TransformerDecoderConfig {
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// The relative position information applied inside the self-attention of each layer. Default: None
+    pub position_encoding: Option<AttentionPositionConfig>,
 }
 
 /// The transformer decoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -195,12 +202,15 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
+            .with_position_encoding(config.position_encoding.clone())
             .init(device);
 
         let cross_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
             .init(device);
         let norm_1 = LayerNormConfig::new(config.d_model).init(device);
         let norm_2 = LayerNormConfig::new(config.d_model).init(device);
@@ -230,11 +240,14 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
+            .with_position_encoding(config.position_encoding.clone())
             .init_with(record.self_attn);
         let cross_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
             .init_with(record.cross_attn);
         let norm_1 = LayerNormConfig::new(config.d_model).init_with(record.norm_1);
         let norm_2 = LayerNormConfig::new(config.d_model).init_with(record.norm_2);
@@ -453,7 +466,10 @@ impl<B: Backend> TransformerDecoder<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{
+        nn::{attention::generate_autoregressive_mask, RotaryEncodingConfig},
+        TestBackend,
+    };
     use burn_tensor::Distribution;
 
     #[test]
@@ -477,6 +493,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_autoregressive_rotary() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_position_encoding(Some(AttentionPositionConfig::Rotary(
+                    RotaryEncodingConfig::new(16, d_model / n_heads),
+                ))),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_alibi() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_position_encoding(Some(AttentionPositionConfig::Alibi)),
+        )
+    }
+
     fn test_autoregressive(config: TransformerDecoderConfig) {
         let device = Default::default();
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
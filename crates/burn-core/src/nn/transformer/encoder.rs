@@ -11,7 +11,9 @@ use crate::{
     config::Config,
     module::Module,
     nn::{
-        attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+        attention::{
+            AttentionPositionConfig, MhaInput, MultiHeadAttention, MultiHeadAttentionConfig,
+        },
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
     tensor::{backend::Backend, Tensor},
@@ -26,6 +28,9 @@ pub struct TransformerEncoderConfig {
     pub d_ff: usize,
     /// The number of attention heads.
     pub n_heads: usize,
+    /// The number of heads of the keys and values, which are shared by groups of attention
+    /// heads. Default: n_heads
+    pub n_kv_heads: Option<usize>,
     /// The number of layers.
     pub n_layers: usize,
     /// The dropout rate. Default: 0.1
@@ -47,6 +52,8 @@ pub struct TransformerEncoderConfig {
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// The relative position information applied inside the attention of each layer. Default: None
+    pub position_encoding: Option<AttentionPositionConfig>,
 }
 
 /// The transformer encoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -184,6 +191,8 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
+            .with_position_encoding(config.position_encoding.clone())
             .init_with(record.mha);
         let norm_1 = LayerNormConfig::new(config.d_model).init_with(record.norm_1);
         let norm_2 = LayerNormConfig::new(config.d_model).init_with(record.norm_2);
@@ -207,6 +216,8 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_n_kv_heads(config.n_kv_heads)
+            .with_position_encoding(config.position_encoding.clone())
             .init(device);
         let norm_1 = LayerNormConfig::new(config.d_model).init(device);
         let norm_2 = LayerNormConfig::new(config.d_model).init(device);
@@ -378,7 +389,10 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{
+        nn::{attention::generate_autoregressive_mask, RotaryEncodingConfig},
+        TestBackend,
+    };
     use burn_tensor::Distribution;
 
     #[test]
@@ -398,6 +412,35 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_autoregressive_grouped_query() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 4, 3];
+        test_autoregressive(
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_n_kv_heads(Some(2)),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_rotary() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        test_autoregressive(
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_position_encoding(Some(AttentionPositionConfig::Rotary(
+                    RotaryEncodingConfig::new(16, d_model / n_heads),
+                ))),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_alibi() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        test_autoregressive(
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_position_encoding(Some(AttentionPositionConfig::Alibi)),
+        )
+    }
+
     fn test_autoregressive(config: TransformerEncoderConfig) {
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
         let device = Default::default();
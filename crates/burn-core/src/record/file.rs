@@ -300,11 +300,11 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
 #[cfg(test)]
 mod tests {
 
-    use burn_tensor::backend::Backend;
+    use burn_tensor::{backend::Backend, QuantizationScheme, Quantized, Tensor};
 
     use super::*;
     use crate::{
-        module::Module,
+        module::{Module, Param},
         nn::{
             conv::{Conv2d, Conv2dConfig},
             Linear, LinearConfig,
@@ -374,6 +374,57 @@ mod tests {
         assert_eq!(model_bytes_after, model_bytes_before);
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_tensors() {
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let file_path = std::env::temp_dir()
+            .as_path()
+            .join("burn_test_file_recorder_quantized");
+        let device = Default::default();
+        let model_before = create_quantized_model(&device, 1.0);
+        recorder
+            .record(model_before.clone().into_record(), file_path.clone())
+            .unwrap();
+
+        let model_after = create_quantized_model(&device, -1.0)
+            .load_record(recorder.load(file_path, &device).unwrap());
+
+        let weight_before = model_before.weight.val();
+        let weight_after = model_after.weight.val();
+        assert_eq!(weight_after.scheme(), weight_before.scheme());
+        assert_eq!(
+            weight_after.values().into_data(),
+            weight_before.values().into_data()
+        );
+        assert_eq!(
+            weight_after.scale().into_data(),
+            weight_before.scale().into_data()
+        );
+        assert_eq!(
+            weight_after.zero_point().into_data(),
+            weight_before.zero_point().into_data()
+        );
+    }
+
+    #[derive(Module, Debug)]
+    pub struct QuantizedModel<B: Backend> {
+        weight: Param<Tensor<B, 2, Quantized>>,
+    }
+
+    fn create_quantized_model(
+        device: &<TestBackend as Backend>::Device,
+        sign: f32,
+    ) -> QuantizedModel<TestBackend> {
+        let weight =
+            Tensor::<TestBackend, 2>::from_floats([[0.5, -1.0, 2.0], [0.0, 4.0, -3.0]], device)
+                .mul_scalar(sign)
+                .quantize_dynamic(QuantizationScheme::PerChannel(0));
+
+        QuantizedModel {
+            weight: Param::from(weight),
+        }
+    }
+
     #[derive(Module, Debug)]
     pub struct Model<B: Backend> {
         conv2d1: Conv2d<B>,
@@ -74,8 +74,13 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S>
 mod tests {
     use super::*;
     use crate::{
-        module::Module, nn, record::FullPrecisionSettings, tensor::backend::Backend, TestBackend,
+        module::Module,
+        nn,
+        record::{Bf16PrecisionSettings, FullPrecisionSettings, HalfPrecisionSettings},
+        tensor::{backend::Backend, Data, Tensor},
+        TestBackend,
     };
+    use half::{bf16, f16};
 
     #[test]
     fn test_can_save_and_load_bin_format() {
@@ -105,6 +110,72 @@ mod tests {
         assert_eq!(bytes1, bytes2_after);
     }
 
+    #[test]
+    fn test_half_precision_round_trip_bin_format() {
+        test_half_precision_round_trip(BinBytesRecorder::<HalfPrecisionSettings>::default())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_half_precision_round_trip_named_mpk_format() {
+        test_half_precision_round_trip(NamedMpkBytesRecorder::<HalfPrecisionSettings>::default())
+    }
+
+    fn test_half_precision_round_trip<Recorder>(recorder: Recorder)
+    where
+        Recorder: BytesRecorder<burn_ndarray::NdArray<f16>>,
+    {
+        let device = Default::default();
+        let data = Data::<f16, 1>::from([
+            f16::MAX,
+            f16::MIN,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::NEG_ZERO,
+            f16::INFINITY,
+            f16::from_f32(0.1),
+        ]);
+        let tensor = Tensor::<burn_ndarray::NdArray<f16>, 1>::from_data(data.clone(), &device);
+
+        let bytes = recorder.record(tensor, ()).unwrap();
+        let tensor: Tensor<burn_ndarray::NdArray<f16>, 1> = recorder.load(bytes, &device).unwrap();
+
+        let bits = |data: Data<f16, 1>| data.value.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(tensor.into_data()), bits(data));
+    }
+
+    #[test]
+    fn test_bf16_precision_round_trip_bin_format() {
+        test_bf16_precision_round_trip(BinBytesRecorder::<Bf16PrecisionSettings>::default())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bf16_precision_round_trip_named_mpk_format() {
+        test_bf16_precision_round_trip(NamedMpkBytesRecorder::<Bf16PrecisionSettings>::default())
+    }
+
+    fn test_bf16_precision_round_trip<Recorder>(recorder: Recorder)
+    where
+        Recorder: BytesRecorder<burn_ndarray::NdArray<bf16>>,
+    {
+        let device = Default::default();
+        let data = Data::<bf16, 1>::from([
+            bf16::MAX,
+            bf16::MIN,
+            bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::NEG_ZERO,
+            bf16::INFINITY,
+            bf16::from_f32(0.1),
+        ]);
+        let tensor = Tensor::<burn_ndarray::NdArray<bf16>, 1>::from_data(data.clone(), &device);
+
+        let bytes = recorder.record(tensor, ()).unwrap();
+        let tensor: Tensor<burn_ndarray::NdArray<bf16>, 1> = recorder.load(bytes, &device).unwrap();
+
+        let bits = |data: Data<bf16, 1>| data.value.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(tensor.into_data()), bits(data));
+    }
+
     pub fn create_model<B: Backend>(device: &B::Device) -> nn::Linear<B> {
         nn::LinearConfig::new(32, 32).with_bias(true).init(device)
     }
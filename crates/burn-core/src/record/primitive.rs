@@ -5,11 +5,11 @@ use alloc::{
 };
 use core::{fmt, marker::PhantomData};
 
-use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
+use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde, QuantizedTensorSerde};
 use super::{PrecisionSettings, Record};
 use crate::module::{Param, ParamId};
 
-use burn_tensor::{backend::Backend, Bool, DataSerialize, Element, Int, Tensor};
+use burn_tensor::{backend::Backend, Bool, DataSerialize, Element, Int, Quantized, Tensor};
 
 use hashbrown::HashMap;
 use serde::{
@@ -223,6 +223,24 @@ where
     }
 }
 
+impl<B, const D: usize> Record<B> for Param<Tensor<B, D, Quantized>>
+where
+    B: Backend,
+{
+    type Item<S: PrecisionSettings> = ParamSerde<QuantizedTensorSerde<S>>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ParamSerde::new(self.id.into_string(), self.value.into_item())
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Param::new(
+            ParamId::from(item.id),
+            Tensor::from_item(item.param, device),
+        )
+    }
+}
+
 // Type that can be serialized as is without any conversion.
 macro_rules! primitive {
     ($type:ty) => {
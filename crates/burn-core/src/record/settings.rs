@@ -17,9 +17,20 @@ pub trait PrecisionSettings:
 pub struct FullPrecisionSettings;
 
 /// Precision settings optimized for compactness.
+///
+/// Float tensors are stored as [f16](half::f16), so records of a backend using `f16` as its float
+/// element round-trip without loss. Backends using [bf16](half::bf16) have a wider exponent range
+/// and round-trip without loss with [bf16 precision settings](Bf16PrecisionSettings).
 #[derive(Debug, Default, Clone)]
 pub struct HalfPrecisionSettings;
 
+/// Precision settings optimized for compactness, keeping the exponent range of `f32`.
+///
+/// Float tensors are stored as [bf16](half::bf16), so records of a backend using `bf16` as its
+/// float element round-trip without loss.
+#[derive(Debug, Default, Clone)]
+pub struct Bf16PrecisionSettings;
+
 /// Precision settings optimized for precision.
 #[derive(Debug, Default, Clone)]
 pub struct DoublePrecisionSettings;
@@ -38,3 +49,8 @@ impl PrecisionSettings for HalfPrecisionSettings {
     type FloatElem = half::f16;
     type IntElem = i16;
 }
+
+impl PrecisionSettings for Bf16PrecisionSettings {
+    type FloatElem = half::bf16;
+    type IntElem = i16;
+}
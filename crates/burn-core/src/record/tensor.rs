@@ -1,5 +1,7 @@
 use super::{PrecisionSettings, Record};
-use burn_tensor::{backend::Backend, Bool, DataSerialize, Int, Tensor};
+use burn_tensor::{
+    backend::Backend, Bool, DataSerialize, Int, QuantizationScheme, Quantized, Tensor,
+};
 use serde::{Deserialize, Serialize};
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
@@ -22,6 +24,18 @@ pub struct BoolTensorSerde {
     data: DataSerialize<bool>,
 }
 
+/// This struct implements serde to lazily serialize and deserialize a quantized tensor, storing
+/// its values and zero points as `i8` and its scales using the given
+/// [record settings](RecordSettings).
+#[derive(new, Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct QuantizedTensorSerde<S: PrecisionSettings> {
+    values: DataSerialize<i8>,
+    scale: DataSerialize<S::FloatElem>,
+    zero_point: DataSerialize<i8>,
+    scheme: QuantizationScheme,
+}
+
 // --- SERDE IMPLEMENTATIONS --- //
 
 impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
@@ -132,3 +146,29 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
         Tensor::from_data(item.data, device)
     }
 }
+
+impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Quantized> {
+    type Item<S: PrecisionSettings> = QuantizedTensorSerde<S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        todo!("Recording quantized tensors isn't yet supported on wasm.");
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        QuantizedTensorSerde::new(
+            self.values().into_data().convert().serialize(),
+            self.scale().into_data().convert().serialize(),
+            self.zero_point().into_data().convert().serialize(),
+            self.scheme(),
+        )
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Tensor::from_parts(
+            Tensor::from_data(item.values.convert(), device),
+            Tensor::from_data(item.scale.convert::<B::FloatElem>(), device),
+            Tensor::from_data(item.zero_point.convert(), device),
+            item.scheme,
+        )
+    }
+}
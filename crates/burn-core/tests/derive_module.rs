@@ -235,3 +235,26 @@ mod require_grad {
         y.backward()
     }
 }
+
+#[cfg(feature = "std")]
+mod autodiff {
+    use burn::module::AutodiffModule;
+
+    use super::*;
+
+    #[test]
+    fn should_get_valid_generic_module() {
+        let device = <TestAutodiffBackend as Backend>::Device::default();
+        let module = ModuleWithGenericModule::<TestAutodiffBackend, _> {
+            module: ModuleBasic::<TestAutodiffBackend>::new(&device),
+            _backend: PhantomData,
+        };
+
+        let valid: ModuleWithGenericModule<TestBackend, ModuleBasic<TestBackend>> = module.valid();
+
+        assert_eq!(
+            valid.module.weight_basic.to_data(),
+            module.module.weight_basic.val().inner().to_data()
+        );
+    }
+}
@@ -111,6 +111,28 @@ impl ModuleCodegen for EnumModuleCodegen {
         }
     }
 
+    fn gen_from_inner(&self) -> TokenStream {
+        let mut match_arms = quote! {};
+
+        for variant in self.variants.iter() {
+            let name = &variant.ident;
+
+            match_arms.extend(quote! {
+                Self::InnerModule::#name(module) => {
+                    Self::#name(burn::module::AutodiffModule::<B>::from_inner(module))
+                },
+            })
+        }
+
+        quote! {
+            fn from_inner(module: Self::InnerModule) -> Self {
+                match module {
+                    #match_arms
+                }
+            }
+        }
+    }
+
     fn gen_into_record(&self) -> TokenStream {
         let match_body = self.gen_variants_match_fn(|variant| {
             quote! {
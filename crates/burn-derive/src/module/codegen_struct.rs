@@ -131,6 +131,22 @@ impl ModuleCodegen for StructModuleCodegen {
         }
     }
 
+    fn gen_from_inner(&self) -> TokenStream {
+        let body = self.gen_fields_fn(|name| {
+            quote! {
+                #name: burn::module::AutodiffModule::<B>::from_inner(module.#name),
+            }
+        });
+
+        quote! {
+            fn from_inner(module: Self::InnerModule) -> Self {
+                Self {
+                    #body
+                }
+            }
+        }
+    }
+
     fn gen_into_record(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
             quote! {
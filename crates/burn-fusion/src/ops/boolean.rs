@@ -387,6 +387,120 @@ impl<B: FusionBackend> BoolTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        #[derive(new)]
+        struct AndOps<const D: usize> {
+            desc: BinaryOperationDescription,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B> for AndOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let lhs = handles.get_bool_tensor::<D>(&self.desc.lhs);
+                let rhs = handles.get_bool_tensor(&self.desc.rhs);
+                let output = B::bool_and(lhs, rhs);
+                handles.register_bool_tensor(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Bool(BoolOperationDescription::And(desc.clone())),
+            AndOps::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        #[derive(new)]
+        struct OrOps<const D: usize> {
+            desc: BinaryOperationDescription,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B> for OrOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let lhs = handles.get_bool_tensor::<D>(&self.desc.lhs);
+                let rhs = handles.get_bool_tensor(&self.desc.rhs);
+                let output = B::bool_or(lhs, rhs);
+                handles.register_bool_tensor(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Bool(BoolOperationDescription::Or(desc.clone())),
+            OrOps::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        #[derive(new)]
+        struct XorOps<const D: usize> {
+            desc: BinaryOperationDescription,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B> for XorOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let lhs = handles.get_bool_tensor::<D>(&self.desc.lhs);
+                let rhs = handles.get_bool_tensor(&self.desc.rhs);
+                let output = B::bool_xor(lhs, rhs);
+                handles.register_bool_tensor(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape));
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Bool(BoolOperationDescription::Xor(desc.clone())),
+            XorOps::<D>::new(desc),
+        );
+
+        out
+    }
+
     fn bool_swap_dims<const D: usize>(
         tensor: BoolTensor<Self, D>,
         dim1: usize,
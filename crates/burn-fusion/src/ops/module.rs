@@ -5,10 +5,11 @@ use crate::{
         AdaptiveAvgPool2dBackwardDescription, AdaptiveAvgPool2dDescription,
         AvgPool1dBackwardDescription, AvgPool1dDescription, AvgPool2dBackwardDescription,
         AvgPool2dDescription, Conv1dDescription, Conv2dDescription, ConvTranspose1dDescription,
-        ConvTranspose2dDescription, InterpolateDescription, MaxPool1dDescription,
-        MaxPool1dWithIndicesBackwardDescription, MaxPool1dWithIndicesDescription,
-        MaxPool2dDescription, MaxPool2dWithIndicesBackwardDescription,
-        MaxPool2dWithIndicesDescription, Operation, OperationDescription,
+        ConvTranspose2dDescription, InterpolateBackwardDescription, InterpolateDescription,
+        MaxPool1dDescription, MaxPool1dWithIndicesBackwardDescription,
+        MaxPool1dWithIndicesDescription, MaxPool2dDescription,
+        MaxPool2dWithIndicesBackwardDescription, MaxPool2dWithIndicesDescription, Operation,
+        OperationDescription,
     },
     Fusion, FusionBackend, HandleContainer,
 };
@@ -1013,4 +1014,45 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
 
         out
     }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        make_ops!(
+            InterpolateBackwardOps,
+            InterpolateBackwardDescription,
+            |args: InterpolateBackwardDescription, handles: &mut HandleContainer<B>| {
+                let x = handles.get_float_tensor(&args.x);
+                let grad = handles.get_float_tensor(&args.grad);
+                let output =
+                    B::interpolate_backward(x, grad, args.output_size, args.options.clone().into());
+
+                handles.register_float_tensor(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = grad.stream;
+        let out = x.client.tensor_uninitialized(x.shape.clone());
+
+        let desc = InterpolateBackwardDescription {
+            x: x.into_description(),
+            grad: grad.into_description(),
+            output_size,
+            options: options.into(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Module(
+                crate::stream::ModuleOperationDescription::InterpolateBackward(desc.clone()),
+            ),
+            InterpolateBackwardOps::new(desc),
+        );
+
+        out
+    }
 }
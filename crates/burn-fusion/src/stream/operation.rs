@@ -122,6 +122,9 @@ pub enum ModuleOperationDescription {
     MaxPool2dWithIndicesBackward(MaxPool2dWithIndicesBackwardDescription),
     /// Operation corresponding to [interpolate](burn_tensor::ops::ModuleOps::interpolate).
     Interpolate(InterpolateDescription),
+    /// Operation corresponding to
+    /// [interpolate backward](burn_tensor::ops::ModuleOps::interpolate_backward).
+    InterpolateBackward(InterpolateBackwardDescription),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -396,6 +399,35 @@ pub enum NumericOperationDescription<E> {
 pub enum IntOperationDescription {
     /// Operation corresponding to [into float](burn_tensor::ops::IntTensorOps::int_into_float).
     IntoFloat(UnaryOperationDescription),
+    /// Operation corresponding to [bitwise and](burn_tensor::ops::IntTensorOps::int_bitwise_and).
+    BitwiseAnd(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise and scalar](burn_tensor::ops::IntTensorOps::int_bitwise_and_scalar).
+    BitwiseAndScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise or](burn_tensor::ops::IntTensorOps::int_bitwise_or).
+    BitwiseOr(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise or scalar](burn_tensor::ops::IntTensorOps::int_bitwise_or_scalar).
+    BitwiseOrScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise xor](burn_tensor::ops::IntTensorOps::int_bitwise_xor).
+    BitwiseXor(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise xor scalar](burn_tensor::ops::IntTensorOps::int_bitwise_xor_scalar).
+    BitwiseXorScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise not](burn_tensor::ops::IntTensorOps::int_bitwise_not).
+    BitwiseNot(UnaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise left shift](burn_tensor::ops::IntTensorOps::int_bitwise_left_shift).
+    BitwiseLeftShift(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise left shift scalar](burn_tensor::ops::IntTensorOps::int_bitwise_left_shift_scalar).
+    BitwiseLeftShiftScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to
+    /// [bitwise right shift](burn_tensor::ops::IntTensorOps::int_bitwise_right_shift).
+    BitwiseRightShift(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise right shift scalar](burn_tensor::ops::IntTensorOps::int_bitwise_right_shift_scalar).
+    BitwiseRightShiftScalar(ScalarOperationDescription<i32>),
 }
 
 /// Operation description specific to a bool tensor.
@@ -407,6 +439,12 @@ pub enum BoolOperationDescription {
     IntoInt(UnaryOperationDescription),
     /// Operation corresponding to [not](burn_tensor::ops::BoolTensorOps::bool_not).
     Not(UnaryOperationDescription),
+    /// Operation corresponding to [and](burn_tensor::ops::BoolTensorOps::bool_and).
+    And(BinaryOperationDescription),
+    /// Operation corresponding to [or](burn_tensor::ops::BoolTensorOps::bool_or).
+    Or(BinaryOperationDescription),
+    /// Operation corresponding to [xor](burn_tensor::ops::BoolTensorOps::bool_xor).
+    Xor(BinaryOperationDescription),
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -908,14 +946,19 @@ pub struct MaxPool2dWithIndicesBackwardDescription {
 #[allow(missing_docs)]
 pub enum InterpolateModeDescription {
     Nearest,
+    Linear,
     Bilinear,
     Bicubic,
+    Trilinear,
+    Area,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct InterpolateOptionsDescription {
     pub mode: InterpolateModeDescription,
+    pub align_corners: bool,
+    pub antialias: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -927,12 +970,25 @@ pub struct InterpolateDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct InterpolateBackwardDescription {
+    pub x: TensorDescription,
+    pub grad: TensorDescription,
+    pub output_size: [usize; 2],
+    pub options: InterpolateOptionsDescription,
+    pub out: TensorDescription,
+}
+
 impl From<InterpolateModeDescription> for InterpolateMode {
     fn from(val: InterpolateModeDescription) -> Self {
         match val {
             InterpolateModeDescription::Nearest => Self::Nearest,
+            InterpolateModeDescription::Linear => Self::Linear,
             InterpolateModeDescription::Bilinear => Self::Bilinear,
             InterpolateModeDescription::Bicubic => Self::Bicubic,
+            InterpolateModeDescription::Trilinear => Self::Trilinear,
+            InterpolateModeDescription::Area => Self::Area,
         }
     }
 }
@@ -941,6 +997,8 @@ impl From<InterpolateOptionsDescription> for InterpolateOptions {
     fn from(val: InterpolateOptionsDescription) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
+            antialias: val.antialias,
         }
     }
 }
@@ -949,8 +1007,11 @@ impl From<InterpolateMode> for InterpolateModeDescription {
     fn from(val: InterpolateMode) -> Self {
         match val {
             InterpolateMode::Nearest => Self::Nearest,
+            InterpolateMode::Linear => Self::Linear,
             InterpolateMode::Bilinear => Self::Bilinear,
             InterpolateMode::Bicubic => Self::Bicubic,
+            InterpolateMode::Trilinear => Self::Trilinear,
+            InterpolateMode::Area => Self::Area,
         }
     }
 }
@@ -959,6 +1020,8 @@ impl From<InterpolateOptions> for InterpolateOptionsDescription {
     fn from(val: InterpolateOptions) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
+            antialias: val.antialias,
         }
     }
 }
@@ -1160,6 +1223,21 @@ impl IntOperationDescription {
     fn nodes(&self) -> Vec<&TensorDescription> {
         match self {
             IntOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::BitwiseAnd(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseAndScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseOr(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseOrScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseXor(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseXorScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseNot(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::BitwiseLeftShift(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseLeftShiftScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseRightShift(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseRightShiftScalar(desc) => vec![&desc.lhs, &desc.out],
         }
     }
 }
@@ -1170,6 +1248,9 @@ impl BoolOperationDescription {
             BoolOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
             BoolOperationDescription::IntoInt(desc) => vec![&desc.input, &desc.out],
             BoolOperationDescription::Not(desc) => vec![&desc.input, &desc.out],
+            BoolOperationDescription::And(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            BoolOperationDescription::Or(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            BoolOperationDescription::Xor(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
         }
     }
 }
@@ -1256,6 +1337,9 @@ impl ModuleOperationDescription {
             ModuleOperationDescription::Interpolate(desc) => {
                 vec![&desc.x, &desc.out]
             }
+            ModuleOperationDescription::InterpolateBackward(desc) => {
+                vec![&desc.x, &desc.out, &desc.grad]
+            }
         }
     }
 }
@@ -1268,6 +1352,11 @@ impl core::hash::Hash for RandomOperationDescription {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::TruncatedNormal(_, _, _, _) => 5u8.hash(state),
+            Distribution::Exponential(_) => 6u8.hash(state),
+            Distribution::Gamma(_, _) => 7u8.hash(state),
+            Distribution::Beta(_, _) => 8u8.hash(state),
+            Distribution::Poisson(_) => 9u8.hash(state),
         }
     }
 }
@@ -10,15 +10,20 @@ fn main() {
         .input("tests/add/add.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
+        .input("tests/bitwise/bitwise.onnx")
+        .input("tests/boolean/boolean.onnx")
         .input("tests/clip/clip_opset16.onnx")
         .input("tests/clip/clip_opset7.onnx")
         .input("tests/concat/concat.onnx")
         .input("tests/conv1d/conv1d.onnx")
         .input("tests/conv2d/conv2d.onnx")
         .input("tests/cos/cos.onnx")
+        .input("tests/cumsum/cumsum.onnx")
+        .input("tests/det/det.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
+        .input("tests/einsum/einsum.onnx")
         .input("tests/equal/equal.onnx")
         .input("tests/erf/erf.onnx")
         .input("tests/exp/exp.onnx")
@@ -27,14 +32,19 @@ fn main() {
         .input("tests/gelu/gelu.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
         .input("tests/linear/linear.onnx")
+        .input("tests/local_response_norm/local_response_norm.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
         .input("tests/log/log.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
         .input("tests/mul/mul.onnx")
         .input("tests/neg/neg.onnx")
+        .input("tests/pad/pad.onnx")
         .input("tests/recip/recip.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/resize/resize.onnx")
+        .input("tests/resize/resize_nearest.onnx")
+        .input("tests/rounding/rounding.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/softmax/softmax.onnx")
         .input("tests/sqrt/sqrt.onnx")
@@ -42,6 +52,7 @@ fn main() {
         .input("tests/sub/sub.onnx")
         .input("tests/tanh/tanh.onnx")
         .input("tests/transpose/transpose.onnx")
+        .input("tests/trigonometric/trigonometric.onnx")
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
@@ -0,0 +1,47 @@
+#!/usr/bin/env python3
+
+# used to generate model: bitwise.onnx
+
+# PyTorch exports the shifts as multiplications and divisions, so the model is built with the
+# onnx helpers. The BitShift operator is only defined for unsigned integers in the specification,
+# but the values are int64 since burn only imports signed integers.
+
+import numpy as np
+import onnx
+from onnx import TensorProto, helper
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    outputs = ["and", "or", "xor", "not", "left", "right"]
+    nodes = [
+        helper.make_node("BitwiseAnd", ["x", "y"], ["and"], name="/BitwiseAnd"),
+        helper.make_node("BitwiseOr", ["x", "y"], ["or"], name="/BitwiseOr"),
+        helper.make_node("BitwiseXor", ["x", "y"], ["xor"], name="/BitwiseXor"),
+        helper.make_node("BitwiseNot", ["x"], ["not"], name="/BitwiseNot"),
+        helper.make_node("BitShift", ["x", "y"], ["left"], name="/BitShift", direction="LEFT"),
+        helper.make_node("BitShift", ["x", "y"], ["right"], name="/BitShift_1", direction="RIGHT"),
+    ]
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [helper.make_tensor_value_info(name, TensorProto.INT64, [1, 4]) for name in ["x", "y"]],
+        [helper.make_tensor_value_info(name, TensorProto.INT64, [1, 4]) for name in outputs],
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 18)])
+
+    onnx_name = "bitwise.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    x = np.array([[12, 10, 7, 1]], dtype=np.int64)
+    y = np.array([[10, 3, 2, 0]], dtype=np.int64)
+
+    print("Test input data: {}, {}".format(x, y))
+    output = ReferenceEvaluator(model).run(None, {"x": x, "y": y})
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: boolean.onnx
+
+import torch
+import torch.nn as nn
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+
+    def forward(self, x, y):
+        return x & y, x | y, x ^ y, ~x
+
+
+def main():
+    # Export to onnx
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+    onnx_name = "boolean.onnx"
+    x = torch.tensor([[True, True, False, False]], device=device)
+    y = torch.tensor([[True, False, True, False]], device=device)
+
+    torch.onnx.export(model, (x, y), onnx_name,
+                      input_names=["x", "y"],
+                      output_names=["and", "or", "xor", "not"],
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    print("Test input data: {}, {}".format(x, y))
+    output = model.forward(x, y)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: einsum.onnx
+
+import torch
+import torch.nn as nn
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+
+    def forward(self, x, y):
+        return torch.einsum("bij,bjk->bik", x, y)
+
+
+def main():
+    # Set random seed for reproducibility
+    torch.manual_seed(0)
+
+    # Export to onnx
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+    onnx_name = "einsum.onnx"
+    dummy_x = torch.randn(2, 2, 3, device=device)
+    dummy_y = torch.randn(2, 3, 2, device=device)
+
+    torch.onnx.export(model, (dummy_x, dummy_y), onnx_name,
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test
+    test_x = torch.arange(12, dtype=torch.float).reshape(2, 2, 3)
+    test_y = torch.arange(12, dtype=torch.float).reshape(2, 3, 2)
+
+    print("Test input data: {}, {}".format(test_x, test_y))
+    output = model.forward(test_x, test_y)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,40 @@
+#!/usr/bin/env python3
+
+# used to generate model: local_response_norm.onnx
+
+# PyTorch exports `nn.LocalResponseNorm` as a sequence of pooling operations, so the model is
+# built directly with the ONNX helpers to get an `LRN` node.
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    node = helper.make_node(
+        "LRN", inputs=["x"], outputs=["y"], name="/LRN",
+        size=3, alpha=0.5, beta=0.75, bias=2.0,
+    )
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 4, 1, 2])],
+        [helper.make_tensor_value_info("y", TensorProto.FLOAT, [1, 4, 1, 2])],
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "local_response_norm.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    x = np.array([[[[1.0, -2.0]], [[0.5, 3.0]], [[-1.5, 0.0]], [[2.0, 1.0]]]], dtype=np.float32)
+    print("Test input data: {}".format(x))
+    output = ReferenceEvaluator(model).run(None, {"x": x})[0]
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -17,15 +17,20 @@ include_models!(
     add,
     avg_pool2d,
     batch_norm,
+    bitwise,
+    boolean,
     clip_opset16,
     clip_opset7,
     concat,
     conv1d,
     conv2d,
     cos,
+    cumsum,
+    det,
     div,
     dropout_opset16,
     dropout_opset7,
+    einsum,
     equal,
     erf,
     exp,
@@ -34,14 +39,19 @@ include_models!(
     gelu,
     global_avr_pool,
     linear,
+    local_response_norm,
     log_softmax,
     log,
     maxpool2d,
     mul,
     neg,
+    pad,
     recip,
     relu,
     reshape,
+    resize,
+    resize_nearest,
+    rounding,
     sigmoid,
     softmax,
     sqrt,
@@ -49,6 +59,7 @@ include_models!(
     sub,
     tanh,
     transpose,
+    trigonometric,
     conv_transpose2d,
     pow,
     pow_int,
@@ -61,7 +72,7 @@ mod tests {
 
     use super::*;
 
-    use burn::tensor::{Data, Int, Shape, Tensor};
+    use burn::tensor::{Bool, Data, Int, Shape, Tensor};
 
     use float_cmp::ApproxEq;
 
@@ -254,6 +265,98 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn cumsum() {
+        let model: cumsum::Model<Backend> = cumsum::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 2>::from_floats([[0., 1., 2.], [3., 4., 5.]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[0., 1., 3.], [3., 7., 12.]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn pad() {
+        let model: pad::Model<Backend> = pad::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[3., 2., 1., 2., 3., 2.], [6., 5., 4., 5., 6., 5.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn resize() {
+        let model: resize::Model<Backend> = resize::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 4>::from_floats([[[[0., 1., 2.], [3., 4., 5.]]]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[[
+            [-0.4219, 0.1927, 1.1745, 1.7891],
+            [1.3945, 2.0091, 2.9909, 3.6055],
+            [3.2109, 3.8255, 4.8073, 5.4219],
+        ]]]);
+
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn resize_nearest() {
+        let model: resize_nearest::Model<Backend> = resize_nearest::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 4>::from_floats([[[[0., 1., 2.], [3., 4., 5.]]]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[[[0., 0., 1., 2.], [0., 0., 1., 2.], [3., 3., 4., 5.]]]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn det() {
+        let model: det::Model<Backend> = det::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[2.0, 1.0, 0.0], [1.0, -3.0, 1.0], [0.0, -1.0, 2.0]],
+                [[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = Data::from([-12.0, 1.0]);
+
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn einsum() {
+        let model: einsum::Model<Backend> = einsum::Model::default();
+
+        let device = Default::default();
+        let x = Tensor::<Backend, 3>::from_floats(
+            [[[0., 1., 2.], [3., 4., 5.]], [[6., 7., 8.], [9., 10., 11.]]],
+            &device,
+        );
+        let y = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0., 1.], [2., 3.], [4., 5.]],
+                [[6., 7.], [8., 9.], [10., 11.]],
+            ],
+            &device,
+        );
+        let output = model.forward(x, y);
+        let expected = Data::from([[[10., 13.], [28., 40.]], [[172., 193.], [244., 274.]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn erf() {
         let model: erf::Model<Backend> = erf::Model::default();
@@ -332,6 +435,26 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn local_response_norm() {
+        let model: local_response_norm::Model<Backend> = local_response_norm::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[[1.0, -2.0]], [[0.5, 3.0]], [[-1.5, 0.0]], [[2.0, 1.0]]]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = Data::from([[
+            [[0.552, -0.6858]],
+            [[0.2454, 1.0287]],
+            [[-0.6447, 0.0]],
+            [[0.8684, 0.56]],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
     #[test]
     fn log_softmax() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -572,6 +695,88 @@ mod tests {
         assert_eq!(scalar_out, expected_scalar);
     }
 
+    #[test]
+    fn boolean() {
+        let device = Default::default();
+        let model: boolean::Model<Backend> = boolean::Model::new(&device);
+
+        // Run the model
+        let x = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[true, true, false, false]]),
+            &device,
+        );
+        let y = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[true, false, true, false]]),
+            &device,
+        );
+        let (and, or, xor, not) = model.forward(x, y);
+
+        assert_eq!(and.to_data(), Data::from([[true, false, false, false]]));
+        assert_eq!(or.to_data(), Data::from([[true, true, true, false]]));
+        assert_eq!(xor.to_data(), Data::from([[false, true, true, false]]));
+        assert_eq!(not.to_data(), Data::from([[false, false, true, true]]));
+    }
+
+    #[test]
+    fn bitwise() {
+        let device = Default::default();
+        let model: bitwise::Model<Backend> = bitwise::Model::new(&device);
+
+        // Run the model
+        let x = Tensor::<Backend, 2, Int>::from_ints([[12, 10, 7, 1]], &device);
+        let y = Tensor::<Backend, 2, Int>::from_ints([[10, 3, 2, 0]], &device);
+        let (and, or, xor, not, left, right) = model.forward(x, y);
+
+        assert_eq!(and.to_data(), Data::from([[8, 2, 2, 0]]));
+        assert_eq!(or.to_data(), Data::from([[14, 11, 7, 1]]));
+        assert_eq!(xor.to_data(), Data::from([[6, 9, 5, 1]]));
+        assert_eq!(not.to_data(), Data::from([[-13, -11, -8, -2]]));
+        assert_eq!(left.to_data(), Data::from([[12288, 80, 28, 1]]));
+        assert_eq!(right.to_data(), Data::from([[0, 1, 1, 1]]));
+    }
+
+    #[test]
+    fn rounding() {
+        let device = Default::default();
+        let model: rounding::Model<Backend> = rounding::Model::new(&device);
+
+        // Run the model
+        let x = Tensor::<Backend, 2>::from_floats([[-1.5, -0.2, 0.5, 2.7]], &device);
+        let (floor, ceil, round, sign) = model.forward(x);
+
+        assert_eq!(floor.to_data(), Data::from([[-2.0, -1.0, 0.0, 2.0]]));
+        assert_eq!(ceil.to_data(), Data::from([[-1.0, 0.0, 1.0, 3.0]]));
+        assert_eq!(round.to_data(), Data::from([[-2.0, 0.0, 0.0, 3.0]]));
+        assert_eq!(sign.to_data(), Data::from([[-1.0, -1.0, 1.0, 1.0]]));
+    }
+
+    #[test]
+    fn trigonometric() {
+        let device = Default::default();
+        let model: trigonometric::Model<Backend> = trigonometric::Model::new(&device);
+
+        // Run the model
+        let x = Tensor::<Backend, 2>::from_floats([[-0.9, -0.3, 0.2, 0.5]], &device);
+        let (tan, asin, acos, atan, sinh, cosh) = model.forward(x);
+
+        tan.to_data()
+            .assert_approx_eq(&Data::from([[-1.26016, -0.30934, 0.20271, 0.54630]]), 3);
+        asin.to_data().assert_approx_eq(
+            &Data::from([[-1.11977, -0.30469, 0.20136, consts::FRAC_PI_6 as f32]]),
+            3,
+        );
+        acos.to_data().assert_approx_eq(
+            &Data::from([[2.69057, 1.87549, 1.36944, consts::FRAC_PI_3 as f32]]),
+            3,
+        );
+        atan.to_data()
+            .assert_approx_eq(&Data::from([[-0.73282, -0.29146, 0.19740, 0.46365]]), 3);
+        sinh.to_data()
+            .assert_approx_eq(&Data::from([[-1.02652, -0.30452, 0.20134, 0.52110]]), 3);
+        cosh.to_data()
+            .assert_approx_eq(&Data::from([[1.43309, 1.04534, 1.02007, 1.12763]]), 3);
+    }
+
     #[test]
     fn clip_opset16() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+
+# used to generate model: resize_nearest.onnx
+
+import torch
+import torch.nn as nn
+import torch.nn.functional as F
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+
+    def forward(self, x):
+        # exported with the asymmetric coordinates and the floor rounding
+        x = F.interpolate(x, scale_factor=2, mode="nearest")
+        x = F.interpolate(x, size=(3, 4), mode="nearest")
+        return x
+
+
+def main():
+    # Set random seed for reproducibility
+    torch.manual_seed(0)
+
+    # Export to onnx
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+    onnx_name = "resize_nearest.onnx"
+    dummy_input = torch.randn(1, 1, 2, 3, device=device)
+
+    torch.onnx.export(model, (dummy_input), onnx_name,
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test
+    test_input = torch.arange(6, dtype=torch.float).reshape(1, 1, 2, 3)
+
+    print("Test input data: {}".format(test_input))
+    output = model.forward(test_input)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
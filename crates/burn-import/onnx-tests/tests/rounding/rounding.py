@@ -0,0 +1,38 @@
+#!/usr/bin/env python3
+
+# used to generate model: rounding.onnx
+
+import torch
+import torch.nn as nn
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+
+    def forward(self, x):
+        return torch.floor(x), torch.ceil(x), torch.round(x), torch.sign(x)
+
+
+def main():
+    # Export to onnx
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+    onnx_name = "rounding.onnx"
+    x = torch.tensor([[-1.5, -0.2, 0.5, 2.7]], device=device)
+
+    torch.onnx.export(model, (x), onnx_name,
+                      input_names=["x"],
+                      output_names=["floor", "ceil", "round", "sign"],
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    print("Test input data: {}".format(x))
+    output = model.forward(x)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
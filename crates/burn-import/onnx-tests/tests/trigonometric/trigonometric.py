@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: trigonometric.onnx
+
+import torch
+import torch.nn as nn
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+
+    def forward(self, x):
+        return (torch.tan(x), torch.asin(x), torch.acos(x), torch.atan(x),
+                torch.sinh(x), torch.cosh(x))
+
+
+def main():
+    # Export to onnx
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+    onnx_name = "trigonometric.onnx"
+    x = torch.tensor([[-0.9, -0.3, 0.2, 0.5]], device=device)
+
+    torch.onnx.export(model, (x), onnx_name,
+                      input_names=["x"],
+                      output_names=["tan", "asin", "acos", "atan", "sinh", "cosh"],
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    print("Test input data: {}".format(x))
+    output = model.forward(x)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
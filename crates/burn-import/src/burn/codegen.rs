@@ -3,6 +3,8 @@ use quote::quote;
 
 use burn::nn::PaddingConfig1d;
 use burn::nn::PaddingConfig2d;
+use burn::tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn::tensor::PadMode;
 
 fn convert_primitive<T: ToString>(primitive: T) -> TokenStream {
     let value = primitive.to_string();
@@ -42,6 +44,14 @@ impl<T: Copy + ToTokens> ToTokens for Vec<T> {
     }
 }
 
+impl<A: ToTokens, B: ToTokens> ToTokens for (A, B) {
+    fn to_tokens(&self) -> TokenStream {
+        let first = self.0.to_tokens();
+        let second = self.1.to_tokens();
+        quote! { (#first, #second) }
+    }
+}
+
 /// Prettier output for `usize`
 impl ToTokens for usize {
     fn to_tokens(&self) -> TokenStream {
@@ -56,10 +66,17 @@ impl ToTokens for i64 {
     }
 }
 
-/// Prettier output for `f64`
+/// Prettier output for `f64`, always written as a float literal
 impl ToTokens for f64 {
     fn to_tokens(&self) -> TokenStream {
-        convert_primitive(self)
+        format!("{self:?}").parse().unwrap()
+    }
+}
+
+/// Prettier output for `f32`, always written as a float literal
+impl ToTokens for f32 {
+    fn to_tokens(&self) -> TokenStream {
+        format!("{self:?}").parse().unwrap()
     }
 }
 
@@ -91,3 +108,40 @@ impl ToTokens for PaddingConfig2d {
         }
     }
 }
+
+/// Padding mode
+impl ToTokens for PadMode {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::Constant(value) => {
+                let value = value.to_tokens();
+                quote! { PadMode::Constant(#value) }
+            }
+            Self::Reflect => quote! { PadMode::Reflect },
+            Self::Replicate => quote! { PadMode::Replicate },
+            Self::Circular => quote! { PadMode::Circular },
+        }
+    }
+}
+
+/// Interpolation options
+impl ToTokens for InterpolateOptions {
+    fn to_tokens(&self) -> TokenStream {
+        let mode = match self.mode {
+            InterpolateMode::Nearest => quote! { InterpolateMode::Nearest },
+            InterpolateMode::Linear => quote! { InterpolateMode::Linear },
+            InterpolateMode::Bilinear => quote! { InterpolateMode::Bilinear },
+            InterpolateMode::Bicubic => quote! { InterpolateMode::Bicubic },
+            InterpolateMode::Trilinear => quote! { InterpolateMode::Trilinear },
+            InterpolateMode::Area => quote! { InterpolateMode::Area },
+        };
+        let align_corners = self.align_corners;
+        let antialias = self.antialias;
+
+        quote! {
+            InterpolateOptions::new(#mode)
+                .with_align_corners(#align_corners)
+                .with_antialias(#antialias)
+        }
+    }
+}
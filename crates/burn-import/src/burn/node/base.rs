@@ -2,9 +2,10 @@ use super::unsqueeze::UnsqueezeNode;
 use super::{
     avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
     concat::ConcatNode, constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, gather::GatherNode,
-    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, matmul::MatmulNode,
-    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, einsum::EinsumNode,
+    gather::GatherNode, global_avg_pool::GlobalAvgPoolNode, linear::LinearNode,
+    local_response_norm::LocalResponseNormNode, matmul::MatmulNode, max_pool2d::MaxPool2dNode,
+    reshape::ReshapeNode, resize::ResizeNode, unary::UnaryNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -84,12 +85,15 @@ pub enum Node<PS: PrecisionSettings> {
     Conv2d(Conv2dNode<PS>),
     ConvTranspose2d(ConvTranspose2dNode<PS>),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Gather(GatherNode),
     GlobalAvgPool(GlobalAvgPoolNode),
     Linear(LinearNode<PS>),
+    LocalResponseNorm(LocalResponseNormNode),
     Matmul(MatmulNode),
     MaxPool2d(MaxPool2dNode),
     Reshape(ReshapeNode),
+    Resize(ResizeNode),
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
 }
@@ -108,12 +112,15 @@ macro_rules! match_all {
             Node::Conv2d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::LocalResponseNorm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Resize(node) => $func(node),
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
         }
@@ -142,12 +149,15 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv2d(_) => "conv2d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Gather(_) => "gather",
             Node::GlobalAvgPool(_) => "global_avg_pool",
             Node::Linear(_) => "linear",
+            Node::LocalResponseNorm(_) => "local_response_norm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Reshape(_) => "reshape",
+            Node::Resize(_) => "resize",
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
         }
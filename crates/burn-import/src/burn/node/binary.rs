@@ -14,6 +14,14 @@ pub enum BinaryType {
     Equal,
     Powf,
     Powi,
+    And,
+    Or,
+    Xor,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitShiftLeft,
+    BitShiftRight,
 }
 
 impl BinaryType {
@@ -26,6 +34,14 @@ impl BinaryType {
             BinaryType::Equal => "equal",
             BinaryType::Powi => "powi",
             BinaryType::Powf => "powf",
+            BinaryType::And => "and",
+            BinaryType::Or => "or",
+            BinaryType::Xor => "xor",
+            BinaryType::BitwiseAnd => "bitwise_and",
+            BinaryType::BitwiseOr => "bitwise_or",
+            BinaryType::BitwiseXor => "bitwise_xor",
+            BinaryType::BitShiftLeft => "bitshift_left",
+            BinaryType::BitShiftRight => "bitshift_right",
         }
     }
 }
@@ -173,6 +189,126 @@ impl BinaryNode {
         };
         Self::new(lhs, rhs, output, BinaryType::Powi, Arc::new(function))
     }
+
+    pub(crate) fn and(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_and(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs && #rhs },
+            _ => panic!("And is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::And, Arc::new(function))
+    }
+
+    pub(crate) fn or(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_or(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs || #rhs },
+            _ => panic!("Or is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::Or, Arc::new(function))
+    }
+
+    pub(crate) fn xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_xor(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs ^ #rhs },
+            _ => panic!("Xor is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::Xor, Arc::new(function))
+    }
+
+    pub(crate) fn bitwise_and(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bitwise_and(#rhs) },
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_and_scalar(#rhs) }
+            }
+            (Type::Scalar(_), Type::Tensor(_)) => {
+                move |lhs, rhs| quote! { #rhs.bitwise_and_scalar(#lhs) }
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs & #rhs },
+            _ => panic!("Bitwise and is supported for tensor and scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::BitwiseAnd, Arc::new(function))
+    }
+
+    pub(crate) fn bitwise_or(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bitwise_or(#rhs) },
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_or_scalar(#rhs) }
+            }
+            (Type::Scalar(_), Type::Tensor(_)) => {
+                move |lhs, rhs| quote! { #rhs.bitwise_or_scalar(#lhs) }
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs | #rhs },
+            _ => panic!("Bitwise or is supported for tensor and scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::BitwiseOr, Arc::new(function))
+    }
+
+    pub(crate) fn bitwise_xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bitwise_xor(#rhs) },
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_xor_scalar(#rhs) }
+            }
+            (Type::Scalar(_), Type::Tensor(_)) => {
+                move |lhs, rhs| quote! { #rhs.bitwise_xor_scalar(#lhs) }
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs ^ #rhs },
+            _ => panic!("Bitwise xor is supported for tensor and scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::BitwiseXor, Arc::new(function))
+    }
+
+    pub(crate) fn bitshift_left(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_left_shift(#rhs) }
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_left_shift_scalar(#rhs) }
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs << #rhs },
+            _ => panic!("Bit shift is supported for tensor and scalar only"),
+        };
+
+        Self::new(
+            lhs,
+            rhs,
+            output,
+            BinaryType::BitShiftLeft,
+            Arc::new(function),
+        )
+    }
+
+    pub(crate) fn bitshift_right(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_right_shift(#rhs) }
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.bitwise_right_shift_scalar(#rhs) }
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs >> #rhs },
+            _ => panic!("Bit shift is supported for tensor and scalar only"),
+        };
+
+        Self::new(
+            lhs,
+            rhs,
+            output,
+            BinaryType::BitShiftRight,
+            Arc::new(function),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -380,6 +516,26 @@ mod tests {
         assert_tokens(graph.codegen(), expected);
     }
 
+    #[test]
+    fn test_binary_codegen_bitwise_and_scalar() {
+        test_binary_operator_on_tensor_and_scalar!(bitwise_and, bitwise_and_scalar);
+    }
+
+    #[test]
+    fn test_binary_codegen_bitwise_xor_scalars() {
+        test_binary_operator_on_scalar_and_scalar!(bitwise_xor, ^);
+    }
+
+    #[test]
+    fn test_binary_codegen_bitshift_left_scalar() {
+        test_binary_operator_on_tensor_and_scalar!(bitshift_left, bitwise_left_shift_scalar);
+    }
+
+    #[test]
+    fn test_binary_codegen_bitshift_right_scalars() {
+        test_binary_operator_on_scalar_and_scalar!(bitshift_right, >>);
+    }
+
     #[test]
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::LocalResponseNormConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+#[derive(Debug, Clone)]
+pub struct LocalResponseNormNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: LocalResponseNormConfig,
+}
+
+impl LocalResponseNormNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: LocalResponseNormConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    LocalResponseNorm
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LocalResponseNormNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, _with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+
+        let size = self.config.size.to_tokens();
+        let alpha = self.config.alpha.to_tokens();
+        let beta = self.config.beta.to_tokens();
+        let k = self.config.k.to_tokens();
+
+        let tokens = quote! {
+            let #name = LocalResponseNormConfig::new(#size)
+                .with_alpha(#alpha)
+                .with_beta(#beta)
+                .with_k(#k)
+                .init();
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input);
+        }
+    }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::LocalResponseNorm");
+        imports.register("burn::nn::LocalResponseNormConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::LocalResponseNorm(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{nn::LocalResponseNormConfig, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LocalResponseNormNode::new(
+            "lrn",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            LocalResponseNormConfig::new(3).with_alpha(0.5),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::LocalResponseNorm;
+            use burn::nn::LocalResponseNormConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lrn: LocalResponseNorm,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lrn = LocalResponseNormConfig::new(3)
+                        .with_alpha(0.5)
+                        .with_beta(0.75)
+                        .with_k(1.0)
+                        .init();
+
+                    Self {
+                        lrn,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.lrn.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -10,12 +10,15 @@ pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod gather;
 pub(crate) mod global_avg_pool;
 pub(crate) mod linear;
+pub(crate) mod local_response_norm;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
 pub(crate) mod reshape;
+pub(crate) mod resize;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
 pub(crate) use base::*;
@@ -0,0 +1,200 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use burn::tensor::ops::InterpolateOptions;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The size of the spatial dimensions after the resize.
+#[derive(Debug, Clone)]
+pub enum ResizeSize {
+    /// The size of each spatial dimension.
+    Sizes(Vec<usize>),
+    /// The factor each spatial dimension is multiplied by.
+    Scales(Vec<f32>),
+}
+
+#[derive(Debug, Clone, new)]
+pub struct ResizeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub size: ResizeSize,
+    pub options: InterpolateOptions,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let options = self.options.to_tokens();
+
+        // the dimensions before the spatial ones are the batch and the channels
+        let (dims, sizes) = match &self.size {
+            ResizeSize::Sizes(sizes) => (
+                quote! {},
+                sizes.iter().map(|size| size.to_tokens()).collect(),
+            ),
+            ResizeSize::Scales(scales) => (
+                quote! { let dims = #input_name.dims(); },
+                scales
+                    .iter()
+                    .enumerate()
+                    .map(|(i, scale)| {
+                        let dim = (i + 2).to_tokens();
+                        let scale = scale.to_tokens();
+                        quote! { (dims[#dim] as f32 * #scale) as usize }
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
+        let resize = match self.input.dim {
+            3 => quote! { interpolate1d(#input, #(#sizes)*, #options) },
+            4 => quote! { interpolate(#input, [#(#sizes),*], #options) },
+            5 => quote! { interpolate3d(#input, [#(#sizes),*], #options) },
+            dim => panic!("Resize: {dim}D tensors are not supported"),
+        };
+
+        quote! {
+            #dims
+            let #output = #resize;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.input.dim {
+            3 => imports.register("burn::tensor::module::interpolate1d"),
+            4 => imports.register("burn::tensor::module::interpolate"),
+            _ => imports.register("burn::tensor::module::interpolate3d"),
+        }
+        imports.register("burn::tensor::ops::InterpolateMode");
+        imports.register("burn::tensor::ops::InterpolateOptions");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Resize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+    use burn::tensor::ops::InterpolateMode;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_resize_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            ResizeSize::Sizes(vec![4, 6]),
+            InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::module::interpolate;
+            use burn::tensor::ops::InterpolateMode;
+            use burn::tensor::ops::InterpolateOptions;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = interpolate(
+                        tensor1,
+                        [4, 6],
+                        InterpolateOptions::new(InterpolateMode::Bilinear)
+                            .with_align_corners(false)
+                            .with_antialias(false)
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_resize_scales() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            ResizeSize::Scales(vec![2.0]),
+            InterpolateOptions::new(InterpolateMode::Nearest),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::module::interpolate1d;
+            use burn::tensor::ops::InterpolateMode;
+            use burn::tensor::ops::InterpolateOptions;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let dims = tensor1.dims();
+                    let tensor2 = interpolate1d(
+                        tensor1,
+                        (dims[2] as f32 * 2.0) as usize,
+                        InterpolateOptions::new(InterpolateMode::Nearest)
+                            .with_align_corners(true)
+                            .with_antialias(false)
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
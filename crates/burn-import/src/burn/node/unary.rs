@@ -1,6 +1,7 @@
 use super::{Node, NodeCodegen};
 use crate::burn::{BurnImports, Scope, ToTokens, Type};
 use burn::record::PrecisionSettings;
+use burn::tensor::PadMode;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::rc::Rc;
@@ -20,20 +21,35 @@ pub struct UnaryNode {
 /// Type of unary node.
 #[derive(Clone)]
 pub enum UnaryNodeKind {
+    Acos,
+    Asin,
+    Atan,
+    BitwiseNot,
     Cast,
+    Ceil,
     Cos,
+    Cosh,
+    CumSum,
+    Det,
     Erf,
     Exp,
     Flatten,
+    Floor,
     Gelu,
     Log,
     LogSoftmax,
     Neg,
+    Not,
+    Pad,
     Reciprocal,
     Relu,
+    Round,
     Sigmoid,
+    Sign,
+    Sinh,
     Softmax,
     Sqrt,
+    Tan,
     Tanh,
     Transpose,
 }
@@ -41,20 +57,35 @@ pub enum UnaryNodeKind {
 impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
+            Self::Acos => "acos",
+            Self::Asin => "asin",
+            Self::Atan => "atan",
+            Self::BitwiseNot => "bitwise_not",
             Self::Cast => "cast",
+            Self::Ceil => "ceil",
             Self::Cos => "cos",
+            Self::Cosh => "cosh",
+            Self::CumSum => "cumsum",
+            Self::Det => "det",
             Self::Erf => "erf",
             Self::Exp => "exp",
             Self::Flatten => "flatten",
+            Self::Floor => "floor",
             Self::Gelu => "gelu",
             Self::Log => "log",
             Self::LogSoftmax => "log_softmax",
             Self::Neg => "neg",
+            Self::Not => "not",
+            Self::Pad => "pad",
             Self::Reciprocal => "reciprocal",
             Self::Relu => "relu",
+            Self::Round => "round",
             Self::Sigmoid => "sigmoid",
+            Self::Sign => "sign",
+            Self::Sinh => "sinh",
             Self::Softmax => "softmax",
             Self::Sqrt => "sqrt",
+            Self::Tan => "tan",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
         }
@@ -114,6 +145,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
             UnaryNodeKind::Neg => {
                 imports.register("core::ops::Neg");
             }
+            UnaryNodeKind::Pad => {
+                imports.register("burn::tensor::PadMode");
+            }
             _ => {}
         }
     }
@@ -155,6 +189,36 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Softmax, Rc::new(function))
     }
 
+    pub(crate) fn cumsum(input: Type, output: Type, dim: usize) -> Self {
+        let dim = dim.to_tokens();
+        let function = move |input| quote! { #input.cumsum(#dim) };
+        Self::new(input, output, UnaryNodeKind::CumSum, Rc::new(function))
+    }
+
+    pub(crate) fn pad(input: Type, output: Type, pads: Vec<(usize, usize)>, mode: PadMode) -> Self {
+        let pads = pads.to_tokens();
+        let mode = mode.to_tokens();
+        let function = move |input| quote! { #input.pad(#pads, #mode) };
+        Self::new(input, output, UnaryNodeKind::Pad, Rc::new(function))
+    }
+
+    pub(crate) fn det(input: Type, output: Type) -> Self {
+        let dim = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("det input must be a tensor"),
+        };
+
+        // The determinants of shape `[..., 1, 1]` are flattened into the batch dimensions, or a
+        // single element when there are none.
+        let start_dim = dim.saturating_sub(3).to_tokens();
+        let end_dim = (dim - 1).to_tokens();
+        let function = move |input| {
+            quote! { burn::tensor::linalg::det(#input).flatten(#start_dim, #end_dim) }
+        };
+
+        Self::new(input, output, UnaryNodeKind::Det, Rc::new(function))
+    }
+
     pub(crate) fn sqrt(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.sqrt()};
         Self::new(input, output, UnaryNodeKind::Sqrt, Rc::new(function))
@@ -180,6 +244,56 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Cos, Rc::new(function))
     }
 
+    pub(crate) fn acos(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.acos() };
+        Self::new(input, output, UnaryNodeKind::Acos, Rc::new(function))
+    }
+
+    pub(crate) fn asin(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.asin() };
+        Self::new(input, output, UnaryNodeKind::Asin, Rc::new(function))
+    }
+
+    pub(crate) fn atan(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.atan() };
+        Self::new(input, output, UnaryNodeKind::Atan, Rc::new(function))
+    }
+
+    pub(crate) fn ceil(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.ceil() };
+        Self::new(input, output, UnaryNodeKind::Ceil, Rc::new(function))
+    }
+
+    pub(crate) fn cosh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.cosh() };
+        Self::new(input, output, UnaryNodeKind::Cosh, Rc::new(function))
+    }
+
+    pub(crate) fn floor(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.floor() };
+        Self::new(input, output, UnaryNodeKind::Floor, Rc::new(function))
+    }
+
+    pub(crate) fn round(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.round() };
+        Self::new(input, output, UnaryNodeKind::Round, Rc::new(function))
+    }
+
+    pub(crate) fn sign(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.sign() };
+        Self::new(input, output, UnaryNodeKind::Sign, Rc::new(function))
+    }
+
+    pub(crate) fn sinh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.sinh() };
+        Self::new(input, output, UnaryNodeKind::Sinh, Rc::new(function))
+    }
+
+    pub(crate) fn tan(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.tan() };
+        Self::new(input, output, UnaryNodeKind::Tan, Rc::new(function))
+    }
+
     pub(crate) fn exp(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.exp()};
         Self::new(input, output, UnaryNodeKind::Exp, Rc::new(function))
@@ -200,6 +314,24 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Neg, Rc::new(function))
     }
 
+    pub(crate) fn not(input: Type, output: Type) -> Self {
+        let function = match &input {
+            Type::Tensor(_) => move |input| quote! { #input.bool_not() },
+            Type::Scalar(_) => move |input| quote! { !#input },
+            _ => panic!("Not is supported for tensor and scalar only"),
+        };
+        Self::new(input, output, UnaryNodeKind::Not, Rc::new(function))
+    }
+
+    pub(crate) fn bitwise_not(input: Type, output: Type) -> Self {
+        let function = match &input {
+            Type::Tensor(_) => move |input| quote! { #input.bitwise_not() },
+            Type::Scalar(_) => move |input| quote! { !#input },
+            _ => panic!("Bitwise not is supported for tensor and scalar only"),
+        };
+        Self::new(input, output, UnaryNodeKind::BitwiseNot, Rc::new(function))
+    }
+
     /// Casts the input to the output type.
     ///
     /// Currently this function only supports the following conversions:
@@ -267,6 +399,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_det() {
+        one_node_graph(
+            UnaryNode::det(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = burn::tensor::linalg::det(tensor1).flatten(1, 3);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_erf() {
         one_node_graph(
@@ -286,6 +437,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_cumsum() {
+        one_node_graph(
+            UnaryNode::cumsum(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                1,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.cumsum(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_pad() {
+        one_node_graph(
+            UnaryNode::pad(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                vec![(0, 0), (2, 1)],
+                PadMode::Constant(1.0),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.pad([(0, 0), (2, 1)], PadMode::Constant(1.0));
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_relu() {
         one_node_graph(
@@ -455,6 +647,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_not() {
+        one_node_graph(
+            UnaryNode::not(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Bool)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: bool) -> bool {
+                    let scalar2 = !scalar1;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_cos() {
         one_node_graph(
@@ -474,6 +685,196 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_acos() {
+        one_node_graph(
+            UnaryNode::acos(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.acos();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_asin() {
+        one_node_graph(
+            UnaryNode::asin(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.asin();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_atan() {
+        one_node_graph(
+            UnaryNode::atan(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.atan();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_ceil() {
+        one_node_graph(
+            UnaryNode::ceil(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.ceil();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_cosh() {
+        one_node_graph(
+            UnaryNode::cosh(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.cosh();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_floor() {
+        one_node_graph(
+            UnaryNode::floor(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.floor();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_round() {
+        one_node_graph(
+            UnaryNode::round(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.round();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_sign() {
+        one_node_graph(
+            UnaryNode::sign(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.sign();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_sinh() {
+        one_node_graph(
+            UnaryNode::sinh(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.sinh();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_tan() {
+        one_node_graph(
+            UnaryNode::tan(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.tan();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_exp() {
         one_node_graph(
@@ -5,29 +5,44 @@ use protobuf::Enum;
 use super::{
     from_onnx::OnnxGraphIO,
     ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
-    op_configuration::flatten_config,
+    op_configuration::{einsum_config, flatten_config},
     protos::tensor_proto::DataType,
 };
 
 /// Infer the dimension of each output tensor and update them.
 pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
     match node.node_type {
+        NodeType::Acos => same_as_input(node),
         NodeType::Add => same_as_input(node),
+        NodeType::And => same_as_input(node),
+        NodeType::Asin => same_as_input(node),
+        NodeType::Atan => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
+        NodeType::BitShift => same_as_input(node),
+        NodeType::BitwiseAnd => same_as_input(node),
+        NodeType::BitwiseNot => same_as_input(node),
+        NodeType::BitwiseOr => same_as_input(node),
+        NodeType::BitwiseXor => same_as_input(node),
         NodeType::Cast => cast_update_outputs(node),
+        NodeType::Ceil => same_as_input(node),
         NodeType::Clip => same_as_input(node),
         NodeType::Concat => concat_update_outputs(node),
         NodeType::Constant => constant_update_outputs(node),
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::Cosh => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
+        NodeType::Det => det_update_outputs(node),
         NodeType::Div => same_as_input(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => equal_update_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
         NodeType::Flatten => flatten_update_outputs(node),
+        NodeType::Floor => same_as_input(node),
         NodeType::Gelu => same_as_input(node),
         NodeType::GatherElements => same_as_input(node),
         NodeType::GlobalAveragePool => same_as_input(node),
@@ -35,21 +50,31 @@ pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
         NodeType::Linear => linear_update_outputs(node),
         NodeType::Log => same_as_input(node),
         NodeType::LogSoftmax => same_as_input(node),
+        NodeType::LRN => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
+        NodeType::Not => same_as_input(node),
+        NodeType::Or => same_as_input(node),
+        NodeType::Pad => same_as_input(node),
         NodeType::Reciprocal => same_as_input(node),
         NodeType::ReduceMean => mean_update_outputs(node),
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
+        NodeType::Resize => same_as_input(node),
+        NodeType::Round => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
+        NodeType::Sign => same_as_input(node),
+        NodeType::Sinh => same_as_input(node),
         NodeType::Softmax => same_as_input(node),
         NodeType::Sqrt => same_as_input(node),
         NodeType::Sub => same_as_input(node),
+        NodeType::Tan => same_as_input(node),
         NodeType::Tanh => same_as_input(node),
         NodeType::Transpose => same_as_input(node),
         NodeType::Unsqueeze => unsqueeze_update_output(node),
+        NodeType::Xor => same_as_input(node),
         NodeType::Pow => same_as_input(node),
         // Intentionally letting outputs leave unchanged but issue a warning so IR file can be generated.
         _ => temporary_pass_through_stub(node),
@@ -179,6 +204,67 @@ fn concat_update_outputs(node: &mut Node) {
     node.outputs[0].ty = ArgType::Tensor(tensor.clone());
 }
 
+/// Remove the matrix dimensions, keeping a single dimension when the input is a single matrix
+fn det_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Det: only tensor input is valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: usize::max(tensor.dim - 2, 1),
+        shape: None,
+        ..tensor
+    });
+}
+
+/// Infer the rank of the output tensor from the labels of the einsum equation
+fn einsum_update_outputs(node: &mut Node) {
+    let equation: String = einsum_config(node)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let (lhs, rhs) = match equation.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (equation.as_str(), None),
+    };
+    let num_labels = |term: &str| term.chars().filter(|c| c.is_ascii_alphabetic()).count();
+
+    // The ellipsis covers the dimensions that aren't labeled, broadcast from the right
+    let mut num_ellipsis_dims = 0;
+    for (term, input) in lhs.split(',').zip(node.inputs.iter()) {
+        if let (true, ArgType::Tensor(tensor)) = (term.contains("..."), &input.ty) {
+            num_ellipsis_dims = usize::max(num_ellipsis_dims, tensor.dim - num_labels(term));
+        }
+    }
+
+    let dim = match rhs {
+        Some(rhs) if rhs.contains("...") => num_labels(rhs) + num_ellipsis_dims,
+        Some(rhs) => num_labels(rhs),
+        None => {
+            let labels: Vec<char> = lhs.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+            let num_unique = labels
+                .iter()
+                .filter(|label| labels.iter().filter(|other| other == label).count() == 1)
+                .count();
+
+            num_unique + num_ellipsis_dims
+        }
+    };
+
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Einsum: only tensor inputs are valid"),
+    };
+
+    // Burn returns a tensor of shape [1] when every label is summed
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: usize::max(dim, 1),
+        shape: None,
+    });
+}
+
 fn reshape_update_outputs(node: &mut Node) {
     assert_eq!(node.inputs.len(), 2);
 
@@ -17,13 +17,16 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 7] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 10] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
     NodeType::Dropout,
+    NodeType::Pad,
     NodeType::Reshape,
+    NodeType::Resize,
     NodeType::Unsqueeze,
 ];
 
@@ -2,8 +2,13 @@ use burn::nn::{
     conv::Conv1dConfig,
     conv::{Conv2dConfig, ConvTranspose2dConfig},
     pool::{AvgPool2dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, LinearConfig, LocalResponseNormConfig, PaddingConfig1d,
+    PaddingConfig2d,
 };
+use burn::tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn::tensor::PadMode;
+
+use crate::burn::node::resize::ResizeSize;
 
 use super::ir::{ArgType, AttributeValue, Data, Node};
 
@@ -341,6 +346,26 @@ pub fn dropout_config(node: &Node) -> DropoutConfig {
     DropoutConfig::new(prob)
 }
 
+/// Create a LocalResponseNormConfig from the attributes of the node
+pub fn local_response_norm_config(node: &Node) -> LocalResponseNormConfig {
+    let mut size = None;
+    let mut config = LocalResponseNormConfig::new(0);
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "size" => size = Some(value.clone().into_i64() as usize),
+            "alpha" => config.alpha = value.clone().into_f32() as f64,
+            "beta" => config.beta = value.clone().into_f32() as f64,
+            "bias" => config.k = value.clone().into_f32() as f64,
+            _ => {}
+        }
+    }
+
+    config.size = size.expect("LRN: size attribute must be present");
+
+    config
+}
+
 /// Create log_softmax config from the attributes of the node
 pub fn log_softmax_config(node: &Node) -> usize {
     // the axis is the last dimension (Default: 1 per ONNX spec)
@@ -438,6 +463,22 @@ pub fn concat_config(node: &Node) -> usize {
     axis as usize
 }
 
+/// Extract the equation of an einsum node
+pub fn einsum_config(node: &Node) -> String {
+    match node.attrs.get("equation") {
+        Some(equation) => equation.clone().into_string(),
+        None => panic!("Einsum: equation attribute is required"),
+    }
+}
+
+/// Extract the direction of a bitshift node
+pub fn bitshift_config(node: &Node) -> String {
+    match node.attrs.get("direction") {
+        Some(direction) => direction.clone().into_string(),
+        None => panic!("BitShift: direction attribute is required"),
+    }
+}
+
 /// Create a BatchNormConfig from the attributes of the node
 pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
     // extract the shape of the weight tensor
@@ -609,6 +650,221 @@ pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     (min_result, max_result)
 }
 
+/// Create the cumulative sum dimension from the attributes and inputs of the node
+pub fn cumsum_config(node: &Node) -> usize {
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" if value.clone().into_i64() != 0 => {
+                panic!("CumSum: exclusive cumulative sum is not supported")
+            }
+            "reverse" if value.clone().into_i64() != 0 => {
+                panic!("CumSum: reverse cumulative sum is not supported")
+            }
+            _ => {}
+        }
+    }
+
+    if node.inputs.len() != 2 || node.inputs[1].value.is_none() {
+        panic!("CumSum: axis must be a constant input");
+    }
+
+    // extract the rank of the input tensor
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("CumSum: only tensor input is valid"),
+    };
+
+    let axis = match node.inputs[1].value.clone().unwrap().into_scalar() {
+        Data::Int32(axis) => axis as i64,
+        Data::Int64(axis) => axis,
+        _ => panic!("CumSum: axis must be an int32 or int64 scalar"),
+    };
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        (axis + rank) as usize
+    } else {
+        axis as usize
+    }
+}
+
+/// Create the padding of each dimension and the padding mode from the attributes and inputs of
+/// the node
+pub fn pad_config(node: &Node) -> (Vec<(usize, usize)>, PadMode) {
+    // extract the rank of the input tensor
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    let mut mode = "constant".to_string();
+    let mut pads = None;
+    let mut value = 0.0;
+
+    for (key, value_attr) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => mode = value_attr.clone().into_string(),
+            // before opset 11, the pads and the constant value are attributes
+            "pads" => pads = Some(value_attr.clone().into_i64s()),
+            "value" => value = value_attr.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    if let Some(input) = node.inputs.get(1) {
+        match &input.value {
+            Some(Data::Int64s(values)) => pads = Some(values.clone()),
+            _ => panic!("Pad: pads must be a constant int64 tensor"),
+        }
+    }
+
+    if let Some(Some(constant)) = node.inputs.get(2).map(|input| input.value.clone()) {
+        value = match constant.into_scalar() {
+            Data::Float32(value) => value,
+            Data::Float64(value) => value as f32,
+            Data::Int32(value) => value as f32,
+            Data::Int64(value) => value as f32,
+            _ => panic!("Pad: constant value must be a float or int scalar"),
+        };
+    }
+
+    // the pads apply to all the dimensions, unless axes are given
+    let axes: Vec<usize> = match node.inputs.get(3).map(|input| input.value.clone()) {
+        Some(Some(Data::Int64s(axes))) => axes
+            .into_iter()
+            .map(|axis| if axis < 0 { axis + rank as i64 } else { axis } as usize)
+            .collect(),
+        Some(Some(_)) => panic!("Pad: axes must be a constant int64 tensor"),
+        _ => (0..rank).collect(),
+    };
+
+    let pads = pads.expect("Pad: pads must be present");
+    if pads.len() != 2 * axes.len() {
+        panic!("Pad: expected {} pads, got {}", 2 * axes.len(), pads.len());
+    }
+    if pads.iter().any(|pad| *pad < 0) {
+        panic!("Pad: negative pads are not supported");
+    }
+
+    // the pads are given as [begin_0, ..., begin_n, end_0, ..., end_n]
+    let mut pads_dims = vec![(0, 0); rank];
+    for (i, axis) in axes.iter().enumerate() {
+        pads_dims[*axis] = (pads[i] as usize, pads[i + axes.len()] as usize);
+    }
+
+    let mode = match mode.as_str() {
+        "constant" => PadMode::Constant(value),
+        "reflect" => PadMode::Reflect,
+        "edge" => PadMode::Replicate,
+        "wrap" => PadMode::Circular,
+        _ => panic!("Pad: mode {mode} is not supported"),
+    };
+
+    (pads_dims, mode)
+}
+
+/// Create the output size and the interpolation options from the attributes and inputs of the
+/// node
+pub fn resize_config(node: &Node) -> (ResizeSize, InterpolateOptions) {
+    // extract the rank of the input tensor
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Resize: only tensor input is valid"),
+    };
+
+    let mut mode = "nearest".to_string();
+    let mut coordinate_transformation_mode = "half_pixel".to_string();
+    let mut nearest_mode = "round_prefer_floor".to_string();
+    let mut cubic_coeff_a = -0.75;
+    let mut antialias = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => mode = value.clone().into_string(),
+            "coordinate_transformation_mode" => {
+                coordinate_transformation_mode = value.clone().into_string()
+            }
+            "nearest_mode" => nearest_mode = value.clone().into_string(),
+            "cubic_coeff_a" => cubic_coeff_a = value.clone().into_f32(),
+            "antialias" => antialias = value.clone().into_i64() != 0,
+            "axes" => panic!("Resize: axes are not supported"),
+            _ => {}
+        }
+    }
+
+    // the roi is the second input, and is only used by the tf_crop_and_resize mode
+    let scales = match node.inputs.get(2).map(|input| input.value.clone()) {
+        Some(Some(Data::Float32s(scales))) if !scales.is_empty() => Some(scales),
+        Some(Some(Data::Float32s(_))) | Some(None) | None => None,
+        Some(Some(_)) => panic!("Resize: scales must be a constant float32 tensor"),
+    };
+    let sizes = match node.inputs.get(3).map(|input| input.value.clone()) {
+        Some(Some(Data::Int64s(sizes))) => Some(sizes),
+        Some(None) | None => None,
+        Some(Some(_)) => panic!("Resize: sizes must be a constant int64 tensor"),
+    };
+
+    // the batch and the channel dimensions can't be resized
+    let size = match (scales, sizes) {
+        (Some(scales), None) => {
+            if scales.len() != rank || scales[0] != 1.0 || scales[1] != 1.0 {
+                panic!("Resize: only the spatial dimensions can be scaled");
+            }
+            ResizeSize::Scales(scales[2..].to_vec())
+        }
+        (None, Some(sizes)) => {
+            if sizes.len() != rank {
+                panic!("Resize: expected {rank} sizes, got {}", sizes.len());
+            }
+            ResizeSize::Sizes(sizes[2..].iter().map(|size| *size as usize).collect())
+        }
+        _ => panic!("Resize: exactly one of scales and sizes must be given"),
+    };
+
+    let mode = match (mode.as_str(), rank) {
+        ("nearest", _) => {
+            if coordinate_transformation_mode != "asymmetric" || nearest_mode != "floor" {
+                panic!("Resize: nearest mode only supports the asymmetric coordinates with floor rounding");
+            }
+            InterpolateMode::Nearest
+        }
+        ("linear", 3) => InterpolateMode::Linear,
+        ("linear", 4) => InterpolateMode::Bilinear,
+        ("linear", 5) => InterpolateMode::Trilinear,
+        ("cubic", 4) => {
+            // the antialiased cubic interpolation uses its own coefficient
+            if !antialias && cubic_coeff_a != -0.75 {
+                panic!("Resize: cubic mode only supports a cubic coefficient of -0.75");
+            }
+            InterpolateMode::Bicubic
+        }
+        _ => panic!("Resize: {mode} mode is not supported for {rank}D tensors"),
+    };
+
+    // the nearest mode has been checked to use the asymmetric coordinates
+    let align_corners = match (coordinate_transformation_mode.as_str(), &mode) {
+        (_, InterpolateMode::Nearest) => false,
+        ("align_corners", _) => true,
+        ("half_pixel", _) => false,
+        // the coordinate of an output of size one is zero instead of the half-pixel one, which
+        // only makes a difference when an input of a size greater than one is downsampled
+        ("pytorch_half_pixel", _) => match &size {
+            ResizeSize::Sizes(sizes) if sizes.iter().all(|size| *size > 1) => false,
+            ResizeSize::Scales(scales) if scales.iter().all(|scale| *scale >= 1.0) => false,
+            _ => panic!("Resize: pytorch_half_pixel coordinates are only supported when no output size is one"),
+        },
+        _ => panic!(
+            "Resize: coordinate transformation mode {coordinate_transformation_mode} is not supported for {mode:?} mode"
+        ),
+    };
+
+    let options = InterpolateOptions::new(mode)
+        .with_align_corners(align_corners)
+        .with_antialias(antialias);
+
+    (size, options)
+}
+
 /// Calculate the padding configuration for a 1D operations such as Convolution and Pooling.
 ///
 /// # Arguments
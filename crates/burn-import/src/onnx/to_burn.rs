@@ -23,12 +23,15 @@ use crate::{
             conv2d::Conv2dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             gather::GatherNode,
             global_avg_pool::GlobalAvgPoolNode,
             linear::LinearNode,
+            local_response_norm::LocalResponseNormNode,
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
             reshape::ReshapeNode,
+            resize::ResizeNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
         },
@@ -47,8 +50,8 @@ use super::{
     from_onnx::parse_onnx,
     ir::{self, ArgType, Argument, Data, ElementType, OnnxGraph},
     op_configuration::{
-        avg_pool2d_config, clip_config, concat_config, dropout_config, reshape_config,
-        softmax_config,
+        avg_pool2d_config, bitshift_config, clip_config, concat_config, cumsum_config,
+        dropout_config, einsum_config, pad_config, reshape_config, resize_config, softmax_config,
     },
 };
 
@@ -230,10 +233,29 @@ impl OnnxGraph {
                 NodeType::Mul => graph.register(Self::mul_conversion(node)),
                 NodeType::Div => graph.register(Self::div_conversion(node)),
                 NodeType::Equal => graph.register(Self::equal_conversion(node)),
+                NodeType::And => graph.register(Self::and_conversion(node)),
+                NodeType::Or => graph.register(Self::or_conversion(node)),
+                NodeType::Xor => graph.register(Self::xor_conversion(node)),
+                NodeType::Not => graph.register(Self::not_conversion(node)),
+                NodeType::BitwiseAnd => graph.register(Self::bitwise_and_conversion(node)),
+                NodeType::BitwiseOr => graph.register(Self::bitwise_or_conversion(node)),
+                NodeType::BitwiseXor => graph.register(Self::bitwise_xor_conversion(node)),
+                NodeType::BitwiseNot => graph.register(Self::bitwise_not_conversion(node)),
+                NodeType::BitShift => graph.register(Self::bitshift_conversion(node)),
                 NodeType::Erf => graph.register(Self::erf_conversion(node)),
                 NodeType::Exp => graph.register(Self::exp_conversion(node)),
                 NodeType::Clip => graph.register(Self::clip_conversion(node)),
                 NodeType::Cos => graph.register(Self::cos_conversion(node)),
+                NodeType::Acos => graph.register(Self::acos_conversion(node)),
+                NodeType::Asin => graph.register(Self::asin_conversion(node)),
+                NodeType::Atan => graph.register(Self::atan_conversion(node)),
+                NodeType::Ceil => graph.register(Self::ceil_conversion(node)),
+                NodeType::Cosh => graph.register(Self::cosh_conversion(node)),
+                NodeType::Floor => graph.register(Self::floor_conversion(node)),
+                NodeType::Round => graph.register(Self::round_conversion(node)),
+                NodeType::Sign => graph.register(Self::sign_conversion(node)),
+                NodeType::Sinh => graph.register(Self::sinh_conversion(node)),
+                NodeType::Tan => graph.register(Self::tan_conversion(node)),
                 NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
                 NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
@@ -251,16 +273,22 @@ impl OnnxGraph {
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
                 NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
+                NodeType::Pad => graph.register(Self::pad_conversion(node)),
+                NodeType::Det => graph.register(Self::det_conversion(node)),
                 NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
                 NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
                 NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
                 NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
+                NodeType::Resize => graph.register(Self::resize_conversion(node)),
                 NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
                 NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
                 NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::LRN => graph.register(Self::local_response_norm_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -390,6 +418,80 @@ impl OnnxGraph {
         BinaryNode::equal(lhs, rhs, output)
     }
 
+    fn and_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::and(lhs, rhs, output)
+    }
+
+    fn or_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::or(lhs, rhs, output)
+    }
+
+    fn xor_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::xor(lhs, rhs, output)
+    }
+
+    fn bitwise_and_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::bitwise_and(lhs, rhs, output)
+    }
+
+    fn bitwise_or_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::bitwise_or(lhs, rhs, output)
+    }
+
+    fn bitwise_xor_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::bitwise_xor(lhs, rhs, output)
+    }
+
+    fn not_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::not(input, output)
+    }
+
+    fn bitwise_not_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::bitwise_not(input, output)
+    }
+
+    fn bitshift_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        match bitshift_config(&node).as_str() {
+            "LEFT" => BinaryNode::bitshift_left(lhs, rhs, output),
+            "RIGHT" => BinaryNode::bitshift_right(lhs, rhs, output),
+            direction => panic!("BitShift: unsupported direction {direction}"),
+        }
+    }
+
     fn erf_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -502,6 +604,37 @@ impl OnnxGraph {
         UnaryNode::softmax(input, output, dim)
     }
 
+    fn cumsum_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let dim = cumsum_config(&node);
+
+        UnaryNode::cumsum(input, output, dim)
+    }
+
+    fn pad_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (pads, mode) = pad_config(&node);
+
+        UnaryNode::pad(input, output, pads, mode)
+    }
+
+    fn resize_conversion(node: Node) -> ResizeNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (size, options) = resize_config(&node);
+
+        ResizeNode::new(input, output, size, options)
+    }
+
+    fn det_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::det(input, output)
+    }
+
     fn sqrt_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -529,6 +662,19 @@ impl OnnxGraph {
         ConcatNode::new(inputs, output, dim)
     }
 
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node
+            .inputs
+            .iter()
+            .map(|input| input.to_tensor_type())
+            .collect();
+
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let equation = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, equation)
+    }
+
     fn linear_conversion<PS: PrecisionSettings>(node: Node) -> LinearNode<PS> {
         let name = &node.name;
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -551,6 +697,15 @@ impl OnnxGraph {
         DropoutNode::new(name, input, output, config)
     }
 
+    fn local_response_norm_conversion(node: Node) -> LocalResponseNormNode {
+        let name = &node.name;
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let config = local_response_norm_config(&node);
+
+        LocalResponseNormNode::new(name, input, output, config)
+    }
+
     fn batch_norm_conversion<PS: PrecisionSettings>(node: Node) -> BatchNormNode<PS> {
         let config = batch_norm_config(&node);
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -661,6 +816,76 @@ impl OnnxGraph {
         UnaryNode::cos(input, output)
     }
 
+    fn acos_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::acos(input, output)
+    }
+
+    fn asin_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::asin(input, output)
+    }
+
+    fn atan_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::atan(input, output)
+    }
+
+    fn ceil_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::ceil(input, output)
+    }
+
+    fn cosh_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::cosh(input, output)
+    }
+
+    fn floor_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::floor(input, output)
+    }
+
+    fn round_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::round(input, output)
+    }
+
+    fn sign_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::sign(input, output)
+    }
+
+    fn sinh_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::sinh(input, output)
+    }
+
+    fn tan_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::tan(input, output)
+    }
+
     fn exp_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -25,6 +25,9 @@ impl Default for NdArrayDevice {
 ///
 /// This backend is compatible with CPUs and can be compiled for almost any platform, including
 /// `wasm`, `arm`, and `x86`.
+///
+/// The float element can be `f32`, `f64`, [f16](half::f16) or [bf16](half::bf16). Half precision
+/// elements accumulate matrix multiplications and reductions in `f32`.
 #[derive(Clone, Copy, Default, Debug)]
 pub struct NdArray<E = f32> {
     phantom: PhantomData<E>,
@@ -1,4 +1,5 @@
 use burn_tensor::Element;
+use half::{bf16, f16};
 use libm::{exp, fabs, log, log1p, pow, sqrt};
 use libm::{expf, fabsf, log1pf, logf, powf, sqrtf};
 use ndarray::LinalgScalar;
@@ -14,7 +15,6 @@ where
 pub trait NdArrayElement:
     Element
     + ndarray::LinalgScalar
-    + ndarray::ScalarOperand
     + ExpElement
     + num_traits::FromPrimitive
     + core::ops::AddAssign
@@ -37,6 +37,8 @@ pub trait ExpElement {
 
 impl FloatNdArrayElement for f64 {}
 impl FloatNdArrayElement for f32 {}
+impl FloatNdArrayElement for f16 {}
+impl FloatNdArrayElement for bf16 {}
 
 macro_rules! make_elem {
     (
@@ -147,6 +149,61 @@ macro_rules! make_elem {
             }
         }
     };
+    (
+        half
+        $ty:ty
+    ) => {
+        impl NdArrayElement for $ty {}
+
+        impl ExpElement for $ty {
+            #[inline(always)]
+            fn exp_elem(self) -> Self {
+                <$ty>::from_f32(expf(self.to_f32()))
+            }
+
+            #[inline(always)]
+            fn log_elem(self) -> Self {
+                <$ty>::from_f32(logf(self.to_f32()))
+            }
+
+            #[inline(always)]
+            fn log1p_elem(self) -> Self {
+                <$ty>::from_f32(log1pf(self.to_f32()))
+            }
+
+            #[inline(always)]
+            fn powf_elem(self, value: f32) -> Self {
+                <$ty>::from_f32(powf(self.to_f32(), value))
+            }
+
+            #[inline(always)]
+            fn powi_elem(self, value: i32) -> Self {
+                #[cfg(feature = "std")]
+                let val = <$ty>::from_f32(f32::powi(self.to_f32(), value));
+
+                #[cfg(not(feature = "std"))]
+                let val = Self::powf_elem(self, value as f32);
+
+                val
+            }
+
+            #[inline(always)]
+            fn sqrt_elem(self) -> Self {
+                <$ty>::from_f32(sqrtf(self.to_f32()))
+            }
+
+            #[inline(always)]
+            fn abs_elem(self) -> Self {
+                <$ty>::from_f32(fabsf(self.to_f32()))
+            }
+
+            #[inline(always)]
+            fn int_abs_elem(self) -> Self {
+                // Clears the sign bit, without converting the value to an integer.
+                <$ty>::from_bits(self.to_bits() & 0x7fff)
+            }
+        }
+    };
 }
 
 make_elem!(double f64);
@@ -156,3 +213,6 @@ make_elem!(single f32);
 make_elem!(single i32);
 make_elem!(single i16);
 make_elem!(single u8);
+
+make_elem!(half f16);
+make_elem!(half bf16);
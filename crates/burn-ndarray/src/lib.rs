@@ -41,4 +41,10 @@ mod tests {
 
     #[cfg(feature = "std")]
     burn_autodiff::testgen_all!();
+
+    #[cfg(feature = "std")]
+    burn_vmap::testgen_all!();
 }
+
+#[cfg(test)]
+mod tests_half;
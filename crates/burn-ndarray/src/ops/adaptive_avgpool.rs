@@ -3,7 +3,7 @@ use crate::{
     tensor::NdArrayTensor,
 };
 use burn_tensor::ElementConversion;
-use ndarray::Array4;
+use ndarray::{Array4, Array5};
 
 pub(crate) fn adaptive_avg_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -90,6 +90,118 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: FloatNdArrayElement>(
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
 
+pub(crate) fn adaptive_avg_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    output_size: [usize; 3],
+) -> NdArrayTensor<E, 5> {
+    let [batch_size, channels, input_depth, input_height, input_width] = x.shape().dims;
+
+    let x = x.array;
+    let mut output = Array5::from_elem(
+        (
+            batch_size,
+            channels,
+            output_size[0],
+            output_size[1],
+            output_size[2],
+        ),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            for d in 0..output_size[0] {
+                for h in 0..output_size[1] {
+                    for w in 0..output_size[2] {
+                        let id_start = start_index(d, output_size[0], input_depth);
+                        let id_end = end_index(d, output_size[0], input_depth);
+                        let ih_start = start_index(h, output_size[1], input_height);
+                        let ih_end = end_index(h, output_size[1], input_height);
+                        let iw_start = start_index(w, output_size[2], input_width);
+                        let iw_end = end_index(w, output_size[2], input_width);
+
+                        let mut sum_val: E = 0.elem();
+
+                        for id in id_start..id_end {
+                            for ih in ih_start..ih_end {
+                                for iw in iw_start..iw_end {
+                                    sum_val += x[[b, c, id, ih, iw]];
+                                }
+                            }
+                        }
+
+                        let count: E = (((id_end - id_start)
+                            * (ih_end - ih_start)
+                            * (iw_end - iw_start)) as i32)
+                            .elem();
+                        output[[b, c, d, h, w]] = sum_val / count;
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    grad: NdArrayTensor<E, 5>,
+) -> NdArrayTensor<E, 5> {
+    let [_, _, input_depth, input_height, input_width] = x.shape().dims;
+    let [batch_size, channels, output_depth, output_height, output_width] = grad.shape().dims;
+
+    let mut output_grad = Array5::from_elem(
+        (batch_size, channels, input_depth, input_height, input_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_out.get();
+            for od in 0..output_depth {
+                for oh in 0..output_height {
+                    for ow in 0..output_width {
+                        let id_start = start_index(od, output_depth, input_depth);
+                        let id_end = end_index(od, output_depth, input_depth);
+
+                        let ih_start = start_index(oh, output_height, input_height);
+                        let ih_end = end_index(oh, output_height, input_height);
+
+                        let iw_start = start_index(ow, output_width, input_width);
+                        let iw_end = end_index(ow, output_width, input_width);
+
+                        let count: E = (((id_end - id_start)
+                            * (ih_end - ih_start)
+                            * (iw_end - iw_start)) as i32)
+                            .elem();
+
+                        for id in id_start..id_end {
+                            for ih in ih_start..ih_end {
+                                for iw in iw_start..iw_end {
+                                    output_grad[[b, c, id, ih, iw]] +=
+                                        grad.array[[b, c, od, oh, ow]] / count;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
+
 fn start_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
     libm::floorf((output_size_index as f32 * input_size as f32) / output_size as f32) as usize
 }
@@ -0,0 +1,285 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor, NdArray,
+};
+
+use alloc::vec;
+use burn_tensor::ops::{
+    attention::{attention_scale, AttentionDropout},
+    AttentionBackward, AttentionOptions,
+};
+use burn_tensor::ElementConversion;
+use core::ops::Range;
+use libm::exp;
+use ndarray::{ArcArray, Array4, Ix4, IxDyn};
+
+/// The number of keys whose scores are computed together.
+const BLOCK_SIZE: usize = 64;
+
+/// Scaled dot-product attention computed one query and one block of keys at a time, so that the
+/// attention weights are never stored.
+///
+/// The softmax is computed online: the running sum of the exponentials and the running output of
+/// each query are rescaled whenever a block of keys increases the maximum score.
+pub(crate) fn scaled_dot_product_attention<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    options: AttentionOptions,
+) -> NdArrayTensor<E, 4> {
+    let attention = Attention::new(query, key, value, mask, &options);
+    let [batch_size, n_heads, seq_length_1, _] = attention.query.shape().try_into().unwrap();
+    let d_v = attention.value.shape()[3];
+
+    let mut output = Array4::from_elem((batch_size, n_heads, seq_length_1, d_v), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            let output = unsafe_shared_out.get();
+            let mut row = vec![0.0; d_v];
+
+            for i in 0..seq_length_1 {
+                attention.forward_row(b, h, i, &mut row);
+
+                for (d, value) in row.iter().enumerate() {
+                    output[[b, h, i, d]] = value.elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Backward pass of the scaled dot-product attention, which computes the attention weights of
+/// each query again instead of storing them.
+pub(crate) fn scaled_dot_product_attention_backward<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    grad: NdArrayTensor<E, 4>,
+    options: AttentionOptions,
+) -> AttentionBackward<NdArray<E>> {
+    let attention = Attention::new(query, key, value, mask, &options);
+    let grad = to_f64(grad);
+    let [batch_size, n_heads, seq_length_1, d_k] = attention.query.shape().try_into().unwrap();
+    let [_, _, seq_length_2, d_v] = attention.value.shape().try_into().unwrap();
+
+    let mut query_grad = Array4::<f64>::zeros((batch_size, n_heads, seq_length_1, d_k));
+    let mut key_grad = Array4::<f64>::zeros((batch_size, n_heads, seq_length_2, d_k));
+    let mut value_grad = Array4::<f64>::zeros((batch_size, n_heads, seq_length_2, d_v));
+    let unsafe_shared_query_grad = UnsafeSharedRef::new(&mut query_grad);
+    let unsafe_shared_key_grad = UnsafeSharedRef::new(&mut key_grad);
+    let unsafe_shared_value_grad = UnsafeSharedRef::new(&mut value_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            let query_grad = unsafe_shared_query_grad.get();
+            let key_grad = unsafe_shared_key_grad.get();
+            let value_grad = unsafe_shared_value_grad.get();
+            let mut row = vec![0.0; d_v];
+
+            for i in 0..seq_length_1 {
+                let (max, sum) = attention.forward_row(b, h, i, &mut row);
+
+                if sum == 0.0 {
+                    continue;
+                }
+
+                // The sum of the weights multiplied by the gradients of the weights, which is the
+                // dot product of the output and its gradient.
+                let delta = (0..d_v).map(|d| grad[[b, h, i, d]] * row[d]).sum::<f64>();
+
+                for j in attention.keys(i) {
+                    let score = match attention.score(b, h, i, j) {
+                        Some(score) => score,
+                        None => continue,
+                    };
+                    let weight = exp(score - max) / sum;
+                    let dropout = attention.dropout(b, h, i, j);
+
+                    let weight_grad = (0..d_v)
+                        .map(|d| grad[[b, h, i, d]] * attention.value[[b, h, j, d]])
+                        .sum::<f64>()
+                        * dropout;
+                    let score_grad = weight * (weight_grad - delta) * attention.scale;
+
+                    for d in 0..d_v {
+                        value_grad[[b, h, j, d]] += weight * dropout * grad[[b, h, i, d]];
+                    }
+                    for d in 0..d_k {
+                        query_grad[[b, h, i, d]] += score_grad * attention.key[[b, h, j, d]];
+                        key_grad[[b, h, j, d]] += score_grad * attention.query[[b, h, i, d]];
+                    }
+                }
+            }
+        })
+    });
+
+    AttentionBackward::new(
+        from_f64(query_grad),
+        from_f64(key_grad),
+        from_f64(value_grad),
+    )
+}
+
+/// The inputs of the attention converted to `f64`, with the options resolved.
+struct Attention {
+    query: Array4<f64>,
+    key: Array4<f64>,
+    value: Array4<f64>,
+    mask: Option<ArcArray<bool, IxDyn>>,
+    scale: f64,
+    causal: bool,
+    dropout: Option<AttentionDropout>,
+}
+
+impl Attention {
+    fn new<E: FloatNdArrayElement>(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        options: &AttentionOptions,
+    ) -> Self {
+        let [_, _, _, d_k] = query.shape().dims;
+
+        Self {
+            query: to_f64(query),
+            key: to_f64(key),
+            value: to_f64(value),
+            mask: mask.map(|mask| mask.array),
+            scale: attention_scale(d_k, options),
+            causal: options.causal,
+            dropout: (options.dropout > 0.0)
+                .then(|| AttentionDropout::new(options.seed.unwrap_or(0), options.dropout)),
+        }
+    }
+
+    /// The keys the query at position `i` can attend to, the causal mask being applied by
+    /// skipping the keys after the position of the query aligned with the last key.
+    fn keys(&self, i: usize) -> Range<usize> {
+        let seq_length_1 = self.query.shape()[2];
+        let seq_length_2 = self.key.shape()[2];
+
+        match self.causal {
+            true => {
+                0..usize::min(
+                    i + seq_length_2.saturating_sub(seq_length_1) + 1,
+                    seq_length_2,
+                )
+            }
+            false => 0..seq_length_2,
+        }
+    }
+
+    /// The scaled dot product of the query `i` and the key `j`, or `None` if the key is masked.
+    fn score(&self, b: usize, h: usize, i: usize, j: usize) -> Option<f64> {
+        if let Some(mask) = &self.mask {
+            let mask_b = if mask.shape()[0] == 1 { 0 } else { b };
+            let mask_h = if mask.shape()[1] == 1 { 0 } else { h };
+
+            if mask[[mask_b, mask_h, i, j]] {
+                return None;
+            }
+        }
+
+        let d_k = self.query.shape()[3];
+        let dot = (0..d_k)
+            .map(|d| self.query[[b, h, i, d]] * self.key[[b, h, j, d]])
+            .sum::<f64>();
+
+        Some(dot * self.scale)
+    }
+
+    /// The factor multiplying the attention weight of the query `i` and the key `j` for the
+    /// dropout.
+    fn dropout(&self, b: usize, h: usize, i: usize, j: usize) -> f64 {
+        match &self.dropout {
+            Some(dropout) if dropout.keep([b, h, i, j]) => dropout.factor(),
+            Some(_) => 0.0,
+            None => 1.0,
+        }
+    }
+
+    /// Computes the output of the query `i` in `output`, returning the maximum score and the sum
+    /// of the exponentials of the scores minus the maximum, which is zero when all the keys are
+    /// masked.
+    fn forward_row(&self, b: usize, h: usize, i: usize, output: &mut [f64]) -> (f64, f64) {
+        let mut scores = [0.0; BLOCK_SIZE];
+        let mut max = f64::NEG_INFINITY;
+        let mut sum = 0.0;
+        output.fill(0.0);
+
+        let keys = self.keys(i);
+
+        for start in keys.clone().step_by(BLOCK_SIZE) {
+            let end = usize::min(start + BLOCK_SIZE, keys.end);
+            let mut block_max = f64::NEG_INFINITY;
+
+            for j in start..end {
+                let score = self.score(b, h, i, j).unwrap_or(f64::NEG_INFINITY);
+                block_max = f64::max(block_max, score);
+                scores[j - start] = score;
+            }
+
+            if block_max == f64::NEG_INFINITY {
+                continue;
+            }
+
+            // Rescale the running sum and output to the new maximum.
+            let new_max = f64::max(max, block_max);
+            let correction = exp(max - new_max);
+            sum *= correction;
+            output.iter_mut().for_each(|value| *value *= correction);
+            max = new_max;
+
+            for j in start..end {
+                let score = scores[j - start];
+                if score == f64::NEG_INFINITY {
+                    continue;
+                }
+
+                let weight = exp(score - max);
+                sum += weight;
+
+                let weight = weight * self.dropout(b, h, i, j);
+                for (d, value) in output.iter_mut().enumerate() {
+                    *value += weight * self.value[[b, h, j, d]];
+                }
+            }
+        }
+
+        if sum > 0.0 {
+            output.iter_mut().for_each(|value| *value /= sum);
+        }
+
+        (max, sum)
+    }
+}
+
+fn to_f64<E: FloatNdArrayElement>(tensor: NdArrayTensor<E, 4>) -> Array4<f64> {
+    tensor
+        .array
+        .mapv(|value| value.elem::<f64>())
+        .into_dimensionality::<Ix4>()
+        .unwrap()
+}
+
+fn from_f64<E: FloatNdArrayElement>(array: Array4<f64>) -> NdArrayTensor<E, 4> {
+    NdArrayTensor::new(
+        array
+            .mapv(|value| value.elem::<E>())
+            .into_dyn()
+            .into_shared(),
+    )
+}
@@ -1,9 +1,11 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use burn_tensor::Data;
-use burn_tensor::ElementConversion;
-use core::{marker::PhantomData, ops::Range};
+use burn_tensor::{ElementComparison, ElementConversion};
+use core::{cmp::Ordering, marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
+use ndarray::ArrayD;
 use ndarray::Zip;
 
 use burn_tensor::Shape;
@@ -110,6 +112,31 @@ where
 
         NdArrayTensor::new(array)
     }
+
+    /// Applies the function to the elements of the two tensors, broadcasting them to the same
+    /// shape.
+    pub(crate) fn broadcast_op<const D: usize, O>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        op: impl Fn(E, E) -> O,
+    ) -> NdArrayTensor<O, D> {
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(lhs, rhs)| if *lhs == 1 { *rhs } else { *lhs })
+            .collect::<Vec<_>>();
+        let lhs = lhs.array.broadcast(shape.clone()).unwrap();
+        let rhs = rhs.array.broadcast(shape).unwrap();
+
+        NdArrayTensor::new(
+            Zip::from(&lhs)
+                .and(&rhs)
+                .map_collect(|lhs, rhs| op(*lhs, *rhs))
+                .into_shared(),
+        )
+    }
 }
 
 impl<E> NdArrayMathOps<E>
@@ -127,7 +154,7 @@ where
     }
 
     pub fn add_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array + rhs;
+        let array = lhs.array.mapv_into(|a| a + rhs);
         let array = array.into_shared();
 
         NdArrayTensor { array }
@@ -144,7 +171,7 @@ where
     }
 
     pub fn sub_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array - rhs;
+        let array = lhs.array.mapv_into(|a| a - rhs);
         let array = array.into_shared();
 
         NdArrayTensor { array }
@@ -161,7 +188,7 @@ where
     }
 
     pub fn mul_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array * rhs;
+        let array = lhs.array.mapv_into(|a| a * rhs);
         let array = array.into_shared();
 
         NdArrayTensor { array }
@@ -178,7 +205,7 @@ where
     }
 
     pub fn div_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array / rhs;
+        let array = lhs.array.mapv_into(|a| a / rhs);
         let array = array.into_shared();
 
         NdArrayTensor { array }
@@ -461,6 +488,205 @@ where
         tensor
     }
 
+    pub fn sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+
+        array.lanes_mut(Axis(dim)).into_iter().for_each(|mut lane| {
+            let mut values = lane.to_vec();
+            values.sort_unstable_by(|a, b| compare(a, b, descending));
+            lane.iter_mut()
+                .zip(values)
+                .for_each(|(elem, value)| *elem = value);
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut array = tensor.array.into_owned();
+        let mut indices = ArrayD::<i64>::zeros(array.raw_dim());
+
+        Zip::from(array.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|mut lane, mut lane_indices| {
+                let mut pairs = lane.iter().copied().enumerate().collect::<Vec<_>>();
+                pairs.sort_by(|(_, a), (_, b)| compare(a, b, descending));
+
+                for (i, (index, value)) in pairs.into_iter().enumerate() {
+                    lane[i] = value;
+                    lane_indices[i] = index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(array.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        let mut indices = ArrayD::<i64>::zeros(tensor.array.raw_dim());
+
+        Zip::from(tensor.array.lanes(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|lane, mut lane_indices| {
+                let mut positions = (0..lane.len()).collect::<Vec<_>>();
+                positions.sort_by(|&a, &b| compare(&lane[a], &lane[b], descending));
+
+                for (i, position) in positions.into_iter().enumerate() {
+                    lane_indices[i] = position as i64;
+                }
+            });
+
+        NdArrayTensor::new(indices.into_shared())
+    }
+
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        Self::cumulative_cmp(tensor, dim, Ordering::Greater)
+    }
+
+    pub fn cummin<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        Self::cumulative_cmp(tensor, dim, Ordering::Less)
+    }
+
+    pub fn cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        Self::cumulative_cmp_with_indices(tensor, dim, Ordering::Greater)
+    }
+
+    pub fn cummin_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        Self::cumulative_cmp_with_indices(tensor, dim, Ordering::Less)
+    }
+
+    /// Keeps the previous running value only when it compares as `keep` against the current one,
+    /// so that ties select the last occurrence.
+    fn cumulative_cmp<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        keep: Ordering,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |prev, curr| {
+            if prev.cmp(curr) == keep {
+                *curr = *prev;
+            }
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    fn cumulative_cmp_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        keep: Ordering,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut array = tensor.array.into_owned();
+        let mut indices = ArrayD::<i64>::zeros(array.raw_dim());
+
+        Zip::from(array.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|mut lane, mut lane_indices| {
+                let mut index = 0;
+
+                for i in 0..lane.len() {
+                    if i > 0 && lane[i - 1].cmp(&lane[i]) == keep {
+                        lane[i] = lane[i - 1];
+                    } else {
+                        index = i;
+                    }
+                    lane_indices[i] = index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(array.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn unique(
+        tensor: NdArrayTensor<E, 1>,
+    ) -> (
+        NdArrayTensor<E, 1>,
+        NdArrayTensor<i64, 1>,
+        NdArrayTensor<i64, 1>,
+    ) {
+        let mut pairs = tensor.array.iter().copied().enumerate().collect::<Vec<_>>();
+        pairs.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+        let mut values = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = vec![0; pairs.len()];
+
+        for (index, value) in pairs {
+            if values.last().map(|last: &E| last.cmp(&value)) != Some(Ordering::Equal) {
+                values.push(value);
+                counts.push(0);
+            }
+
+            inverse[index] = (values.len() - 1) as i64;
+            *counts.last_mut().unwrap() += 1;
+        }
+
+        let num_unique = values.len();
+        let num_elements = inverse.len();
+
+        (
+            NdArrayTensor::from_data(Data::new(values, Shape::new([num_unique]))),
+            NdArrayTensor::from_data(Data::new(inverse, Shape::new([num_elements]))),
+            NdArrayTensor::from_data(Data::new(counts, Shape::new([num_unique]))),
+        )
+    }
+
+    pub fn searchsorted<const D: usize>(
+        sorted: NdArrayTensor<E, 1>,
+        values: NdArrayTensor<E, D>,
+        right: bool,
+    ) -> NdArrayTensor<i64, D> {
+        let sorted = sorted.array.iter().copied().collect::<Vec<_>>();
+        let indices = values.array.mapv(|value| {
+            let index = match right {
+                true => sorted.partition_point(|elem| elem.cmp(&value) != Ordering::Greater),
+                false => sorted.partition_point(|elem| elem.cmp(&value) == Ordering::Less),
+            };
+
+            index as i64
+        });
+
+        NdArrayTensor::new(indices.into_shared())
+    }
+
     pub(crate) fn elementwise_op<const D: usize, OtherE>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<OtherE, D>,
@@ -482,6 +708,14 @@ where
     }
 }
 
+fn compare<E: ElementComparison>(a: &E, b: &E, descending: bool) -> Ordering {
+    if descending {
+        b.cmp(a)
+    } else {
+        a.cmp(b)
+    }
+}
+
 enum CmpType {
     Min,
     Max,
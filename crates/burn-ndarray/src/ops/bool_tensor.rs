@@ -114,6 +114,27 @@ impl<E: FloatNdArrayElement> BoolTensorOps<Self> for NdArray<E> {
         NdArrayTensor { array }
     }
 
+    fn bool_and<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs & rhs)
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs | rhs)
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+
     fn bool_into_float<const D: usize>(
         tensor: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> <NdArray<E> as Backend>::FloatTensorPrimitive<D> {
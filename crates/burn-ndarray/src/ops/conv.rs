@@ -1,12 +1,20 @@
 use burn_tensor::{
-    ops::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions},
-    ElementConversion,
+    ops::{
+        conv::{calculate_conv_output_size, calculate_conv_transpose_output_size},
+        ConvOptions, ConvTransposeOptions, FloatTensorOps,
+    },
+    ElementConversion, Precision,
 };
-use ndarray::{s, Array3, Array4, ArrayView2, ArrayViewMut2, Axis, Dim};
+use ndarray::{s, Array3, Array4, Array5, ArrayView2, ArrayViewMut2, Axis, Dim};
 
 use crate::{
-    element::FloatNdArrayElement, iter_par, iter_range_par, ops::padding::apply_padding_4d,
-    run_par, sharing::UnsafeSharedRef, tensor::NdArrayTensor,
+    element::FloatNdArrayElement,
+    iter_par, iter_range_par,
+    ops::padding::{apply_padding_4d, apply_padding_5d},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+    NdArray,
 };
 
 #[inline(always)]
@@ -53,6 +61,15 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
     bias: Option<NdArrayTensor<E, 1>>,
     options: ConvOptions<2>,
 ) -> NdArrayTensor<E, 4> {
+    // Half precision products are accumulated in full precision, as done for matmul.
+    if let Precision::Half = E::precision() {
+        let x = NdArray::<E>::float_to_full_precision(&x);
+        let weight = NdArray::<E>::float_to_full_precision(&weight);
+        let bias = bias.map(|bias| NdArray::<E>::float_to_full_precision(&bias));
+
+        return NdArray::<E>::float_from_full_precision(conv2d(x, weight, bias, options));
+    }
+
     let [dilation_height, dilation_width] = options.dilation;
     let [padding_height, padding_width] = options.padding;
     let [stride_height, stride_width] = options.stride;
@@ -90,7 +107,7 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
                 |(k, mut output)| {
                     let b = k / out_channels;
                     let oc = k % out_channels;
-                    let g = k % options.groups;
+                    let g = oc / (out_channels / options.groups);
 
                     for ic in (in_channels * g)..(in_channels * (g + 1)) {
                         let weight_ic = ic - (g * in_channels);
@@ -172,6 +189,15 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
     bias: Option<NdArrayTensor<E, 1>>,
     options: ConvTransposeOptions<2>,
 ) -> NdArrayTensor<E, 4> {
+    // Half precision products are accumulated in full precision, as done for matmul.
+    if let Precision::Half = E::precision() {
+        let x = NdArray::<E>::float_to_full_precision(&x);
+        let weight = NdArray::<E>::float_to_full_precision(&weight);
+        let bias = bias.map(|bias| NdArray::<E>::float_to_full_precision(&bias));
+
+        return NdArray::<E>::float_from_full_precision(conv_transpose2d(x, weight, bias, options));
+    }
+
     let [dilation_height, dilation_width] = options.dilation;
     let [padding_height, padding_width] = options.padding;
     let [stride_height, stride_width] = options.stride;
@@ -203,7 +229,7 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
         iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
             let b = k / (out_channels * options.groups);
             let oc = k % out_channels;
-            let g = k % options.groups;
+            let g = (k / out_channels) % options.groups;
 
             let output = unsafe_shared_out.get();
 
@@ -250,3 +276,234 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+pub(crate) fn conv3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    // Half precision products are accumulated in full precision, as done for matmul.
+    if let Precision::Half = E::precision() {
+        let x = NdArray::<E>::float_to_full_precision(&x);
+        let weight = NdArray::<E>::float_to_full_precision(&weight);
+        let bias = bias.map(|bias| NdArray::<E>::float_to_full_precision(&bias));
+
+        return NdArray::<E>::float_from_full_precision(conv3d(x, weight, bias, options));
+    }
+
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [out_channels, in_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+
+    let out_depth = calculate_conv_output_size(
+        kernel_depth,
+        stride_depth,
+        padding_depth,
+        dilation_depth,
+        in_depth,
+    );
+    let out_height = calculate_conv_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    let x = apply_padding_5d(x, options.padding, 0i32.elem()).array;
+
+    // Convert inputs from dynamic indexes to static to improve perf.
+    let x = x.into_dimensionality::<ndarray::Ix5>().unwrap();
+    let weights = weight.array.into_dimensionality::<ndarray::Ix5>().unwrap();
+
+    let mut output = Array4::zeros(Dim([
+        batch_size * out_channels,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+
+    run_par!(|| {
+        iter_par!(output.axis_iter_mut(Axis(0)))
+            .enumerate()
+            .for_each(|(k, mut output)| {
+                let b = k / out_channels;
+                let oc = k % out_channels;
+                let g = oc / (out_channels / options.groups);
+
+                for ic in (in_channels * g)..(in_channels * (g + 1)) {
+                    let weight_ic = ic - (g * in_channels);
+
+                    let x = x.slice(s![b, ic, .., .., ..]);
+                    let k = weights.slice(s![oc, weight_ic, .., .., ..]);
+
+                    for od in 0..out_depth {
+                        let mut output = output.slice_mut(s![od, .., ..]);
+
+                        for kd in 0..kernel_depth {
+                            let x = x.slice(s![od * stride_depth + kd * dilation_depth, .., ..]);
+
+                            for kh in 0..kernel_height {
+                                for kw in 0..kernel_width {
+                                    conv2d_mad_inner(
+                                        output.view_mut(),
+                                        x.view(),
+                                        k[[kd, kh, kw]],
+                                        (kh, kw),
+                                        (out_width, out_height),
+                                        (stride_width, stride_height),
+                                        (dilation_width, dilation_height),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(bias) = &bias {
+                    let bias = bias.array[oc];
+                    output.mapv_inplace(|value| value + bias);
+                }
+            });
+    });
+
+    let output = output
+        .into_shape([batch_size, out_channels, out_depth, out_height, out_width])
+        .unwrap()
+        .into_dyn()
+        .into_shared();
+
+    NdArrayTensor::new(output)
+}
+
+pub(crate) fn conv_transpose3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    // Half precision products are accumulated in full precision, as done for matmul.
+    if let Precision::Half = E::precision() {
+        let x = NdArray::<E>::float_to_full_precision(&x);
+        let weight = NdArray::<E>::float_to_full_precision(&weight);
+        let bias = bias.map(|bias| NdArray::<E>::float_to_full_precision(&bias));
+
+        return NdArray::<E>::float_from_full_precision(conv_transpose3d(x, weight, bias, options));
+    }
+
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [out_padding_depth, out_padding_height, out_padding_width] = options.padding_out;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [in_channels, out_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+
+    let out_depth = calculate_conv_transpose_output_size(
+        kernel_depth,
+        stride_depth,
+        padding_depth,
+        out_padding_depth,
+        dilation_depth,
+        in_depth,
+    );
+    let out_height = calculate_conv_transpose_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        out_padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_transpose_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        out_padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    let x = x.array;
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels * options.groups,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
+            let b = k / (out_channels * options.groups);
+            let oc = k % out_channels;
+            let g = (k / out_channels) % options.groups;
+
+            let output = unsafe_shared_out.get();
+
+            let oc_out = oc + (out_channels * g);
+            let ic_start = g * (in_channels / options.groups);
+            let ic_end = ic_start + in_channels / options.groups;
+
+            for ic in ic_start..ic_end {
+                for id in 0..in_depth {
+                    for ih in 0..in_height {
+                        for iw in 0..in_width {
+                            for kd in 0..kernel_depth {
+                                for kh in 0..kernel_height {
+                                    for kw in 0..kernel_width {
+                                        let od = id * stride_depth + kd * dilation_depth;
+                                        let oh = ih * stride_height + kh * dilation_height;
+                                        let ow = iw * stride_width + kw * dilation_width;
+
+                                        if od >= out_depth + padding_depth
+                                            || oh >= out_height + padding_height
+                                            || ow >= out_width + padding_width
+                                            || od < padding_depth
+                                            || oh < padding_height
+                                            || ow < padding_width
+                                        {
+                                            continue;
+                                        }
+
+                                        let od = od - padding_depth;
+                                        let oh = oh - padding_height;
+                                        let ow = ow - padding_width;
+
+                                        output[[b, oc_out, od, oh, ow]] += x[[b, ic, id, ih, iw]]
+                                            * weight.array[[ic, oc, kd, kh, kw]];
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(bias) = &bias {
+                for od in 0..out_depth {
+                    for oh in 0..out_height {
+                        for ow in 0..out_width {
+                            output[[b, oc_out, od, oh, ow]] += bias.array[oc_out];
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -0,0 +1,244 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::ElementConversion;
+use core::f64::consts::PI;
+use libm::{cos, sin};
+use ndarray::{ArrayD, Axis, Zip};
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+
+pub(crate) fn fft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    transform_lanes(real, imag, dim, false)
+}
+
+pub(crate) fn ifft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    transform_lanes(real, imag, dim, true)
+}
+
+pub(crate) fn rfft<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let n = tensor.array.shape()[dim];
+    let mut shape = tensor.array.raw_dim();
+    shape[dim] = n / 2 + 1;
+
+    let mut real = ArrayD::<E>::zeros(shape.clone());
+    let mut imag = ArrayD::<E>::zeros(shape);
+
+    Zip::from(tensor.array.lanes(Axis(dim)))
+        .and(real.lanes_mut(Axis(dim)))
+        .and(imag.lanes_mut(Axis(dim)))
+        .for_each(|lane, mut lane_real, mut lane_imag| {
+            let mut buffer_real: Vec<f64> = lane.iter().map(|x| x.elem()).collect();
+            let mut buffer_imag = vec![0.0; n];
+
+            transform(&mut buffer_real, &mut buffer_imag, false);
+
+            for i in 0..lane_real.len() {
+                lane_real[i] = buffer_real[i].elem();
+                lane_imag[i] = buffer_imag[i].elem();
+            }
+        });
+
+    (
+        NdArrayTensor::new(real.into_shared()),
+        NdArrayTensor::new(imag.into_shared()),
+    )
+}
+
+pub(crate) fn irfft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+    n: usize,
+) -> NdArrayTensor<E, D> {
+    let mut shape = real.array.raw_dim();
+    shape[dim] = n;
+
+    let mut output = ArrayD::<E>::zeros(shape);
+
+    Zip::from(real.array.lanes(Axis(dim)))
+        .and(imag.array.lanes(Axis(dim)))
+        .and(output.lanes_mut(Axis(dim)))
+        .for_each(|lane_real, lane_imag, mut lane_output| {
+            let size = lane_real.len();
+            let mut buffer_real = vec![0.0; n];
+            let mut buffer_imag = vec![0.0; n];
+
+            // Rebuild the negative frequencies from the Hermitian symmetry of real signals.
+            for j in 0..size {
+                buffer_real[j] = lane_real[j].elem();
+                buffer_imag[j] = lane_imag[j].elem();
+
+                if j > 0 && n - j >= size {
+                    buffer_real[n - j] = buffer_real[j];
+                    buffer_imag[n - j] = -buffer_imag[j];
+                }
+            }
+
+            transform(&mut buffer_real, &mut buffer_imag, true);
+
+            for i in 0..n {
+                lane_output[i] = buffer_real[i].elem();
+            }
+        });
+
+    NdArrayTensor::new(output.into_shared())
+}
+
+fn transform_lanes<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let mut real = real.array.into_owned();
+    let mut imag = imag.array.into_owned();
+
+    Zip::from(real.lanes_mut(Axis(dim)))
+        .and(imag.lanes_mut(Axis(dim)))
+        .for_each(|mut lane_real, mut lane_imag| {
+            let mut buffer_real: Vec<f64> = lane_real.iter().map(|x| x.elem()).collect();
+            let mut buffer_imag: Vec<f64> = lane_imag.iter().map(|x| x.elem()).collect();
+
+            transform(&mut buffer_real, &mut buffer_imag, inverse);
+
+            for i in 0..buffer_real.len() {
+                lane_real[i] = buffer_real[i].elem();
+                lane_imag[i] = buffer_imag[i].elem();
+            }
+        });
+
+    (
+        NdArrayTensor::new(real.into_shared()),
+        NdArrayTensor::new(imag.into_shared()),
+    )
+}
+
+/// Computes the discrete Fourier transform in place, where the inverse transform is normalized.
+fn transform(real: &mut [f64], imag: &mut [f64], inverse: bool) {
+    let n = real.len();
+
+    // The inverse transform is the conjugate of the forward transform of the conjugate.
+    if inverse {
+        imag.iter_mut().for_each(|x| *x = -*x);
+    }
+
+    if n.is_power_of_two() {
+        radix2(real, imag);
+    } else if n > 1 {
+        bluestein(real, imag);
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f64;
+        real.iter_mut().for_each(|x| *x *= scale);
+        imag.iter_mut().for_each(|x| *x *= -scale);
+    }
+}
+
+/// Iterative Cooley-Tukey transform for sizes that are powers of two.
+fn radix2(real: &mut [f64], imag: &mut [f64]) {
+    let n = real.len();
+    let mut j = 0;
+
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            real.swap(i, j);
+            imag.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+
+        for k in 0..half {
+            let angle = -2.0 * PI * k as f64 / size as f64;
+            let (w_real, w_imag) = (cos(angle), sin(angle));
+
+            for start in (0..n).step_by(size) {
+                let (a, b) = (start + k, start + k + half);
+                let t_real = real[b] * w_real - imag[b] * w_imag;
+                let t_imag = real[b] * w_imag + imag[b] * w_real;
+
+                real[b] = real[a] - t_real;
+                imag[b] = imag[a] - t_imag;
+                real[a] += t_real;
+                imag[a] += t_imag;
+            }
+        }
+
+        size <<= 1;
+    }
+}
+
+/// Bluestein's algorithm, which expresses a transform of any size as a convolution computed with
+/// power of two transforms.
+fn bluestein(real: &mut [f64], imag: &mut [f64]) {
+    let n = real.len();
+    let m = (2 * n - 1).next_power_of_two();
+
+    // Chirp `w_k = exp(-i * pi * k^2 / n)`, where `k^2` is reduced modulo `2n` for accuracy.
+    let (chirp_real, chirp_imag): (Vec<f64>, Vec<f64>) = (0..n)
+        .map(|k| {
+            let angle = PI * ((k as u64 * k as u64) % (2 * n as u64)) as f64 / n as f64;
+            (cos(angle), -sin(angle))
+        })
+        .unzip();
+
+    let mut a_real = vec![0.0; m];
+    let mut a_imag = vec![0.0; m];
+    let mut b_real = vec![0.0; m];
+    let mut b_imag = vec![0.0; m];
+
+    for k in 0..n {
+        a_real[k] = real[k] * chirp_real[k] - imag[k] * chirp_imag[k];
+        a_imag[k] = real[k] * chirp_imag[k] + imag[k] * chirp_real[k];
+        b_real[k] = chirp_real[k];
+        b_imag[k] = -chirp_imag[k];
+
+        if k > 0 {
+            b_real[m - k] = chirp_real[k];
+            b_imag[m - k] = -chirp_imag[k];
+        }
+    }
+
+    radix2(&mut a_real, &mut a_imag);
+    radix2(&mut b_real, &mut b_imag);
+
+    // Pointwise product followed by an inverse transform, computed with conjugates.
+    for i in 0..m {
+        let product_real = a_real[i] * b_real[i] - a_imag[i] * b_imag[i];
+        let product_imag = a_real[i] * b_imag[i] + a_imag[i] * b_real[i];
+        a_real[i] = product_real;
+        a_imag[i] = -product_imag;
+    }
+
+    radix2(&mut a_real, &mut a_imag);
+
+    let scale = 1.0 / m as f64;
+    for k in 0..n {
+        let conv_real = a_real[k] * scale;
+        let conv_imag = -a_imag[k] * scale;
+
+        real[k] = conv_real * chirp_real[k] - conv_imag * chirp_imag[k];
+        imag[k] = conv_real * chirp_imag[k] + conv_imag * chirp_real[k];
+    }
+}
@@ -17,7 +17,7 @@ use crate::{NdArrayDevice, SEED};
 // Workspace crates
 use burn_tensor::{backend::Backend, Data, Shape};
 
-use super::{NdArrayMathOps, NdArrayOps};
+use super::{quantization, NdArrayMathOps, NdArrayOps};
 
 impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     fn int_from_data<const D: usize>(
@@ -425,4 +425,191 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.pow(rhs as u32))
     }
+
+    fn int_sort<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::argsort(tensor, dim, descending)
+    }
+
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cummin(tensor, dim)
+    }
+
+    fn int_cummin_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummin_with_indices(tensor, dim)
+    }
+
+    fn int_unique(
+        tensor: NdArrayTensor<i64, 1>,
+    ) -> (
+        NdArrayTensor<i64, 1>,
+        NdArrayTensor<i64, 1>,
+        NdArrayTensor<i64, 1>,
+    ) {
+        NdArrayMathOps::unique(tensor)
+    }
+
+    fn int_searchsorted<const D: usize>(
+        sorted: NdArrayTensor<i64, 1>,
+        values: NdArrayTensor<i64, D>,
+        right: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::searchsorted(sorted, values, right)
+    }
+
+    fn int_bincount(tensor: NdArrayTensor<i64, 1>, min_length: usize) -> NdArrayTensor<i64, 1> {
+        let length = tensor
+            .array
+            .iter()
+            .map(|value| *value as usize + 1)
+            .fold(min_length, usize::max);
+        let mut counts = vec![0; length];
+
+        for value in tensor.array.iter() {
+            counts[*value as usize] += 1;
+        }
+
+        NdArrayTensor::from_data(Data::new(counts, Shape::new([length])))
+    }
+
+    fn int_matmul<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        quantization::int_matmul(lhs, rhs)
+    }
+
+    fn int8_matmul<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        quantization::int8_matmul(lhs, rhs)
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs & rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a & rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs | rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a | rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a ^ rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(tensor, |a: i64| !a)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs.wrapping_shl(rhs as u32))
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.wrapping_shl(rhs as u32))
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |lhs, rhs| lhs.wrapping_shr(rhs as u32))
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.wrapping_shr(rhs as u32))
+    }
 }
@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use burn_tensor::ops::linalg::{
+    matrix_cholesky, matrix_eigh, matrix_qr, matrix_slogdet, matrix_solve, matrix_svd,
+};
+use burn_tensor::{ElementConversion, Shape};
+use ndarray::{ArrayD, IxDyn};
+
+use crate::{element::FloatNdArrayElement, iter_range_par, run_par, tensor::NdArrayTensor};
+
+pub(crate) fn solve<E: FloatNdArrayElement, const D: usize>(
+    a: NdArrayTensor<E, D>,
+    b: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let shape = b.shape();
+    let [n, k] = matrix_dims(&shape);
+    let a = matrices(a);
+    let b = matrices(b);
+
+    let outputs: Vec<Vec<f64>> = run_par!(|| {
+        iter_range_par!(0, b.len())
+            .map(|i| {
+                let (mut a, mut b) = (a[i].clone(), b[i].clone());
+                matrix_solve(&mut a, &mut b, n, k);
+                b
+            })
+            .collect()
+    });
+
+    from_matrices(outputs, &shape, n, k)
+}
+
+pub(crate) fn slogdet<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let [n, _] = matrix_dims(&shape);
+    let inputs = matrices(tensor);
+
+    let outputs: Vec<(f64, f64)> = run_par!(|| {
+        iter_range_par!(0, inputs.len())
+            .map(|i| matrix_slogdet(&mut inputs[i].clone(), n))
+            .collect()
+    });
+    let (sign, logabsdet): (Vec<_>, Vec<_>) = outputs
+        .into_iter()
+        .map(|(sign, logabsdet)| (Vec::from([sign]), Vec::from([logabsdet])))
+        .unzip();
+
+    (
+        from_matrices(sign, &shape, 1, 1),
+        from_matrices(logabsdet, &shape, 1, 1),
+    )
+}
+
+pub(crate) fn cholesky<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> NdArrayTensor<E, D> {
+    let shape = tensor.shape();
+    let [n, _] = matrix_dims(&shape);
+    let inputs = matrices(tensor);
+
+    let outputs: Vec<Vec<f64>> = run_par!(|| {
+        iter_range_par!(0, inputs.len())
+            .map(|i| matrix_cholesky(&inputs[i], n))
+            .collect()
+    });
+
+    from_matrices(outputs, &shape, n, n)
+}
+
+pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let [m, n] = matrix_dims(&shape);
+    let k = usize::min(m, n);
+    let inputs = matrices(tensor);
+
+    let outputs: Vec<(Vec<f64>, Vec<f64>)> = run_par!(|| {
+        iter_range_par!(0, inputs.len())
+            .map(|i| matrix_qr(&inputs[i], m, n))
+            .collect()
+    });
+    let (q, r): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+
+    (
+        from_matrices(q, &shape, m, k),
+        from_matrices(r, &shape, k, n),
+    )
+}
+
+pub(crate) fn svd<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+) {
+    let shape = tensor.shape();
+    let [m, n] = matrix_dims(&shape);
+    let k = usize::min(m, n);
+    let inputs = matrices(tensor);
+
+    let outputs: Vec<(Vec<f64>, Vec<f64>, Vec<f64>)> = run_par!(|| {
+        iter_range_par!(0, inputs.len())
+            .map(|i| matrix_svd(&inputs[i], m, n))
+            .collect()
+    });
+
+    let mut u = Vec::with_capacity(outputs.len());
+    let mut s = Vec::with_capacity(outputs.len());
+    let mut vh = Vec::with_capacity(outputs.len());
+    for (output_u, output_s, output_vh) in outputs {
+        u.push(output_u);
+        s.push(output_s);
+        vh.push(output_vh);
+    }
+
+    (
+        from_matrices(u, &shape, m, k),
+        from_matrices(s, &shape, 1, k),
+        from_matrices(vh, &shape, k, n),
+    )
+}
+
+pub(crate) fn eigh<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let [n, _] = matrix_dims(&shape);
+    let inputs = matrices(tensor);
+
+    let outputs: Vec<(Vec<f64>, Vec<f64>)> = run_par!(|| {
+        iter_range_par!(0, inputs.len())
+            .map(|i| matrix_eigh(&inputs[i], n))
+            .collect()
+    });
+    let (values, vectors): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+
+    (
+        from_matrices(values, &shape, 1, n),
+        from_matrices(vectors, &shape, n, n),
+    )
+}
+
+/// Splits a tensor into its row-major matrices in full precision.
+fn matrices<E: FloatNdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) -> Vec<Vec<f64>> {
+    let [m, n] = matrix_dims(&tensor.shape());
+    let values: Vec<f64> = tensor
+        .array
+        .as_standard_layout()
+        .iter()
+        .map(|value| value.elem())
+        .collect();
+
+    values.chunks(usize::max(m * n, 1)).map(Vec::from).collect()
+}
+
+/// Stacks row-major matrices of shape `[rows, cols]` into a tensor with the batch dimensions of
+/// the given shape.
+fn from_matrices<E: FloatNdArrayElement, const D: usize>(
+    matrices: Vec<Vec<f64>>,
+    shape: &Shape<D>,
+    rows: usize,
+    cols: usize,
+) -> NdArrayTensor<E, D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    let values = matrices
+        .into_iter()
+        .flatten()
+        .map(|value| value.elem())
+        .collect();
+    let array = ArrayD::from_shape_vec(IxDyn(&dims), values).unwrap();
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+fn matrix_dims<const D: usize>(shape: &Shape<D>) -> [usize; 2] {
+    [shape.dims[D - 2], shape.dims[D - 1]]
+}
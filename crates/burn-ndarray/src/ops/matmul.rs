@@ -1,7 +1,7 @@
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
 use crate::{iter_range_par, run_par, UnsafeSharedRef};
-use burn_tensor::ElementConversion;
 use burn_tensor::{ops::FloatTensorOps, Shape};
+use burn_tensor::{ElementConversion, Precision};
 use ndarray::s;
 
 pub(crate) fn matmul<E, const D: usize>(
@@ -11,6 +11,15 @@ pub(crate) fn matmul<E, const D: usize>(
 where
     E: FloatNdArrayElement,
 {
+    // Half precision products are accumulated in full precision to avoid overflow and rounding
+    // errors growing with the size of the inner dimension.
+    if let Precision::Half = E::precision() {
+        let lhs = NdArray::<E>::float_to_full_precision(&lhs);
+        let rhs = NdArray::<E>::float_to_full_precision(&rhs);
+
+        return NdArray::<E>::float_from_full_precision(matmul(lhs, rhs));
+    }
+
     let shape_ori_lhs = lhs.shape();
     let shape_ori_rhs = rhs.shape();
 
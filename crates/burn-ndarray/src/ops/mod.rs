@@ -6,12 +6,16 @@ mod module;
 mod tensor;
 
 pub(crate) mod adaptive_avgpool;
+pub(crate) mod attention;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod fft;
 pub(crate) mod interpolate;
+pub(crate) mod linalg;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
 pub(crate) mod padding;
+pub(crate) mod quantization;
 
 pub(crate) use base::*;
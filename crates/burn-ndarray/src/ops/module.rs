@@ -1,9 +1,17 @@
 use super::{
-    adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
-    avgpool::{avg_pool2d, avg_pool2d_backward},
-    conv::{conv2d, conv_transpose2d},
-    interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
-    maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
+    adaptive_avgpool::{
+        adaptive_avg_pool2d, adaptive_avg_pool2d_backward, adaptive_avg_pool3d,
+        adaptive_avg_pool3d_backward,
+    },
+    attention::{scaled_dot_product_attention, scaled_dot_product_attention_backward},
+    avgpool::{avg_pool2d, avg_pool2d_backward, avg_pool3d, avg_pool3d_backward},
+    conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
+    interpolate::{interpolate, interpolate_backward},
+    maxpool::{
+        max_pool2d, max_pool2d_backward, max_pool2d_with_indices, max_pool3d, max_pool3d_backward,
+        max_pool3d_with_indices,
+    },
+    quantization::{int8_conv2d, int_conv2d},
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
 use burn_tensor::ops::*;
@@ -18,6 +26,24 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         conv2d(x, weight, bias, options)
     }
 
+    fn int_conv2d(
+        x: NdArrayTensor<i64, 4>,
+        weight: NdArrayTensor<i64, 4>,
+        bias: Option<NdArrayTensor<i64, 1>>,
+        options: ConvOptions<2>,
+    ) -> NdArrayTensor<i64, 4> {
+        int_conv2d(x, weight, bias, options)
+    }
+
+    fn int8_conv2d(
+        x: NdArrayTensor<i64, 4>,
+        weight: NdArrayTensor<i64, 4>,
+        bias: Option<NdArrayTensor<i64, 1>>,
+        options: ConvOptions<2>,
+    ) -> NdArrayTensor<i64, 4> {
+        int8_conv2d(x, weight, bias, options)
+    }
+
     fn conv_transpose2d(
         x: NdArrayTensor<E, 4>,
         weight: NdArrayTensor<E, 4>,
@@ -101,15 +127,125 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         adaptive_avg_pool2d_backward(x, grad)
     }
 
+    fn conv3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv3d(x, weight, bias, options)
+    }
+
+    fn conv_transpose3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv_transpose3d(x, weight, bias, options)
+    }
+
+    fn avg_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d(x, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn avg_pool3d_backward(
+        x: NdArrayTensor<E, 5>,
+        grad: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn max_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        max_pool3d(x, kernel_size, stride, padding, dilation)
+    }
+
+    fn max_pool3d_with_indices(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<NdArray<E>> {
+        let (output, indices) = max_pool3d_with_indices(x, kernel_size, stride, padding, dilation);
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: NdArrayTensor<E, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        output_grad: NdArrayTensor<E, 5>,
+        indices: NdArrayTensor<i64, 5>,
+    ) -> MaxPool3dBackward<NdArray<E>> {
+        MaxPool3dBackward::new(max_pool3d_backward(x, output_grad, indices))
+    }
+
+    fn adaptive_avg_pool3d(x: NdArrayTensor<E, 5>, output_size: [usize; 3]) -> NdArrayTensor<E, 5> {
+        adaptive_avg_pool3d(x, output_size)
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        x: NdArrayTensor<E, 5>,
+        grad: NdArrayTensor<E, 5>,
+    ) -> NdArrayTensor<E, 5> {
+        adaptive_avg_pool3d_backward(x, grad)
+    }
+
     fn interpolate(
         x: NdArrayTensor<E, 4>,
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> NdArrayTensor<E, 4> {
-        match options.mode {
-            InterpolateMode::Nearest => nearest_interpolate(x, output_size),
-            InterpolateMode::Bilinear => bilinear_interpolate(x, output_size),
-            InterpolateMode::Bicubic => bicubic_interpolate(x, output_size),
-        }
+        interpolate(x, output_size, options)
+    }
+
+    fn interpolate_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate_backward(x, grad, output_size, options)
+    }
+
+    fn scaled_dot_product_attention(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        options: AttentionOptions,
+    ) -> NdArrayTensor<E, 4> {
+        scaled_dot_product_attention(query, key, value, mask, options)
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        grad: NdArrayTensor<E, 4>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<Self> {
+        scaled_dot_product_attention_backward(query, key, value, mask, grad, options)
     }
 }
@@ -0,0 +1,281 @@
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    Shape,
+};
+use ndarray::{s, ArcArray, Array3, Array4, Axis, Dim, IxDyn, LinalgScalar};
+
+use crate::{iter_par, iter_range_par, run_par, tensor::NdArrayTensor, UnsafeSharedRef};
+
+#[cfg(all(test, feature = "std"))]
+std::thread_local! {
+    /// The number of calls of the kernels accumulating in `i32` on the current thread.
+    static INT8_KERNEL_CALLS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+fn count_int8_kernel() {
+    #[cfg(all(test, feature = "std"))]
+    INT8_KERNEL_CALLS.with(|calls| calls.set(calls.get() + 1));
+}
+
+pub(crate) fn int_matmul<const D: usize>(
+    lhs: NdArrayTensor<i64, D>,
+    rhs: NdArrayTensor<i64, D>,
+) -> NdArrayTensor<i64, D> {
+    NdArrayTensor::new(general_matmul::<i64, D>(lhs.array, rhs.array))
+}
+
+/// Matrix multiplication of values between -128 and 127, whose products are accumulated in
+/// `i32`.
+pub(crate) fn int8_matmul<const D: usize>(
+    lhs: NdArrayTensor<i64, D>,
+    rhs: NdArrayTensor<i64, D>,
+) -> NdArrayTensor<i64, D> {
+    count_int8_kernel();
+
+    let lhs = lhs.array.mapv(|value| value as i32).into_shared();
+    let rhs = rhs.array.mapv(|value| value as i32).into_shared();
+    let out = general_matmul::<i32, D>(lhs, rhs);
+
+    NdArrayTensor::new(out.mapv(|value| value as i64).into_shared())
+}
+
+fn general_matmul<A: LinalgScalar + Send + Sync, const D: usize>(
+    lhs: ArcArray<A, IxDyn>,
+    rhs: ArcArray<A, IxDyn>,
+) -> ArcArray<A, IxDyn> {
+    let shape_lhs = Shape::<D>::from(lhs.shape().to_vec());
+    let shape_rhs = Shape::<D>::from(rhs.shape().to_vec());
+
+    let (batch_size_lhs, m, k) = matrices(&shape_lhs);
+    let (batch_size_rhs, _, n) = matrices(&shape_rhs);
+    let batch_size = usize::max(batch_size_lhs, batch_size_rhs);
+
+    if batch_size_lhs != batch_size && batch_size_lhs != 1
+        || batch_size_rhs != batch_size && batch_size_rhs != 1
+    {
+        panic!("Broadcast on multiple dimensions is not yet supported");
+    }
+
+    let lhs = lhs.into_shape((batch_size_lhs, m, k)).unwrap();
+    let rhs = rhs.into_shape((batch_size_rhs, k, n)).unwrap();
+
+    let mut out = Array3::<A>::zeros((batch_size, m, n));
+
+    run_par!(|| {
+        iter_par!(out.axis_iter_mut(Axis(0)))
+            .enumerate()
+            .for_each(|(b, mut out)| {
+                let lhs = lhs.index_axis(Axis(0), usize::min(b, batch_size_lhs - 1));
+                let rhs = rhs.index_axis(Axis(0), usize::min(b, batch_size_rhs - 1));
+
+                out.assign(&lhs.dot(&rhs));
+            })
+    });
+
+    let mut shape_out = match batch_size_lhs > batch_size_rhs {
+        true => shape_lhs,
+        false => shape_rhs,
+    };
+    shape_out.dims[D - 2] = m;
+    shape_out.dims[D - 1] = n;
+
+    out.into_shape(shape_out.dims.to_vec())
+        .unwrap()
+        .into_shared()
+}
+
+/// The batch size, rows and columns of a tensor of matrices.
+fn matrices<const D: usize>(shape: &Shape<D>) -> (usize, usize, usize) {
+    if D < 2 {
+        return (1, 1, shape.dims[0]);
+    }
+
+    let batch_size = shape.dims[..D - 2].iter().product();
+
+    (batch_size, shape.dims[D - 2], shape.dims[D - 1])
+}
+
+pub(crate) fn int_conv2d(
+    x: NdArrayTensor<i64, 4>,
+    weight: NdArrayTensor<i64, 4>,
+    bias: Option<NdArrayTensor<i64, 1>>,
+    options: ConvOptions<2>,
+) -> NdArrayTensor<i64, 4> {
+    let output = general_conv2d::<i64>(x.array, weight.array, &options);
+
+    add_bias(output, bias)
+}
+
+/// Convolution of values between -128 and 127, whose products are accumulated in `i32`.
+pub(crate) fn int8_conv2d(
+    x: NdArrayTensor<i64, 4>,
+    weight: NdArrayTensor<i64, 4>,
+    bias: Option<NdArrayTensor<i64, 1>>,
+    options: ConvOptions<2>,
+) -> NdArrayTensor<i64, 4> {
+    count_int8_kernel();
+
+    let output = general_conv2d::<i32>(
+        x.array.mapv(|value| value as i32).into_shared(),
+        weight.array.mapv(|value| value as i32).into_shared(),
+        &options,
+    );
+
+    add_bias(output.mapv(|value| value as i64), bias)
+}
+
+fn add_bias(mut output: Array4<i64>, bias: Option<NdArrayTensor<i64, 1>>) -> NdArrayTensor<i64, 4> {
+    if let Some(bias) = bias {
+        for (mut output, bias) in output.axis_iter_mut(Axis(1)).zip(bias.array.iter()) {
+            output += *bias;
+        }
+    }
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+fn general_conv2d<A: LinalgScalar + Send + Sync>(
+    x: ArcArray<A, IxDyn>,
+    weight: ArcArray<A, IxDyn>,
+    options: &ConvOptions<2>,
+) -> Array4<A> {
+    let [dilation_height, dilation_width] = options.dilation;
+    let [padding_height, padding_width] = options.padding;
+    let [stride_height, stride_width] = options.stride;
+
+    let x = x.into_dimensionality::<ndarray::Ix4>().unwrap();
+    let weight = weight.into_dimensionality::<ndarray::Ix4>().unwrap();
+
+    let (batch_size, channels, in_height, in_width) = x.dim();
+    let (out_channels, in_channels, kernel_height, kernel_width) = weight.dim();
+
+    let out_height = calculate_conv_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    let mut padded = Array4::<A>::zeros((
+        batch_size,
+        channels,
+        in_height + 2 * padding_height,
+        in_width + 2 * padding_width,
+    ));
+    padded
+        .slice_mut(s![
+            ..,
+            ..,
+            padding_height..padding_height + in_height,
+            padding_width..padding_width + in_width
+        ])
+        .assign(&x);
+
+    let mut output = Array3::<A>::zeros(Dim([batch_size * out_channels, out_height, out_width]));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * out_channels).for_each(|k| unsafe {
+            let output = unsafe_shared_out.get();
+            let b = k / out_channels;
+            let oc = k % out_channels;
+            let g = oc / (out_channels / options.groups);
+
+            for oh in 0..out_height {
+                for ow in 0..out_width {
+                    let mut sum = A::zero();
+
+                    for weight_ic in 0..in_channels {
+                        let ic = g * in_channels + weight_ic;
+
+                        for kh in 0..kernel_height {
+                            let ih = oh * stride_height + kh * dilation_height;
+
+                            for kw in 0..kernel_width {
+                                let iw = ow * stride_width + kw * dilation_width;
+
+                                sum =
+                                    sum + padded[[b, ic, ih, iw]] * weight[[oc, weight_ic, kh, kw]];
+                            }
+                        }
+                    }
+
+                    output[[k, oh, ow]] = sum;
+                }
+            }
+        })
+    });
+
+    output
+        .into_shape([batch_size, out_channels, out_height, out_width])
+        .unwrap()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::NdArray;
+    use burn_tensor::{module::quantized_conv2d, QuantizationScheme, Tensor};
+
+    fn int8_kernel_calls() -> usize {
+        INT8_KERNEL_CALLS.with(|calls| calls.get())
+    }
+
+    #[test]
+    fn asymmetric_quantized_matmul_should_accumulate_in_i32() {
+        let device = Default::default();
+        let lhs = Tensor::<NdArray, 2>::from_floats([[0.5, 1.0, 2.5], [3.0, 0.25, 1.5]], &device);
+        let rhs = Tensor::<NdArray, 2>::from_floats([[1.0, 2.0], [0.5, 3.0], [2.0, 0.75]], &device);
+        let calls = int8_kernel_calls();
+
+        let output = lhs
+            .clone()
+            .quantize_dynamic(QuantizationScheme::PerTensor)
+            .matmul(
+                rhs.clone()
+                    .quantize_dynamic(QuantizationScheme::PerChannel(1)),
+            );
+
+        assert_eq!(int8_kernel_calls(), calls + 1);
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 1);
+    }
+
+    #[test]
+    fn asymmetric_quantized_conv2d_should_accumulate_in_i32() {
+        let device = Default::default();
+        let x = Tensor::<NdArray, 1>::from_floats(
+            [0.5, 1.0, 2.5, 3.0, 0.25, 1.5, 2.0, 0.75, 1.25],
+            &device,
+        )
+        .reshape([1, 1, 3, 3]);
+        let weight =
+            Tensor::<NdArray, 1>::from_floats([1.0, 0.5, 2.0, 0.25], &device).reshape([1, 1, 2, 2]);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+        let calls = int8_kernel_calls();
+
+        let output = quantized_conv2d(
+            x.clone().quantize_dynamic(QuantizationScheme::PerTensor),
+            weight
+                .clone()
+                .quantize_dynamic(QuantizationScheme::PerChannel(0)),
+            None,
+            options.clone(),
+        );
+
+        assert!(int8_kernel_calls() > calls);
+        output.into_data().assert_approx_eq(
+            &burn_tensor::module::conv2d(x, weight, None, options).into_data(),
+            1,
+        );
+    }
+}
@@ -1,20 +1,26 @@
 // Language
+use alloc::vec;
 use alloc::vec::Vec;
 use core::ops::Range;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{fft, linalg, matmul::matmul, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
-use burn_tensor::{backend::Backend, ops::FloatTensorOps, Data, ElementConversion, Shape};
+use burn_tensor::{
+    backend::Backend, ops::FloatTensorOps, Data, ElementConversion, Precision, Shape,
+};
 use burn_tensor::{Distribution, Reader};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{
+    acos, asin, atan, atan2, ceil, cos, cosh, erf, expm1, floor, lgamma, rint, sin, sinh, tan,
+    tanh, trunc,
+};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -310,25 +316,49 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
     }
 
     fn float_mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        NdArrayMathOps::mean(tensor)
+        match E::precision() {
+            Precision::Half => {
+                let tensor = Self::float_to_full_precision(&tensor);
+                Self::float_from_full_precision(NdArrayMathOps::mean(tensor))
+            }
+            _ => NdArrayMathOps::mean(tensor),
+        }
     }
 
     fn float_sum<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        NdArrayMathOps::sum(tensor)
+        match E::precision() {
+            Precision::Half => {
+                let tensor = Self::float_to_full_precision(&tensor);
+                Self::float_from_full_precision(NdArrayMathOps::sum(tensor))
+            }
+            _ => NdArrayMathOps::sum(tensor),
+        }
     }
 
     fn float_mean_dim<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<E, D> {
-        NdArrayMathOps::mean_dim(tensor, dim)
+        match E::precision() {
+            Precision::Half => {
+                let tensor = Self::float_to_full_precision(&tensor);
+                Self::float_from_full_precision(NdArrayMathOps::mean_dim(tensor, dim))
+            }
+            _ => NdArrayMathOps::mean_dim(tensor, dim),
+        }
     }
 
     fn float_sum_dim<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<E, D> {
-        NdArrayMathOps::sum_dim(tensor, dim)
+        match E::precision() {
+            Precision::Half => {
+                let tensor = Self::float_to_full_precision(&tensor);
+                Self::float_from_full_precision(NdArrayMathOps::sum_dim(tensor, dim))
+            }
+            _ => NdArrayMathOps::sum_dim(tensor, dim),
+        }
     }
 
     fn float_to_full_precision<const D: usize>(
@@ -448,6 +478,137 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn float_floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| floor(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| ceil(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| rint(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_trunc<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| trunc(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_sign<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let zero = 0.elem::<E>();
+        let array = tensor
+            .array
+            .mapv_into(|a| match a.partial_cmp(&zero) {
+                Some(core::cmp::Ordering::Greater) => 1.elem(),
+                Some(core::cmp::Ordering::Less) => (-1).elem(),
+                _ => a,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_tan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| tan(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_asin<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| asin(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_acos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| acos(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_atan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| atan(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_atan2<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::broadcast_op(lhs, rhs, |a, b| {
+            atan2(a.to_f64().unwrap(), b.to_f64().unwrap()).elem()
+        })
+    }
+
+    fn float_sinh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| sinh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_cosh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| cosh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_lgamma<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| lgamma(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn float_expm1<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| expm1(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
@@ -484,4 +645,235 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<E, D> {
         NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a.powf_elem(b.to_f32().unwrap()))
     }
+
+    fn float_sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::sort(tensor, dim, descending)
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::argsort(tensor, dim, descending)
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn float_cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cummin(tensor, dim)
+    }
+
+    fn float_cummin_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummin_with_indices(tensor, dim)
+    }
+
+    fn float_unique(
+        tensor: NdArrayTensor<E, 1>,
+    ) -> (
+        NdArrayTensor<E, 1>,
+        NdArrayTensor<i64, 1>,
+        NdArrayTensor<i64, 1>,
+    ) {
+        NdArrayMathOps::unique(tensor)
+    }
+
+    fn float_searchsorted<const D: usize>(
+        sorted: NdArrayTensor<E, 1>,
+        values: NdArrayTensor<E, D>,
+        right: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::searchsorted(sorted, values, right)
+    }
+
+    fn float_histc(
+        tensor: NdArrayTensor<E, 1>,
+        bins: usize,
+        min: E,
+        max: E,
+    ) -> NdArrayTensor<E, 1> {
+        let min = min.to_f64().unwrap();
+        let max = max.to_f64().unwrap();
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0.elem::<E>(); bins];
+
+        for value in tensor.array.iter() {
+            let value = value.to_f64().unwrap();
+
+            // The maximum falls in the last bin.
+            if (min..=max).contains(&value) {
+                let index = usize::min(((value - min) / width) as usize, bins - 1);
+                counts[index] += 1.elem();
+            }
+        }
+
+        NdArrayTensor::from_data(Data::new(counts, Shape::new([bins])))
+    }
+
+    fn float_fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        fft::fft(real, imag, dim)
+    }
+
+    fn float_ifft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        fft::ifft(real, imag, dim)
+    }
+
+    fn float_rfft<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        fft::rfft(tensor, dim)
+    }
+
+    fn float_irfft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        n: usize,
+    ) -> NdArrayTensor<E, D> {
+        fft::irfft(real, imag, dim, n)
+    }
+
+    fn float_solve<const D: usize>(
+        a: NdArrayTensor<E, D>,
+        b: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        linalg::solve(a, b)
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::slogdet(tensor)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::cholesky(tensor)
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::qr(tensor)
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        linalg::svd(tensor)
+    }
+
+    fn float_eigh<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::eigh(tensor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+    use half::{bf16, f16};
+
+    #[test]
+    fn should_accumulate_half_precision_sum_in_full_precision() {
+        // Summing ones stops increasing after 2048 in f16 and 256 in bf16.
+        let tensor = Tensor::<NdArray<f16>, 2>::ones([2, 4096], &Default::default());
+
+        assert_eq!(tensor.clone().sum().into_scalar(), f16::from_f32(8192.0));
+        assert_eq!(
+            tensor.clone().sum_dim(1).into_data(),
+            Data::from([[f16::from_f32(4096.0)], [f16::from_f32(4096.0)]])
+        );
+        assert_eq!(
+            tensor.mean_dim(1).into_data(),
+            Data::from([[f16::ONE], [f16::ONE]])
+        );
+
+        let tensor = Tensor::<NdArray<bf16>, 1>::ones([4096], &Default::default());
+
+        assert_eq!(tensor.clone().sum().into_scalar(), bf16::from_f32(4096.0));
+        assert_eq!(tensor.mean().into_scalar(), bf16::ONE);
+    }
+
+    #[test]
+    fn should_accumulate_half_precision_matmul_in_full_precision() {
+        let device = Default::default();
+        let lhs = Tensor::<NdArray<f16>, 2>::ones([2, 4096], &device);
+        let rhs = Tensor::<NdArray<f16>, 2>::ones([4096, 3], &device);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[f16::from_f32(4096.0); 3]; 2])
+        );
+
+        let lhs = Tensor::<NdArray<bf16>, 3>::ones([2, 1, 1024], &device);
+        let rhs = Tensor::<NdArray<bf16>, 3>::ones([1, 1024, 1], &device);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[bf16::from_f32(1024.0)]], [[bf16::from_f32(1024.0)]]])
+        );
+    }
 }
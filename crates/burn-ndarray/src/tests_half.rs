@@ -0,0 +1,230 @@
+//! Tests of the half precision float elements.
+//!
+//! Most of the burn-tensor test suite builds its inputs and expected values from `f32` literals,
+//! so only the element agnostic test groups are generated for `f16` and `bf16`. The other
+//! operations are checked by running them with each half precision element and comparing the
+//! output with the one computed with `f32`.
+
+use crate::NdArray;
+use alloc::vec::Vec;
+use burn_tensor::activation;
+use burn_tensor::backend::Backend;
+use burn_tensor::module::{
+    adaptive_avg_pool2d, avg_pool2d, conv2d, conv_transpose2d, interpolate, max_pool2d,
+};
+use burn_tensor::ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions};
+use burn_tensor::{Data, Shape, Tensor};
+use half::{bf16, f16};
+
+/// The test groups of burn-tensor that don't depend on the float element.
+macro_rules! testgen_half {
+    () => {
+        use alloc::vec;
+
+        burn_tensor::testgen_arange!();
+        burn_tensor::testgen_arange_step!();
+        burn_tensor::testgen_argwhere_nonzero!();
+        burn_tensor::testgen_bitwise!();
+        burn_tensor::testgen_chunk!();
+        burn_tensor::testgen_diagonal!();
+        burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_map_comparison!();
+        burn_tensor::testgen_squeeze!();
+    };
+}
+
+mod f16_suite {
+    type TestBackend = crate::NdArray<half::f16>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    testgen_half!();
+}
+
+mod bf16_suite {
+    type TestBackend = crate::NdArray<half::bf16>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    testgen_half!();
+}
+
+/// Relative tolerance of the `f16` outputs, about 5 times its machine epsilon.
+const F16_TOLERANCE: f32 = 5e-3;
+/// Relative tolerance of the `bf16` outputs, about 5 times its machine epsilon.
+const BF16_TOLERANCE: f32 = 2e-2;
+
+/// Input of shape `[2, 3, 4, 4]` whose values are multiples of `1/16` in `[-1.875, 1.875]`, which
+/// are exactly representable with every float element.
+fn input<B: Backend>() -> Tensor<B, 4> {
+    let values = (0..96)
+        .map(|i| ((i * 37 + 11) % 61) as f32 / 16.0 - 1.875)
+        .collect::<Vec<_>>();
+    let data = Data::new(values, Shape::new([96]));
+
+    Tensor::<B, 1>::from_data(data.convert(), &Default::default()).reshape([2, 3, 4, 4])
+}
+
+fn assert_close<const D: usize>(
+    expected: Data<f32, D>,
+    actual: Data<f32, D>,
+    tolerance: f32,
+    elem: &str,
+) {
+    assert_eq!(expected.shape, actual.shape);
+
+    for (i, (expected, actual)) in expected.value.iter().zip(actual.value.iter()).enumerate() {
+        assert!(
+            (expected - actual).abs() <= tolerance * expected.abs().max(1.0),
+            "Element {i} differs with {elem}: expected {expected}, got {actual}"
+        );
+    }
+}
+
+/// Generates a test running the operation on the input with `f32`, `f16` and `bf16` elements.
+macro_rules! test_half {
+    ($name:ident, |$x:ident| -> Tensor<$dim:literal> $body:block) => {
+        #[test]
+        fn $name() {
+            fn run<B: Backend>() -> Data<f32, $dim> {
+                let $x = input::<B>();
+                let output: Tensor<B, $dim> = $body;
+
+                output.into_data().convert()
+            }
+
+            let expected = run::<NdArray<f32>>();
+
+            assert_close(
+                expected.clone(),
+                run::<NdArray<f16>>(),
+                F16_TOLERANCE,
+                "f16",
+            );
+            assert_close(expected, run::<NdArray<bf16>>(), BF16_TOLERANCE, "bf16");
+        }
+    };
+}
+
+test_half!(binary_ops, |x| -> Tensor<4> {
+    let y = x.clone().swap_dims(2, 3);
+    x.clone()
+        .add(y.clone())
+        .mul(x.clone().sub_scalar(0.5))
+        .div(y.abs().add_scalar(1.0))
+});
+
+test_half!(unary_ops, |x| -> Tensor<4> {
+    x.clone().exp() + x.clone().abs().log1p() + x.clone().abs().sqrt() + x.clone().neg().tanh()
+        - x.clone().erf()
+        + x.abs().add_scalar(0.5).recip()
+});
+
+test_half!(powf, |x| -> Tensor<4> {
+    let exponent = x.clone().sin();
+    x.clone().abs().add_scalar(0.5).powf(exponent) + x.abs().powf_scalar(1.5)
+});
+
+test_half!(clamp, |x| -> Tensor<4> { x.clamp(-0.5, 1.0) });
+
+test_half!(activations, |x| -> Tensor<4> {
+    activation::sigmoid(x.clone()) + activation::gelu(x.clone()) + activation::silu(x)
+});
+
+test_half!(softmax, |x| -> Tensor<4> {
+    activation::softmax(x.clone(), 3) + activation::log_softmax(x, 1)
+});
+
+test_half!(sum, |x| -> Tensor<1> { x.sum() });
+
+test_half!(mean, |x| -> Tensor<1> { x.mean() });
+
+test_half!(sum_dim, |x| -> Tensor<4> { x.sum_dim(3) });
+
+test_half!(mean_dim, |x| -> Tensor<4> { x.mean_dim(1) });
+
+test_half!(var, |x| -> Tensor<4> { x.var(2) });
+
+test_half!(max_dim_with_indices, |x| -> Tensor<4> {
+    let (values, indices) = x.max_dim_with_indices(2);
+    values + indices.float()
+});
+
+test_half!(min_dim_with_indices, |x| -> Tensor<4> {
+    let (values, indices) = x.min_dim_with_indices(3);
+    values + indices.float()
+});
+
+test_half!(argmax, |x| -> Tensor<4> { x.argmax(1).float() });
+
+test_half!(sort, |x| -> Tensor<4> { x.sort(3) });
+
+test_half!(sort_descending_with_indices, |x| -> Tensor<4> {
+    let (values, indices) = x.sort_descending_with_indices(1);
+    values + indices.float()
+});
+
+test_half!(cumsum, |x| -> Tensor<4> { x.cumsum(2) });
+
+test_half!(cumprod, |x| -> Tensor<4> {
+    x.mul_scalar(0.5).add_scalar(1.0).cumprod(3)
+});
+
+test_half!(matmul, |x| -> Tensor<4> { x.clone().matmul(x.transpose()) });
+
+test_half!(convolution, |x| -> Tensor<4> {
+    let weight = x.clone().slice([0..2, 0..3, 0..3, 0..3]);
+    let bias = x.clone().slice([0..1, 0..1, 0..1, 0..2]).reshape([2]);
+    conv2d(
+        x,
+        weight,
+        Some(bias),
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+    )
+});
+
+test_half!(grouped_convolution, |x| -> Tensor<4> {
+    let weight = x
+        .clone()
+        .slice([0..2, 0..3, 0..2, 0..2])
+        .reshape([6, 1, 2, 2]);
+    conv2d(x, weight, None, ConvOptions::new([2, 1], [0, 1], [1, 1], 3))
+});
+
+test_half!(transposed_convolution, |x| -> Tensor<4> {
+    let weight = x
+        .clone()
+        .slice([0..1, 0..3, 0..2, 0..2])
+        .reshape([3, 1, 2, 2]);
+    conv_transpose2d(
+        x,
+        weight,
+        None,
+        ConvTransposeOptions::new([2, 2], [0, 0], [0, 0], [1, 1], 1),
+    )
+});
+
+test_half!(pooling, |x| -> Tensor<4> {
+    max_pool2d(x.clone(), [2, 2], [1, 1], [1, 1], [1, 1]).slice([0..2, 0..3, 0..4, 0..4])
+        + avg_pool2d(x.clone(), [3, 3], [1, 1], [1, 1], true)
+        + adaptive_avg_pool2d(x, [4, 4])
+});
+
+test_half!(interpolate_bilinear, |x| -> Tensor<4> {
+    interpolate(
+        x,
+        [7, 5],
+        InterpolateOptions::new(InterpolateMode::Bilinear),
+    )
+});
+
+test_half!(mask_where, |x| -> Tensor<4> {
+    let mask = x.clone().greater_elem(0.25);
+    x.clone().mask_where(mask, x.neg().mul_scalar(2.0))
+});
+
+test_half!(select_assign, |x| -> Tensor<4> {
+    let indices = Tensor::from_ints([2, 0, 2], &Default::default());
+    let values = x.clone().slice([0..2, 0..3, 0..3, 0..4]);
+    x.select_assign(2, indices, values)
+});
@@ -1,7 +1,7 @@
-use burn_tensor::Shape;
+use burn_tensor::{Distribution, Generator, Shape};
 use tch::Scalar;
 
-use crate::{LibTorchDevice, TchShape, TchTensor};
+use crate::{element::TchElement, LibTorchDevice, TchShape, TchTensor};
 use std::{marker::PhantomData, ops::Range};
 
 pub struct TchOps<E: tch::kind::Element + Copy + Default> {
@@ -467,4 +467,88 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
             |lhs, rhs| lhs.f_pow(rhs).unwrap(),
         )
     }
+
+    pub fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.sort(dim as i64, descending).0)
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor
+            .tensor
+            .sort_stable(/*stable*/ true, dim as i64, descending);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchTensor::new(
+            tensor
+                .tensor
+                .argsort_stable(/*stable*/ true, dim as i64, descending),
+        )
+    }
+
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        TchTensor::new(tensor.tensor.cumsum(dim as i64, kind))
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        let kind = tensor.tensor.kind();
+        TchTensor::new(tensor.tensor.cumprod(dim as i64, kind))
+    }
+
+    pub fn cummax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        Self::cummax_with_indices(tensor, dim).0
+    }
+
+    pub fn cummax_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor.tensor.cummax(dim as i64);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
+    pub fn cummin<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        Self::cummin_with_indices(tensor, dim).0
+    }
+
+    pub fn cummin_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor.tensor.cummin(dim as i64);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+}
+
+impl<E: TchElement> TchOps<E> {
+    /// Samples a distribution not supported by LibTorch on the host, with a generator seeded by
+    /// LibTorch so that the backend seed still applies.
+    pub fn random_host<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &LibTorchDevice,
+    ) -> TchTensor<E, D> {
+        let seed = tch::Tensor::randint(i64::MAX, [1], (tch::Kind::Int64, tch::Device::Cpu))
+            .int64_value(&[0]);
+        let data = Generator::new(seed as u64).sample(shape, distribution);
+
+        TchTensor::from_data(data, (*device).into())
+    }
 }
@@ -97,6 +97,45 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         )
     }
 
+    fn bool_and<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_and_(rhs),
+            |lhs, rhs| rhs.logical_and_(lhs),
+            |lhs, rhs| lhs.logical_and(rhs),
+        )
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_or_(rhs),
+            |lhs, rhs| rhs.logical_or_(lhs),
+            |lhs, rhs| lhs.logical_or(rhs),
+        )
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_xor_(rhs),
+            |lhs, rhs| rhs.logical_xor_(lhs),
+            |lhs, rhs| lhs.logical_xor(rhs),
+        )
+    }
+
     fn bool_into_int<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<i64, D> {
         let tensor = tensor.tensor.to_kind(tch::Kind::Int64);
         TchTensor::new(tensor)
@@ -445,6 +445,7 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
                 let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            _ => TchOps::random_host(shape, distribution, device),
         }
     }
 
@@ -458,4 +459,180 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
 
         TchTensor::new(tensor)
     }
+
+    fn int_sort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::argsort(tensor, dim, descending)
+    }
+
+    fn int_cumsum<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cummax(tensor, dim)
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cummin(tensor, dim)
+    }
+
+    fn int_cummin_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::cummin_with_indices(tensor, dim)
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_and_tensor_(rhs),
+            |lhs, rhs| rhs.bitwise_and_tensor_(lhs),
+            |lhs, rhs| lhs.bitwise_and_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.bitwise_and_(rhs),
+            |tensor| tensor.bitwise_and(rhs),
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_or_tensor_(rhs),
+            |lhs, rhs| rhs.bitwise_or_tensor_(lhs),
+            |lhs, rhs| lhs.bitwise_or_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.bitwise_or_(rhs),
+            |tensor| tensor.bitwise_or(rhs),
+        )
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_xor_tensor_(rhs),
+            |lhs, rhs| rhs.bitwise_xor_tensor_(lhs),
+            |lhs, rhs| lhs.bitwise_xor_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.bitwise_xor_(rhs),
+            |tensor| tensor.bitwise_xor(rhs),
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.bitwise_not_(),
+            |tensor| tensor.bitwise_not(),
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_left_shift_(rhs),
+            |lhs, rhs| lhs.bitwise_left_shift(rhs),
+            |lhs, rhs| lhs.bitwise_left_shift(rhs),
+        )
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.bitwise_left_shift_tensor_scalar_(rhs),
+            |tensor| tensor.bitwise_left_shift_tensor_scalar(rhs),
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_right_shift_(rhs),
+            |lhs, rhs| lhs.bitwise_right_shift(rhs),
+            |lhs, rhs| lhs.bitwise_right_shift(rhs),
+        )
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.bitwise_right_shift_tensor_scalar_(rhs),
+            |tensor| tensor.bitwise_right_shift_tensor_scalar(rhs),
+        )
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{element::TchElement, LibTorch, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
+    interpolate, ConvOptions, ConvTransposeOptions, GridSampleMode, GridSampleOptions,
+    GridSamplePaddingMode, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
     MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
@@ -289,20 +290,52 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> TchTensor<E, 4> {
+        if options.antialias && !matches!(options.mode, InterpolateMode::Nearest) {
+            return interpolate::interpolate_from_matmul::<Self>(x, output_size, options);
+        }
+
         let output_size = output_size.map(|e| e as i64);
+        let align_corners = options.align_corners;
 
         let tensor = match options.mode {
             InterpolateMode::Nearest => {
                 tch::Tensor::upsample_nearest2d(&x.tensor, output_size, None, None)
             }
-            InterpolateMode::Bilinear => {
-                tch::Tensor::upsample_bilinear2d(&x.tensor, output_size, true, None, None)
+            InterpolateMode::Linear | InterpolateMode::Bilinear | InterpolateMode::Trilinear => {
+                tch::Tensor::upsample_bilinear2d(&x.tensor, output_size, align_corners, None, None)
             }
             InterpolateMode::Bicubic => {
-                tch::Tensor::upsample_bicubic2d(&x.tensor, output_size, true, None, None)
+                tch::Tensor::upsample_bicubic2d(&x.tensor, output_size, align_corners, None, None)
             }
+            InterpolateMode::Area => tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample_2d(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> TchTensor<E, 4> {
+        let mode = match options.mode {
+            GridSampleMode::Bilinear => 0,
+            GridSampleMode::Nearest => 1,
+        };
+        let padding_mode = match options.padding_mode {
+            GridSamplePaddingMode::Zeros => 0,
+            GridSamplePaddingMode::Border => 1,
+            GridSamplePaddingMode::Reflection => 2,
         };
 
+        let tensor = tch::Tensor::grid_sampler_2d(
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+        );
+
         TchTensor::new(tensor)
     }
 }
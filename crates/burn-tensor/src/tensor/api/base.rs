@@ -45,7 +45,7 @@ where
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
-    K: BasicOps<B>,
+    K: TensorKind<B>,
 {
     /// Converts the tensor into a primitive tensor.
     pub fn into_primitive(self) -> K::Primitive<D> {
@@ -57,11 +57,6 @@ where
         Self::new(tensor)
     }
 
-    /// Create an empty tensor of the given shape.
-    pub fn empty<S: Into<Shape<D>>>(shape: S, device: &B::Device) -> Self {
-        Self::new(K::empty(shape.into(), device))
-    }
-
     /// Returns the dimensions of the current tensor.
     ///
     /// Equivalent to `tensor.shape().dims`.
@@ -74,6 +69,22 @@ where
         K::shape(&self.primitive)
     }
 
+    /// Returns the device of the current tensor.
+    pub fn device(&self) -> B::Device {
+        K::device(&self.primitive)
+    }
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Create an empty tensor of the given shape.
+    pub fn empty<S: Into<Shape<D>>>(shape: S, device: &B::Device) -> Self {
+        Self::new(K::empty(shape.into(), device))
+    }
+
     /// Reshape the tensor to have the given shape.
     ///
     /// A `-1` in the shape is used to infer the remaining dimensions, e.g.: `[2, -1]`
@@ -463,11 +474,6 @@ where
         Self::new(K::slice_assign(self.primitive, ranges, values.primitive))
     }
 
-    /// Returns the device of the current tensor.
-    pub fn device(&self) -> B::Device {
-        K::device(&self.primitive)
-    }
-
     /// Returns a new tensor on the given device.
     pub fn to_device(self, device: &B::Device) -> Self {
         Self::new(K::to_device(self.primitive, device))
@@ -1016,26 +1022,6 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D>;
 
-    /// Returns the shape of the tensor.
-    ///
-    /// # Arguments
-    ///
-    /// * `tensor` - The tensor.
-    ///
-    /// # Returns
-    ///
-    /// The shape of the tensor.
-    ///
-    /// # Remarks
-    ///
-    /// This is a low-level function used internally by the library to call different backend functions
-    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
-    /// or use this function directly.
-    ///
-    /// For getting the shape of a tensor, users should prefer the [Tensor::shape](Tensor::shape) function,
-    /// which is more high-level and designed for public use.
-    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D>;
-
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -1138,26 +1124,6 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         value: Self::Primitive<D1>,
     ) -> Self::Primitive<D1>;
 
-    /// Returns the device on which the tensor is allocated.
-    ///
-    /// # Arguments
-    ///
-    /// * `tensor` - The tensor.
-    ///
-    /// # Returns
-    ///
-    /// The device on which the tensor is allocated.
-    ///
-    /// # Remarks
-    ///
-    /// This is a low-level function used internally by the library to call different backend functions
-    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
-    /// or use this function directly.
-    ///
-    /// For getting the device of a tensor, users should prefer the [Tensor::device](Tensor::device) function,
-    /// which is more high-level and designed for public use.
-    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device;
-
     /// Moves the tensor to the given device.
     ///
     /// # Arguments
@@ -1410,9 +1376,6 @@ impl<B: Backend> BasicOps<B> for Float {
     fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::float_empty(shape, device)
     }
-    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
-        B::float_shape(tensor)
-    }
 
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
@@ -1449,10 +1412,6 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_slice_assign(tensor, ranges, value)
     }
 
-    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
-        B::float_device(tensor)
-    }
-
     fn to_device<const D: usize>(
         tensor: Self::Primitive<D>,
         device: &<B as Backend>::Device,
@@ -1520,9 +1479,6 @@ impl<B: Backend> BasicOps<B> for Int {
     fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::int_empty(shape, device)
     }
-    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
-        B::int_shape(tensor)
-    }
 
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
@@ -1559,10 +1515,6 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_slice_assign(tensor, ranges, value)
     }
 
-    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
-        B::int_device(tensor)
-    }
-
     fn to_device<const D: usize>(
         tensor: Self::Primitive<D>,
         device: &<B as Backend>::Device,
@@ -1630,9 +1582,6 @@ impl<B: Backend> BasicOps<B> for Bool {
     fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::bool_empty(shape, device)
     }
-    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
-        B::bool_shape(tensor)
-    }
 
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
@@ -1669,10 +1618,6 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_slice_assign(tensor, ranges, value)
     }
 
-    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
-        B::bool_device(tensor)
-    }
-
     fn to_device<const D: usize>(
         tensor: Self::Primitive<D>,
         device: &<B as Backend>::Device,
@@ -0,0 +1,96 @@
+use crate::{backend::Backend, ops::IntTensor, Data};
+use alloc::vec::Vec;
+
+const READ_SYNC_ERROR: &str = "Failed to synchronously read tensor data. \
+    This operation is not supported until this backend has a native bitwise implementation.";
+
+/// Applies a bitwise operation to the elements of two int tensors, broadcasting them to the same
+/// shape.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor.
+/// * `op` - The operation applied to the elements, converted to `i64`.
+///
+/// # Returns
+///
+/// A tensor with the result of the operation on each pair of elements.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bitwise_binary<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    op: impl Fn(i64, i64) -> i64,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&lhs);
+    let lhs = broadcast_to::<B, D>(lhs, &B::int_shape(&rhs).dims);
+    let rhs = broadcast_to::<B, D>(rhs, &B::int_shape(&lhs).dims);
+
+    let lhs = read::<B, D>(lhs);
+    let rhs = read::<B, D>(rhs);
+    let value = lhs
+        .value
+        .into_iter()
+        .zip(rhs.value)
+        .map(|(a, b)| op(a, b))
+        .collect::<Vec<_>>();
+
+    B::int_from_data(Data::new(value, lhs.shape).convert(), &device)
+}
+
+/// Applies a bitwise operation to the elements of an int tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `op` - The operation applied to the elements, converted to `i64`.
+///
+/// # Returns
+///
+/// A tensor with the result of the operation on each element.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bitwise_unary<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    op: impl Fn(i64) -> i64,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = read::<B, D>(tensor);
+    let value = data.value.into_iter().map(op).collect::<Vec<_>>();
+
+    B::int_from_data(Data::new(value, data.shape).convert(), &device)
+}
+
+fn read<B: Backend, const D: usize>(tensor: IntTensor<B, D>) -> Data<i64, D> {
+    B::int_into_data(tensor)
+        .read_sync()
+        .expect(READ_SYNC_ERROR)
+        .convert()
+}
+
+/// Repeats the dimensions of size one of the tensor that are larger in the given shape.
+fn broadcast_to<B: Backend, const D: usize>(
+    mut tensor: IntTensor<B, D>,
+    dims: &[usize; D],
+) -> IntTensor<B, D> {
+    let shape = B::int_shape(&tensor);
+
+    for (dim, (&size, &target)) in shape.dims.iter().zip(dims).enumerate() {
+        if size == 1 && target > 1 {
+            tensor = B::int_repeat(tensor, dim, target);
+        }
+    }
+
+    tensor
+}
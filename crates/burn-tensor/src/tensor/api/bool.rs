@@ -1,3 +1,5 @@
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Bool, Data, Int, Tensor};
 use alloc::vec::Vec;
 
@@ -28,6 +30,24 @@ where
         Tensor::new(B::bool_not(self.primitive))
     }
 
+    /// Applies element wise logical and operation.
+    pub fn bool_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("And", &self, &other));
+        Tensor::new(B::bool_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical or operation.
+    pub fn bool_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Or", &self, &other));
+        Tensor::new(B::bool_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical exclusive or operation.
+    pub fn bool_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Xor", &self, &other));
+        Tensor::new(B::bool_xor(self.primitive, other.primitive))
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Returns
@@ -1,4 +1,6 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{
+    backend::Backend, ops::InterpolateMode, BasicOps, PadMode, QuantizationScheme, Shape, Tensor,
+};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -323,9 +325,9 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn matmul<B: Backend, const D: usize>(
-        lhs: &Tensor<B, D>,
-        rhs: &Tensor<B, D>,
+    pub(crate) fn matmul<B: Backend, const D: usize, K: BasicOps<B>>(
+        lhs: &Tensor<B, D, K>,
+        rhs: &Tensor<B, D, K>,
     ) -> Self {
         let mut check = Self::Ok;
 
@@ -749,6 +751,240 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't sort a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn topk<const D: usize>(k: usize, dim: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::sort_dim::<D>("Topk", dim);
+
+        if dim < D && k > shape.dims[dim] {
+            check = check.register(
+                "Topk",
+                TensorError::new(format!(
+                    "Can't select the top ({k}) elements of a dimension with ({}) elements",
+                    shape.dims[dim]
+                ))
+                .details(format!("Tensor shape {:?}, dim {dim}", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histc",
+                TensorError::new("Can't compute a histogram without bins"),
+            );
+        }
+
+        if min > max {
+            check = check.register(
+                "Histc",
+                TensorError::new(format!(
+                    "The minimum ({min}) must be lower or equal to the maximum ({max})"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial<const D: usize>(
+        shape: &Shape<D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let num_categories = shape.dims[D - 1];
+
+        if num_categories == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't sample from distributions without categories")
+                    .details(format!("Tensor shape {:?}", shape.dims)),
+            );
+        } else if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new(format!(
+                    "Can't sample ({num_samples}) categories without replacement from \
+                     distributions with ({num_categories}) categories"
+                ))
+                .details(format!("Tensor shape {:?}", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        pads: &[(usize, usize); D],
+        mode: PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if matches!(mode, PadMode::Constant(_)) {
+            return check;
+        }
+
+        for (dim, (before, after)) in pads.iter().enumerate() {
+            if shape.dims[dim] == 0 && before + after > 0 {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(format!(
+                        "Can't pad the empty dimension ({dim}) with the {mode:?} mode"
+                    ))
+                    .details(format!("Tensor shape {:?}, pads {pads:?}", shape.dims)),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn interpolate(spatial_dims: usize, mode: &InterpolateMode) -> Self {
+        let mut check = Self::Ok;
+
+        let valid = match mode {
+            InterpolateMode::Nearest | InterpolateMode::Area => true,
+            InterpolateMode::Linear => spatial_dims == 1,
+            InterpolateMode::Bilinear | InterpolateMode::Bicubic => spatial_dims == 2,
+            InterpolateMode::Trilinear => spatial_dims == 3,
+        };
+
+        if !valid {
+            check = check.register(
+                "Interpolate",
+                TensorError::new(format!(
+                    "The {mode:?} mode can't interpolate tensors with {spatial_dims} spatial dimensions"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn grid_sample_2d(shape_x: &Shape<4>, shape_grid: &Shape<4>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_grid.dims[3] != 2 {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new("The last dimension of the grid must hold the x and y positions")
+                    .details(format!("Grid shape {:?}", shape_grid.dims)),
+            );
+        }
+
+        if shape_x.dims[0] != shape_grid.dims[0] {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new("The input and the grid must have the same batch size").details(
+                    format!(
+                        "Input shape {:?}, grid shape {:?}",
+                        shape_x.dims, shape_grid.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn scaled_dot_product_attention(
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_value: &Shape<4>,
+        shape_mask: Option<&Shape<4>>,
+        dropout: f64,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [batch_size, n_heads, seq_length_1, d_k] = shape_query.dims;
+        let [_, _, seq_length_2, _] = shape_key.dims;
+
+        if shape_key.dims != [batch_size, n_heads, seq_length_2, d_k]
+            || shape_value.dims[..3] != [batch_size, n_heads, seq_length_2]
+        {
+            check = check.register(
+                "Scaled Dot-Product Attention",
+                TensorError::new(
+                    "The queries, keys and values must have the same batch size and number of \
+                     heads, the keys and values the same sequence length, and the queries and \
+                     keys the same size",
+                )
+                .details(format!(
+                    "Query shape {:?}, key shape {:?}, value shape {:?}",
+                    shape_query.dims, shape_key.dims, shape_value.dims
+                )),
+            );
+        }
+
+        if let Some(shape_mask) = shape_mask {
+            let [mask_batch_size, mask_n_heads, mask_seq_length_1, mask_seq_length_2] =
+                shape_mask.dims;
+
+            if ![1, batch_size].contains(&mask_batch_size)
+                || ![1, n_heads].contains(&mask_n_heads)
+                || [mask_seq_length_1, mask_seq_length_2] != [seq_length_1, seq_length_2]
+            {
+                check = check.register(
+                    "Scaled Dot-Product Attention",
+                    TensorError::new(
+                        "The mask must have the shape [batch_size, n_heads, seq_length_1, \
+                         seq_length_2], where the batch size and the number of heads can be one",
+                    )
+                    .details(format!(
+                        "Mask shape {:?}, query shape {:?}, key shape {:?}",
+                        shape_mask.dims, shape_query.dims, shape_key.dims
+                    )),
+                );
+            }
+        }
+
+        if !(0.0..1.0).contains(&dropout) {
+            check = check.register(
+                "Scaled Dot-Product Attention",
+                TensorError::new("The dropout probability must be in the range [0, 1)")
+                    .details(format!("Dropout probability {dropout}")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn affine_grid_2d(shape_theta: &Shape<3>, size: [usize; 4]) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_theta.dims[1..] != [2, 3] || shape_theta.dims[0] != size[0] {
+            check = check.register(
+                "Affine Grid",
+                TensorError::new("The affine matrices must have the shape [batch_size, 2, 3]")
+                    .details(format!(
+                        "Affine matrices shape {:?}, size {size:?}",
+                        shape_theta.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -767,6 +1003,352 @@ impl TensorCheck {
         })
     }
 
+    pub(crate) fn complex<const D: usize>(real: &Shape<D>, imag: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if real != imag {
+            check = check.register(
+                "Complex",
+                TensorError::new("The real and imaginary parts must have the same shape.").details(
+                    format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        real.dims, imag.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_coo<const D: usize>(indices: &Shape<2>, values: &Shape<1>) -> Self {
+        let mut check = Self::Ok;
+
+        if indices.dims[1] != D || indices.dims[0] != values.dims[0] {
+            check = check.register(
+                "Sparse",
+                TensorError::new(format!(
+                    "The indices must have the shape [nnz, {D}] with one value per element."
+                ))
+                .details(format!(
+                    "Indices shape {:?}, values shape {:?}.",
+                    indices.dims, values.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_csr(
+        row_offsets: &Shape<1>,
+        col_indices: &Shape<1>,
+        values: &Shape<1>,
+        shape: &Shape<2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if row_offsets.dims[0] != shape.dims[0] + 1 {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The row offsets must have one more element than rows.").details(
+                    format!(
+                        "Row offsets shape {:?}, tensor shape {:?}.",
+                        row_offsets.dims, shape.dims
+                    ),
+                ),
+            );
+        }
+
+        if col_indices != values {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The column indices and values must have the same shape.")
+                    .details(format!(
+                        "Column indices shape {:?}, values shape {:?}.",
+                        col_indices.dims, values.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_shape<const D: usize>(ops: &str, lhs: &Shape<D>, rhs: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs != rhs {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The sparse tensor and the other tensor must have the same shape.",
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn spmm(lhs: &Shape<2>, rhs: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs.dims[1] != rhs.dims[0] {
+            check = check.register(
+                "Spmm",
+                TensorError::new(format!(
+                    "The inner dimension of spmm should be the same, but got {} and {}.",
+                    lhs.dims[1], rhs.dims[0]
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn irfft<const D: usize>(dim: usize, n: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::dim_ops::<D>("Irfft", dim);
+
+        if dim < D && (n == 0 || n / 2 + 1 != shape.dims[dim]) {
+            check = check.register(
+                "Irfft",
+                TensorError::new(format!(
+                    "Can't compute a real signal of size ({n}) from ({}) frequencies",
+                    shape.dims[dim]
+                ))
+                .details(format!(
+                    "The number of frequencies must be equal to n / 2 + 1. Tensor shape {:?}, \
+                     dim {dim}",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<const D: usize>(equation: &str, dims: &[Vec<usize>]) -> Self {
+        let mut check = Self::Ok;
+
+        match super::einsum::Equation::parse(equation, dims) {
+            Ok(parsed) => {
+                let rank = usize::max(parsed.output.len(), 1);
+
+                if rank != D {
+                    check = check.register(
+                        "Einsum",
+                        TensorError::new(format!(
+                            "The output of the equation has rank ({rank}), but rank ({D}) was \
+                             requested"
+                        ))
+                        .details(format!("Equation '{equation}', operand shapes {dims:?}")),
+                    );
+                }
+            }
+            Err(reason) => {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new(reason)
+                        .details(format!("Equation '{equation}', operand shapes {dims:?}")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn matrix<const D: usize>(ops: &str, shape: &Shape<D>, square: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Expected a tensor of matrices with a rank of at least 2, got rank ({D})"
+                )),
+            );
+        } else if square && shape.dims[D - 2] != shape.dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new("Expected a tensor of square matrices").details(format!(
+                    "The last two dimensions must be equal, got shape {:?}",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn solve<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Self {
+        let mut check = Self::matrix::<D>("Solve", lhs, true);
+
+        if matches!(check, Self::Ok) && lhs.dims[..D - 1] != rhs.dims[..D - 1] {
+            check = check.register(
+                "Solve",
+                TensorError::new(
+                    "The right-hand side must have the same batch dimensions and number of rows \
+                     as the matrix",
+                )
+                .details(format!(
+                    "Matrix shape {:?}, right-hand side shape {:?}",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantization_scheme<const D: usize>(scheme: QuantizationScheme) -> Self {
+        let mut check = Self::Ok;
+
+        if let QuantizationScheme::PerChannel(dim) = scheme {
+            if dim >= D {
+                check = check.register(
+                    "Quantize",
+                    TensorError::new(format!(
+                        "Can't quantize per channel along dimension ({dim}) of a tensor of rank \
+                         ({D})"
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn quantization<const D: usize>(
+        shape: &Shape<D>,
+        scale: &Shape<1>,
+        zero_point: &Shape<1>,
+        scheme: QuantizationScheme,
+    ) -> Self {
+        let mut check = Self::quantization_scheme::<D>(scheme);
+
+        if scale != zero_point {
+            check = check.register(
+                "Quantize",
+                TensorError::new("The scales and zero points must have the same shape.").details(
+                    format!(
+                        "Scale shape {:?}, zero point shape {:?}.",
+                        scale.dims, zero_point.dims
+                    ),
+                ),
+            );
+        }
+
+        let expected = match scheme {
+            QuantizationScheme::PerTensor => 1,
+            QuantizationScheme::PerChannel(dim) if dim < D => shape.dims[dim],
+            QuantizationScheme::PerChannel(_) => return check,
+        };
+
+        if scale.dims[0] != expected {
+            check = check.register(
+                "Quantize",
+                TensorError::new(format!(
+                    "Expected ({expected}) scales for the {scheme:?} quantization, got ({})",
+                    scale.dims[0]
+                ))
+                .details(format!("Tensor shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantized_matmul<const D: usize>(
+        lhs: &Shape<D>,
+        lhs_scheme: QuantizationScheme,
+        rhs: &Shape<D>,
+        rhs_scheme: QuantizationScheme,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check;
+        }
+
+        if lhs_scheme != QuantizationScheme::PerTensor
+            && lhs_scheme != QuantizationScheme::PerChannel(D - 2)
+        {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The left tensor must be quantized per tensor or per row, got {lhs_scheme:?}"
+                )),
+            );
+        }
+
+        if rhs_scheme != QuantizationScheme::PerTensor
+            && rhs_scheme != QuantizationScheme::PerChannel(D - 1)
+        {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The right tensor must be quantized per tensor or per column, got \
+                     {rhs_scheme:?}"
+                )),
+            );
+        }
+
+        if lhs.dims[D - 1] != rhs.dims[D - 2] {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The inner dimension of matmul should be the same, but got {} and {}.",
+                    lhs.dims[D - 1],
+                    rhs.dims[D - 2]
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantized_conv2d(
+        x_scheme: QuantizationScheme,
+        weight_scheme: QuantizationScheme,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if x_scheme != QuantizationScheme::PerTensor {
+            check = check.register(
+                "Conv2d",
+                TensorError::new(format!(
+                    "The input must be quantized per tensor, got {x_scheme:?}"
+                )),
+            );
+        }
+
+        if !matches!(
+            weight_scheme,
+            QuantizationScheme::PerTensor | QuantizationScheme::PerChannel(0)
+        ) {
+            check = check.register(
+                "Conv2d",
+                TensorError::new(format!(
+                    "The weight must be quantized per tensor or per output channel, got \
+                     {weight_scheme:?}"
+                )),
+            );
+        }
+
+        check
+    }
+
     /// Checks if shapes are compatible for element wise operations supporting broadcasting.
     pub(crate) fn binary_ops_ew_shape<const D: usize>(
         self,
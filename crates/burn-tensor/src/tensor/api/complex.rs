@@ -0,0 +1,172 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Shape, Tensor};
+
+/// A complex tensor stored as a pair of float tensors holding the real and imaginary parts.
+///
+/// Since both parts are regular float tensors, every operation on complex tensors is
+/// differentiable when the backend supports autodiff.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = B::Device::default();
+///     let signal = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+///     let spectrum = signal.rfft(0);
+///     println!("{}", spectrum.real());
+///     // [10.0, -2.0, -2.0]
+///     println!("{}", spectrum.imag());
+///     // [0.0, 2.0, 0.0]
+///     let signal = spectrum.irfft(0, 4);
+///     println!("{signal}");
+///     // [1.0, 2.0, 3.0, 4.0]
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComplexTensor<B: Backend, const D: usize> {
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> ComplexTensor<B, D> {
+    /// Creates a complex tensor from its real and imaginary parts, which must have the same shape.
+    pub fn new(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex::<D>(&real.shape(), &imag.shape()));
+        Self { real, imag }
+    }
+
+    /// Creates a complex tensor with the given real part and an imaginary part filled with zeros.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+        Self { real, imag }
+    }
+
+    /// Creates a complex tensor from its magnitude and phase.
+    ///
+    /// `z = magnitude * (cos(phase) + i * sin(phase))`
+    pub fn from_polar(magnitude: Tensor<B, D>, phase: Tensor<B, D>) -> Self {
+        let real = magnitude.clone() * phase.clone().cos();
+        let imag = magnitude * phase.sin();
+        Self::new(real, imag)
+    }
+
+    /// Returns the real part of the tensor.
+    pub fn real(&self) -> Tensor<B, D> {
+        self.real.clone()
+    }
+
+    /// Returns the imaginary part of the tensor.
+    pub fn imag(&self) -> Tensor<B, D> {
+        self.imag.clone()
+    }
+
+    /// Converts the complex tensor into its real and imaginary parts.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (self.real, self.imag)
+    }
+
+    /// Returns the shape of the current tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.real.shape()
+    }
+
+    /// Returns the dimensions of the current tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.real.dims()
+    }
+
+    /// Returns the device of the current tensor.
+    pub fn device(&self) -> B::Device {
+        self.real.device()
+    }
+
+    /// Returns a new tensor on the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            real: self.real.to_device(device),
+            imag: self.imag.to_device(device),
+        }
+    }
+
+    /// Applies element wise complex conjugate operation.
+    ///
+    /// `y = re(x) - i * im(x)`
+    pub fn conj(self) -> Self {
+        Self {
+            real: self.real,
+            imag: self.imag.neg(),
+        }
+    }
+
+    /// Returns the magnitude of each element.
+    ///
+    /// `y = sqrt(re(x)^2 + im(x)^2)`
+    pub fn abs(self) -> Tensor<B, D> {
+        (self.real.powf_scalar(2.0) + self.imag.powf_scalar(2.0)).sqrt()
+    }
+
+    /// Applies element wise addition operation.
+    ///
+    /// `y = x2 + x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        Self::new(self.real + other.real, self.imag + other.imag)
+    }
+
+    /// Applies element wise subtraction operation.
+    ///
+    /// `y = x2 - x1`
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        Self::new(self.real - other.real, self.imag - other.imag)
+    }
+
+    /// Applies element wise complex multiplication operation.
+    ///
+    /// `y = (re(x1) * re(x2) - im(x1) * im(x2)) + i * (re(x1) * im(x2) + im(x1) * re(x2))`
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        let real = self.real.clone() * other.real.clone() - self.imag.clone() * other.imag.clone();
+        let imag = self.real * other.imag + self.imag * other.real;
+        Self::new(real, imag)
+    }
+
+    /// Computes the discrete Fourier transform along the given dimension.
+    ///
+    /// The transform isn't normalized, so applying [ifft](ComplexTensor::ifft) to the result
+    /// returns the original tensor.
+    pub fn fft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Fft", dim));
+        let (real, imag) = B::float_fft(self.real.primitive, self.imag.primitive, dim);
+        Self::new(Tensor::new(real), Tensor::new(imag))
+    }
+
+    /// Computes the inverse discrete Fourier transform along the given dimension.
+    ///
+    /// The output is normalized by the size of the dimension.
+    pub fn ifft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Ifft", dim));
+        let (real, imag) = B::float_ifft(self.real.primitive, self.imag.primitive, dim);
+        Self::new(Tensor::new(real), Tensor::new(imag))
+    }
+
+    /// Computes the inverse of [rfft](Tensor::rfft) along the given dimension, returning a real
+    /// signal of size `n`.
+    ///
+    /// The tensor must hold the `n / 2 + 1` non-negative frequencies along the given dimension.
+    /// The imaginary parts of the zero frequency and, when `n` is even, of the Nyquist frequency
+    /// are ignored.
+    pub fn irfft(self, dim: usize, n: usize) -> Tensor<B, D> {
+        check!(TensorCheck::irfft::<D>(dim, n, &self.shape()));
+        Tensor::new(B::float_irfft(
+            self.real.primitive,
+            self.imag.primitive,
+            dim,
+            n,
+        ))
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatElem, FloatTensor, IntTensor},
+    BasicOps, Element, ElementConversion, Int, Numeric, Tensor, TensorKind,
+};
+use alloc::vec;
+
+/// Finds the unique elements of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// The unique elements sorted in ascending order, the index of each element of the input tensor
+/// in the unique elements, and the number of occurrences of each unique element.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn unique<B: Backend, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<1>,
+) -> (K::Primitive<1>, IntTensor<B, 1>, IntTensor<B, 1>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let tensor = Tensor::<B, 1, K>::new(tensor);
+    let device = tensor.device();
+    let [size] = tensor.dims();
+
+    if size == 0 {
+        let empty = || Tensor::<B, 1, Int>::empty([0], &device).into_primitive();
+        return (tensor.into_primitive(), empty(), empty());
+    }
+
+    let (sorted, indices) = tensor.sort_with_indices(0);
+
+    // Each sorted element that differs from the previous one starts a new group.
+    let starts = sorted
+        .clone()
+        .narrow(0, 1, size - 1)
+        .not_equal(sorted.clone().narrow(0, 0, size - 1))
+        .int();
+    let groups = Tensor::cat(vec![Tensor::zeros([1], &device), starts], 0).cumsum(0);
+    let num_unique = groups
+        .clone()
+        .narrow(0, size - 1, 1)
+        .into_scalar()
+        .elem::<i64>() as usize
+        + 1;
+
+    let inverse = Tensor::<B, 1, Int>::zeros([size], &device).scatter(0, indices, groups.clone());
+    let counts = Tensor::<B, 1, Int>::zeros([num_unique], &device).scatter(
+        0,
+        groups,
+        Tensor::ones([size], &device),
+    );
+    let offsets = counts.clone().cumsum(0) - counts.clone();
+    let values = sorted.select(0, offsets);
+
+    (
+        values.into_primitive(),
+        inverse.into_primitive(),
+        counts.into_primitive(),
+    )
+}
+
+/// Finds the indices where the `values` would be inserted in the `sorted` tensor to keep it sorted.
+///
+/// # Arguments
+///
+/// * `sorted` - The tensor sorted in ascending order.
+/// * `values` - The values to insert.
+/// * `right` - Whether the index after the elements equal to a value is returned, instead of the
+///   index before them.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the values, containing the insertion index of each value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn searchsorted<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    sorted: K::Primitive<1>,
+    values: K::Primitive<D>,
+    right: bool,
+) -> IntTensor<B, D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let sorted = Tensor::<B, 1, K>::new(sorted);
+    let values = Tensor::<B, D, K>::new(values);
+    let shape = values.shape();
+    let num_values = shape.num_elements();
+    let [size] = sorted.dims();
+
+    if size == 0 {
+        return Tensor::<B, D, Int>::zeros(shape, &values.device()).into_primitive();
+    }
+
+    // Compares each value with every sorted element.
+    let sorted = sorted.reshape([1, size]).repeat(0, num_values);
+    let values = values.reshape([num_values, 1]).repeat(1, size);
+    let before = match right {
+        true => sorted.lower_equal(values),
+        false => sorted.lower(values),
+    };
+
+    before.int().sum_dim(1).reshape(shape).into_primitive()
+}
+
+/// Counts the occurrences of each non-negative value of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor, whose values must be non-negative.
+/// * `min_length` - The minimum number of counts returned.
+///
+/// # Returns
+///
+/// A tensor whose element at index `i` is the number of occurrences of `i`, with a length of one
+/// more than the largest value, or `min_length` if it is larger.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bincount<B: Backend>(tensor: IntTensor<B, 1>, min_length: usize) -> IntTensor<B, 1> {
+    let tensor = Tensor::<B, 1, Int>::new(tensor);
+    let device = tensor.device();
+    let [size] = tensor.dims();
+
+    if size == 0 {
+        return Tensor::<B, 1, Int>::zeros([min_length], &device).into_primitive();
+    }
+
+    let max = tensor.clone().max().into_scalar().elem::<i64>() as usize;
+    let length = usize::max(max + 1, min_length);
+
+    Tensor::<B, 1, Int>::zeros([length], &device)
+        .scatter(0, tensor, Tensor::ones([size], &device))
+        .into_primitive()
+}
+
+/// Computes the histogram of the input `tensor` with bins of equal width between `min` and `max`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `bins` - The number of bins.
+/// * `min` - The lower end of the first bin.
+/// * `max` - The upper end of the last bin, which includes it.
+///
+/// # Returns
+///
+/// A tensor with the number of elements in each bin, ignoring the elements outside of the range.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn histc<B: Backend>(
+    tensor: FloatTensor<B, 1>,
+    bins: usize,
+    min: FloatElem<B>,
+    max: FloatElem<B>,
+) -> FloatTensor<B, 1> {
+    let tensor = Tensor::<B, 1>::new(tensor);
+    let device = tensor.device();
+    let [size] = tensor.dims();
+
+    if size == 0 {
+        return Tensor::<B, 1>::zeros([bins], &device).into_primitive();
+    }
+
+    let min = min.elem::<f64>();
+    let max = max.elem::<f64>();
+    let width = (max - min) / bins as f64;
+
+    let in_range = tensor
+        .clone()
+        .greater_equal_elem(min)
+        .bool_and(tensor.clone().lower_equal_elem(max));
+    // The values outside of the range are moved to a bin with a weight of zero, and the maximum
+    // falls in the last bin.
+    let indices = tensor
+        .sub_scalar(min)
+        .div_scalar(width)
+        .clamp(0.0, (bins - 1) as f64)
+        .int();
+
+    Tensor::<B, 1>::zeros([bins], &device)
+        .scatter(0, indices, in_range.float())
+        .into_primitive()
+}
@@ -0,0 +1,271 @@
+use crate::{
+    backend::Backend, narrow, ops::IntTensor, BasicOps, Bool, Element, Numeric, Shape, Tensor,
+    TensorKind,
+};
+use core::ops::Range;
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative sum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the sum of all the
+/// preceding elements (inclusive) along the given dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumsum<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan::<B, D, K, _>(tensor, dim, |previous, current| K::add(previous, current))
+}
+
+/// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative product.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the product of all the
+/// preceding elements (inclusive) along the given dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumprod<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan::<B, D, K, _>(tensor, dim, |previous, current| K::mul(previous, current))
+}
+
+/// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative maximum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the maximum of all the
+/// preceding elements (inclusive) along the given dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummax<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan::<B, D, K, _>(tensor, dim, |previous, current| {
+        let mask = K::greater(previous.clone(), current.clone());
+        K::mask_where(current, mask, previous)
+    })
+}
+
+/// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative minimum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the minimum of all the
+/// preceding elements (inclusive) along the given dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummin<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan::<B, D, K, _>(tensor, dim, |previous, current| {
+        let mask = K::lower(previous.clone(), current.clone());
+        K::mask_where(current, mask, previous)
+    })
+}
+
+/// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative maximum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor and the corresponding indices, where each
+/// index points to the position of the running maximum along the given dimension. When the
+/// maximum value occurs more than once, the index of the last occurrence is returned.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummax_with_indices<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan_with_indices::<B, D, K, _>(tensor, dim, |previous, current| {
+        K::greater(previous, current)
+    })
+}
+
+/// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative minimum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor and the corresponding indices, where each
+/// index points to the position of the running minimum along the given dimension. When the
+/// minimum value occurs more than once, the index of the last occurrence is returned.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummin_with_indices<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    scan_with_indices::<B, D, K, _>(tensor, dim, |previous, current| K::lower(previous, current))
+}
+
+/// Inclusive scan along `dim` using the Hillis-Steele algorithm, which only needs
+/// `log2(n)` passes of whole-tensor operations.
+fn scan<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>, F>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    combine: F,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+    F: Fn(K::Primitive<D>, K::Primitive<D>) -> K::Primitive<D>,
+{
+    let shape = K::shape(&tensor);
+    let size = shape.dims[dim];
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let previous = narrow::<B, D, K>(output.clone(), dim, 0, size - offset);
+        let current = narrow::<B, D, K>(output.clone(), dim, offset, size - offset);
+        let ranges = ranges_along(&shape, dim, offset..size);
+
+        output = K::slice_assign(output, ranges, combine(previous, current));
+        offset *= 2;
+    }
+
+    output
+}
+
+/// Same as [scan](scan), but keeps track of the position of the selected element, which is taken
+/// from the previous elements only where `take_previous` is true.
+fn scan_with_indices<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>, F>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    take_previous: F,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+    F: Fn(K::Primitive<D>, K::Primitive<D>) -> Tensor<B, D, Bool>,
+{
+    let shape = K::shape(&tensor);
+    let device = K::device(&tensor);
+    let size = shape.dims[dim];
+
+    let mut arange_shape = [1; D];
+    arange_shape[dim] = size;
+    let arange = B::int_reshape(
+        B::int_arange(0..size as i64, &device),
+        Shape::new(arange_shape),
+    );
+
+    let mut output = tensor;
+    let mut indices = B::int_add(B::int_zeros(shape.clone(), &device), arange);
+    let mut offset = 1;
+
+    while offset < size {
+        let previous = narrow::<B, D, K>(output.clone(), dim, 0, size - offset);
+        let current = narrow::<B, D, K>(output.clone(), dim, offset, size - offset);
+        let previous_indices = B::int_narrow(indices.clone(), dim, 0, size - offset);
+        let current_indices = B::int_narrow(indices.clone(), dim, offset, size - offset);
+        let mask = take_previous(previous.clone(), current.clone());
+        let ranges = ranges_along(&shape, dim, offset..size);
+
+        indices = B::int_slice_assign(
+            indices,
+            ranges.clone(),
+            B::int_mask_where(
+                current_indices,
+                mask.clone().into_primitive(),
+                previous_indices,
+            ),
+        );
+        output = K::slice_assign(output, ranges, K::mask_where(current, mask, previous));
+        offset *= 2;
+    }
+
+    (output, indices)
+}
+
+fn ranges_along<const D: usize>(
+    shape: &Shape<D>,
+    dim: usize,
+    range: Range<usize>,
+) -> [Range<usize>; D] {
+    let mut ranges: [Range<usize>; D] = shape.dims.map(|size| 0..size);
+    ranges[dim] = range;
+    ranges
+}
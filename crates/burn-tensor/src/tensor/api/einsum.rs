@@ -0,0 +1,597 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Data, Int, Shape, Tensor};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The first label used to name the dimensions covered by an ellipsis.
+const ELLIPSIS_LABEL: u32 = 0xE000;
+
+/// An operand of [einsum](einsum).
+///
+/// Operands are created from float tensors of any rank with [From], which allows tensors of
+/// different ranks to be passed to the same expression.
+#[derive(Clone, Debug)]
+pub struct EinsumOperand<B: Backend> {
+    tensor: Tensor<B, 1>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        let dims = tensor.dims().to_vec();
+        let num_elements: usize = dims.iter().product();
+
+        Self {
+            tensor: tensor.reshape([num_elements]),
+            dims,
+        }
+    }
+}
+
+/// Evaluates the Einstein summation expression on the given operands.
+///
+/// The equation lists the labels of each operand separated by commas, optionally followed by `->`
+/// and the labels of the output. Labels are ASCII letters, and `...` stands for the dimensions
+/// that aren't labeled, which are broadcast from the right like the leading dimensions of
+/// element wise operations. Labels repeated in the same operand select its diagonal, and labels
+/// missing from the output are summed. Without `->`, the output contains the ellipsis dimensions
+/// followed by the labels used exactly once, in alphabetical order.
+///
+/// Operands are contracted two at a time, starting with the pair producing the smallest
+/// intermediate tensor. Each step is lowered to [matmul](Tensor::matmul),
+/// [sum_dim](Tensor::sum_dim) and [swap_dims](Tensor::swap_dims), or [select](Tensor::select) to
+/// reorder more than 6 dimensions, so every backend supports it, including under autodiff.
+/// Intermediate tensors can have any rank.
+///
+/// # Arguments
+///
+/// * `equation` - The Einstein summation equation.
+/// * `operands` - The operands, one for each term of the equation.
+///
+/// # Returns
+///
+/// The tensor of rank `D` holding the result, where an output without labels is returned as a
+/// tensor of shape `[1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = B::Device::default();
+///     let query = Tensor::<B, 4>::ones([2, 4, 8, 16], &device);
+///     let key = Tensor::<B, 4>::ones([2, 4, 10, 16], &device);
+///     let scores = einsum::<B, 4>("bhqd,bhkd->bhqk", vec![query.into(), key.into()]);
+///     println!("{:?}", scores.dims());
+///     // [2, 4, 8, 10]
+/// }
+/// ```
+pub fn einsum<B: Backend, const D: usize>(
+    equation: &str,
+    operands: Vec<EinsumOperand<B>>,
+) -> Tensor<B, D> {
+    let dims: Vec<Vec<usize>> = operands
+        .iter()
+        .map(|operand| operand.dims.clone())
+        .collect();
+    check!(TensorCheck::einsum::<D>(equation, &dims));
+
+    let equation = Equation::parse(equation, &dims).unwrap();
+    let output = equation.output;
+
+    let mut terms: Vec<Term<B>> = operands
+        .into_iter()
+        .zip(equation.inputs)
+        .map(|(operand, labels)| Term::new(operand.tensor, labels, operand.dims).diagonal())
+        .collect();
+
+    while terms.len() > 1 {
+        // Labels used by a single term and absent from the output are summed right away.
+        for i in 0..terms.len() {
+            let needed = needed_labels(&terms, &output, &[i]);
+            let term = terms[i].clone();
+            terms[i] = term.sum(&needed);
+        }
+
+        let (lhs, rhs) = cheapest_pair(&terms, &output);
+        let needed = needed_labels(&terms, &output, &[lhs, rhs]);
+        let term_rhs = terms.remove(rhs);
+        let term_lhs = terms.remove(lhs);
+
+        terms.push(term_lhs.contract(term_rhs, &needed));
+    }
+
+    let term = terms.remove(0).sum(&output).permute(&output);
+    let shape: [usize; D] = match term.dims.is_empty() {
+        true => [1; D],
+        false => term.dims.try_into().unwrap(),
+    };
+
+    term.tensor.reshape(shape)
+}
+
+/// The parsed labels of an einsum equation, with the ellipsis replaced by private labels.
+pub(crate) struct Equation {
+    pub(crate) inputs: Vec<Vec<char>>,
+    pub(crate) output: Vec<char>,
+}
+
+impl Equation {
+    /// Parses the equation for operands of the given dimensions.
+    pub(crate) fn parse(equation: &str, dims: &[Vec<usize>]) -> Result<Self, String> {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (lhs, rhs) = match equation.split_once("->") {
+            Some((lhs, rhs)) => (lhs, Some(rhs)),
+            None => (equation.as_str(), None),
+        };
+
+        let terms: Vec<&str> = lhs.split(',').collect();
+        if terms.len() != dims.len() {
+            return Err(format!(
+                "The equation has {} input terms, but {} operands were provided",
+                terms.len(),
+                dims.len()
+            ));
+        }
+
+        let mut inputs = Vec::with_capacity(terms.len());
+        let mut num_ellipsis_dims = 0;
+
+        for (term, dims) in terms.iter().zip(dims) {
+            let (labels, ellipsis) = parse_term(term)?;
+            let labels = match ellipsis {
+                Some(position) => {
+                    if labels.len() > dims.len() {
+                        return Err(format!(
+                            "The term '{term}' has more labels than the rank of its operand ({})",
+                            dims.len()
+                        ));
+                    }
+
+                    let size = dims.len() - labels.len();
+                    num_ellipsis_dims = usize::max(num_ellipsis_dims, size);
+
+                    let mut expanded = labels[..position].to_vec();
+                    expanded.extend((0..size).rev().map(ellipsis_label));
+                    expanded.extend_from_slice(&labels[position..]);
+                    expanded
+                }
+                None => labels,
+            };
+
+            if labels.len() != dims.len() {
+                return Err(format!(
+                    "The term '{term}' has {} labels, but its operand has rank {}",
+                    labels.len(),
+                    dims.len()
+                ));
+            }
+
+            inputs.push(labels);
+        }
+
+        let mut sizes: Vec<(char, usize)> = Vec::new();
+        for (labels, dims) in inputs.iter().zip(dims) {
+            for (label, size) in labels.iter().zip(dims) {
+                match sizes.iter().find(|(other, _)| other == label) {
+                    Some((_, other)) if other != size => {
+                        return Err(format!(
+                            "The label '{}' is used for dimensions of different sizes ({other} \
+                             and {size})",
+                            display_label(*label)
+                        ));
+                    }
+                    Some(_) => {}
+                    None => sizes.push((*label, *size)),
+                }
+            }
+        }
+
+        let ellipsis_labels = (0..num_ellipsis_dims).rev().map(ellipsis_label);
+        let output = match rhs {
+            Some(term) => {
+                let (labels, ellipsis) = parse_term(term)?;
+                let mut output = Vec::with_capacity(labels.len() + num_ellipsis_dims);
+
+                match ellipsis {
+                    Some(position) => {
+                        output.extend_from_slice(&labels[..position]);
+                        output.extend(ellipsis_labels);
+                        output.extend_from_slice(&labels[position..]);
+                    }
+                    None => output.extend(labels),
+                }
+
+                for (i, label) in output.iter().enumerate() {
+                    if output[..i].contains(label) {
+                        return Err(format!(
+                            "The label '{}' is repeated in the output",
+                            display_label(*label)
+                        ));
+                    }
+
+                    if !sizes.iter().any(|(other, _)| other == label) {
+                        return Err(format!(
+                            "The output label '{}' isn't used by any operand",
+                            display_label(*label)
+                        ));
+                    }
+                }
+
+                output
+            }
+            None => {
+                let mut labels: Vec<char> = sizes
+                    .iter()
+                    .map(|(label, _)| *label)
+                    .filter(|label| (*label as u32) < ELLIPSIS_LABEL)
+                    .filter(|label| inputs.iter().flatten().filter(|l| *l == label).count() == 1)
+                    .collect();
+                labels.sort();
+
+                ellipsis_labels.chain(labels).collect()
+            }
+        };
+
+        Ok(Self { inputs, output })
+    }
+}
+
+/// Returns the labels of a term and the position of its ellipsis, if any.
+fn parse_term(term: &str) -> Result<(Vec<char>, Option<usize>), String> {
+    let mut labels = Vec::new();
+    let mut ellipsis = None;
+    let mut chars = term.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '.' {
+            if ellipsis.is_some() || chars.next() != Some('.') || chars.next() != Some('.') {
+                return Err(format!("The term '{term}' has an invalid ellipsis"));
+            }
+            ellipsis = Some(labels.len());
+        } else if c.is_ascii_alphabetic() {
+            labels.push(c);
+        } else {
+            return Err(format!("The term '{term}' has an invalid label '{c}'"));
+        }
+    }
+
+    Ok((labels, ellipsis))
+}
+
+fn ellipsis_label(index: usize) -> char {
+    char::from_u32(ELLIPSIS_LABEL + index as u32).unwrap()
+}
+
+fn display_label(label: char) -> String {
+    match (label as u32).checked_sub(ELLIPSIS_LABEL) {
+        Some(index) => format!("...[{index}]"),
+        None => format!("{label}"),
+    }
+}
+
+/// An intermediate tensor flattened to one dimension, with the label and size of each dimension.
+#[derive(Clone)]
+struct Term<B: Backend> {
+    tensor: Tensor<B, 1>,
+    labels: Vec<char>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend> Term<B> {
+    fn new(tensor: Tensor<B, 1>, labels: Vec<char>, dims: Vec<usize>) -> Self {
+        Self {
+            tensor,
+            labels,
+            dims,
+        }
+    }
+
+    fn num_elements(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Selects the diagonal over the dimensions sharing the same label.
+    fn diagonal(self) -> Self {
+        let mut labels: Vec<char> = Vec::with_capacity(self.labels.len());
+        let mut dims = Vec::with_capacity(self.dims.len());
+
+        for (label, size) in self.labels.iter().zip(&self.dims) {
+            if !labels.contains(label) {
+                labels.push(*label);
+                dims.push(*size);
+            }
+        }
+
+        if labels.len() == self.labels.len() {
+            return self;
+        }
+
+        // The stride of each unique label sums the strides of the dimensions it labels.
+        let mut strides = alloc::vec![0; labels.len()];
+        let mut stride = 1;
+        for (label, size) in self.labels.iter().zip(&self.dims).rev() {
+            let index = labels.iter().position(|other| other == label).unwrap();
+            strides[index] += stride;
+            stride *= size;
+        }
+
+        let num_elements: usize = dims.iter().product();
+        let indices: Vec<i64> = (0..num_elements)
+            .map(|mut position| {
+                let mut index = 0;
+                for (size, stride) in dims.iter().zip(&strides).rev() {
+                    index += (position % size) * stride;
+                    position /= size;
+                }
+                index as i64
+            })
+            .collect();
+
+        let device = self.tensor.device();
+        let indices = Tensor::<B, 1, Int>::from_data(
+            Data::new(indices, Shape::new([num_elements])).convert(),
+            &device,
+        );
+
+        Self::new(self.tensor.select(0, indices), labels, dims)
+    }
+
+    /// Sums the dimensions whose labels aren't part of `keep`.
+    fn sum(self, keep: &[char]) -> Self {
+        let (kept, summed): (Vec<char>, Vec<char>) =
+            self.labels.iter().partition(|label| keep.contains(label));
+
+        if summed.is_empty() {
+            return self;
+        }
+
+        let order: Vec<char> = kept.iter().chain(&summed).copied().collect();
+        let term = self.permute(&order);
+        let dims = term.dims[..kept.len()].to_vec();
+        let size_kept: usize = dims.iter().product();
+        let size_summed = term.num_elements() / size_kept;
+
+        let tensor = term
+            .tensor
+            .reshape([size_kept, size_summed])
+            .sum_dim(1)
+            .reshape([size_kept]);
+
+        Self::new(tensor, kept, dims)
+    }
+
+    /// Contracts two terms with a batched matrix multiplication, keeping the shared labels that
+    /// are part of `keep` as batch dimensions.
+    fn contract(self, other: Self, keep: &[char]) -> Self {
+        let (shared, lhs): (Vec<char>, Vec<char>) = self
+            .labels
+            .iter()
+            .partition(|label| other.labels.contains(label));
+        let (batch, contracted): (Vec<char>, Vec<char>) =
+            shared.iter().partition(|label| keep.contains(label));
+        let rhs: Vec<char> = other
+            .labels
+            .iter()
+            .filter(|label| !self.labels.contains(label))
+            .copied()
+            .collect();
+
+        let size_of = |labels: &[char]| -> usize {
+            labels
+                .iter()
+                .map(|label| {
+                    self.size(*label)
+                        .unwrap_or_else(|| other.size(*label).unwrap())
+                })
+                .product()
+        };
+        let (size_batch, size_lhs, size_contracted, size_rhs) = (
+            size_of(&batch),
+            size_of(&lhs),
+            size_of(&contracted),
+            size_of(&rhs),
+        );
+
+        let labels: Vec<char> = batch.iter().chain(&lhs).chain(&rhs).copied().collect();
+        let dims: Vec<usize> = labels
+            .iter()
+            .map(|label| {
+                self.size(*label)
+                    .unwrap_or_else(|| other.size(*label).unwrap())
+            })
+            .collect();
+
+        let order_lhs: Vec<char> = batch
+            .iter()
+            .chain(&lhs)
+            .chain(&contracted)
+            .copied()
+            .collect();
+        let order_rhs: Vec<char> = batch
+            .iter()
+            .chain(&contracted)
+            .chain(&rhs)
+            .copied()
+            .collect();
+
+        let tensor_lhs =
+            self.permute(&order_lhs)
+                .tensor
+                .reshape([size_batch, size_lhs, size_contracted]);
+        let tensor_rhs =
+            other
+                .permute(&order_rhs)
+                .tensor
+                .reshape([size_batch, size_contracted, size_rhs]);
+        let tensor = tensor_lhs
+            .matmul(tensor_rhs)
+            .reshape([size_batch * size_lhs * size_rhs]);
+
+        Self::new(tensor, labels, dims)
+    }
+
+    /// Reorders the dimensions to follow the given labels.
+    fn permute(self, order: &[char]) -> Self {
+        if order == self.labels.as_slice() {
+            return self;
+        }
+
+        let axes: Vec<usize> = order
+            .iter()
+            .map(|label| self.labels.iter().position(|other| other == label).unwrap())
+            .collect();
+        let dims = axes.iter().map(|axis| self.dims[*axis]).collect();
+
+        // Axes that stay next to each other in the same order are moved as a single axis.
+        let mut groups: Vec<(usize, usize)> = Vec::with_capacity(axes.len());
+        for axis in axes.iter() {
+            match groups.last_mut() {
+                Some((start, len)) if *start + *len == *axis => *len += 1,
+                _ => groups.push((*axis, 1)),
+            }
+        }
+
+        let mut starts: Vec<usize> = groups.iter().map(|(start, _)| *start).collect();
+        starts.sort();
+        let merged_dims: Vec<usize> = starts
+            .iter()
+            .map(|start| {
+                let (_, len) = groups.iter().find(|(other, _)| other == start).unwrap();
+                self.dims[*start..*start + *len].iter().product()
+            })
+            .collect();
+        let merged_axes: Vec<usize> = groups
+            .iter()
+            .map(|(start, _)| starts.iter().position(|other| other == start).unwrap())
+            .collect();
+
+        macro_rules! permute {
+            ($($rank:literal),*) => {
+                match merged_dims.len() {
+                    $($rank => permute::<B, $rank>(self.tensor, &merged_dims, &merged_axes),)*
+                    _ => permute_with_indices(self.tensor, &merged_dims, &merged_axes),
+                }
+            };
+        }
+
+        // Higher ranks aren't supported by every backend.
+        let tensor = permute!(2, 3, 4, 5, 6);
+
+        Self::new(tensor, order.to_vec(), dims)
+    }
+
+    fn size(&self, label: char) -> Option<usize> {
+        self.labels
+            .iter()
+            .position(|other| *other == label)
+            .map(|index| self.dims[index])
+    }
+}
+
+/// Permutes a flattened tensor of rank `D` with a sequence of dimension swaps.
+fn permute<B: Backend, const D: usize>(
+    tensor: Tensor<B, 1>,
+    dims: &[usize],
+    axes: &[usize],
+) -> Tensor<B, 1> {
+    let num_elements = tensor.dims()[0];
+    let shape: [usize; D] = dims.try_into().unwrap();
+    let mut tensor = tensor.reshape(shape);
+    let mut current: Vec<usize> = (0..D).collect();
+
+    for (i, axis) in axes.iter().enumerate() {
+        let j = current.iter().position(|other| other == axis).unwrap();
+
+        if i != j {
+            tensor = tensor.swap_dims(i, j);
+            current.swap(i, j);
+        }
+    }
+
+    tensor.reshape([num_elements])
+}
+
+/// Permutes a flattened tensor of any rank by selecting its elements in the permuted order.
+fn permute_with_indices<B: Backend>(
+    tensor: Tensor<B, 1>,
+    dims: &[usize],
+    axes: &[usize],
+) -> Tensor<B, 1> {
+    let num_elements = tensor.dims()[0];
+
+    let mut strides = alloc::vec![0; dims.len()];
+    let mut stride = 1;
+    for (axis, size) in dims.iter().enumerate().rev() {
+        strides[axis] = stride;
+        stride *= size;
+    }
+
+    let indices: Vec<i64> = (0..num_elements)
+        .map(|mut position| {
+            let mut index = 0;
+            for axis in axes.iter().rev() {
+                index += (position % dims[*axis]) * strides[*axis];
+                position /= dims[*axis];
+            }
+            index as i64
+        })
+        .collect();
+
+    let device = tensor.device();
+    let indices = Tensor::<B, 1, Int>::from_data(
+        Data::new(indices, Shape::new([num_elements])).convert(),
+        &device,
+    );
+
+    tensor.select(0, indices)
+}
+
+/// Returns the labels that must be kept by the given terms, which are the labels of the output
+/// and of every other term.
+fn needed_labels<B: Backend>(terms: &[Term<B>], output: &[char], excluded: &[usize]) -> Vec<char> {
+    let mut labels = output.to_vec();
+
+    for (i, term) in terms.iter().enumerate() {
+        if !excluded.contains(&i) {
+            labels.extend_from_slice(&term.labels);
+        }
+    }
+
+    labels
+}
+
+/// Returns the pair of terms whose contraction produces the smallest intermediate tensor.
+fn cheapest_pair<B: Backend>(terms: &[Term<B>], output: &[char]) -> (usize, usize) {
+    let mut best = (0, 1);
+    let mut best_size = usize::MAX;
+
+    for lhs in 0..terms.len() {
+        for rhs in lhs + 1..terms.len() {
+            let needed = needed_labels(terms, output, &[lhs, rhs]);
+            let mut size = 1;
+
+            for (label, dim) in terms[lhs].labels.iter().zip(&terms[lhs].dims) {
+                if needed.contains(label) || !terms[rhs].labels.contains(label) {
+                    size *= dim;
+                }
+            }
+            for (label, dim) in terms[rhs].labels.iter().zip(&terms[rhs].dims) {
+                if !terms[lhs].labels.contains(label) {
+                    size *= dim;
+                }
+            }
+
+            if size < best_size {
+                best = (lhs, rhs);
+                best_size = size;
+            }
+        }
+    }
+
+    best
+}
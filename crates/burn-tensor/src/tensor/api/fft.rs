@@ -0,0 +1,205 @@
+use crate::{backend::Backend, ops::FloatTensor, Data, Shape, Tensor};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// Computes the discrete Fourier transform of a complex tensor along a given dimension.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input tensor.
+/// * `imag` - The imaginary part of the input tensor.
+/// * `dim` - The axis along which to compute the transform.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the unnormalized transform.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn fft<B: Backend, const D: usize>(
+    real: FloatTensor<B, D>,
+    imag: FloatTensor<B, D>,
+    dim: usize,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let real = Tensor::<B, D>::from_primitive(real);
+    let imag = Tensor::<B, D>::from_primitive(imag);
+    let n = real.dims()[dim];
+    let device = real.device();
+
+    let cos = dft_matrix::<B>([n, n], n, &device, |_, angle| libm::cos(angle));
+    let sin = dft_matrix::<B>([n, n], n, &device, |_, angle| libm::sin(angle));
+
+    let output_real =
+        dft_along(real.clone(), cos.clone(), dim) + dft_along(imag.clone(), sin.clone(), dim);
+    let output_imag = dft_along(imag, cos, dim) - dft_along(real, sin, dim);
+
+    (output_real.into_primitive(), output_imag.into_primitive())
+}
+
+/// Computes the inverse discrete Fourier transform of a complex tensor along a given dimension.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input tensor.
+/// * `imag` - The imaginary part of the input tensor.
+/// * `dim` - The axis along which to compute the transform.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, normalized by the size of the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn ifft<B: Backend, const D: usize>(
+    real: FloatTensor<B, D>,
+    imag: FloatTensor<B, D>,
+    dim: usize,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let real = Tensor::<B, D>::from_primitive(real);
+    let imag = Tensor::<B, D>::from_primitive(imag);
+    let n = real.dims()[dim];
+    let device = real.device();
+    let scale = 1.0 / n as f64;
+
+    let cos = dft_matrix::<B>([n, n], n, &device, |_, angle| libm::cos(angle) * scale);
+    let sin = dft_matrix::<B>([n, n], n, &device, |_, angle| libm::sin(angle) * scale);
+
+    let output_real =
+        dft_along(real.clone(), cos.clone(), dim) - dft_along(imag.clone(), sin.clone(), dim);
+    let output_imag = dft_along(imag, cos, dim) + dft_along(real, sin, dim);
+
+    (output_real.into_primitive(), output_imag.into_primitive())
+}
+
+/// Computes the discrete Fourier transform of a real tensor along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the transform.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the unnormalized transform. Since the transform of a real
+/// signal is Hermitian symmetric, only the `n / 2 + 1` non-negative frequencies are returned.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn rfft<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    dim: usize,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let tensor = Tensor::<B, D>::from_primitive(tensor);
+    let n = tensor.dims()[dim];
+    let device = tensor.device();
+    let size = n / 2 + 1;
+
+    let cos = dft_matrix::<B>([n, size], n, &device, |_, angle| libm::cos(angle));
+    let sin = dft_matrix::<B>([n, size], n, &device, |_, angle| -libm::sin(angle));
+
+    let output_real = dft_along(tensor.clone(), cos, dim);
+    let output_imag = dft_along(tensor, sin, dim);
+
+    (output_real.into_primitive(), output_imag.into_primitive())
+}
+
+/// Computes the inverse of [rfft](rfft) along a given dimension.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the non-negative frequencies.
+/// * `imag` - The imaginary part of the non-negative frequencies.
+/// * `dim` - The axis along which to compute the transform.
+/// * `n` - The size of the output along the given dimension, where `n / 2 + 1` must match the
+///   size of the input.
+///
+/// # Returns
+///
+/// The real signal, normalized by `n`. The imaginary parts of the zero frequency and, when `n` is
+/// even, of the Nyquist frequency are ignored.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn irfft<B: Backend, const D: usize>(
+    real: FloatTensor<B, D>,
+    imag: FloatTensor<B, D>,
+    dim: usize,
+    n: usize,
+) -> FloatTensor<B, D> {
+    let real = Tensor::<B, D>::from_primitive(real);
+    let imag = Tensor::<B, D>::from_primitive(imag);
+    let size = real.dims()[dim];
+    let device = real.device();
+
+    // Each frequency other than the zero and Nyquist ones also stands for its conjugate pair.
+    let weight = |j: usize| match j == 0 || 2 * j == n {
+        true => 1.0 / n as f64,
+        false => 2.0 / n as f64,
+    };
+    let cos = dft_matrix::<B>([size, n], n, &device, |j, angle| {
+        libm::cos(angle) * weight(j)
+    });
+    let sin = dft_matrix::<B>([size, n], n, &device, |j, angle| {
+        libm::sin(angle) * weight(j)
+    });
+
+    let output = dft_along(real, cos, dim) - dft_along(imag, sin, dim);
+
+    output.into_primitive()
+}
+
+/// Creates a `[rows, cols]` matrix where each entry `(j, k)` is computed from the row index and
+/// the angle `2 * pi * j * k / n`.
+fn dft_matrix<B: Backend>(
+    [rows, cols]: [usize; 2],
+    n: usize,
+    device: &B::Device,
+    func: impl Fn(usize, f64) -> f64,
+) -> Tensor<B, 2> {
+    let mut values = Vec::with_capacity(rows * cols);
+
+    for j in 0..rows {
+        for k in 0..cols {
+            // Reducing the product modulo `n` keeps the angle accurate for large sizes.
+            let angle = 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+            values.push(func(j, angle));
+        }
+    }
+
+    let data = Data::new(values, Shape::new([rows, cols]));
+
+    Tensor::from_data(data.convert(), device)
+}
+
+/// Multiplies the given dimension of the tensor with a `[n_in, n_out]` matrix.
+fn dft_along<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    matrix: Tensor<B, 2>,
+    dim: usize,
+) -> Tensor<B, D> {
+    let [size_in, size_out] = matrix.dims();
+    let tensor = tensor.swap_dims(dim, D - 1);
+    let mut dims = tensor.dims();
+    let batch_size = dims[..D - 1].iter().product::<usize>();
+
+    let output = tensor.reshape([batch_size, size_in]).matmul(matrix);
+    dims[D - 1] = size_out;
+
+    output.reshape(dims).swap_dims(dim, D - 1)
+}
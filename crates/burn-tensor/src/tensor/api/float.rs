@@ -5,7 +5,9 @@ use crate::check;
 use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Data, Distribution, Shape};
+use crate::tensor::{Data, Distribution, Generator, Shape};
+use crate::ComplexTensor;
+use crate::ElementConversion;
 use crate::Int;
 use crate::Tensor;
 
@@ -59,6 +61,38 @@ where
         Self::new(B::float_erf(self.primitive))
     }
 
+    /// Applies the [inverse error function](https://en.wikipedia.org/wiki/Error_function#Inverse_functions)
+    /// element wise.
+    ///
+    /// `y = erfinv(x)`
+    pub fn erfinv(self) -> Self {
+        Self::new(B::float_erfinv(self.primitive))
+    }
+
+    /// Applies the exponential minus one element wise, which is more accurate than `exp(x) - 1`
+    /// for small values.
+    ///
+    /// `y = e^x - 1`
+    pub fn expm1(self) -> Self {
+        Self::new(B::float_expm1(self.primitive))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    /// `y = log(|Γ(x)|)`
+    pub fn lgamma(self) -> Self {
+        Self::new(B::float_lgamma(self.primitive))
+    }
+
+    /// Applies the [digamma function](https://en.wikipedia.org/wiki/Digamma_function) element
+    /// wise, the derivative of [lgamma](Tensor::lgamma).
+    ///
+    /// `y = Γ'(x) / Γ(x)`
+    pub fn digamma(self) -> Self {
+        Self::new(B::float_digamma(self.primitive))
+    }
+
     /// Applies element wise reciprocal operation.
     pub fn recip(self) -> Self {
         Self::new(B::float_recip(self.primitive))
@@ -84,6 +118,72 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Applies element wise tangent operation.
+    pub fn tan(self) -> Self {
+        Self::new(B::float_tan(self.primitive))
+    }
+
+    /// Applies element wise arcsine operation.
+    pub fn asin(self) -> Self {
+        Self::new(B::float_asin(self.primitive))
+    }
+
+    /// Applies element wise arccosine operation.
+    pub fn acos(self) -> Self {
+        Self::new(B::float_acos(self.primitive))
+    }
+
+    /// Applies element wise arctangent operation.
+    pub fn atan(self) -> Self {
+        Self::new(B::float_atan(self.primitive))
+    }
+
+    /// Applies element wise four-quadrant arctangent of `self / other`, returning the angles
+    /// between `-π` and `π`.
+    ///
+    /// `y = atan2(self, other)`
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(B::float_atan2(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise hyperbolic sine operation.
+    pub fn sinh(self) -> Self {
+        Self::new(B::float_sinh(self.primitive))
+    }
+
+    /// Applies element wise hyperbolic cosine operation.
+    pub fn cosh(self) -> Self {
+        Self::new(B::float_cosh(self.primitive))
+    }
+
+    /// Rounds the elements down to the nearest integer.
+    pub fn floor(self) -> Self {
+        Self::new(B::float_floor(self.primitive))
+    }
+
+    /// Rounds the elements up to the nearest integer.
+    pub fn ceil(self) -> Self {
+        Self::new(B::float_ceil(self.primitive))
+    }
+
+    /// Rounds the elements to the nearest integer, with halfway values rounded to the nearest
+    /// even integer.
+    pub fn round(self) -> Self {
+        Self::new(B::float_round(self.primitive))
+    }
+
+    /// Rounds the elements toward zero.
+    pub fn trunc(self) -> Self {
+        Self::new(B::float_trunc(self.primitive))
+    }
+
+    /// Returns the sign of the elements: `-1` for negative values, `1` for positive values and `0`
+    /// for zero.
+    pub fn sign(self) -> Self {
+        Self::new(B::float_sign(self.primitive))
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -212,6 +312,87 @@ where
         let tensor = B::float_random(shape.into(), distribution, device);
         Self::new(tensor)
     }
+
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution with the given [generator](Generator).
+    ///
+    /// Unlike [random](Tensor::random), the values only depend on the state of the generator.
+    pub fn random_with_generator<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution,
+        generator: &mut Generator,
+        device: &B::Device,
+    ) -> Self {
+        let data = generator.sample(shape.into(), distribution);
+        Self::from_data(data, device)
+    }
+
+    /// Samples category indices from the categorical distributions given by the last dimension of
+    /// the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of indices sampled from each distribution.
+    /// * `replacement` - If a category can be sampled more than once from the same distribution.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor except for the last dimension, which holds
+    /// the `num_samples` sampled indices of each distribution.
+    ///
+    /// # Notes
+    ///
+    /// The values along the last dimension are the non-negative weights of the categories, which
+    /// don't have to sum to one. Without replacement, the number of samples must not exceed the
+    /// number of categories with a non-zero weight.
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::multinomial::<D>(
+            &self.shape(),
+            num_samples,
+            replacement
+        ));
+
+        let device = self.device();
+        let mut shape = self.shape();
+        let num_categories = shape.dims[D - 1];
+        let batch_size = shape.num_elements() / num_categories;
+        shape.dims[D - 1] = num_samples;
+
+        let weights = self.reshape([batch_size, num_categories]);
+
+        let indices = if replacement {
+            let cumulative = weights.cumsum(1);
+            let total = cumulative.clone().narrow(1, num_categories - 1, 1);
+            let thresholds =
+                Tensor::<B, 2>::random([batch_size, num_samples], Distribution::Default, &device)
+                    .mul(total);
+
+            // The sampled index is the number of categories whose cumulative weight is below the
+            // threshold, which is uniformly distributed between zero and the total weight.
+            let cumulative = cumulative
+                .reshape([batch_size, 1, num_categories])
+                .repeat(1, num_samples);
+            let thresholds = thresholds
+                .reshape([batch_size, num_samples, 1])
+                .repeat(2, num_categories);
+
+            cumulative
+                .lower_equal(thresholds)
+                .int()
+                .sum_dim(2)
+                .clamp_max(num_categories as i64 - 1)
+                .reshape([batch_size, num_samples])
+        } else {
+            // The indices of the largest log weights perturbed by Gumbel noise are sampled without
+            // replacement.
+            let noise = weights.random_like(Distribution::Default);
+            let keys = weights.log() - noise.log().neg().log();
+
+            keys.topk_with_indices(num_samples, 1).1
+        };
+
+        indices.reshape(shape)
+    }
     /// Returns a tensor with full precision based on the selected backend.
     pub fn to_full_precision(&self) -> Tensor<B::FullPrecisionBackend, D> {
         Tensor::new(B::float_to_full_precision(&self.primitive))
@@ -271,4 +452,64 @@ where
             .matmul(centered)
             .div_scalar(n as f32 - correction_factor as f32)
     }
+
+    /// Computes the discrete Fourier transform of the real tensor along the given dimension.
+    ///
+    /// Since the transform of a real signal is Hermitian symmetric, only the `n / 2 + 1`
+    /// non-negative frequencies are returned. Use [irfft](ComplexTensor::irfft) to recover the
+    /// original signal.
+    pub fn rfft(self, dim: usize) -> ComplexTensor<B, D> {
+        check!(TensorCheck::dim_ops::<D>("Rfft", dim));
+        let (real, imag) = B::float_rfft(self.primitive, dim);
+        ComplexTensor::new(Tensor::new(real), Tensor::new(imag))
+    }
+}
+
+impl<B> Tensor<B, 1>
+where
+    B: Backend,
+{
+    /// Computes the histogram of the tensor with bins of equal width between `min` and `max`.
+    ///
+    /// The elements outside of the range are ignored, and the last bin includes `max`. When `min`
+    /// and `max` are equal, the range of the elements is used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower end of the first bin.
+    /// * `max` - The upper end of the last bin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 1.0, 4.0], &device);
+    ///     let histogram = tensor.histc(4, 0.0, 4.0);
+    ///     println!("{histogram}");
+    ///     // [0.0, 2.0, 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Self {
+        check!(TensorCheck::histc(bins, min, max));
+
+        let (min, max) = match min == max && self.dims()[0] > 0 {
+            true => (
+                self.clone().min().into_scalar().elem::<f64>(),
+                self.clone().max().into_scalar().elem::<f64>(),
+            ),
+            false => (min, max),
+        };
+        // A single value is counted in the middle of a range of width two, like PyTorch.
+        let (min, max) = match min == max {
+            true => (min - 1.0, max + 1.0),
+            false => (min, max),
+        };
+
+        Self::new(B::float_histc(self.primitive, bins, min.elem(), max.elem()))
+    }
 }
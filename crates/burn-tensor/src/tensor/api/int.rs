@@ -1,4 +1,6 @@
-use crate::{backend::Backend, Data, Float, Int, Tensor};
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Data, Distribution, ElementConversion, Float, Int, Tensor};
 use core::ops::Range;
 
 impl<B> Tensor<B, 1, Int>
@@ -24,6 +26,45 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Returns a random permutation of the integers from `0` to `n - 1` on the specified device.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of integers to permute.
+    /// * `device` - The device to create the tensor on.
+    pub fn randperm(n: usize, device: &B::Device) -> Self {
+        Tensor::<B, 1>::random([n], Distribution::Default, device).argsort(0)
+    }
+
+    /// Counts the occurrences of each value of the tensor, which must be non-negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_length` - The minimum number of counts returned.
+    ///
+    /// # Returns
+    ///
+    /// A tensor whose element at index `i` is the number of occurrences of `i`, with a length of one
+    /// more than the largest value, or `min_length` if it is larger.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 1, 0], &device);
+    ///     let counts = tensor.bincount(0);
+    ///     println!("{counts}");
+    ///     // [1, 2, 0, 1]
+    /// }
+    /// ```
+    pub fn bincount(self, min_length: usize) -> Self {
+        Tensor::new(B::int_bincount(self.primitive, min_length))
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -66,4 +107,127 @@ where
     pub fn float(self) -> Tensor<B, D, Float> {
         Tensor::new(B::int_into_float(self.primitive))
     }
+
+    /// Applies the matrix multiplication operation.
+    ///
+    /// `C = AB`
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have a compatible shape.
+    pub fn matmul(self, other: Self) -> Self {
+        check!(TensorCheck::matmul(&self, &other));
+        Self::new(B::int_matmul(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise and operation.
+    ///
+    /// `y = x1 & x2`
+    pub fn bitwise_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseAnd", &self, &other));
+        Self::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise and operation with a scalar.
+    ///
+    /// `y = x & s`
+    pub fn bitwise_and_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_and_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise or operation.
+    ///
+    /// `y = x1 | x2`
+    pub fn bitwise_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseOr", &self, &other));
+        Self::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise or operation with a scalar.
+    ///
+    /// `y = x | s`
+    pub fn bitwise_or_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_or_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise exclusive or operation.
+    ///
+    /// `y = x1 ^ x2`
+    pub fn bitwise_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseXor", &self, &other));
+        Self::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise exclusive or operation with a scalar.
+    ///
+    /// `y = x ^ s`
+    pub fn bitwise_xor_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_xor_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise not operation, inverting every bit.
+    ///
+    /// `y = !x`
+    pub fn bitwise_not(self) -> Self {
+        Self::new(B::int_bitwise_not(self.primitive))
+    }
+
+    /// Shifts the bits of each element to the left by the corresponding element of the other
+    /// tensor.
+    ///
+    /// `y = x1 << x2`
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    pub fn bitwise_left_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseLeftShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of each element to the left by a scalar.
+    ///
+    /// `y = x << s`
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    pub fn bitwise_left_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_left_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
+
+    /// Shifts the bits of each element to the right by the corresponding element of the other
+    /// tensor, keeping the sign of the elements.
+    ///
+    /// `y = x1 >> x2`
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    pub fn bitwise_right_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseRightShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of each element to the right by a scalar, keeping the sign of the
+    /// elements.
+    ///
+    /// `y = x >> s`
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    pub fn bitwise_right_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_right_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::backend::Backend;
+use crate::{backend::Backend, QuantizedPrimitive, Shape, SparsePrimitive};
 
 /// A type-level representation of the kind of a float tensor
 #[derive(Clone, Debug)]
@@ -12,13 +12,66 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a sparse float tensor.
+#[derive(Clone, Debug)]
+pub struct Sparse;
+
+/// A type-level representation of the kind of a quantized tensor.
+#[derive(Clone, Debug)]
+pub struct Quantized;
+
 /// A type-level representation of the kind of a tensor.
+///
+/// The shape and the device are retrieved through this trait rather than through
+/// [BasicOps](crate::BasicOps), since kinds like [Sparse] and [Quantized] don't support the basic
+/// operations. Custom kinds implement [shape](TensorKind::shape) and [device](TensorKind::device)
+/// here, and their [BasicOps](crate::BasicOps) implementation no longer defines them.
 pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     /// The primitive type of the tensor.
     type Primitive<const D: usize>: Clone + core::fmt::Debug + Sync + Send;
 
     /// The name of the tensor kind.
     fn name() -> &'static str;
+
+    /// Returns the shape of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The shape of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the shape of a tensor, users should prefer the [Tensor::shape](crate::Tensor::shape) function,
+    /// which is more high-level and designed for public use.
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D>;
+
+    /// Returns the device on which the tensor is allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The device on which the tensor is allocated.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the device of a tensor, users should prefer the [Tensor::device](crate::Tensor::device) function,
+    /// which is more high-level and designed for public use.
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device;
 }
 
 impl<B: Backend> TensorKind<B> for Float {
@@ -26,6 +79,14 @@ impl<B: Backend> TensorKind<B> for Float {
     fn name() -> &'static str {
         "Float"
     }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::float_shape(tensor)
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        B::float_device(tensor)
+    }
 }
 
 impl<B: Backend> TensorKind<B> for Int {
@@ -33,6 +94,14 @@ impl<B: Backend> TensorKind<B> for Int {
     fn name() -> &'static str {
         "Int"
     }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::int_shape(tensor)
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        B::int_device(tensor)
+    }
 }
 
 impl<B: Backend> TensorKind<B> for Bool {
@@ -40,4 +109,48 @@ impl<B: Backend> TensorKind<B> for Bool {
     fn name() -> &'static str {
         "Bool"
     }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        B::bool_device(tensor)
+    }
+}
+
+impl<B: Backend> TensorKind<B> for Sparse {
+    type Primitive<const D: usize> = SparsePrimitive<B, D>;
+    fn name() -> &'static str {
+        "Sparse"
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        match tensor {
+            SparsePrimitive::Coo { shape, .. } => shape.clone(),
+            SparsePrimitive::Csr { shape, .. } => shape.clone(),
+        }
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        match tensor {
+            SparsePrimitive::Coo { values, .. } => values.device(),
+            SparsePrimitive::Csr { values, .. } => values.device(),
+        }
+    }
+}
+
+impl<B: Backend> TensorKind<B> for Quantized {
+    type Primitive<const D: usize> = QuantizedPrimitive<B, D>;
+    fn name() -> &'static str {
+        "Quantized"
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        tensor.values.shape()
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> B::Device {
+        tensor.values.device()
+    }
 }
@@ -3,18 +3,40 @@ pub(crate) mod check;
 mod argwhere;
 mod autodiff;
 mod base;
+mod bitwise;
 mod bool;
 mod chunk;
+mod complex;
+mod counting;
+mod cumulative;
+mod einsum;
+mod fft;
 mod float;
 mod int;
 mod kind;
 mod narrow;
 mod numeric;
+mod pad;
+mod quantized;
+mod sort;
+mod sparse;
+mod special;
 
 pub use argwhere::argwhere;
 pub use autodiff::*;
 pub use base::*;
+pub use bitwise::{bitwise_binary, bitwise_unary};
 pub use chunk::chunk;
+pub use complex::ComplexTensor;
+pub use counting::{bincount, histc, searchsorted, unique};
+pub use cumulative::{cummax, cummax_with_indices, cummin, cummin_with_indices, cumprod, cumsum};
+pub use einsum::{einsum, EinsumOperand};
+pub use fft::{fft, ifft, irfft, rfft};
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use pad::{pad, pad_indices, PadMode};
+pub use quantized::{QuantizationScheme, QuantizedPrimitive};
+pub use sort::{argsort, sort, sort_with_indices};
+pub use sparse::{SparseLayout, SparsePrimitive};
+pub use special::{atan2, digamma, erfinv, float_map, lgamma, trigamma};
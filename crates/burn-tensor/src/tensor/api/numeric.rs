@@ -1,6 +1,6 @@
 use crate::{
     backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    Int, PadMode, Shape, Tensor, TensorKind,
 };
 use num_traits::Zero;
 
@@ -414,6 +414,166 @@ where
         self.mask_where(mask, other)
     }
 
+    /// Sort the elements by value in ascending order along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    pub fn sort(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::sort_dim::<D>("Sort", dim));
+        Tensor::new(K::sort(self.primitive, dim, /*descending*/ false))
+    }
+
+    /// Sort the elements by value in descending order along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    pub fn sort_descending(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::sort_dim::<D>("Sort", dim));
+        Tensor::new(K::sort(self.primitive, dim, /*descending*/ true))
+    }
+
+    /// Sort the elements by value in ascending order along a given dimension.
+    /// Also returns the indices.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    pub fn sort_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
+        let (values, indices) =
+            K::sort_with_indices(self.primitive, dim, /*descending*/ false);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Sort the elements by value in descending order along a given dimension.
+    /// Also returns the indices.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    pub fn sort_descending_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
+        let (values, indices) = K::sort_with_indices(self.primitive, dim, /*descending*/ true);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Returns the indices that sort the elements by value in ascending order along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::sort_dim::<D>("Argsort", dim));
+        Tensor::new(K::argsort(self.primitive, dim, /*descending*/ false))
+    }
+
+    /// Returns the indices that sort the elements by value in descending order along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    pub fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::sort_dim::<D>("Argsort", dim));
+        Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
+    }
+
+    /// Returns the `k` largest elements of the given input tensor along a given dimension.
+    ///
+    /// The elements are returned in descending order.
+    pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::topk::<D>(k, dim, &self.shape()));
+        self.sort_descending(dim).narrow(dim, 0, k)
+    }
+
+    /// Returns the `k` largest elements of the given input tensor along a given dimension.
+    /// Also returns the indices.
+    ///
+    /// The elements are returned in descending order.
+    pub fn topk_with_indices(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(k, dim, &self.shape()));
+        let (values, indices) = self.sort_descending_with_indices(dim);
+        (values.narrow(dim, 0, k), indices.narrow(dim, 0, k))
+    }
+
+    /// Computes the cumulative sum of the elements along a given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.cumsum(1);
+    ///     println!("{tensor}");
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along a given dimension.
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along a given dimension.
+    pub fn cummax(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+        Self::new(K::cummax(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along a given dimension.
+    /// Also returns the indices of the running maximum.
+    ///
+    /// When the maximum value occurs more than once, the index of the last occurrence is returned.
+    pub fn cummax_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+        let (values, indices) = K::cummax_with_indices(self.primitive, dim);
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Computes the cumulative minimum of the elements along a given dimension.
+    pub fn cummin(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMin", dim));
+        Self::new(K::cummin(self.primitive, dim))
+    }
+
+    /// Computes the cumulative minimum of the elements along a given dimension.
+    /// Also returns the indices of the running minimum.
+    ///
+    /// When the minimum value occurs more than once, the index of the last occurrence is returned.
+    pub fn cummin_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("CumMin", dim));
+        let (values, indices) = K::cummin_with_indices(self.primitive, dim);
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Pads each dimension of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `pads` - The number of values added before and after each dimension.
+    /// * `mode` - How the added values are computed.
+    ///
+    /// # Panics
+    ///
+    /// If a padded dimension is empty and the mode isn't [constant](PadMode::Constant).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect);
+    ///     println!("{tensor}");
+    ///     // [[3.0, 2.0, 1.0, 2.0, 3.0, 2.0], [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]]
+    /// }
+    /// ```
+    pub fn pad(self, pads: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad::<D>(&self.shape(), &pads, mode));
+        Self::new(K::pad(self.primitive, pads, mode))
+    }
+
     /// Clamp the tensor between the given min and max values.
     ///
     /// # Arguments
@@ -671,6 +831,86 @@ where
     }
 }
 
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Returns the unique elements of the tensor, sorted in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 3, 2, 1], &device);
+    ///     let tensor = tensor.unique();
+    ///     println!("{tensor}");
+    ///     // [1, 2, 3]
+    /// }
+    /// ```
+    pub fn unique(self) -> Self {
+        let (values, _, _) = K::unique(self.primitive);
+        Self::new(values)
+    }
+
+    /// Returns the unique elements of the tensor, sorted in ascending order.
+    /// Also returns the number of occurrences of each unique element.
+    pub fn unique_with_counts(self) -> (Self, Tensor<B, 1, Int>) {
+        let (values, _, counts) = K::unique(self.primitive);
+        (Self::new(values), Tensor::new(counts))
+    }
+
+    /// Returns the unique elements of the tensor, sorted in ascending order.
+    /// Also returns the index of each element of the tensor in the unique elements, and the number
+    /// of occurrences of each unique element.
+    ///
+    /// Selecting the unique elements with the inverse indices gives back the original tensor.
+    pub fn unique_with_inverse_and_counts(self) -> (Self, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        let (values, inverse, counts) = K::unique(self.primitive);
+        (Self::new(values), Tensor::new(inverse), Tensor::new(counts))
+    }
+
+    /// Finds the indices where the values would be inserted in this tensor, sorted in ascending
+    /// order, to keep it sorted.
+    ///
+    /// When a value is equal to some elements, the index of the first of them is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let sorted = Tensor::<B, 1>::from_floats([1.0, 3.0, 5.0, 7.0], &device);
+    ///     let values = Tensor::<B, 2>::from_floats([[3.0, 6.0], [0.0, 9.0]], &device);
+    ///     let indices = sorted.searchsorted(values);
+    ///     println!("{indices}");
+    ///     // [[1, 3], [0, 4]]
+    /// }
+    /// ```
+    pub fn searchsorted<const D2: usize>(self, values: Tensor<B, D2, K>) -> Tensor<B, D2, Int> {
+        Tensor::new(K::searchsorted(self.primitive, values.primitive, false))
+    }
+
+    /// Finds the indices where the values would be inserted in this tensor, sorted in ascending
+    /// order, to keep it sorted.
+    ///
+    /// When a value is equal to some elements, the index after the last of them is returned.
+    pub fn searchsorted_right<const D2: usize>(
+        self,
+        values: Tensor<B, D2, K>,
+    ) -> Tensor<B, D2, Int> {
+        Tensor::new(K::searchsorted(self.primitive, values.primitive, true))
+    }
+}
+
 /// Trait that list all operations that can be applied on all numerical tensors.
 ///
 /// # Warnings
@@ -1753,6 +1993,298 @@ where
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D>;
+
+    /// Sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements are sorted by value.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::sort](Tensor::sort) function,
+    /// which is more high-level and designed for public use.
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D>;
+
+    /// Sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and corresponding indices, where
+    /// the elements are sorted by value and the indices map back to the original input tensor.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For sorting the elements of a tensor, users should prefer the
+    /// [Tensor::sort_with_indices](Tensor::sort_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
+
+    /// Returns the indices that sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::argsort](Tensor::argsort) function,
+    /// which is more high-level and designed for public use.
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D>;
+
+    /// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cumsum](Tensor::cumsum) function,
+    /// which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all the
+    /// preceding elements (inclusive) along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cumprod](Tensor::cumprod) function,
+    /// which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummax](Tensor::cummax) function,
+    /// which is more high-level and designed for public use.
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running maximum along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummax_with_indices](Tensor::cummax_with_indices) function,
+    /// which is more high-level and designed for public use.
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummin](Tensor::cummin) function,
+    /// which is more high-level and designed for public use.
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running minimum along the given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummin_with_indices](Tensor::cummin_with_indices) function,
+    /// which is more high-level and designed for public use.
+    fn cummin_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
+
+    /// Pads each dimension of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `pads` - The number of values added before and after each dimension.
+    /// * `mode` - How the added values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor, where each dimension grows by the sum of its padding.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::pad](Tensor::pad) function,
+    /// which is more high-level and designed for public use.
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D>;
+
+    /// Finds the unique elements of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// The unique elements sorted in ascending order, the index of each element of the input tensor
+    /// in the unique elements, and the number of occurrences of each unique element.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::unique](Tensor::unique) function,
+    /// which is more high-level and designed for public use.
+    fn unique(
+        tensor: Self::Primitive<1>,
+    ) -> (
+        Self::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+    );
+
+    /// Finds the indices where the `values` would be inserted in the `sorted` tensor to keep it sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The tensor sorted in ascending order.
+    /// * `values` - The values to insert.
+    /// * `right` - Whether the index after the elements equal to a value is returned, instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the values, containing the insertion index of each value.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::searchsorted](Tensor::searchsorted) function,
+    /// which is more high-level and designed for public use.
+    fn searchsorted<const D: usize>(
+        sorted: Self::Primitive<1>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -2041,6 +2573,86 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_powf_scalar(lhs, rhs.elem())
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
+
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cummin(tensor, dim)
+    }
+
+    fn cummin_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::int_cummin_with_indices(tensor, dim)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::int_pad(tensor, pads, mode)
+    }
+
+    fn unique(
+        tensor: Self::Primitive<1>,
+    ) -> (
+        Self::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+    ) {
+        B::int_unique(tensor)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted: Self::Primitive<1>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::int_searchsorted(sorted, values, right)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2330,6 +2942,86 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Self::Primitive<D> {
         B::float_powf_scalar(lhs, rhs.elem())
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::float_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::float_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::float_argsort(tensor, dim, descending)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::float_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::float_cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::float_cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::float_cummax_with_indices(tensor, dim)
+    }
+
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::float_cummin(tensor, dim)
+    }
+
+    fn cummin_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::float_cummin_with_indices(tensor, dim)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::float_pad(tensor, pads, mode)
+    }
+
+    fn unique(
+        tensor: Self::Primitive<1>,
+    ) -> (
+        Self::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+        <Int as TensorKind<B>>::Primitive<1>,
+    ) {
+        B::float_unique(tensor)
+    }
+
+    fn searchsorted<const D: usize>(
+        sorted: Self::Primitive<1>,
+        values: Self::Primitive<D>,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive<D> {
+        B::float_searchsorted(sorted, values, right)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
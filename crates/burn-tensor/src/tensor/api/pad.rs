@@ -0,0 +1,134 @@
+use crate::{backend::Backend, BasicOps, Data, Element, Int, Numeric, Shape, Tensor, TensorKind};
+use alloc::vec::Vec;
+
+/// How the values added by [pad](Tensor::pad) are computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// Pads with the given value.
+    Constant(f32),
+
+    /// Pads with the reflection of the tensor on its border, without repeating the border.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides is `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+
+    /// Pads by repeating the border of the tensor.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides is `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+
+    /// Pads by wrapping the tensor around, as if it were periodic.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides is `[2, 3, 1, 2, 3, 1, 2]`.
+    Circular,
+}
+
+impl Default for PadMode {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+/// Pads each dimension of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `pads` - The number of values added before and after each dimension.
+/// * `mode` - How the added values are computed.
+///
+/// # Returns
+///
+/// The padded tensor, where each dimension grows by the sum of its padding.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn pad<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    pads: [(usize, usize); D],
+    mode: PadMode,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let mut tensor = tensor;
+
+    for (dim, (before, after)) in pads.into_iter().enumerate() {
+        if before == 0 && after == 0 {
+            continue;
+        }
+
+        let device = K::device(&tensor);
+        let shape = K::shape(&tensor);
+
+        tensor = match mode {
+            PadMode::Constant(value) => {
+                let mut tensors = Vec::with_capacity(3);
+                let mut padding = shape.clone();
+
+                if before > 0 {
+                    padding.dims[dim] = before;
+                    tensors.push(K::full(padding.clone(), value, &device));
+                }
+                tensors.push(tensor);
+                if after > 0 {
+                    padding.dims[dim] = after;
+                    tensors.push(K::full(padding, value, &device));
+                }
+
+                K::cat(tensors, dim)
+            }
+            _ => {
+                let indices = pad_indices(shape.dims[dim], before, after, mode);
+                let indices = Data::new(indices, Shape::new([shape.dims[dim] + before + after]));
+
+                K::select(
+                    tensor,
+                    dim,
+                    Tensor::<B, 1, Int>::from_data(indices.convert(), &device),
+                )
+            }
+        };
+    }
+
+    tensor
+}
+
+/// Returns, for each position of a dimension of `size` elements padded with `before` and `after`
+/// values, the index of the element it is copied from.
+///
+/// # Panics
+///
+/// If the mode is [constant](PadMode::Constant), which doesn't copy elements.
+pub fn pad_indices(size: usize, before: usize, after: usize, mode: PadMode) -> Vec<i64> {
+    let size = size as i64;
+    let start = -(before as i64);
+    let end = size + after as i64;
+
+    (start..end)
+        .map(|index| match mode {
+            PadMode::Reflect => {
+                // Reflecting twice is the identity, so the reflections are periodic.
+                let period = 2 * (size - 1);
+                let index = if period == 0 {
+                    0
+                } else {
+                    index.rem_euclid(period)
+                };
+
+                if index < size {
+                    index
+                } else {
+                    period - index
+                }
+            }
+            PadMode::Replicate => index.clamp(0, size - 1),
+            PadMode::Circular => index.rem_euclid(size),
+            PadMode::Constant(_) => panic!("Constant padding doesn't copy elements"),
+        })
+        .collect()
+}
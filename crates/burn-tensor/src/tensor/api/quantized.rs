@@ -0,0 +1,260 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Int, Quantized, Tensor};
+
+/// The smallest quantized value.
+const QUANTIZED_MIN: i64 = -128;
+
+/// The largest quantized value.
+const QUANTIZED_MAX: i64 = 127;
+
+/// How the scales and zero points of a [quantized](Quantized) tensor apply to its values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum QuantizationScheme {
+    /// A single scale and zero point for the whole tensor.
+    PerTensor,
+
+    /// One scale and zero point for each index of the given dimension, usually the output channels
+    /// of a weight.
+    PerChannel(usize),
+}
+
+impl QuantizationScheme {
+    /// The shape of the scales or zero points reshaped to broadcast over the quantized values.
+    fn broadcast_shape<const D: usize>(&self, num_parameters: usize) -> [usize; D] {
+        let mut shape = [1; D];
+
+        if let Self::PerChannel(dim) = self {
+            shape[*dim] = num_parameters;
+        }
+
+        shape
+    }
+}
+
+/// The primitive of a [quantized](Quantized) tensor.
+///
+/// The values are signed 8-bit integers stored in an int tensor, while the real values they
+/// represent are `(values - zero_point) * scale`.
+#[derive(Clone, Debug)]
+pub struct QuantizedPrimitive<B: Backend, const D: usize> {
+    /// The quantized values, between -128 and 127.
+    pub values: Tensor<B, D, Int>,
+    /// The scales, with one element for the whole tensor or one element per channel.
+    pub scale: Tensor<B, 1>,
+    /// The zero points, between -128 and 127, with one element for the whole tensor or one element
+    /// per channel.
+    pub zero_point: Tensor<B, 1, Int>,
+    /// How the scales and zero points apply to the values.
+    pub scheme: QuantizationScheme,
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Quantizes the tensor to signed 8-bit integers with the given scales and zero points.
+    ///
+    /// `q = clamp(round(x / scale) + zero_point, -128, 127)`, rounding half to even.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scales, with one element for the whole tensor or one element per channel.
+    /// * `zero_point` - The zero points, with the same shape as the scales.
+    /// * `scheme` - How the scales and zero points apply to the values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, QuantizationScheme, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([-1.0, 0.0, 0.5, 2.0], &device);
+    ///     let scale = Tensor::<B, 1>::from_floats([0.1], &device);
+    ///     let zero_point = Tensor::<B, 1, Int>::from_ints([-10], &device);
+    ///     let quantized = tensor.quantize(scale, zero_point, QuantizationScheme::PerTensor);
+    ///     println!("{}", quantized.values());
+    ///     // [-20, -10, -5, 10]
+    ///     println!("{}", quantized.dequantize());
+    ///     // [-1.0, 0.0, 0.5, 2.0]
+    /// }
+    /// ```
+    pub fn quantize(
+        self,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1, Int>,
+        scheme: QuantizationScheme,
+    ) -> Tensor<B, D, Quantized> {
+        check!(TensorCheck::quantization::<D>(
+            &self.shape(),
+            &scale.shape(),
+            &zero_point.shape(),
+            scheme
+        ));
+
+        let shape = scheme.broadcast_shape::<D>(scale.dims()[0]);
+        let values =
+            self.div(scale.clone().reshape(shape)) + zero_point.clone().reshape(shape).float();
+
+        Tensor::new(QuantizedPrimitive {
+            values: round_to_quantized(values),
+            scale,
+            zero_point,
+            scheme,
+        })
+    }
+
+    /// Quantizes the tensor to signed 8-bit integers with scales and zero points computed from its
+    /// values, so that the range of the values extended to include zero maps to `[-128, 127]`.
+    pub fn quantize_dynamic(self, scheme: QuantizationScheme) -> Tensor<B, D, Quantized> {
+        check!(TensorCheck::quantization_scheme::<D>(scheme));
+
+        let (min, max) = match scheme {
+            QuantizationScheme::PerTensor => (self.clone().min(), self.clone().max()),
+            QuantizationScheme::PerChannel(dim) => {
+                let num_channels = self.dims()[dim];
+                let channels = self
+                    .clone()
+                    .swap_dims(0, dim)
+                    .reshape([num_channels, self.shape().num_elements() / num_channels]);
+
+                (
+                    channels.clone().min_dim(1).reshape([num_channels]),
+                    channels.max_dim(1).reshape([num_channels]),
+                )
+            }
+        };
+
+        let min = min.clamp_max(0.0);
+        let max = max.clamp_min(0.0);
+
+        let scale = (max - min.clone()).div_scalar((QUANTIZED_MAX - QUANTIZED_MIN) as f64);
+        // A channel only holding zeros can use any scale.
+        let scale = scale.clone().mask_fill(scale.equal_elem(0.0), 1.0);
+        let zero_point = round_to_quantized(
+            min.div(scale.clone())
+                .neg()
+                .add_scalar(QUANTIZED_MIN as f64),
+        );
+
+        self.quantize(scale, zero_point, scheme)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Quantized> {
+    /// Creates a quantized tensor from its quantized values, scales and zero points.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The quantized values, between -128 and 127.
+    /// * `scale` - The scales, with one element for the whole tensor or one element per channel.
+    /// * `zero_point` - The zero points, with the same shape as the scales.
+    /// * `scheme` - How the scales and zero points apply to the values.
+    pub fn from_parts(
+        values: Tensor<B, D, Int>,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1, Int>,
+        scheme: QuantizationScheme,
+    ) -> Self {
+        check!(TensorCheck::quantization::<D>(
+            &values.shape(),
+            &scale.shape(),
+            &zero_point.shape(),
+            scheme
+        ));
+
+        Self::new(QuantizedPrimitive {
+            values,
+            scale,
+            zero_point,
+            scheme,
+        })
+    }
+
+    /// Returns the quantized values.
+    pub fn values(&self) -> Tensor<B, D, Int> {
+        self.primitive.values.clone()
+    }
+
+    /// Returns the scales.
+    pub fn scale(&self) -> Tensor<B, 1> {
+        self.primitive.scale.clone()
+    }
+
+    /// Returns the zero points.
+    pub fn zero_point(&self) -> Tensor<B, 1, Int> {
+        self.primitive.zero_point.clone()
+    }
+
+    /// Returns how the scales and zero points apply to the values.
+    pub fn scheme(&self) -> QuantizationScheme {
+        self.primitive.scheme
+    }
+
+    /// Converts the quantized tensor to the float tensor of the real values it represents.
+    ///
+    /// `x = (q - zero_point) * scale`
+    pub fn dequantize(self) -> Tensor<B, D> {
+        let (values, zero_point, scale) = self.broadcast_parts();
+
+        (values - zero_point).float().mul(scale)
+    }
+
+    /// Performs the matrix multiplication of two quantized tensors, accumulating the products of
+    /// the quantized values in integers, and returns the real values of the result.
+    ///
+    /// The left tensor can be quantized per tensor or per row (dimension `D - 2`), while the right
+    /// tensor can be quantized per tensor or per column (dimension `D - 1`).
+    pub fn matmul(self, other: Self) -> Tensor<B, D> {
+        check!(TensorCheck::quantized_matmul::<D>(
+            &self.shape(),
+            self.scheme(),
+            &other.shape(),
+            other.scheme()
+        ));
+
+        let size = self.dims()[D - 1];
+        let (lhs, lhs_zero_point, lhs_scale) = self.broadcast_parts();
+        let (rhs, rhs_zero_point, rhs_scale) = other.broadcast_parts();
+
+        // The values are multiplied without being shifted by their zero points, so that they stay
+        // in the range of `i8`, using (l - a)(r - b) = lr - a sum(r) - sum(l) b + size a b.
+        let product =
+            Tensor::<B, D, Int>::new(B::int8_matmul(lhs.primitive.clone(), rhs.primitive.clone()));
+        let product = product
+            - lhs_zero_point.clone().mul(rhs.sum_dim(D - 2))
+            - lhs.sum_dim(D - 1).mul(rhs_zero_point.clone())
+            + lhs_zero_point.mul(rhs_zero_point).mul_scalar(size as i64);
+
+        product.float().mul(lhs_scale).mul(rhs_scale)
+    }
+
+    /// Returns the quantized values, and the zero points and the scales reshaped to broadcast
+    /// over them.
+    pub(crate) fn broadcast_parts(self) -> (Tensor<B, D, Int>, Tensor<B, D, Int>, Tensor<B, D>) {
+        let QuantizedPrimitive {
+            values,
+            scale,
+            zero_point,
+            scheme,
+        } = self.primitive;
+        let shape = scheme.broadcast_shape::<D>(scale.dims()[0]);
+
+        (values, zero_point.reshape(shape), scale.reshape(shape))
+    }
+}
+
+/// Clamps the values to the quantized range and rounds them half to even.
+fn round_to_quantized<B: Backend, const D: usize>(values: Tensor<B, D>) -> Tensor<B, D, Int> {
+    // Shifting the values to be positive makes the truncation to integers a floor.
+    let shifted = values
+        .clamp(QUANTIZED_MIN as f64, QUANTIZED_MAX as f64)
+        .add_scalar(0.5 - QUANTIZED_MIN as f64);
+    let rounded = shifted.clone().int();
+
+    // The values halfway between two integers are rounded up by the floor, and must be rounded
+    // down instead when the result is odd.
+    let halfway = rounded.clone().float().equal(shifted).int();
+    let odd = rounded.clone() - rounded.clone().div_scalar(2).mul_scalar(2);
+
+    rounded.sub(halfway.mul(odd)).add_scalar(QUANTIZED_MIN)
+}
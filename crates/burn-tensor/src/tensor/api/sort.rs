@@ -0,0 +1,218 @@
+use core::cmp::Ordering;
+
+use crate::{
+    backend::Backend, ops::IntTensor, BasicOps, Data, Device, Element, ElementComparison,
+    ElementConversion, TensorKind,
+};
+use alloc::vec::Vec;
+
+const READ_SYNC_ERROR: &str = "Failed to synchronously read tensor data. \
+    This operation is not supported until this backend has a native sorting implementation.";
+
+/// Sort the elements of the input `tensor` by value along a given dimension.
+///
+/// This sort is unstable (i.e., may reorder equal elements).
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to sort.
+/// * `descending` - The sorting order.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where the elements are sorted by value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn sort<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    descending: bool,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor).read_sync().expect(READ_SYNC_ERROR);
+
+    sort_data::<B, D, K>(data, dim, &device, descending)
+}
+
+/// Sort the elements of the input `tensor` by value along a given dimension.
+///
+/// This sort is stable (i.e., does not reorder equal elements).
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to sort.
+/// * `descending` - The sorting order.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor and corresponding indices, where
+/// the elements are sorted by value and the indices map back to the original input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn sort_with_indices<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    descending: bool,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor).read_sync().expect(READ_SYNC_ERROR);
+
+    sort_data_with_indices::<B, D, K>(data, dim, &device, descending)
+}
+
+/// Returns the indices that sort the elements of the input `tensor` along a given dimension.
+///
+/// This sort is stable (i.e., does not reorder equal elements).
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to sort.
+/// * `descending` - The sorting order.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn argsort<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    descending: bool,
+) -> IntTensor<B, D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor).read_sync().expect(READ_SYNC_ERROR);
+
+    argsort_data::<B, D>(data, dim, &device, descending)
+}
+
+fn sort_data<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    mut data: Data<<K as BasicOps<B>>::Elem, D>,
+    dim: usize,
+    device: &Device<B>,
+    descending: bool,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let dims = data.shape.dims;
+
+    for_each_lane(&dims, dim, |lane| {
+        let mut values = lane
+            .iter()
+            .map(|&index| data.value[index])
+            .collect::<Vec<_>>();
+        values.sort_unstable_by(|a, b| compare(a, b, descending));
+
+        for (&index, value) in lane.iter().zip(values) {
+            data.value[index] = value;
+        }
+    });
+
+    K::from_data(data, device)
+}
+
+fn sort_data_with_indices<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    mut data: Data<<K as BasicOps<B>>::Elem, D>,
+    dim: usize,
+    device: &Device<B>,
+    descending: bool,
+) -> (K::Primitive<D>, IntTensor<B, D>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let dims = data.shape.dims;
+    let mut indices_data = Data::<B::IntElem, D>::zeros(data.shape.clone());
+
+    for_each_lane(&dims, dim, |lane| {
+        let mut pairs = lane
+            .iter()
+            .enumerate()
+            .map(|(position, &index)| (position, data.value[index]))
+            .collect::<Vec<_>>();
+        pairs.sort_by(|(_, a), (_, b)| compare(a, b, descending));
+
+        for (&index, (position, value)) in lane.iter().zip(pairs) {
+            data.value[index] = value;
+            indices_data.value[index] = (position as i64).elem();
+        }
+    });
+
+    (
+        K::from_data(data, device),
+        B::int_from_data(indices_data, device),
+    )
+}
+
+fn argsort_data<B: Backend, const D: usize>(
+    data: Data<impl Element, D>,
+    dim: usize,
+    device: &Device<B>,
+    descending: bool,
+) -> IntTensor<B, D> {
+    let dims = data.shape.dims;
+    let mut indices_data = Data::<B::IntElem, D>::zeros(data.shape.clone());
+
+    for_each_lane(&dims, dim, |lane| {
+        let mut positions = (0..lane.len()).collect::<Vec<_>>();
+        positions.sort_by(|&a, &b| compare(&data.value[lane[a]], &data.value[lane[b]], descending));
+
+        for (&index, position) in lane.iter().zip(positions) {
+            indices_data.value[index] = (position as i64).elem();
+        }
+    });
+
+    B::int_from_data(indices_data, device)
+}
+
+/// Calls `func` with the flat (row-major) indices of every lane along `dim`.
+fn for_each_lane<const D: usize, F: FnMut(&[usize])>(dims: &[usize; D], dim: usize, mut func: F) {
+    let dim_size = dims[dim];
+    let stride = dims[dim + 1..].iter().product::<usize>();
+    let num_outer = dims[..dim].iter().product::<usize>();
+    let mut lane = Vec::with_capacity(dim_size);
+
+    for outer in 0..num_outer {
+        let offset = outer * dim_size * stride;
+
+        for inner in 0..stride {
+            lane.clear();
+            lane.extend((0..dim_size).map(|i| offset + i * stride + inner));
+            func(&lane);
+        }
+    }
+}
+
+fn compare<E: ElementComparison>(a: &E, b: &E, descending: bool) -> Ordering {
+    if descending {
+        b.cmp(a)
+    } else {
+        a.cmp(b)
+    }
+}
@@ -0,0 +1,407 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Data, ElementConversion, Int, Shape, Sparse, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The memory layout of a sparse tensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SparseLayout {
+    /// Coordinate format, storing the indices of every element.
+    Coo,
+    /// Compressed sparse row format, storing the column of every element and the offsets of every
+    /// row. Only available for matrices.
+    Csr,
+}
+
+/// The primitive of a [sparse](Sparse) tensor.
+///
+/// Sparse tensors only store their non-zero elements as float tensors, so every operation is
+/// differentiable with respect to the stored values when the backend supports autodiff.
+#[derive(Clone, Debug)]
+pub enum SparsePrimitive<B: Backend, const D: usize> {
+    /// Coordinate format.
+    Coo {
+        /// The indices of the elements with the shape `[nnz, D]`, one row per element.
+        indices: Tensor<B, 2, Int>,
+        /// The values of the elements with the shape `[nnz]`.
+        values: Tensor<B, 1>,
+        /// The shape of the dense tensor.
+        shape: Shape<D>,
+    },
+    /// Compressed sparse row format.
+    Csr {
+        /// The offsets of each row in the column indices and values, with the shape `[rows + 1]`.
+        row_offsets: Tensor<B, 1, Int>,
+        /// The columns of the elements with the shape `[nnz]`.
+        col_indices: Tensor<B, 1, Int>,
+        /// The values of the elements with the shape `[nnz]`.
+        values: Tensor<B, 1>,
+        /// The shape of the dense tensor.
+        shape: Shape<D>,
+    },
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Sparse> {
+    /// Creates a sparse tensor in the coordinate format.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the elements with the shape `[nnz, D]`, one row per element.
+    /// * `values` - The values of the elements with the shape `[nnz]`.
+    /// * `shape` - The shape of the dense tensor.
+    ///
+    /// # Notes
+    ///
+    /// Duplicated indices are allowed, the values of the same element are summed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Shape, Sparse, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+    ///     let values = Tensor::<B, 1>::from_floats([2.0, 3.0], &device);
+    ///     let tensor = Tensor::<B, 2, Sparse>::from_coo(indices, values, Shape::new([2, 2]));
+    ///     println!("{}", tensor.to_dense());
+    ///     // [[0.0, 2.0], [3.0, 0.0]]
+    /// }
+    /// ```
+    pub fn from_coo(indices: Tensor<B, 2, Int>, values: Tensor<B, 1>, shape: Shape<D>) -> Self {
+        check!(TensorCheck::sparse_coo::<D>(
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(SparsePrimitive::Coo {
+            indices,
+            values,
+            shape,
+        })
+    }
+
+    /// Returns the layout of the sparse tensor.
+    pub fn layout(&self) -> SparseLayout {
+        match &self.primitive {
+            SparsePrimitive::Coo { .. } => SparseLayout::Coo,
+            SparsePrimitive::Csr { .. } => SparseLayout::Csr,
+        }
+    }
+
+    /// Returns the number of stored elements.
+    pub fn nnz(&self) -> usize {
+        self.values().dims()[0]
+    }
+
+    /// Returns the stored values with the shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        match &self.primitive {
+            SparsePrimitive::Coo { values, .. } => values.clone(),
+            SparsePrimitive::Csr { values, .. } => values.clone(),
+        }
+    }
+
+    /// Returns the indices of the stored values with the shape `[nnz, D]`, one row per element.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        match self.clone().into_coo().primitive {
+            SparsePrimitive::Coo { indices, .. } => indices,
+            SparsePrimitive::Csr { .. } => unreachable!(),
+        }
+    }
+
+    /// Converts the sparse tensor to the coordinate format.
+    pub fn into_coo(self) -> Self {
+        let SparsePrimitive::Csr {
+            row_offsets,
+            col_indices,
+            values,
+            shape,
+        } = self.primitive
+        else {
+            return self;
+        };
+
+        let nnz = values.dims()[0];
+        let rows = row_offsets.dims()[0] - 1;
+        let device = values.device();
+
+        // Marks the start of every row after the first one, so that the cumulative sum of the
+        // marks gives the row of every element.
+        let mut marks = Tensor::<B, 1, Int>::zeros([nnz + 1], &device);
+        if rows > 1 {
+            marks = marks.select_assign(
+                0,
+                row_offsets.narrow(0, 1, rows - 1),
+                Tensor::ones([rows - 1], &device),
+            );
+        }
+        let row_indices = marks.cumsum(0).narrow(0, 0, nnz);
+
+        let indices = Tensor::stack(vec![row_indices, col_indices], 1);
+
+        Self::new(SparsePrimitive::Coo {
+            indices,
+            values,
+            shape,
+        })
+    }
+
+    /// Converts the sparse tensor to a dense tensor.
+    pub fn to_dense(self) -> Tensor<B, D> {
+        let shape = self.shape();
+        let device = self.device();
+        let indices = self.indices();
+        let values = self.values();
+
+        Tensor::zeros([shape.num_elements()], &device)
+            .select_assign(0, linear_indices(indices, &shape), values)
+            .reshape(shape)
+    }
+
+    /// Applies the given function to the stored values.
+    ///
+    /// # Notes
+    ///
+    /// The elements that aren't stored are left untouched, so the function should map zero to
+    /// zero for the result to match the same function applied to the dense tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, -2.0], [4.0, 0.0]], &device);
+    ///     let tensor = tensor.to_sparse_coo().map_values(|values| values.abs().sqrt());
+    ///     println!("{}", tensor.to_dense());
+    ///     // [[0.0, 1.4142], [2.0, 0.0]]
+    /// }
+    /// ```
+    pub fn map_values<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, 1>) -> Tensor<B, 1>,
+    {
+        let primitive = match self.primitive {
+            SparsePrimitive::Coo {
+                indices,
+                values,
+                shape,
+            } => SparsePrimitive::Coo {
+                indices,
+                values: func(values),
+                shape,
+            },
+            SparsePrimitive::Csr {
+                row_offsets,
+                col_indices,
+                values,
+                shape,
+            } => SparsePrimitive::Csr {
+                row_offsets,
+                col_indices,
+                values: func(values),
+                shape,
+            },
+        };
+
+        Self::new(primitive)
+    }
+
+    /// Applies element wise multiplication with a dense tensor of the same shape.
+    ///
+    /// The result keeps the sparsity of the sparse tensor.
+    pub fn mul_dense(self, other: Tensor<B, D>) -> Self {
+        check!(TensorCheck::sparse_shape::<D>(
+            "Mul",
+            &self.shape(),
+            &other.shape()
+        ));
+
+        let shape = self.shape();
+        let other = other
+            .reshape([shape.num_elements()])
+            .select(0, linear_indices(self.indices(), &shape));
+
+        self.map_values(|values| values.mul(other))
+    }
+
+    /// Applies element wise addition with another sparse tensor of the same shape.
+    ///
+    /// The result is in the coordinate format, with the elements of both tensors.
+    pub fn add_sparse(self, other: Self) -> Self {
+        check!(TensorCheck::sparse_shape::<D>(
+            "Add",
+            &self.shape(),
+            &other.shape()
+        ));
+
+        let shape = self.shape();
+        let indices = Tensor::cat(vec![self.indices(), other.indices()], 0);
+        let values = Tensor::cat(vec![self.values(), other.values()], 0);
+
+        Self::from_coo(indices, values, shape)
+    }
+}
+
+impl<B: Backend> Tensor<B, 2, Sparse> {
+    /// Creates a sparse matrix in the compressed sparse row format.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_offsets` - The offsets of each row with the shape `[rows + 1]`, where the elements
+    ///   of the row `i` are stored between `row_offsets[i]` and `row_offsets[i + 1]`.
+    /// * `col_indices` - The columns of the elements with the shape `[nnz]`.
+    /// * `values` - The values of the elements with the shape `[nnz]`.
+    /// * `shape` - The shape of the dense matrix.
+    pub fn from_csr(
+        row_offsets: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+        values: Tensor<B, 1>,
+        shape: Shape<2>,
+    ) -> Self {
+        check!(TensorCheck::sparse_csr(
+            &row_offsets.shape(),
+            &col_indices.shape(),
+            &values.shape(),
+            &shape
+        ));
+
+        Self::new(SparsePrimitive::Csr {
+            row_offsets,
+            col_indices,
+            values,
+            shape,
+        })
+    }
+
+    /// Converts the sparse matrix to the compressed sparse row format.
+    ///
+    /// The elements are sorted by row and column.
+    pub fn into_csr(self) -> Self {
+        let SparsePrimitive::Coo {
+            indices,
+            values,
+            shape,
+        } = self.primitive
+        else {
+            return self;
+        };
+
+        let [nnz, _] = indices.dims();
+        let [rows, _] = shape.dims;
+        let device = values.device();
+
+        let order = linear_indices(indices.clone(), &shape).argsort(0);
+        let indices = indices.select(0, order.clone());
+        let values = values.select(0, order);
+
+        let row_indices = indices.clone().narrow(1, 0, 1).reshape([nnz]);
+        let col_indices = indices.narrow(1, 1, 1).reshape([nnz]);
+
+        // Counts the elements of every row after a leading zero, so that the cumulative sum of
+        // the counts gives the offsets of every row.
+        let row_offsets = Tensor::<B, 1, Int>::zeros([rows + 1], &device)
+            .select_assign(0, row_indices.add_scalar(1), Tensor::ones([nnz], &device))
+            .cumsum(0);
+
+        Self::new(SparsePrimitive::Csr {
+            row_offsets,
+            col_indices,
+            values,
+            shape,
+        })
+    }
+
+    /// Performs the matrix multiplication of the sparse matrix with a dense matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Shape, Sparse, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+    ///     let values = Tensor::<B, 1>::from_floats([2.0, 3.0], &device);
+    ///     let lhs = Tensor::<B, 2, Sparse>::from_coo(indices, values, Shape::new([2, 2]));
+    ///     let rhs = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///     println!("{}", lhs.spmm(rhs));
+    ///     // [[6.0, 8.0], [3.0, 6.0]]
+    /// }
+    /// ```
+    pub fn spmm(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        check!(TensorCheck::spmm(&self.shape(), &rhs.shape()));
+
+        let [rows, _] = self.dims();
+        let [_, cols] = rhs.dims();
+        let device = rhs.device();
+        let indices = self.indices();
+        let values = self.values();
+        let nnz = values.dims()[0];
+
+        let row_indices = indices.clone().narrow(1, 0, 1).reshape([nnz]);
+        let col_indices = indices.narrow(1, 1, 1).reshape([nnz]);
+
+        // Every stored element scales a row of the dense matrix, which is summed into the row of
+        // the element.
+        let products = rhs.select(0, col_indices) * values.reshape([nnz, 1]);
+
+        Tensor::zeros([rows, cols], &device).select_assign(0, row_indices, products)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Converts the tensor to a sparse tensor in the coordinate format, storing the non-zero
+    /// elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_sparse_coo(self) -> Tensor<B, D, Sparse> {
+        let shape = self.shape();
+        let indices = self.clone().not_equal_elem(0.0).argwhere();
+        let values = self
+            .reshape([shape.num_elements()])
+            .select(0, linear_indices(indices.clone(), &shape));
+
+        Tensor::from_coo(indices, values, shape)
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Converts the matrix to a sparse matrix in the compressed sparse row format, storing the
+    /// non-zero elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn to_sparse_csr(self) -> Tensor<B, 2, Sparse> {
+        self.to_sparse_coo().into_csr()
+    }
+}
+
+/// Returns the indices of the elements in the flattened dense tensor.
+fn linear_indices<B: Backend, const D: usize>(
+    indices: Tensor<B, 2, Int>,
+    shape: &Shape<D>,
+) -> Tensor<B, 1, Int> {
+    let [nnz, _] = indices.dims();
+    let mut strides = vec![0; D];
+    let mut stride = 1;
+    for dim in (0..D).rev() {
+        strides[dim] = stride;
+        stride *= shape.dims[dim];
+    }
+
+    let strides: Vec<B::IntElem> = strides
+        .into_iter()
+        .map(|stride| (stride as i64).elem())
+        .collect();
+    let strides =
+        Tensor::<B, 1, Int>::from_data(Data::new(strides, Shape::new([D])), &indices.device());
+
+    indices
+        .mul(strides.reshape([1, D]))
+        .sum_dim(1)
+        .reshape([nnz])
+}
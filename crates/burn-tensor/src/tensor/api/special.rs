@@ -0,0 +1,326 @@
+use crate::{backend::Backend, ops::FloatTensor, Data, Tensor};
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+const READ_SYNC_ERROR: &str = "Failed to synchronously read tensor data. \
+    This operation is not supported until this backend has a native implementation.";
+
+/// The number of steps of the recurrence applied before the asymptotic series of the gamma
+/// functions, which is accurate enough for arguments larger than this shift.
+const SHIFT: usize = 6;
+
+/// Applies a function to the elements of a float tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `op` - The function applied to the elements, converted to `f64`.
+///
+/// # Returns
+///
+/// A tensor with the result of the function on each element.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn float_map<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    op: impl Fn(f64) -> f64,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let data: Data<f64, D> = B::float_into_data(tensor)
+        .read_sync()
+        .expect(READ_SYNC_ERROR)
+        .convert();
+    let value = data.value.into_iter().map(op).collect::<Vec<_>>();
+
+    B::float_from_data(Data::new(value, data.shape).convert(), &device)
+}
+
+/// Computes the four-quadrant arctangent of `lhs / rhs`, broadcasting them to the same shape.
+///
+/// # Arguments
+///
+/// * `lhs` - The y coordinates.
+/// * `rhs` - The x coordinates.
+///
+/// # Returns
+///
+/// A tensor with the angles in radians, between `-π` and `π`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn atan2<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let y = Tensor::<B, D>::new(lhs);
+    let x = Tensor::<B, D>::new(rhs);
+    let vertical = x.clone().equal_elem(0.0);
+    let angle = y
+        .clone()
+        .div(x.clone().mask_fill(vertical.clone(), 1.0))
+        .atan();
+
+    // The angles of the left half plane are moved by half a turn, and the vertical axis doesn't
+    // have a defined quotient.
+    let turn = y
+        .clone()
+        .lower_elem(0.0)
+        .float()
+        .mul_scalar(-2.0 * PI)
+        .add_scalar(PI);
+    let angle = angle.clone().mask_where(x.lower_elem(0.0), angle + turn);
+
+    angle
+        .mask_where(vertical, y.sign().mul_scalar(PI / 2.0))
+        .into_primitive()
+}
+
+/// Computes the natural logarithm of the absolute value of the gamma function.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `tensor` with the log-gamma values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn lgamma<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let x = Tensor::<B, D>::new(tensor);
+    let reflected = x.clone().lower_elem(0.5);
+    let z = x
+        .clone()
+        .mask_where(reflected.clone(), x.clone().neg().add_scalar(1.0));
+
+    // lgamma(z) = lgamma(z + n) - ln(z (z + 1) ... (z + n - 1))
+    let mut shift = z.zeros_like();
+    for k in 0..SHIFT {
+        shift = shift + z.clone().add_scalar(k as f64).log();
+    }
+    let w = z.add_scalar(SHIFT as f64);
+    let w_inv = w.clone().recip();
+    let w_inv2 = w_inv.clone().powf_scalar(2.0);
+    let series = w_inv2
+        .clone()
+        .mul_scalar(1.0 / 1260.0)
+        .sub_scalar(1.0 / 360.0)
+        .mul(w_inv2)
+        .add_scalar(1.0 / 12.0)
+        .mul(w_inv);
+    let positive = w.clone().sub_scalar(0.5).mul(w.clone().log()) - w
+        + series.add_scalar(0.5 * libm::log(2.0 * PI))
+        - shift;
+
+    // lgamma(x) = ln(π / |sin(πx)|) - lgamma(1 - x)
+    let reflection = x
+        .mask_fill(reflected.clone().bool_not(), 0.5)
+        .mul_scalar(PI)
+        .sin()
+        .abs()
+        .recip()
+        .mul_scalar(PI)
+        .log()
+        .sub(positive.clone());
+
+    positive.mask_where(reflected, reflection).into_primitive()
+}
+
+/// Computes the digamma function, the derivative of the log-gamma function.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `tensor` with the digamma values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn digamma<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let x = Tensor::<B, D>::new(tensor);
+    let reflected = x.clone().lower_elem(0.5);
+    let z = x
+        .clone()
+        .mask_where(reflected.clone(), x.clone().neg().add_scalar(1.0));
+
+    // digamma(z) = digamma(z + n) - 1 / z - 1 / (z + 1) - ... - 1 / (z + n - 1)
+    let mut shift = z.zeros_like();
+    for k in 0..SHIFT {
+        shift = shift + z.clone().add_scalar(k as f64).recip();
+    }
+    let w = z.add_scalar(SHIFT as f64);
+    let w_inv = w.clone().recip();
+    let w_inv2 = w_inv.clone().powf_scalar(2.0);
+    let series = w_inv2
+        .clone()
+        .mul_scalar(-1.0 / 252.0)
+        .add_scalar(1.0 / 120.0)
+        .mul(w_inv2.clone())
+        .sub_scalar(1.0 / 12.0)
+        .mul(w_inv2);
+    let positive = w.log() - w_inv.mul_scalar(0.5) + series - shift;
+
+    // digamma(x) = digamma(1 - x) - π / tan(πx)
+    let x = x.mask_fill(reflected.clone().bool_not(), 0.25);
+    let reflection = positive.clone() - x.mul_scalar(PI).tan().recip().mul_scalar(PI);
+
+    positive.mask_where(reflected, reflection).into_primitive()
+}
+
+/// Computes the trigamma function, the derivative of the digamma function.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `tensor` with the trigamma values.
+///
+/// # Remarks
+///
+/// This function is used to compute the gradient of the digamma function, and it is not designed
+/// for direct usage by users.
+pub fn trigamma<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let x = Tensor::<B, D>::new(tensor);
+    let reflected = x.clone().lower_elem(0.5);
+    let z = x
+        .clone()
+        .mask_where(reflected.clone(), x.clone().neg().add_scalar(1.0));
+
+    // trigamma(z) = trigamma(z + n) + 1 / z^2 + 1 / (z + 1)^2 + ... + 1 / (z + n - 1)^2
+    let mut shift = z.zeros_like();
+    for k in 0..SHIFT {
+        shift = shift + z.clone().add_scalar(k as f64).powf_scalar(2.0).recip();
+    }
+    let w = z.add_scalar(SHIFT as f64);
+    let w_inv = w.recip();
+    let w_inv2 = w_inv.clone().powf_scalar(2.0);
+    let series = w_inv2
+        .clone()
+        .mul_scalar(-1.0 / 30.0)
+        .add_scalar(1.0 / 42.0)
+        .mul(w_inv2.clone())
+        .sub_scalar(1.0 / 30.0)
+        .mul(w_inv2.clone())
+        .add_scalar(1.0 / 6.0)
+        .mul(w_inv.clone())
+        .add_scalar(0.5)
+        .mul(w_inv.clone())
+        .add_scalar(1.0)
+        .mul(w_inv);
+    let positive = series + shift;
+
+    // trigamma(x) = π² / sin²(πx) - trigamma(1 - x)
+    let reflection = x
+        .mask_fill(reflected.clone().bool_not(), 0.5)
+        .mul_scalar(PI)
+        .sin()
+        .powf_scalar(2.0)
+        .recip()
+        .mul_scalar(PI * PI)
+        - positive.clone();
+
+    positive.mask_where(reflected, reflection).into_primitive()
+}
+
+/// Computes the inverse of the error function.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor, whose values must be between `-1` and `1`.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `tensor` with the inverse error function values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn erfinv<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    const CENTRAL: [f64; 9] = [
+        2.810_226_36e-8,
+        3.432_739_39e-7,
+        -3.523_387_7e-6,
+        -4.391_506_54e-6,
+        2.185_808_7e-4,
+        -1.253_725_03e-3,
+        -4.177_681_64e-3,
+        2.466_407_27e-1,
+        1.501_409_41,
+    ];
+    const TAIL: [f64; 9] = [
+        -2.002_142_57e-4,
+        1.009_505_58e-4,
+        1.349_343_22e-3,
+        -3.673_428_44e-3,
+        5.739_507_73e-3,
+        -7.622_461_3e-3,
+        9.438_870_47e-3,
+        1.001_674_06,
+        2.832_976_82,
+    ];
+
+    let x = Tensor::<B, D>::new(tensor);
+    let polynomial = |w: Tensor<B, D>, coefficients: &[f64]| {
+        coefficients[1..]
+            .iter()
+            .fold(w.zeros_like().add_scalar(coefficients[0]), |p, &c| {
+                p.mul(w.clone()).add_scalar(c)
+            })
+    };
+
+    // The bounds and the values outside of them are replaced after the approximation, which is
+    // only evaluated on finite values.
+    let bound = x.clone().abs().greater_equal_elem(1.0);
+    let inner = x.clone().mask_fill(bound, 0.0);
+
+    // Giles' approximation, refined with a step of Newton's method.
+    let w = inner
+        .clone()
+        .neg()
+        .add_scalar(1.0)
+        .mul(inner.clone().add_scalar(1.0))
+        .log()
+        .neg();
+    let central = polynomial(w.clone().sub_scalar(2.5), &CENTRAL);
+    let tail = polynomial(w.clone().sqrt().sub_scalar(3.0), &TAIL);
+    let y = central.mask_where(w.greater_equal_elem(5.0), tail) * inner.clone();
+
+    let error = y.clone().erf() - inner;
+    let derivative = y
+        .clone()
+        .powf_scalar(2.0)
+        .neg()
+        .exp()
+        .mul_scalar(FRAC_2_SQRT_PI);
+    let y = y - error / derivative;
+
+    y.mask_fill(x.clone().abs().greater_elem(1.0), f64::NAN)
+        .mask_fill(x.clone().equal_elem(1.0), f64::INFINITY)
+        .mask_fill(x.equal_elem(-1.0), f64::NEG_INFINITY)
+        .into_primitive()
+}
@@ -2,7 +2,7 @@ use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 
-use crate::{tensor::Shape, Element, ElementConversion};
+use crate::{tensor::Shape, Element, ElementConversion, TruncatedNormal};
 
 use rand::{distributions::Standard, Rng, RngCore};
 
@@ -39,6 +39,22 @@ pub enum Distribution {
 
     /// Normal distribution with the given mean and standard deviation.
     Normal(f64, f64),
+
+    /// Normal distribution with the given mean and standard deviation, restricted to the given
+    /// lower and upper bounds.
+    TruncatedNormal(f64, f64, f64, f64),
+
+    /// Exponential distribution with the given rate.
+    Exponential(f64),
+
+    /// Gamma distribution with the given shape and scale.
+    Gamma(f64, f64),
+
+    /// Beta distribution with the given alpha and beta.
+    Beta(f64, f64),
+
+    /// Poisson distribution with the given rate.
+    Poisson(f64),
 }
 
 /// Distribution sampler for random value of a tensor.
@@ -70,6 +86,21 @@ where
 
     /// Normal distribution.
     Normal(rand_distr::Normal<f64>),
+
+    /// Truncated normal distribution.
+    TruncatedNormal(TruncatedNormal),
+
+    /// Exponential distribution.
+    Exponential(rand_distr::Exp<f64>),
+
+    /// Gamma distribution.
+    Gamma(rand_distr::Gamma<f64>),
+
+    /// Beta distribution.
+    Beta(rand_distr::Beta<f64>),
+
+    /// Poisson distribution.
+    Poisson(rand_distr::Poisson<f64>),
 }
 
 impl<'a, E, R> DistributionSampler<'a, E, R>
@@ -92,6 +123,15 @@ where
                 }
             }
             DistributionSamplerKind::Normal(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::TruncatedNormal(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
+            DistributionSamplerKind::Exponential(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
+            DistributionSamplerKind::Gamma(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Beta(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Poisson(distribution) => self.rng.sample(distribution).elem(),
         }
     }
 }
@@ -124,6 +164,21 @@ impl Distribution {
             Distribution::Normal(mean, std) => {
                 DistributionSamplerKind::Normal(rand_distr::Normal::new(mean, std).unwrap())
             }
+            Distribution::TruncatedNormal(mean, std, low, high) => {
+                DistributionSamplerKind::TruncatedNormal(TruncatedNormal::new(mean, std, low, high))
+            }
+            Distribution::Exponential(rate) => {
+                DistributionSamplerKind::Exponential(rand_distr::Exp::new(rate).unwrap())
+            }
+            Distribution::Gamma(shape, scale) => {
+                DistributionSamplerKind::Gamma(rand_distr::Gamma::new(shape, scale).unwrap())
+            }
+            Distribution::Beta(alpha, beta) => {
+                DistributionSamplerKind::Beta(rand_distr::Beta::new(alpha, beta).unwrap())
+            }
+            Distribution::Poisson(rate) => {
+                DistributionSamplerKind::Poisson(rand_distr::Poisson::<f64>::new(rate).unwrap())
+            }
         };
 
         DistributionSampler::new(kind, rng)
@@ -459,6 +514,34 @@ impl<
     }
 }
 
+impl<
+        Elem: core::fmt::Debug + Copy,
+        const A: usize,
+        const B: usize,
+        const C: usize,
+        const D: usize,
+        const E: usize,
+    > From<[[[[[Elem; E]; D]; C]; B]; A]> for Data<Elem, 5>
+{
+    fn from(elems: [[[[[Elem; E]; D]; C]; B]; A]) -> Self {
+        let mut data = Vec::with_capacity(A * B * C * D * E);
+
+        for elem in elems.into_iter().take(A) {
+            for elem in elem.into_iter().take(B) {
+                for elem in elem.into_iter().take(C) {
+                    for elem in elem.into_iter().take(D) {
+                        for elem in elem.into_iter().take(E) {
+                            data.push(elem);
+                        }
+                    }
+                }
+            }
+        }
+
+        Data::new(data, Shape::new([A, B, C, D, E]))
+    }
+}
+
 impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(format!("{:?}", &self.value).as_str())
@@ -1,4 +1,5 @@
 use crate::Distribution;
+use core::cmp::Ordering;
 use half::{bf16, f16};
 use num_traits::{identities::Zero, ToPrimitive};
 use rand::RngCore;
@@ -10,6 +11,7 @@ pub trait Element:
     + ElementRandom
     + ElementConversion
     + ElementPrecision
+    + ElementComparison
     + core::fmt::Debug
     + core::fmt::Display
     + Default
@@ -54,6 +56,14 @@ pub trait ElementRandom {
         Self: Sized;
 }
 
+/// Element ordering trait for tensor.
+pub trait ElementComparison {
+    /// Returns an [Ordering] between `self` and `other`.
+    ///
+    /// Floating point elements use a total ordering, so `NaN` values are sorted consistently.
+    fn cmp(&self, other: &Self) -> Ordering;
+}
+
 /// Element precision trait for tensor.
 #[derive(Clone, PartialEq, Eq, Copy, Debug)]
 pub enum Precision {
@@ -82,7 +92,8 @@ macro_rules! make_element {
     (
         ty $type:ident $precision:expr,
         convert $convert:expr,
-        random $random:expr
+        random $random:expr,
+        cmp $cmp:expr
 
     ) => {
         impl Element for $type {}
@@ -109,55 +120,70 @@ macro_rules! make_element {
                 $random(distribution, rng)
             }
         }
+
+        impl ElementComparison for $type {
+            fn cmp(&self, other: &Self) -> Ordering {
+                #[allow(clippy::redundant_closure_call)]
+                $cmp(self, other)
+            }
+        }
     };
 }
 
 make_element!(
     ty f64 Precision::Double,
     convert |elem: &dyn ToPrimitive| elem.to_f64().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &f64, b: &f64| a.total_cmp(b)
 );
 
 make_element!(
     ty f32 Precision::Full,
     convert |elem: &dyn ToPrimitive| elem.to_f32().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &f32, b: &f32| a.total_cmp(b)
 );
 
 make_element!(
     ty i64 Precision::Double,
     convert |elem: &dyn ToPrimitive| elem.to_i64().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &i64, b: &i64| Ord::cmp(a, b)
 );
 
 make_element!(
     ty i32 Precision::Full,
     convert |elem: &dyn ToPrimitive| elem.to_i32().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &i32, b: &i32| Ord::cmp(a, b)
 );
 
 make_element!(
     ty u32 Precision::Full,
     convert |elem: &dyn ToPrimitive| elem.to_u32().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &u32, b: &u32| Ord::cmp(a, b)
 );
 
 make_element!(
     ty i16 Precision::Half,
     convert |elem: &dyn ToPrimitive| elem.to_i16().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &i16, b: &i16| Ord::cmp(a, b)
 );
 
 make_element!(
     ty i8 Precision::Other,
     convert |elem: &dyn ToPrimitive| elem.to_i8().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &i8, b: &i8| Ord::cmp(a, b)
 );
 
 make_element!(
     ty u8 Precision::Other,
     convert |elem: &dyn ToPrimitive| elem.to_u8().unwrap(),
-    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample()
+    random |distribution: Distribution, rng: &mut R| distribution.sampler(rng).sample(),
+    cmp |a: &u8, b: &u8| Ord::cmp(a, b)
 );
 
 make_element!(
@@ -166,7 +192,8 @@ make_element!(
     random |distribution: Distribution, rng: &mut R| {
         let sample: f32 = distribution.sampler(rng).sample();
         f16::from_elem(sample)
-    }
+    },
+    cmp |a: &f16, b: &f16| a.total_cmp(b)
 );
 make_element!(
     ty bf16 Precision::Half,
@@ -174,5 +201,6 @@ make_element!(
     random |distribution: Distribution, rng: &mut R| {
         let sample: f32 = distribution.sampler(rng).sample();
         bf16::from_elem(sample)
-    }
+    },
+    cmp |a: &bf16, b: &bf16| a.total_cmp(b)
 );
@@ -0,0 +1,141 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor};
+
+/// Solves the linear systems `a x = b` for batches of square matrices.
+///
+/// # Arguments
+///
+/// * `a` - The square matrices of shape `[..., n, n]`.
+/// * `b` - The right-hand sides of shape `[..., n, k]`, with the same batch dimensions as `a`.
+///
+/// # Returns
+///
+/// The solutions `x` of shape `[..., n, k]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = B::Device::default();
+///     let a = Tensor::<B, 2>::from_floats([[2.0, 1.0], [1.0, 3.0]], &device);
+///     let b = Tensor::<B, 2>::from_floats([[3.0], [5.0]], &device);
+///     let x = linalg::solve(a, b);
+///     println!("{x}");
+///     // [[0.8], [1.4]]
+/// }
+/// ```
+pub fn solve<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::solve::<D>(&a.shape(), &b.shape()));
+
+    Tensor::from_primitive(B::float_solve(a.primitive, b.primitive))
+}
+
+/// Computes the inverse of batches of square matrices of shape `[..., n, n]`.
+pub fn inv<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::matrix::<D>("Inv", &tensor.shape(), true));
+
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let n = shape.dims[D - 1];
+
+    let mut dims = [1; D];
+    dims[D - 2] = n;
+    dims[D - 1] = n;
+    let identity = Tensor::<B, 2>::diagonal(n, &device).reshape(dims);
+
+    solve(tensor, Tensor::zeros(shape, &device) + identity)
+}
+
+/// Computes the determinant of batches of square matrices of shape `[..., n, n]`.
+///
+/// The determinants are returned with the shape `[..., 1, 1]`.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let (sign, logabsdet) = slogdet(tensor);
+
+    sign * logabsdet.exp()
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of
+/// batches of square matrices of shape `[..., n, n]`.
+///
+/// Both are returned with the shape `[..., 1, 1]`. Singular matrices have a sign of zero and a
+/// logarithm of negative infinity. Prefer this function over [det](det) when the determinant
+/// may overflow, like for the log-likelihood of a normalizing flow.
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("Slogdet", &tensor.shape(), true));
+
+    let (sign, logabsdet) = B::float_slogdet(tensor.primitive);
+
+    (
+        Tensor::from_primitive(sign),
+        Tensor::from_primitive(logabsdet),
+    )
+}
+
+/// Computes the Cholesky decomposition of batches of symmetric positive-definite matrices of
+/// shape `[..., n, n]`.
+///
+/// Only the lower triangle of the matrices is used. The lower triangular factors `l` are returned,
+/// where `tensor = l l^T`. Matrices that aren't positive-definite produce NaN values.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::matrix::<D>("Cholesky", &tensor.shape(), true));
+
+    Tensor::from_primitive(B::float_cholesky(tensor.primitive))
+}
+
+/// Computes the reduced QR decomposition of batches of matrices of shape `[..., m, n]`.
+///
+/// Returns the matrices `q` of shape `[..., m, k]` with orthonormal columns and the upper
+/// triangular matrices `r` of shape `[..., k, n]`, where `k = min(m, n)` and `tensor = q r`. The
+/// diagonal of `r` is non-negative, which makes the decomposition unique for matrices of full rank.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("Qr", &tensor.shape(), false));
+
+    let (q, r) = B::float_qr(tensor.primitive);
+
+    (Tensor::from_primitive(q), Tensor::from_primitive(r))
+}
+
+/// Computes the reduced singular value decomposition of batches of matrices of shape
+/// `[..., m, n]`.
+///
+/// Returns the left singular vectors `u` of shape `[..., m, k]`, the singular values `s` of shape
+/// `[..., 1, k]` in descending order and the right singular vectors `vh` of shape `[..., k, n]`,
+/// where `k = min(m, n)`. Since the singular values are stored as a row, the matrices are
+/// reconstructed with `(u * s).matmul(vh)`. The component of largest magnitude of each column of
+/// `u` is positive.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("Svd", &tensor.shape(), false));
+
+    let (u, s, vh) = B::float_svd(tensor.primitive);
+
+    (
+        Tensor::from_primitive(u),
+        Tensor::from_primitive(s),
+        Tensor::from_primitive(vh),
+    )
+}
+
+/// Computes the eigenvalues and eigenvectors of batches of symmetric matrices of shape
+/// `[..., n, n]`.
+///
+/// Only the lower triangle of the matrices is used. Returns the eigenvalues of shape `[..., 1, n]`
+/// in ascending order and the eigenvectors of shape `[..., n, n]` stored as columns, so the
+/// matrices are reconstructed with `(vectors.clone() * values).matmul(vectors.transpose())`. The
+/// component of largest magnitude of each eigenvector is positive.
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("Eigh", &tensor.shape(), true));
+
+    let (values, vectors) = B::float_eigh(tensor.primitive);
+
+    (
+        Tensor::from_primitive(values),
+        Tensor::from_primitive(vectors),
+    )
+}
@@ -3,11 +3,13 @@ pub(crate) mod stats;
 mod api;
 mod data;
 mod element;
+mod random;
 mod shape;
 
 pub use api::*;
 pub use data::*;
 pub use element::*;
+pub use random::*;
 pub use shape::*;
 
 /// The activation module.
@@ -19,6 +21,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -1,7 +1,12 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{
+        AttentionOptions, ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions,
+        UnfoldOptions,
+    },
+    Bool, Int, PadMode, Quantized, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -48,6 +53,105 @@ where
     ))
 }
 
+/// Applies a [2D convolution](crate::ops::ModuleOps::int_conv2d) to quantized tensors,
+/// accumulating the products of the quantized values in integers, and returns the real values of
+/// the result.
+///
+/// The input must be quantized per tensor, while the weight can be quantized per tensor or per
+/// output channel (dimension 0).
+pub fn quantized_conv2d<B>(
+    x: Tensor<B, 4, Quantized>,
+    weight: Tensor<B, 4, Quantized>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<2>,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::quantized_conv2d(x.scheme(), weight.scheme()));
+
+    let device = x.device();
+    let [channels_out, channels_per_group, kernel_height, kernel_width] = weight.dims();
+    let groups = options.groups;
+    let (x, x_zero_point, x_scale) = x.broadcast_parts();
+    let (weight, weight_zero_point, weight_scale) = weight.broadcast_parts();
+    let weight_zero_point = weight_zero_point.reshape([1, -1, 1, 1]);
+
+    // The input is padded with its zero point, so that the padding is zero once shifted.
+    let [padding_height, padding_width] = options.padding;
+    let pads = [
+        (0, 0),
+        (0, 0),
+        (padding_height, padding_height),
+        (padding_width, padding_width),
+    ];
+    let padding = Tensor::<B, 4, Int>::ones(x.shape(), &device)
+        .pad(pads, PadMode::Constant(0.0))
+        .neg()
+        .add_scalar(1);
+    let x = x.pad(pads, PadMode::Constant(0.0)) + padding.mul(x_zero_point.clone());
+    let options = ConvOptions::new(options.stride, [0, 0], options.dilation, groups);
+    let conv = |x: Tensor<B, 4, Int>, weight: Tensor<B, 4, Int>| {
+        Tensor::<B, 4, Int>::new(B::int8_conv2d(
+            x.primitive,
+            weight.primitive,
+            None,
+            options.clone(),
+        ))
+    };
+
+    // The values are convolved without being shifted by their zero points, so that they stay in
+    // the range of `i8`, using (x - a)(w - b) = xw - b sum(x) - a sum(w) + size a b over each
+    // window.
+    let product = conv(x.clone(), weight.clone());
+    let [batch_size, _, height_out, width_out] = product.dims();
+    let x_sum = conv(
+        x,
+        Tensor::ones(
+            [groups, channels_per_group, kernel_height, kernel_width],
+            &device,
+        ),
+    )
+    .reshape([batch_size, groups, 1, height_out, width_out])
+    .repeat(2, channels_out / groups)
+    .reshape([batch_size, channels_out, height_out, width_out]);
+    let weight_sum = weight
+        .sum_dim(3)
+        .sum_dim(2)
+        .sum_dim(1)
+        .reshape([1, channels_out, 1, 1]);
+    let size = channels_per_group * kernel_height * kernel_width;
+
+    let output =
+        product - x_sum.mul(weight_zero_point.clone()) - weight_sum.mul(x_zero_point.clone())
+            + x_zero_point.mul(weight_zero_point).mul_scalar(size as i64);
+    let scale = x_scale.mul(weight_scale).reshape([1, -1, 1, 1]);
+    let output = output.float().mul(scale);
+
+    match bias {
+        Some(bias) => output + bias.reshape([1, channels_out, 1, 1]),
+        None => output,
+    }
+}
+
+/// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
+pub fn conv3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -84,6 +188,24 @@ where
     ))
 }
 
+/// Applies a [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d).
+pub fn conv_transpose3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv_transpose3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [4D to 3D unfold](crate::ops::ModuleOps::unfold4d).
 pub fn unfold4d<B>(x: Tensor<B, 4>, kernel_size: [usize; 2], options: UnfoldOptions) -> Tensor<B, 3>
 where
@@ -132,6 +254,26 @@ where
     ))
 }
 
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_pool3d(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    ))
+}
+
 /// Applies a [2D avg pooling](crate::ops::ModuleOps::avg_pool2d).
 pub fn avg_pool2d<B>(
     x: Tensor<B, 4>,
@@ -152,6 +294,26 @@ where
     ))
 }
 
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::avg_pool3d(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    ))
+}
+
 /// Applies a [1D avg pooling](crate::ops::ModuleOps::avg_pool1d).
 pub fn avg_pool1d<B>(
     x: Tensor<B, 3>,
@@ -204,6 +366,22 @@ where
     (Tensor::new(output.output), Tensor::new(output.indices))
 }
 
+/// Applies a [3D max pooling with indices](crate::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output = B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+    (Tensor::new(output.output), Tensor::new(output.indices))
+}
+
 /// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
 pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
 where
@@ -220,6 +398,28 @@ where
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
 
+/// Applies a [3D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool3d).
+pub fn adaptive_avg_pool3d<B>(x: Tensor<B, 5>, output_size: [usize; 3]) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_avg_pool3d(x.primitive, output_size))
+}
+
+/// Applies a [1D interpolation](crate::ops::ModuleOps::interpolate1d).
+pub fn interpolate1d<B>(
+    x: Tensor<B, 3>,
+    output_size: usize,
+    options: InterpolateOptions,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    check!(TensorCheck::interpolate(1, &options.mode));
+
+    Tensor::new(B::interpolate1d(x.primitive, output_size, options))
+}
+
 /// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
 pub fn interpolate<B>(
     x: Tensor<B, 4>,
@@ -229,5 +429,108 @@ pub fn interpolate<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::interpolate(2, &options.mode));
+
     Tensor::new(B::interpolate(x.primitive, output_size, options))
 }
+
+/// Applies a [3D interpolation](crate::ops::ModuleOps::interpolate3d).
+pub fn interpolate3d<B>(
+    x: Tensor<B, 5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    check!(TensorCheck::interpolate(3, &options.mode));
+
+    Tensor::new(B::interpolate3d(x.primitive, output_size, options))
+}
+
+/// Applies a [2D grid sample](crate::ops::ModuleOps::grid_sample_2d).
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample_2d(&x.shape(), &grid.shape()));
+
+    Tensor::new(B::grid_sample_2d(x.primitive, grid.primitive, options))
+}
+
+/// Applies a [2D affine grid](crate::ops::ModuleOps::affine_grid_2d).
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::affine_grid_2d(&theta.shape(), size));
+
+    Tensor::new(B::affine_grid_2d(theta.primitive, size, align_corners))
+}
+
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::scaled_dot_product_attention).
+///
+/// When the attention weights are dropped without a seed, the seed is drawn from the random
+/// generator of the backend.
+pub fn scaled_dot_product_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    options: AttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::scaled_dot_product_attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        mask.as_ref().map(|mask| mask.shape()).as_ref(),
+        options.dropout,
+    ));
+
+    let options = match options.seed.is_none() && options.dropout > 0.0 {
+        true => options.with_seed(attention_seed::<B>(&query.device())),
+        false => options,
+    };
+
+    Tensor::new(B::scaled_dot_product_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        mask.map(|mask| mask.primitive),
+        options,
+    ))
+}
+
+/// Draws the seed of the attention weights dropped from the random generator of the backend.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn attention_seed<B: Backend>(device: &B::Device) -> u64 {
+    use crate::{Distribution, ElementConversion};
+
+    // Half precision floats can't represent every integer of the range, so integers are drawn,
+    // each one of 31 bits to fit in any integer element.
+    let values = B::int_random(
+        [2].into(),
+        Distribution::Uniform(0.0, i32::MAX as f64),
+        device,
+    );
+
+    Tensor::<B, 1, Int>::new(values)
+        .into_data()
+        .value
+        .into_iter()
+        .fold(0, |seed, value| seed << 31 | value.elem::<i64>() as u64)
+}
+
+/// Draws the seed of the attention weights dropped on the host, since the data of the tensors
+/// can't be read synchronously.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+fn attention_seed<B: Backend>(_device: &B::Device) -> u64 {
+    burn_common::rand::gen_random()
+}
@@ -1,5 +1,9 @@
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Int};
+use crate::{
+    argsort, bincount, bitwise_binary, bitwise_unary, cummax, cummax_with_indices, cummin,
+    cummin_with_indices, cumprod, cumsum, pad, searchsorted, sort, sort_with_indices, unique,
+};
+use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Int, PadMode};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -1125,4 +1129,485 @@ pub trait IntTensorOps<B: Backend> {
         let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
         B::int_equal_elem(sum, (num_elems as i32).elem())
     }
+
+    /// Sort the elements of the input `tensor` by value in along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements are sorted by value.
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        sort::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Sort the elements of the input `tensor` by value in along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and corresponding indices, where
+    /// the elements are sorted by value and the indices map back to the original input tensor.
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Returns the indices that sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        argsort::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumprod::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn int_cummax<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cummax::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running maximum along the given dimension. When the
+    /// maximum value occurs more than once, the index of the last occurrence is returned.
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        cummax_with_indices::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn int_cummin<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cummin::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running minimum along the given dimension. When the
+    /// minimum value occurs more than once, the index of the last occurrence is returned.
+    fn int_cummin_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        cummin_with_indices::<B, D, Int>(tensor, dim)
+    }
+
+    /// Pads each dimension of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `pads` - The number of values added before and after each dimension.
+    /// * `mode` - How the added values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor, where each dimension grows by the sum of its padding.
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<B, D> {
+        pad::<B, D, Int>(tensor, pads, mode)
+    }
+
+    /// Finds the unique elements of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// The unique elements sorted in ascending order, the index of each element of the input tensor
+    /// in the unique elements, and the number of occurrences of each unique element.
+    fn int_unique(tensor: IntTensor<B, 1>) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique::<B, Int>(tensor)
+    }
+
+    /// Finds the indices where the `values` would be inserted in the `sorted` tensor to keep it sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The tensor sorted in ascending order.
+    /// * `values` - The values to insert.
+    /// * `right` - Whether the index after the elements equal to a value is returned, instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the values, containing the insertion index of each value.
+    fn int_searchsorted<const D: usize>(
+        sorted: IntTensor<B, 1>,
+        values: IntTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        searchsorted::<B, D, Int>(sorted, values, right)
+    }
+
+    /// Counts the occurrences of each non-negative value of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, whose values must be non-negative.
+    /// * `min_length` - The minimum number of counts returned.
+    ///
+    /// # Returns
+    ///
+    /// A tensor whose element at index `i` is the number of occurrences of `i`, with a length of one
+    /// more than the largest value, or `min_length` if it is larger.
+    fn int_bincount(tensor: IntTensor<B, 1>, min_length: usize) -> IntTensor<B, 1> {
+        bincount::<B>(tensor, min_length)
+    }
+
+    /// Multiplies two int tensors together using matrix multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of multiplying the two tensors together using matrix multiplication.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation multiplies the tensors converted to floats, which is exact as
+    /// long as the accumulated values fit in the mantissa of the float element type.
+    fn int_matmul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::float_into_int(B::float_matmul(
+            B::int_into_float(lhs),
+            B::int_into_float(rhs),
+        ))
+    }
+
+    /// Performs the matrix multiplication of two int tensors whose values are between -128 and
+    /// 127, such as the values of [quantized](crate::Quantized) tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of multiplying the two tensors together using matrix multiplication.
+    ///
+    /// # Remarks
+    ///
+    /// Backends can accumulate the products in 32-bit integers, which can't overflow as long as
+    /// the shared dimension is smaller than 2<sup>17</sup>. The default implementation calls
+    /// [int_matmul](IntTensorOps::int_matmul).
+    fn int8_matmul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_matmul(lhs, rhs)
+    }
+
+    /// Element-wise bitwise and of two int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise and of the two tensors.
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_binary::<B, D>(lhs, rhs, |a, b| a & b)
+    }
+
+    /// Element-wise bitwise and of an int tensor and a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise and of the tensor and the scalar.
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let b = rhs.elem::<i64>();
+        bitwise_unary::<B, D>(lhs, |a| a & b)
+    }
+
+    /// Element-wise bitwise or of two int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise or of the two tensors.
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_binary::<B, D>(lhs, rhs, |a, b| a | b)
+    }
+
+    /// Element-wise bitwise or of an int tensor and a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise or of the tensor and the scalar.
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let b = rhs.elem::<i64>();
+        bitwise_unary::<B, D>(lhs, |a| a | b)
+    }
+
+    /// Element-wise bitwise exclusive or of two int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise exclusive or of the two tensors.
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_binary::<B, D>(lhs, rhs, |a, b| a ^ b)
+    }
+
+    /// Element-wise bitwise exclusive or of an int tensor and a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise exclusive or of the tensor and the scalar.
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let b = rhs.elem::<i64>();
+        bitwise_unary::<B, D>(lhs, |a| a ^ b)
+    }
+
+    /// Element-wise bitwise not of an int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with each bit of its elements inverted.
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        bitwise_unary::<B, D>(tensor, |a| !a)
+    }
+
+    /// Element-wise bitwise left shift of an int tensor by the elements of another int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift each element by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_binary::<B, D>(lhs, rhs, |a, b| a.wrapping_shl(b as u32))
+    }
+
+    /// Element-wise bitwise left shift of an int tensor by a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift the elements by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let b = rhs.elem::<i64>();
+        bitwise_unary::<B, D>(lhs, |a| a.wrapping_shl(b as u32))
+    }
+
+    /// Element-wise bitwise right shift of an int tensor by the elements of another int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift each element by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, whose elements keep their sign.
+    ///
+    /// # Remarks
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_binary::<B, D>(lhs, rhs, |a, b| a.wrapping_shr(b as u32))
+    }
+
+    /// Element-wise bitwise right shift of an int tensor by a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift the elements by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, whose elements keep their sign.
+    ///
+    /// # Remarks
+    ///
+    /// The result of shifting by a negative amount or by at least the number of bits of the int
+    /// element type depends on the backend.
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let b = rhs.elem::<i64>();
+        bitwise_unary::<B, D>(lhs, |a| a.wrapping_shr(b as u32))
+    }
 }
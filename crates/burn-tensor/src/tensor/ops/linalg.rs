@@ -0,0 +1,617 @@
+use super::FloatTensor;
+use crate::{backend::Backend, Data, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+const READ_SYNC_ERROR: &str = "Failed to synchronously read tensor data. \
+    This operation is not supported until this backend has a native linear algebra implementation.";
+
+/// The maximum number of sweeps of the Jacobi eigenvalue and singular value algorithms.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Solves the linear systems `a x = b` for batches of square matrices `a`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn solve<B: Backend, const D: usize>(
+    a: FloatTensor<B, D>,
+    b: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&b);
+    let shape = B::float_shape(&b);
+    let [n, k] = matrix_dims(&shape);
+    let mut a = read::<B, D>(a);
+    let mut b = read::<B, D>(b);
+
+    for (a, b) in a.chunks_mut(n * n).zip(b.chunks_mut(n * k)) {
+        matrix_solve(a, b, n, k);
+    }
+
+    write::<B, D>(b, shape, &device)
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant for
+/// batches of square matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn slogdet<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let shape = B::float_shape(&tensor);
+    let [n, _] = matrix_dims(&shape);
+    let mut values = read::<B, D>(tensor);
+
+    let (sign, logabsdet): (Vec<f64>, Vec<f64>) = values
+        .chunks_mut(n * n)
+        .map(|matrix| matrix_slogdet(matrix, n))
+        .unzip();
+    let shape = with_matrix_dims(&shape, 1, 1);
+
+    (
+        write::<B, D>(sign, shape.clone(), &device),
+        write::<B, D>(logabsdet, shape, &device),
+    )
+}
+
+/// Computes the lower triangular Cholesky factor of batches of symmetric positive-definite
+/// matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cholesky<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let shape = B::float_shape(&tensor);
+    let [n, _] = matrix_dims(&shape);
+    let values = read::<B, D>(tensor);
+
+    let output = values
+        .chunks(n * n)
+        .flat_map(|matrix| matrix_cholesky(matrix, n))
+        .collect();
+
+    write::<B, D>(output, shape, &device)
+}
+
+/// Computes the reduced QR decomposition of batches of matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn qr<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let shape = B::float_shape(&tensor);
+    let [m, n] = matrix_dims(&shape);
+    let k = usize::min(m, n);
+    let values = read::<B, D>(tensor);
+
+    let mut q = Vec::with_capacity(values.len() / n * k);
+    let mut r = Vec::with_capacity(values.len() / m * k);
+    for matrix in values.chunks(m * n) {
+        let (matrix_q, matrix_r) = matrix_qr(matrix, m, n);
+        q.extend(matrix_q);
+        r.extend(matrix_r);
+    }
+
+    (
+        write::<B, D>(q, with_matrix_dims(&shape, m, k), &device),
+        write::<B, D>(r, with_matrix_dims(&shape, k, n), &device),
+    )
+}
+
+/// Computes the reduced singular value decomposition of batches of matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let shape = B::float_shape(&tensor);
+    let [m, n] = matrix_dims(&shape);
+    let k = usize::min(m, n);
+    let values = read::<B, D>(tensor);
+
+    let mut u = Vec::with_capacity(values.len() / n * k);
+    let mut s = Vec::with_capacity(values.len() / (m * n) * k);
+    let mut vh = Vec::with_capacity(values.len() / m * k);
+    for matrix in values.chunks(m * n) {
+        let (matrix_u, matrix_s, matrix_vh) = matrix_svd(matrix, m, n);
+        u.extend(matrix_u);
+        s.extend(matrix_s);
+        vh.extend(matrix_vh);
+    }
+
+    (
+        write::<B, D>(u, with_matrix_dims(&shape, m, k), &device),
+        write::<B, D>(s, with_matrix_dims(&shape, 1, k), &device),
+        write::<B, D>(vh, with_matrix_dims(&shape, k, n), &device),
+    )
+}
+
+/// Computes the eigenvalues and eigenvectors of batches of symmetric matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn eigh<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let shape = B::float_shape(&tensor);
+    let [n, _] = matrix_dims(&shape);
+    let values = read::<B, D>(tensor);
+
+    let mut eigenvalues = Vec::with_capacity(values.len() / n);
+    let mut eigenvectors = Vec::with_capacity(values.len());
+    for matrix in values.chunks(n * n) {
+        let (matrix_eigenvalues, matrix_eigenvectors) = matrix_eigh(matrix, n);
+        eigenvalues.extend(matrix_eigenvalues);
+        eigenvectors.extend(matrix_eigenvectors);
+    }
+
+    (
+        write::<B, D>(eigenvalues, with_matrix_dims(&shape, 1, n), &device),
+        write::<B, D>(eigenvectors, shape, &device),
+    )
+}
+
+/// Solves `a x = b` in place with Gaussian elimination and partial pivoting, where `a` is a
+/// row-major `n x n` matrix and `b` a row-major `n x k` matrix overwritten with `x`.
+///
+/// Singular matrices produce infinite or NaN values.
+pub fn matrix_solve(a: &mut [f64], b: &mut [f64], n: usize, k: usize) {
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|i, j| a[i * n + col].abs().total_cmp(&a[j * n + col].abs()))
+            .unwrap();
+
+        if pivot != col {
+            swap_rows(a, n, pivot, col);
+            swap_rows(b, k, pivot, col);
+        }
+
+        for row in col + 1..n {
+            let factor = a[row * n + col] / a[col * n + col];
+
+            for j in col..n {
+                a[row * n + j] -= factor * a[col * n + j];
+            }
+            for j in 0..k {
+                b[row * k + j] -= factor * b[col * k + j];
+            }
+        }
+    }
+
+    for row in (0..n).rev() {
+        for j in 0..k {
+            let mut value = b[row * k + j];
+            for i in row + 1..n {
+                value -= a[row * n + i] * b[i * k + j];
+            }
+            b[row * k + j] = value / a[row * n + row];
+        }
+    }
+}
+
+/// Returns the sign and the natural logarithm of the absolute value of the determinant of the
+/// row-major `n x n` matrix, which is overwritten by its LU factorization.
+///
+/// Singular matrices have a sign of zero and a logarithm of negative infinity.
+pub fn matrix_slogdet(a: &mut [f64], n: usize) -> (f64, f64) {
+    let mut sign = 1.0;
+    let mut logabsdet = 0.0;
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|i, j| a[i * n + col].abs().total_cmp(&a[j * n + col].abs()))
+            .unwrap();
+        let value = a[pivot * n + col];
+
+        if value == 0.0 {
+            return (0.0, f64::NEG_INFINITY);
+        }
+
+        if pivot != col {
+            swap_rows(a, n, pivot, col);
+            sign = -sign;
+        }
+
+        sign *= value.signum();
+        logabsdet += value.abs().ln();
+
+        for row in col + 1..n {
+            let factor = a[row * n + col] / value;
+
+            for j in col..n {
+                a[row * n + j] -= factor * a[col * n + j];
+            }
+        }
+    }
+
+    (sign, logabsdet)
+}
+
+/// Returns the lower triangular Cholesky factor of the row-major `n x n` matrix, where only the
+/// lower triangle of the matrix is used.
+///
+/// Matrices that aren't positive-definite produce NaN values.
+pub fn matrix_cholesky(a: &[f64], n: usize) -> Vec<f64> {
+    let mut l = vec![0.0; n * n];
+
+    for j in 0..n {
+        let mut diagonal = a[j * n + j];
+        for k in 0..j {
+            diagonal -= l[j * n + k] * l[j * n + k];
+        }
+        let diagonal = match diagonal > 0.0 {
+            true => diagonal.sqrt(),
+            false => f64::NAN,
+        };
+        l[j * n + j] = diagonal;
+
+        for i in j + 1..n {
+            let mut value = a[i * n + j];
+            for k in 0..j {
+                value -= l[i * n + k] * l[j * n + k];
+            }
+            l[i * n + j] = value / diagonal;
+        }
+    }
+
+    l
+}
+
+/// Returns the reduced QR decomposition of the row-major `m x n` matrix computed with Householder
+/// reflections, where `q` is `m x min(m, n)` and `r` is `min(m, n) x n`.
+///
+/// The diagonal of `r` is made non-negative, which makes the decomposition unique for matrices of
+/// full rank.
+pub fn matrix_qr(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let k = usize::min(m, n);
+    let mut r = a.to_vec();
+    let mut reflectors = Vec::with_capacity(k);
+
+    for j in 0..k {
+        let mut v: Vec<f64> = (j..m).map(|i| r[i * n + j]).collect();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        if norm == 0.0 {
+            reflectors.push(v);
+            continue;
+        }
+
+        v[0] += v[0].signum() * norm;
+        let norm_v = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= norm_v);
+
+        for col in j..n {
+            let dot: f64 = (j..m).map(|i| v[i - j] * r[i * n + col]).sum();
+            for i in j..m {
+                r[i * n + col] -= 2.0 * v[i - j] * dot;
+            }
+        }
+
+        reflectors.push(v);
+    }
+
+    // Apply the reflections to the first columns of the identity, last reflection first.
+    let mut q = vec![0.0; m * k];
+    for i in 0..k {
+        q[i * k + i] = 1.0;
+    }
+    for (j, v) in reflectors.iter().enumerate().rev() {
+        for col in 0..k {
+            let dot: f64 = (j..m).map(|i| v[i - j] * q[i * k + col]).sum();
+            for i in j..m {
+                q[i * k + col] -= 2.0 * v[i - j] * dot;
+            }
+        }
+    }
+
+    let mut r: Vec<f64> = (0..k * n)
+        .map(|index| {
+            let (i, j) = (index / n, index % n);
+            if j < i {
+                0.0
+            } else {
+                r[index]
+            }
+        })
+        .collect();
+
+    for i in 0..k {
+        if r[i * n + i] < 0.0 {
+            (0..n).for_each(|j| r[i * n + j] = -r[i * n + j]);
+            (0..m).for_each(|row| q[row * k + i] = -q[row * k + i]);
+        }
+    }
+
+    (q, r)
+}
+
+/// Returns the reduced singular value decomposition `a = u diag(s) vh` of the row-major `m x n`
+/// matrix computed with the one-sided Jacobi algorithm, where `u` is `m x min(m, n)` and `vh` is
+/// `min(m, n) x n`.
+///
+/// Singular values are sorted in descending order, and the component of largest magnitude of each
+/// column of `u` is made positive.
+pub fn matrix_svd(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if m < n {
+        // The decomposition of the transpose swaps the roles of `u` and `vh`.
+        let (v, s, uh) = matrix_svd(&transpose(a, m, n), n, m);
+        return (transpose(&uh, m, m), s, transpose(&v, n, m));
+    }
+
+    let mut u = a.to_vec();
+    let mut v = identity(n);
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for i in 0..m {
+                    alpha += u[i * n + p] * u[i * n + p];
+                    beta += u[i * n + q] * u[i * n + q];
+                    gamma += u[i * n + p] * u[i * n + q];
+                }
+
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+                converged = false;
+
+                let (cos, sin) = jacobi_rotation(alpha, beta, gamma);
+                rotate_columns(&mut u, n, p, q, cos, sin);
+                rotate_columns(&mut v, n, p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let mut s: Vec<f64> = (0..n)
+        .map(|j| {
+            (0..m)
+                .map(|i| u[i * n + j] * u[i * n + j])
+                .sum::<f64>()
+                .sqrt()
+        })
+        .collect();
+    let max = s.iter().fold(0.0, |acc: f64, x| acc.max(*x));
+    let mut valid: Vec<bool> = s
+        .iter()
+        .map(|x| *x > max * f64::EPSILON * m as f64)
+        .collect();
+
+    for j in 0..n {
+        match valid[j] {
+            true => (0..m).for_each(|i| u[i * n + j] /= s[j]),
+            false => s[j] = 0.0,
+        }
+    }
+
+    // Columns of rank deficient matrices are completed to keep `u` orthonormal.
+    for j in 0..n {
+        if !valid[j] {
+            complete_orthonormal_column(&mut u, m, n, j, &valid);
+            valid[j] = true;
+        }
+    }
+
+    let order = descending_order(&s);
+    let s = order.iter().map(|j| s[*j]).collect();
+    let mut u = permute_columns(&u, m, n, &order);
+    let mut v = permute_columns(&v, n, n, &order);
+    normalize_signs(&mut u, m, n, Some(&mut v));
+
+    (u, s, transpose(&v, n, n))
+}
+
+/// Returns the eigenvalues in ascending order and the eigenvectors stored as columns of the
+/// row-major `n x n` symmetric matrix, computed with the cyclic Jacobi algorithm.
+///
+/// Only the lower triangle of the matrix is used, and the component of largest magnitude of each
+/// eigenvector is made positive.
+pub fn matrix_eigh(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut matrix: Vec<f64> = (0..n * n)
+        .map(|index| {
+            let (i, j) = (index / n, index % n);
+            a[usize::max(i, j) * n + usize::min(i, j)]
+        })
+        .collect();
+    let mut vectors = identity(n);
+    let norm: f64 = matrix.iter().map(|x| x * x).sum();
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| matrix[i * n + j] * matrix[i * n + j])
+            .sum();
+
+        if off_diagonal <= norm * f64::EPSILON * f64::EPSILON {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let gamma = matrix[p * n + q];
+
+                if gamma == 0.0 {
+                    continue;
+                }
+
+                let (cos, sin) = jacobi_rotation(matrix[p * n + p], matrix[q * n + q], gamma);
+                rotate_columns(&mut matrix, n, p, q, cos, sin);
+                rotate_rows(&mut matrix, n, p, q, cos, sin);
+                rotate_columns(&mut vectors, n, p, q, cos, sin);
+            }
+        }
+    }
+
+    let values: Vec<f64> = (0..n).map(|i| matrix[i * n + i]).collect();
+    let mut order = descending_order(&values);
+    order.reverse();
+
+    let values = order.iter().map(|i| values[*i]).collect();
+    let mut vectors = permute_columns(&vectors, n, n, &order);
+    normalize_signs(&mut vectors, n, n, None);
+
+    (values, vectors)
+}
+
+/// Returns the cosine and sine of the rotation that cancels the off-diagonal entry `gamma` of the
+/// symmetric matrix `[[alpha, gamma], [gamma, beta]]`.
+fn jacobi_rotation(alpha: f64, beta: f64, gamma: f64) -> (f64, f64) {
+    let zeta = (beta - alpha) / (2.0 * gamma);
+    let tan = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+    let cos = 1.0 / (1.0 + tan * tan).sqrt();
+
+    (cos, cos * tan)
+}
+
+fn rotate_columns(matrix: &mut [f64], cols: usize, p: usize, q: usize, cos: f64, sin: f64) {
+    for row in 0..matrix.len() / cols {
+        let (x, y) = (matrix[row * cols + p], matrix[row * cols + q]);
+        matrix[row * cols + p] = cos * x - sin * y;
+        matrix[row * cols + q] = sin * x + cos * y;
+    }
+}
+
+fn rotate_rows(matrix: &mut [f64], cols: usize, p: usize, q: usize, cos: f64, sin: f64) {
+    for col in 0..cols {
+        let (x, y) = (matrix[p * cols + col], matrix[q * cols + col]);
+        matrix[p * cols + col] = cos * x - sin * y;
+        matrix[q * cols + col] = sin * x + cos * y;
+    }
+}
+
+/// Replaces a column of an `m x n` matrix with a unit vector orthogonal to the valid columns.
+fn complete_orthonormal_column(u: &mut [f64], m: usize, n: usize, col: usize, valid: &[bool]) {
+    let others: Vec<usize> = (0..n).filter(|j| valid[*j]).collect();
+
+    for basis in 0..m {
+        let mut candidate = vec![0.0; m];
+        candidate[basis] = 1.0;
+
+        for j in others.iter() {
+            let dot: f64 = (0..m).map(|i| candidate[i] * u[i * n + j]).sum();
+            (0..m).for_each(|i| candidate[i] -= dot * u[i * n + j]);
+        }
+
+        let norm = candidate.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > 0.5 {
+            (0..m).for_each(|i| u[i * n + col] = candidate[i] / norm);
+            return;
+        }
+    }
+}
+
+/// Makes the component of largest magnitude of each column positive, flipping the same columns of
+/// `other` when provided.
+fn normalize_signs(matrix: &mut [f64], rows: usize, cols: usize, mut other: Option<&mut Vec<f64>>) {
+    for j in 0..cols {
+        let largest = (0..rows)
+            .map(|i| matrix[i * cols + j])
+            .fold(0.0, |acc: f64, x| if x.abs() > acc.abs() { x } else { acc });
+
+        if largest < 0.0 {
+            (0..rows).for_each(|i| matrix[i * cols + j] = -matrix[i * cols + j]);
+
+            if let Some(other) = other.as_mut() {
+                let other_rows = other.len() / cols;
+                (0..other_rows).for_each(|i| other[i * cols + j] = -other[i * cols + j]);
+            }
+        }
+    }
+}
+
+fn descending_order(values: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|i, j| values[*j].total_cmp(&values[*i]));
+    order
+}
+
+fn permute_columns(matrix: &[f64], rows: usize, cols: usize, order: &[usize]) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|index| matrix[(index / cols) * cols + order[index % cols]])
+        .collect()
+}
+
+fn transpose(matrix: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|index| matrix[(index % rows) * cols + index / rows])
+        .collect()
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    (0..n * n)
+        .map(|index| if index / n == index % n { 1.0 } else { 0.0 })
+        .collect()
+}
+
+fn swap_rows(matrix: &mut [f64], cols: usize, i: usize, j: usize) {
+    for col in 0..cols {
+        matrix.swap(i * cols + col, j * cols + col);
+    }
+}
+
+fn matrix_dims<const D: usize>(shape: &Shape<D>) -> [usize; 2] {
+    [shape.dims[D - 2], shape.dims[D - 1]]
+}
+
+fn with_matrix_dims<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut shape = shape.clone();
+    shape.dims[D - 2] = rows;
+    shape.dims[D - 1] = cols;
+    shape
+}
+
+fn read<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> Vec<f64> {
+    B::float_into_data(tensor)
+        .read_sync()
+        .expect(READ_SYNC_ERROR)
+        .convert::<f64>()
+        .value
+}
+
+fn write<B: Backend, const D: usize>(
+    values: Vec<f64>,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> FloatTensor<B, D> {
+    B::float_from_data(Data::new(values, shape).convert(), device)
+}
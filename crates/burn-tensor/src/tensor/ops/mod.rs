@@ -5,6 +5,9 @@ mod int_tensor;
 mod modules;
 mod tensor;
 
+/// Module with linear algebra operations.
+pub mod linalg;
+
 pub use activation::*;
 pub use alias::*;
 pub use bool_tensor::*;
@@ -0,0 +1,297 @@
+use super::{AttentionBackward, AttentionOptions};
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, Device, FloatTensor, IntTensor},
+    ElementConversion, Shape,
+};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// The factor multiplying the dot products of the queries and the keys.
+pub fn attention_scale(d_k: usize, options: &AttentionOptions) -> f64 {
+    options.scale.unwrap_or_else(|| 1.0 / (d_k as f64).sqrt())
+}
+
+/// The prime modulus of the dropout hash, small enough for the product of two hashes to fit in
+/// any integer element, and such that cubing is a bijection.
+const DROPOUT_MODULUS: i64 = 46_337;
+/// The number of bits of the words the seed and the positions are split in before being hashed.
+const DROPOUT_WORD_BITS: u32 = 15;
+/// The factor and the offset of each round of the dropout hash.
+const DROPOUT_ROUNDS: [(i64, i64); 2] = [(31_667, 12_345), (19_891, 40_503)];
+
+/// The attention weights kept by the dropout, which only depend on the seed and on the position
+/// of the weights, so that they can be generated again during the backward pass, and by backends
+/// computing the weights block by block.
+///
+/// The position of each weight is hashed with integer operations whose values stay below
+/// 2<sup>31</sup>, so that the hash is the same for every backend and can be computed on the device
+/// without using the random generator of the backend.
+#[derive(Clone, Copy, Debug)]
+pub struct AttentionDropout {
+    keys: [i64; 4],
+    threshold: i64,
+    prob: f64,
+}
+
+impl AttentionDropout {
+    /// Creates the dropout of the attention weights with the given seed, dropping each weight
+    /// with the probability `prob`.
+    pub fn new(seed: u64, prob: f64) -> Self {
+        let keys = core::array::from_fn(|axis| {
+            (0..u64::BITS)
+                .step_by(DROPOUT_WORD_BITS as usize)
+                .fold(axis as i64 + 1, |key, shift| {
+                    dropout_mix(key + ((seed >> shift) & dropout_word_mask()) as i64)
+                })
+        });
+        let threshold = (prob * DROPOUT_MODULUS as f64).ceil() as i64;
+
+        Self {
+            keys,
+            threshold,
+            prob,
+        }
+    }
+
+    /// If the attention weight at the position `[batch, head, query, key]` is kept.
+    ///
+    /// The positions must be lower than 2<sup>30</sup>.
+    pub fn keep(&self, position: [usize; 4]) -> bool {
+        let hash = self
+            .keys
+            .iter()
+            .zip(position)
+            .map(|(key, index)| {
+                let low = (index as u64 & dropout_word_mask()) as i64;
+                let high = (index >> DROPOUT_WORD_BITS) as i64;
+                dropout_mix(dropout_mix(key + low) + high)
+            })
+            .sum();
+
+        dropout_mix(hash) >= self.threshold
+    }
+
+    /// The factor multiplying the attention weights that are kept.
+    pub fn factor(&self) -> f64 {
+        1.0 / (1.0 - self.prob)
+    }
+
+    /// The factors multiplying the attention weights of the given shape, which are zero for the
+    /// dropped weights and [the factor](Self::factor) for the others.
+    fn factors<B: Backend>(&self, shape: &Shape<4>, device: &Device<B>) -> FloatTensor<B, 4> {
+        let hash = self.keys.iter().zip(shape.dims).enumerate().fold(
+            B::int_zeros(shape.clone(), device),
+            |hash, (axis, (key, size))| {
+                let index = B::int_arange(0..size as i64, device);
+                let high = B::int_div_scalar(index.clone(), (1i64 << DROPOUT_WORD_BITS).elem());
+                let low = B::int_sub(
+                    index,
+                    B::int_mul_scalar(high.clone(), (1i64 << DROPOUT_WORD_BITS).elem()),
+                );
+                let low = dropout_mix_tensor::<B, 1>(B::int_add_scalar(low, key.elem()));
+                let index_hash = dropout_mix_tensor::<B, 1>(B::int_add(low, high));
+
+                let mut dims = [1; 4];
+                dims[axis] = size;
+                B::int_add(hash, B::int_reshape(index_hash, Shape::new(dims)))
+            },
+        );
+        let keep =
+            B::int_greater_equal_elem(dropout_mix_tensor::<B, 4>(hash), self.threshold.elem());
+
+        B::float_mul_scalar(B::bool_into_float(keep), self.factor().elem())
+    }
+}
+
+fn dropout_word_mask() -> u64 {
+    (1 << DROPOUT_WORD_BITS) - 1
+}
+
+/// The rounds of the dropout hash, each one cubing the value modulo the prime modulus before an
+/// affine transformation.
+fn dropout_mix(mut value: i64) -> i64 {
+    for (factor, offset) in DROPOUT_ROUNDS {
+        value %= DROPOUT_MODULUS;
+        value = value * value % DROPOUT_MODULUS * value % DROPOUT_MODULUS;
+        value = (value * factor + offset) % DROPOUT_MODULUS;
+    }
+
+    value
+}
+
+/// The rounds of [the dropout hash](dropout_mix) applied to the elements of a tensor.
+fn dropout_mix_tensor<B: Backend, const D: usize>(value: IntTensor<B, D>) -> IntTensor<B, D> {
+    let modulo = |value: IntTensor<B, D>| {
+        let quotient = B::int_div_scalar(value.clone(), DROPOUT_MODULUS.elem());
+        B::int_sub(value, B::int_mul_scalar(quotient, DROPOUT_MODULUS.elem()))
+    };
+
+    DROPOUT_ROUNDS
+        .iter()
+        .fold(value, |value, (factor, offset)| {
+            let value = modulo(value);
+            let square = modulo(B::int_mul(value.clone(), value.clone()));
+            let cube = modulo(B::int_mul(square, value));
+            modulo(B::int_add_scalar(
+                B::int_mul_scalar(cube, factor.elem()),
+                offset.elem(),
+            ))
+        })
+}
+
+pub(crate) fn scaled_dot_product_attention<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    options: AttentionOptions,
+) -> FloatTensor<B, 4> {
+    let (_, dropped, _) = attention_weights::<B>(query, key, mask, &options);
+
+    B::float_matmul(dropped, value)
+}
+
+pub(crate) fn scaled_dot_product_attention_backward<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    grad: FloatTensor<B, 4>,
+    options: AttentionOptions,
+) -> AttentionBackward<B> {
+    let [_, _, _, d_k] = B::float_shape(&query).dims;
+    let scale = attention_scale(d_k, &options);
+    let (weights, dropped, dropout) =
+        attention_weights::<B>(query.clone(), key.clone(), mask, &options);
+
+    let value_grad = B::float_matmul(B::float_swap_dims(dropped, 2, 3), grad.clone());
+    let weights_grad = B::float_matmul(grad, B::float_swap_dims(value, 2, 3));
+    let weights_grad = match dropout {
+        Some(dropout) => B::float_mul(weights_grad, dropout),
+        None => weights_grad,
+    };
+
+    // Softmax backward: dS = P * (dP - sum(dP * P)).
+    let sum = B::float_sum_dim(B::float_mul(weights_grad.clone(), weights.clone()), 3);
+    let scores_grad = B::float_mul(weights, B::float_sub(weights_grad, sum));
+    let scores_grad = B::float_mul_scalar(scores_grad, scale.elem());
+
+    let query_grad = B::float_matmul(scores_grad.clone(), key);
+    let key_grad = B::float_matmul(B::float_swap_dims(scores_grad, 2, 3), query);
+
+    AttentionBackward::new(query_grad, key_grad, value_grad)
+}
+
+/// Computes the attention weights, the attention weights after the dropout, and the factors of
+/// the dropout when there is one.
+fn attention_weights<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    options: &AttentionOptions,
+) -> (
+    FloatTensor<B, 4>,
+    FloatTensor<B, 4>,
+    Option<FloatTensor<B, 4>>,
+) {
+    let device = B::float_device(&query);
+    let [batch_size, n_heads, seq_length_1, d_k] = B::float_shape(&query).dims;
+    let [_, _, seq_length_2, _] = B::float_shape(&key).dims;
+    let shape = Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]);
+    let scale = attention_scale(d_k, options);
+
+    let scores = B::float_matmul(query, B::float_swap_dims(key, 2, 3));
+    let scores = B::float_mul_scalar(scores, scale.elem());
+
+    let weights = match attention_mask::<B>(mask, &shape, options.causal, &device) {
+        Some(mask) => masked_softmax::<B>(scores, mask),
+        None => {
+            let max = B::float_max_dim(scores.clone(), 3);
+            let exp = B::float_exp(B::float_sub(scores, max));
+            let sum = B::float_sum_dim(exp.clone(), 3);
+            B::float_div(exp, sum)
+        }
+    };
+
+    if options.dropout <= 0.0 {
+        return (weights.clone(), weights, None);
+    }
+
+    let dropout = AttentionDropout::new(options.seed.unwrap_or(0), options.dropout)
+        .factors::<B>(&shape, &device);
+    let dropped = B::float_mul(weights.clone(), dropout.clone());
+
+    (weights, dropped, Some(dropout))
+}
+
+/// Combines the mask with the causal mask, broadcasting it to the shape of the attention weights.
+fn attention_mask<B: Backend>(
+    mask: Option<BoolTensor<B, 4>>,
+    shape: &Shape<4>,
+    causal: bool,
+    device: &Device<B>,
+) -> Option<BoolTensor<B, 4>> {
+    let [batch_size, n_heads, seq_length_1, seq_length_2] = shape.dims;
+
+    let broadcast = |mut mask: BoolTensor<B, 4>| {
+        let dims = B::bool_shape(&mask).dims;
+        for (dim, size) in [(0, batch_size), (1, n_heads)] {
+            if dims[dim] == 1 && size != 1 {
+                mask = B::bool_repeat(mask, dim, size);
+            }
+        }
+        mask
+    };
+
+    let causal = match causal {
+        true => {
+            // The key is hidden when its position is after the position of the query, the last
+            // query being aligned with the last key.
+            let offset = seq_length_2.saturating_sub(seq_length_1) as i64;
+            let queries = B::int_reshape(
+                B::int_arange(offset..offset + seq_length_1 as i64, device),
+                Shape::new([seq_length_1, 1]),
+            );
+            let keys = B::int_reshape(
+                B::int_arange(0..seq_length_2 as i64, device),
+                Shape::new([1, seq_length_2]),
+            );
+            let causal = B::int_greater_elem(B::int_sub(keys, queries), 0.elem());
+
+            Some(broadcast(B::bool_reshape(
+                causal,
+                Shape::new([1, 1, seq_length_1, seq_length_2]),
+            )))
+        }
+        false => None,
+    };
+
+    match (mask.map(broadcast), causal) {
+        (Some(mask), Some(causal)) => Some(B::bool_or(mask, causal)),
+        (mask, causal) => mask.or(causal),
+    }
+}
+
+/// Softmax over the keys that are not masked, the weights of a query whose keys are all masked
+/// being zero.
+fn masked_softmax<B: Backend>(
+    scores: FloatTensor<B, 4>,
+    mask: BoolTensor<B, 4>,
+) -> FloatTensor<B, 4> {
+    let [_, _, _, seq_length_2] = B::float_shape(&scores).dims;
+
+    // The masked scores are replaced by the smallest score of their row, so that the maximum is
+    // taken over the keys that are not masked without filling with an infinite value.
+    let min = B::float_repeat(B::float_min_dim(scores.clone(), 3), 3, seq_length_2);
+    let scores = B::float_mask_where(scores, mask.clone(), min);
+    let max = B::float_max_dim(scores.clone(), 3);
+
+    let keep = B::bool_into_float(B::bool_not(mask));
+    let exp = B::float_mul(B::float_exp(B::float_sub(scores, max)), keep);
+    let sum = B::float_sum_dim(exp.clone(), 3);
+    let empty = B::bool_into_float(B::float_equal_elem(sum.clone(), 0.elem()));
+
+    B::float_div(exp, B::float_add(sum, empty))
+}
@@ -1,7 +1,7 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, grid_sample, interpolate, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
     Shape,
 };
 
@@ -18,6 +18,19 @@ pub struct Conv2dBackward<B: Backend> {
     pub bias_grad: Option<FloatTensor<B, 1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [conv3d](ModuleOps::conv3d).
+#[derive(new)]
+pub struct Conv3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B, 5>,
+
+    /// Weights gradient.
+    pub weights_grad: FloatTensor<B, 5>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<FloatTensor<B, 1>>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
 #[derive(new)]
 pub struct MaxPool1dBackward<B: Backend> {
@@ -52,6 +65,48 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B, 4>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B, 5>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B, 5>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B, 5>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by
+/// [grid_sample_2d](ModuleOps::grid_sample_2d).
+#[derive(new)]
+pub struct GridSampleBackward<B: Backend> {
+    /// Gradient of the input.
+    pub x_grad: FloatTensor<B, 4>,
+
+    /// Gradient of the grid.
+    pub grid_grad: FloatTensor<B, 4>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by
+/// [scaled_dot_product_attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Gradient of the queries.
+    pub query_grad: FloatTensor<B, 4>,
+
+    /// Gradient of the keys.
+    pub key_grad: FloatTensor<B, 4>,
+
+    /// Gradient of the values.
+    pub value_grad: FloatTensor<B, 4>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [conv1d](ModuleOps::conv1d).
 #[derive(new)]
 pub struct Conv1dBackward<B: Backend> {
@@ -115,11 +170,16 @@ pub struct UnfoldOptions {
 }
 
 /// Algorithm used for upsampling.
+#[derive(Debug, Clone)]
 pub enum InterpolateMode {
     /// Nearest-neighbor interpolation.
     /// <https://en.wikipedia.org/wiki/Nearest-neighbor_interpolation>
     Nearest,
 
+    /// Linear interpolation, for tensors with one spatial dimension.
+    /// <https://en.wikipedia.org/wiki/Linear_interpolation>
+    Linear,
+
     /// Bilinear interpolation.
     /// <https://en.wikipedia.org/wiki/Bilinear_interpolation>
     Bilinear,
@@ -127,13 +187,152 @@ pub enum InterpolateMode {
     /// Bicubic interpolation.
     /// <https://en.wikipedia.org/wiki/Bicubic_interpolation>
     Bicubic,
+
+    /// Trilinear interpolation, for tensors with three spatial dimensions.
+    /// <https://en.wikipedia.org/wiki/Trilinear_interpolation>
+    Trilinear,
+
+    /// Averages the input values covered by each output value, like an adaptive average pooling.
+    Area,
 }
 
 /// Interpolation options.
-#[derive(new)]
+#[derive(new, Debug, Clone)]
 pub struct InterpolateOptions {
     /// Algorithm used for upsampling.
     pub mode: InterpolateMode,
+
+    /// If the corner values of the input and the output are aligned, instead of the corners of
+    /// their corner pixels. Only used by the linear and cubic modes.
+    ///
+    /// Defaults to `true`, while PyTorch defaults to `false`.
+    #[new(value = "true")]
+    pub align_corners: bool,
+
+    /// If an antialiasing filter is applied when downsampling. Only used by the linear and cubic
+    /// modes.
+    #[new(value = "false")]
+    pub antialias: bool,
+}
+
+impl InterpolateOptions {
+    /// Sets if the corner values of the input and the output are aligned.
+    pub fn with_align_corners(mut self, align_corners: bool) -> Self {
+        self.align_corners = align_corners;
+        self
+    }
+
+    /// Sets if an antialiasing filter is applied when downsampling.
+    pub fn with_antialias(mut self, antialias: bool) -> Self {
+        self.antialias = antialias;
+        self
+    }
+}
+
+/// Algorithm used to compute the input values at the grid positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    /// Bilinear interpolation of the four nearest input values.
+    Bilinear,
+
+    /// Nearest input value, rounding half to even.
+    Nearest,
+}
+
+/// How the grid positions outside of the input are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// The input values outside of the input are zeros.
+    Zeros,
+
+    /// The positions are clamped to the border of the input.
+    Border,
+
+    /// The positions are reflected by the border of the input until they are inside of it.
+    Reflection,
+}
+
+/// Grid sample options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// Algorithm used to compute the input values at the grid positions.
+    pub mode: GridSampleMode,
+
+    /// How the grid positions outside of the input are handled.
+    #[new(value = "GridSamplePaddingMode::Zeros")]
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If the grid positions -1 and 1 are the centers of the corner input values, instead of the
+    /// outer edges of the corner input values.
+    #[new(value = "false")]
+    pub align_corners: bool,
+}
+
+impl GridSampleOptions {
+    /// Sets how the grid positions outside of the input are handled.
+    pub fn with_padding_mode(mut self, padding_mode: GridSamplePaddingMode) -> Self {
+        self.padding_mode = padding_mode;
+        self
+    }
+
+    /// Sets if the grid positions -1 and 1 are the centers of the corner input values.
+    pub fn with_align_corners(mut self, align_corners: bool) -> Self {
+        self.align_corners = align_corners;
+        self
+    }
+}
+
+/// Scaled dot-product attention options.
+#[derive(new, Debug, Clone)]
+pub struct AttentionOptions {
+    /// The factor multiplying the dot products of the queries and the keys, `1 / sqrt(d_k)` when
+    /// it is `None`.
+    #[new(default)]
+    pub scale: Option<f64>,
+
+    /// If each query only attends to the keys up to its own position, the last query being
+    /// aligned with the last key.
+    #[new(value = "false")]
+    pub causal: bool,
+
+    /// The probability of dropping each attention weight.
+    #[new(value = "0.0")]
+    pub dropout: f64,
+
+    /// The seed of the attention weights dropped, so that the backward pass drops the same
+    /// weights as the forward pass. It is drawn from the backend's random generator by
+    /// [the tensor API](crate::module::scaled_dot_product_attention) when it is `None`.
+    ///
+    /// The weights dropped for a seed are given by [the attention dropout](super::attention::AttentionDropout),
+    /// which doesn't use the random generator of the backend.
+    #[new(default)]
+    pub seed: Option<u64>,
+}
+
+impl AttentionOptions {
+    /// Sets the factor multiplying the dot products of the queries and the keys.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Sets if each query only attends to the keys up to its own position.
+    pub fn with_causal(mut self, causal: bool) -> Self {
+        self.causal = causal;
+        self
+    }
+
+    /// Sets the probability of dropping each attention weight.
+    pub fn with_dropout(mut self, dropout: f64) -> Self {
+        self.dropout = dropout;
+        self
+    }
+
+    /// Sets the seed of the attention weights dropped.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
 }
 
 /// Module operations trait.
@@ -223,6 +422,53 @@ pub trait ModuleOps<B: Backend> {
         bias: Option<FloatTensor<B, 1>>,
         options: ConvOptions<2>,
     ) -> FloatTensor<B, 4>;
+    /// Two dimensional convolution of int tensors, accumulating the products in integers.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2]`,
+    /// bias:   `[channels_out]`,
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation convolves the tensors converted to floats, which is exact as
+    /// long as the accumulated values fit in the mantissa of the float element type.
+    fn int_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::float_into_int(B::conv2d(
+            B::int_into_float(x),
+            B::int_into_float(weight),
+            bias.map(B::int_into_float),
+            options,
+        ))
+    }
+    /// Two dimensional convolution of int tensors whose values are between -128 and 127, such as
+    /// the values of [quantized](crate::Quantized) tensors.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2]`,
+    /// bias:   `[channels_out]`,
+    ///
+    /// # Remarks
+    ///
+    /// Backends can accumulate the products in 32-bit integers, which can't overflow as long as
+    /// each output sums less than 2<sup>17</sup> products. The default implementation calls
+    /// [int_conv2d](ModuleOps::int_conv2d).
+    fn int8_conv2d(
+        x: IntTensor<B, 4>,
+        weight: IntTensor<B, 4>,
+        bias: Option<IntTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> IntTensor<B, 4> {
+        B::int_conv2d(x, weight, bias, options)
+    }
     /// Backward pass for the [conv2d](ModuleOps::conv2d) operation.
     fn conv2d_backward(
         x: FloatTensor<B, 4>,
@@ -233,6 +479,31 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Three dimensional convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv3d(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<B, 5> {
+        conv::conv3d_from_conv2d::<B>(x, weight, bias, options)
+    }
+    /// Backward pass for the [conv3d](ModuleOps::conv3d) operation.
+    fn conv3d_backward(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        output_grad: FloatTensor<B, 5>,
+        options: ConvOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv3d_backward(x, weight, bias, output_grad, options)
+    }
     /// One dimensional transposed convolution.
     ///
     /// # Shapes
@@ -283,6 +554,33 @@ pub trait ModuleOps<B: Backend> {
         conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
     }
 
+    /// Three dimensional transposed convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv_transpose3d(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<B, 5> {
+        conv::conv_transpose3d_from_conv_transpose2d::<B>(x, weight, bias, options)
+    }
+
+    /// Backward pass for the [conv transpose 3d](ModuleOps::conv_transpose3d) operation.
+    fn conv_transpose3d_backward(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        output_grad: FloatTensor<B, 5>,
+        options: ConvTransposeOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv_transpose3d_backward(x, weight, bias, output_grad, options)
+    }
+
     /// Four-dimensional unfolding.
     ///
     /// # Shapes
@@ -350,6 +648,38 @@ pub trait ModuleOps<B: Backend> {
         padding: [usize; 2],
         count_include_pad: bool,
     ) -> FloatTensor<B, 4>;
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, 5> {
+        pool::avg_pool3d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: FloatTensor<B, 5>,
+        grad: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, 5> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
     /// Two dimensional adaptive avg pooling.
     ///
     /// # Shapes
@@ -376,6 +706,21 @@ pub trait ModuleOps<B: Backend> {
     ) -> FloatTensor<B, 3> {
         pool::adaptive_avg_pool1d_backward_from_2d::<B>(x, grad)
     }
+    /// Three dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_avg_pool3d(x: FloatTensor<B, 5>, output_size: [usize; 3]) -> FloatTensor<B, 5> {
+        pool::adaptive_avg_pool3d_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 3d](ModuleOps::adaptive_avg_pool3d) operation.
+    fn adaptive_avg_pool3d_backward(
+        x: FloatTensor<B, 5>,
+        grad: FloatTensor<B, 5>,
+    ) -> FloatTensor<B, 5> {
+        pool::adaptive_avg_pool3d_backward_from_2d::<B>(x, grad)
+    }
     /// One dimensional max pooling.
     ///
     /// # Shapes
@@ -462,6 +807,58 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<B, 5> {
+        pool::max_pool3d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// The indices are flattened over the depth, height and width of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_1d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<B, 5>,
+        indices: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_with_indices_backward_from_scatter::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        )
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -472,4 +869,122 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B, 4>;
+
+    /// Backward pass for the [interpolate](ModuleOps::interpolate) operation.
+    fn interpolate_backward(
+        x: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 4> {
+        interpolate::interpolate_backward_from_matmul::<B>(x, grad, output_size, options)
+    }
+
+    /// Down/up samples the input with one spatial dimension.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, length]`,
+    fn interpolate1d(
+        x: FloatTensor<B, 3>,
+        output_size: usize,
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 3> {
+        interpolate::interpolate1d_from_2d::<B>(x, output_size, options)
+    }
+
+    /// Down/up samples the input with three spatial dimensions.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, depth, height, width]`,
+    fn interpolate3d(
+        x: FloatTensor<B, 5>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 5> {
+        interpolate::interpolate3d_from_2d::<B>(x, output_size, options)
+    }
+
+    /// Samples the input at the positions of the grid, normalized to `[-1, 1]` where `-1` is the
+    /// left or top of the input and `1` is its right or bottom.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`, where the last dimension holds the `x` and
+    /// `y` positions,
+    /// output: `[batch_size, channels, height_out, width_out]`.
+    fn grid_sample_2d(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::grid_sample_2d::<B>(x, grid, options)
+    }
+
+    /// Backward pass for the [grid sample 2d](ModuleOps::grid_sample_2d) operation.
+    fn grid_sample_2d_backward(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<B> {
+        grid_sample::grid_sample_2d_backward::<B>(x, grid, grad, options)
+    }
+
+    /// Generates the grid of the positions of an output of the given size transformed by the
+    /// affine matrices, to be sampled with [grid sample 2d](ModuleOps::grid_sample_2d).
+    ///
+    /// # Shapes
+    ///
+    /// theta: `[batch_size, 2, 3]`,
+    /// size: `[batch_size, channels, height, width]`,
+    /// output: `[batch_size, height, width, 2]`.
+    fn affine_grid_2d(
+        theta: FloatTensor<B, 3>,
+        size: [usize; 4],
+        align_corners: bool,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::affine_grid_2d::<B>(theta, size, align_corners)
+    }
+
+    /// Computes the attention of the queries over the keys, `softmax(scale * query key^T) value`,
+    /// ignoring the masked keys.
+    ///
+    /// The masked keys are those where the mask is `true`, and a query whose keys are all masked
+    /// has a zero output.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key: `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value: `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// mask: `[batch_size, n_heads, seq_length_1, seq_length_2]`, where the batch and head
+    /// dimensions can be one to be broadcasted,
+    /// output: `[batch_size, n_heads, seq_length_1, d_v]`.
+    fn scaled_dot_product_attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<B, 4> {
+        attention::scaled_dot_product_attention::<B>(query, key, value, mask, options)
+    }
+
+    /// Backward pass for the [scaled dot-product attention](ModuleOps::scaled_dot_product_attention)
+    /// operation, which computes the attention weights again instead of storing them.
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        grad: FloatTensor<B, 4>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<B> {
+        attention::scaled_dot_product_attention_backward::<B>(
+            query, key, value, mask, grad, options,
+        )
+    }
 }
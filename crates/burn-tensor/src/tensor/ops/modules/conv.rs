@@ -1,6 +1,12 @@
 #![allow(clippy::single_range_in_vec_init)]
-use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
-use crate::{backend::Backend, ops::FloatTensor, Shape};
+use super::{Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTransposeOptions};
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Data, Shape,
+};
+use alloc::vec;
+use alloc::vec::Vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -186,6 +192,88 @@ pub(crate) fn conv2d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [3D convolution](crate::ops::ModuleOps::conv3d) backward pass using convolutions.
+pub(crate) fn conv3d_backward<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    output_grad: FloatTensor<B, 5>,
+    options: ConvOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::float_shape(&weight);
+    let weight_device = B::float_device(&weight);
+
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&output_grad).dims;
+    let [channels_out, _, kernel_size_1, kernel_size_2, kernel_size_3] = weight_shape.dims;
+
+    let padding_1_out = calculate_padding_out(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+        depth_out,
+    );
+    let padding_2_out = calculate_padding_out(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        height_in,
+        height_out,
+    );
+    let padding_3_out = calculate_padding_out(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        width_in,
+        width_out,
+    );
+
+    let x_grad = B::conv_transpose3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            [padding_1_out, padding_2_out, padding_3_out],
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv3d_weight_grad_no_groups::<B>(x, output_grad.clone(), weight_shape, options),
+        false => conv3d_weight_grad_groups::<B>(
+            x,
+            B::float_zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::float_swap_dims(output_grad, 0, 1);
+            let grad = B::float_reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::float_sum_dim(grad, 1);
+
+            B::float_reshape(grad, B::float_shape(&b))
+        }),
+    )
+}
+
 /// Calculate the [2D convolution transpose](crate::ops::ModuleOps::conv_transpose2d) backward pass using convolutions.
 pub(crate) fn conv_transpose2d_backward<B: Backend>(
     x: FloatTensor<B, 4>,
@@ -243,6 +331,66 @@ pub(crate) fn conv_transpose2d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [3D convolution transpose](crate::ops::ModuleOps::conv_transpose3d) backward pass using convolutions.
+pub(crate) fn conv_transpose3d_backward<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    output_grad: FloatTensor<B, 5>,
+    options: ConvTransposeOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::float_shape(&weight);
+    let weight_device = B::float_device(&weight);
+
+    let [batch_size, _channels_in, _, _, _] = B::float_shape(&x).dims;
+    let [_, channels_out, depth_out, height_out, width_out] = B::float_shape(&output_grad).dims;
+
+    let x_grad = B::conv3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv_transpose3d_weight_grad_no_groups::<B>(
+            x,
+            output_grad.clone(),
+            weight_shape,
+            options,
+        ),
+        false => conv_transpose3d_weight_grad_groups::<B>(
+            x,
+            B::float_zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::float_swap_dims(output_grad, 0, 1);
+            let grad = B::float_reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::float_sum_dim(grad, 1);
+
+            B::float_reshape(grad, B::float_shape(&b))
+        }),
+    )
+}
+
 /// Calculate the [1D convolution transpose](crate::ops::ModuleOps::conv_transpose1d) backward pass using convolutions.
 pub(crate) fn conv_transpose1d_backward<B: Backend>(
     x: FloatTensor<B, 3>,
@@ -360,6 +508,236 @@ pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     B::float_reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D convolution using 2D convolutions, one for each depth position of the kernel.
+pub(crate) fn conv3d_from_conv2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_out, channels_per_group, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::float_shape(&weight).dims;
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let device = B::float_device(&x);
+
+    let depth_out = calculate_conv_output_size(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+    );
+    let height_out = calculate_conv_output_size(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        height_in,
+    );
+    let width_out = calculate_conv_output_size(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        width_in,
+    );
+
+    let x = pad_depth::<B>(x, options.padding[0]);
+    let mut output = B::float_zeros(
+        Shape::new([batch_size * depth_out, channels_out, height_out, width_out]),
+        &device,
+    );
+
+    for k in 0..kernel_size_1 {
+        // The input planes seen by the k-th plane of the kernel for each output plane.
+        let indices = (0..depth_out)
+            .map(|d| (d * options.stride[0] + k * options.dilation[0]) as i64)
+            .collect();
+        let x = B::float_select(x.clone(), 2, int_indices::<B>(indices, &device));
+        let x = B::float_swap_dims(x, 1, 2);
+        let x = B::float_reshape(
+            x,
+            Shape::new([batch_size * depth_out, channels_in, height_in, width_in]),
+        );
+
+        let weight = B::float_slice(
+            weight.clone(),
+            [
+                0..channels_out,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        let weight = B::float_reshape(
+            weight,
+            Shape::new([
+                channels_out,
+                channels_per_group,
+                kernel_size_2,
+                kernel_size_3,
+            ]),
+        );
+
+        let tensor = B::conv2d(
+            x,
+            weight,
+            None,
+            ConvOptions::new(
+                [options.stride[1], options.stride[2]],
+                [options.padding[1], options.padding[2]],
+                [options.dilation[1], options.dilation[2]],
+                options.groups,
+            ),
+        );
+        output = B::float_add(output, tensor);
+    }
+
+    let output = B::float_reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels_out, height_out, width_out]),
+    );
+    let output = B::float_swap_dims(output, 1, 2);
+
+    add_bias::<B>(output, bias)
+}
+
+/// Execute a 3D transposed convolution using 2D transposed convolutions, one for each depth
+/// position of the kernel.
+pub(crate) fn conv_transpose3d_from_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_in, channels_per_group, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::float_shape(&weight).dims;
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let channels_out = channels_per_group * options.groups;
+    let device = B::float_device(&x);
+
+    let depth_out = calculate_conv_transpose_output_size(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.padding_out[0],
+        options.dilation[0],
+        depth_in,
+    );
+    let height_out = calculate_conv_transpose_output_size(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.padding_out[1],
+        options.dilation[1],
+        height_in,
+    );
+    let width_out = calculate_conv_transpose_output_size(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.padding_out[2],
+        options.dilation[2],
+        width_in,
+    );
+    // The output depth before the padding is removed.
+    let depth_full = depth_out + 2 * options.padding[0];
+
+    let x = B::float_swap_dims(x, 1, 2);
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size * depth_in, channels_in, height_in, width_in]),
+    );
+    let mut output = B::float_zeros(
+        Shape::new([batch_size, depth_full, channels_out, height_out, width_out]),
+        &device,
+    );
+
+    for k in 0..kernel_size_1 {
+        let weight = B::float_slice(
+            weight.clone(),
+            [
+                0..channels_in,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        let weight = B::float_reshape(
+            weight,
+            Shape::new([
+                channels_in,
+                channels_per_group,
+                kernel_size_2,
+                kernel_size_3,
+            ]),
+        );
+
+        let tensor = B::conv_transpose2d(
+            x.clone(),
+            weight,
+            None,
+            ConvTransposeOptions::new(
+                [options.stride[1], options.stride[2]],
+                [options.padding[1], options.padding[2]],
+                [options.padding_out[1], options.padding_out[2]],
+                [options.dilation[1], options.dilation[2]],
+                options.groups,
+            ),
+        );
+        let tensor = B::float_reshape(
+            tensor,
+            Shape::new([batch_size, depth_in, channels_out, height_out, width_out]),
+        );
+
+        // The output planes reached by the k-th plane of the kernel for each input plane.
+        let indices = (0..depth_in)
+            .map(|d| (d * options.stride[0] + k * options.dilation[0]) as i64)
+            .collect();
+        output = B::float_select_assign(output, 1, int_indices::<B>(indices, &device), tensor);
+    }
+
+    let output = B::float_narrow(output, 1, options.padding[0], depth_out);
+    let output = B::float_swap_dims(output, 1, 2);
+
+    add_bias::<B>(output, bias)
+}
+
+fn pad_depth<B: Backend>(x: FloatTensor<B, 5>, padding: usize) -> FloatTensor<B, 5> {
+    if padding == 0 {
+        return x;
+    }
+
+    let mut shape = B::float_shape(&x);
+    shape.dims[2] = padding;
+    let zeros = B::float_zeros(shape, &B::float_device(&x));
+
+    B::float_cat(vec![zeros.clone(), x, zeros], 2)
+}
+
+fn int_indices<B: Backend>(indices: Vec<i64>, device: &B::Device) -> IntTensor<B, 1> {
+    let shape = Shape::new([indices.len()]);
+
+    B::int_from_data(Data::new(indices, shape).convert(), device)
+}
+
+fn add_bias<B: Backend>(
+    output: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+) -> FloatTensor<B, 5> {
+    match bias {
+        Some(bias) => {
+            let [channels_out] = B::float_shape(&bias).dims;
+            let bias = B::float_reshape(bias, Shape::new([1, channels_out, 1, 1, 1]));
+
+            B::float_add(output, bias)
+        }
+        None => output,
+    }
+}
+
 fn conv1d_weight_grad_groups<B: Backend>(
     x: FloatTensor<B, 3>,
     mut weight_grad: FloatTensor<B, 3>,
@@ -497,6 +875,130 @@ fn conv_transpose2d_weight_grad_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_groups<B: Backend>(
+    x: FloatTensor<B, 5>,
+    mut weight_grad: FloatTensor<B, 5>,
+    output_grad: FloatTensor<B, 5>,
+    options: ConvOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_out, increment_ci, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::float_shape(&weight_grad).dims;
+    let increment_co = channels_out / options.groups;
+
+    let x_swapped = B::float_swap_dims(x, 0, 1);
+    let output_grad_swapped = B::float_swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::float_slice(x_swapped.clone(), [start_idx_ci..end_idx_ci]);
+        let grad = B::float_slice(output_grad_swapped.clone(), [start_idx_co..end_idx_co]);
+        let mut weight_grad_tmp = B::conv3d(
+            x,
+            grad,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::float_swap_dims(weight_grad_tmp, 0, 1);
+        let [_, _, kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp] =
+            B::float_shape(&weight_grad_tmp).dims;
+
+        if [kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp]
+            != [kernel_size_1, kernel_size_2, kernel_size_3]
+        {
+            weight_grad_tmp = B::float_slice(
+                weight_grad_tmp,
+                [
+                    0..increment_co,
+                    0..increment_ci,
+                    0..kernel_size_1,
+                    0..kernel_size_2,
+                    0..kernel_size_3,
+                ],
+            );
+        }
+
+        weight_grad = B::float_slice_assign(
+            weight_grad,
+            [
+                start_idx_co..end_idx_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_groups<B: Backend>(
+    x: FloatTensor<B, 5>,
+    mut weight_grad: FloatTensor<B, 5>,
+    output_grad: FloatTensor<B, 5>,
+    options: ConvTransposeOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::float_shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::float_swap_dims(x, 0, 1);
+    let output_grad_swapped = B::float_swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::float_slice(x_swapped.clone(), [start_idx_ci..end_idx_ci]);
+        let grad = B::float_slice(output_grad_swapped.clone(), [start_idx_co..end_idx_co]);
+        let mut weight_grad_tmp = B::conv3d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::float_swap_dims(weight_grad_tmp, 0, 1);
+        let [_, _, kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp] =
+            B::float_shape(&weight_grad_tmp).dims;
+
+        if [kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp]
+            != [kernel_size_1, kernel_size_2, kernel_size_3]
+        {
+            weight_grad_tmp = B::float_slice(
+                weight_grad_tmp,
+                [
+                    0..increment_ci,
+                    0..increment_co,
+                    0..kernel_size_1,
+                    0..kernel_size_2,
+                    0..kernel_size_3,
+                ],
+            );
+        }
+
+        weight_grad = B::float_slice_assign(
+            weight_grad,
+            [
+                start_idx_ci..end_idx_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
 fn conv1d_weight_grad_no_groups<B: Backend>(
     x: FloatTensor<B, 3>,
     output_grad: FloatTensor<B, 3>,
@@ -665,6 +1167,68 @@ fn conv_transpose2d_weight_grad_no_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_no_groups<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_grad: FloatTensor<B, 5>,
+    weight_shape: Shape<5>,
+    options: ConvOptions<3>,
+) -> FloatTensor<B, 5> {
+    let x_swapped = B::float_swap_dims(x, 0, 1);
+    let output_grad_swapped = B::float_swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        x_swapped,
+        output_grad_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::float_swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::float_shape(&weight_grad) != weight_shape {
+        weight_grad = B::float_slice(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_no_groups<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_grad: FloatTensor<B, 5>,
+    weight_shape: Shape<5>,
+    options: ConvTransposeOptions<3>,
+) -> FloatTensor<B, 5> {
+    let x_swapped = B::float_swap_dims(x, 0, 1);
+    let output_grad_swapped = B::float_swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        output_grad_swapped,
+        x_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::float_swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::float_shape(&weight_grad) != weight_shape {
+        weight_grad = B::float_slice(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
 fn calculate_padding_out(
     kernel_size: usize,
     stride: usize,
@@ -0,0 +1,377 @@
+use super::{GridSampleBackward, GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+use crate::{backend::Backend, ops::FloatTensor, Data, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+const READ_SYNC_ERROR: &str = "Failed to synchronously read tensor data. \
+    This operation is not supported until this backend has a native grid sample implementation.";
+
+/// An input position contributing to an output position, with its weight and the derivatives
+/// of its weight with respect to the grid position.
+struct Tap {
+    index: usize,
+    weight: f64,
+    weight_dx: f64,
+    weight_dy: f64,
+}
+
+/// The input positions contributing to each output position of a grid sample.
+struct Sampling {
+    taps: Vec<Vec<Tap>>,
+    /// The derivatives of the input coordinates with respect to the grid positions.
+    coordinates_grad: Vec<(f64, f64)>,
+}
+
+impl Sampling {
+    /// Computes the taps of each output position, where `grid` is `[batch_size, height_out,
+    /// width_out, 2]` and the indices are within a `[height, width]` input.
+    fn new(grid: &[f64], height: usize, width: usize, options: &GridSampleOptions) -> Self {
+        let (taps, coordinates_grad) = grid
+            .chunks(2)
+            .map(|position| {
+                let (x, x_grad) = source_index(position[0], width, options);
+                let (y, y_grad) = source_index(position[1], height, options);
+                let taps = match options.mode {
+                    GridSampleMode::Bilinear => bilinear_taps(x, y, height, width),
+                    GridSampleMode::Nearest => nearest_taps(x, y, height, width),
+                };
+
+                (taps, (x_grad, y_grad))
+            })
+            .unzip();
+
+        Self {
+            taps,
+            coordinates_grad,
+        }
+    }
+}
+
+/// The input coordinate of a grid position and its derivative with respect to the grid position.
+fn source_index(position: f64, size: usize, options: &GridSampleOptions) -> (f64, f64) {
+    let (coordinate, scale) = match options.align_corners {
+        true => (
+            (position + 1.0) / 2.0 * (size - 1) as f64,
+            (size - 1) as f64 / 2.0,
+        ),
+        false => (
+            ((position + 1.0) * size as f64 - 1.0) / 2.0,
+            size as f64 / 2.0,
+        ),
+    };
+
+    let (coordinate, grad) = match options.padding_mode {
+        GridSamplePaddingMode::Zeros => (coordinate, 1.0),
+        GridSamplePaddingMode::Border => clip(coordinate, size),
+        GridSamplePaddingMode::Reflection => {
+            let (coordinate, reflect_grad) = match options.align_corners {
+                true => reflect(coordinate, 0, 2 * (size as i64 - 1)),
+                false => reflect(coordinate, -1, 2 * size as i64 - 1),
+            };
+            let (coordinate, clip_grad) = clip(coordinate, size);
+
+            (coordinate, reflect_grad * clip_grad)
+        }
+    };
+
+    (coordinate, scale * grad)
+}
+
+/// Clamps the coordinate to the input.
+fn clip(coordinate: f64, size: usize) -> (f64, f64) {
+    let max = (size - 1) as f64;
+
+    if coordinate <= 0.0 {
+        (0.0, 0.0)
+    } else if coordinate >= max {
+        (max, 0.0)
+    } else {
+        (coordinate, 1.0)
+    }
+}
+
+/// Reflects the coordinate by the bounds, given doubled to stay integers, until it is between them.
+fn reflect(coordinate: f64, twice_low: i64, twice_high: i64) -> (f64, f64) {
+    if twice_low == twice_high {
+        return (0.0, 0.0);
+    }
+
+    let min = twice_low as f64 / 2.0;
+    let span = (twice_high - twice_low) as f64 / 2.0;
+    let (distance, grad) = match coordinate < min {
+        true => (min - coordinate, -1.0),
+        false => (coordinate - min, 1.0),
+    };
+
+    let extra = distance % span;
+    let flips = (distance / span).floor() as i64;
+
+    match flips % 2 == 0 {
+        true => (extra + min, grad),
+        false => (span - extra + min, -grad),
+    }
+}
+
+fn bilinear_taps(x: f64, y: f64, height: usize, width: usize) -> Vec<Tap> {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let (dx, dy) = (x - x0, y - y0);
+
+    [
+        (x0, y0, (1.0 - dx) * (1.0 - dy), -(1.0 - dy), -(1.0 - dx)),
+        (x0 + 1.0, y0, dx * (1.0 - dy), 1.0 - dy, -dx),
+        (x0, y0 + 1.0, (1.0 - dx) * dy, -dy, 1.0 - dx),
+        (x0 + 1.0, y0 + 1.0, dx * dy, dy, dx),
+    ]
+    .into_iter()
+    .filter_map(|(x, y, weight, weight_dx, weight_dy)| {
+        index(x, y, height, width).map(|index| Tap {
+            index,
+            weight,
+            weight_dx,
+            weight_dy,
+        })
+    })
+    .collect()
+}
+
+fn nearest_taps(x: f64, y: f64, height: usize, width: usize) -> Vec<Tap> {
+    index(round_half_even(x), round_half_even(y), height, width)
+        .map(|index| Tap {
+            index,
+            weight: 1.0,
+            weight_dx: 0.0,
+            weight_dy: 0.0,
+        })
+        .into_iter()
+        .collect()
+}
+
+fn round_half_even(value: f64) -> f64 {
+    let rounded = value.round();
+
+    if (value - value.trunc()).abs() == 0.5 {
+        2.0 * (value / 2.0).round()
+    } else {
+        rounded
+    }
+}
+
+/// The index of the position in a `[height, width]` input, if it is inside of it.
+fn index(x: f64, y: f64, height: usize, width: usize) -> Option<usize> {
+    if x < 0.0 || y < 0.0 || x >= width as f64 || y >= height as f64 {
+        return None;
+    }
+
+    Some(y as usize * width + x as usize)
+}
+
+/// Samples the input at the positions of the grid.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn grid_sample_2d<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let device = B::float_device(&x);
+    let [batch_size, channels, height, width] = B::float_shape(&x).dims;
+    let [_, height_out, width_out, _] = B::float_shape(&grid).dims;
+    let sampling = Sampling::new(&read::<B>(grid), height, width, &options);
+    let x = read::<B>(x);
+
+    let positions_out = height_out * width_out;
+    let mut output = vec![0.0; batch_size * channels * positions_out];
+
+    for (k, output) in output.chunks_mut(positions_out).enumerate() {
+        let b = k / channels;
+        let x = &x[k * height * width..(k + 1) * height * width];
+        let taps = &sampling.taps[b * positions_out..(b + 1) * positions_out];
+
+        for (output, taps) in output.iter_mut().zip(taps) {
+            *output = taps.iter().map(|tap| x[tap.index] * tap.weight).sum();
+        }
+    }
+
+    write::<B, 4>(
+        output,
+        Shape::new([batch_size, channels, height_out, width_out]),
+        &device,
+    )
+}
+
+/// Computes the gradients of [grid sample 2d](super::ModuleOps::grid_sample_2d) with respect to
+/// the input and the grid.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn grid_sample_2d_backward<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> GridSampleBackward<B> {
+    let device = B::float_device(&x);
+    let shape_x = B::float_shape(&x);
+    let shape_grid = B::float_shape(&grid);
+    let [_, channels, height, width] = shape_x.dims;
+    let [_, height_out, width_out, _] = shape_grid.dims;
+    let sampling = Sampling::new(&read::<B>(grid), height, width, &options);
+    let x = read::<B>(x);
+    let grad = read::<B>(grad);
+
+    let positions = height * width;
+    let positions_out = height_out * width_out;
+    let mut x_grad = vec![0.0; x.len()];
+    let mut grid_grad = vec![0.0; sampling.taps.len() * 2];
+
+    for (k, grad) in grad.chunks(positions_out).enumerate() {
+        let b = k / channels;
+        let x = &x[k * positions..(k + 1) * positions];
+        let x_grad = &mut x_grad[k * positions..(k + 1) * positions];
+
+        for (position, grad) in grad.iter().enumerate() {
+            let position = b * positions_out + position;
+
+            for tap in sampling.taps[position].iter() {
+                x_grad[tap.index] += tap.weight * grad;
+                grid_grad[2 * position] += x[tap.index] * tap.weight_dx * grad;
+                grid_grad[2 * position + 1] += x[tap.index] * tap.weight_dy * grad;
+            }
+        }
+    }
+
+    for (grid_grad, (x_grad, y_grad)) in grid_grad
+        .chunks_mut(2)
+        .zip(sampling.coordinates_grad.iter())
+    {
+        grid_grad[0] *= x_grad;
+        grid_grad[1] *= y_grad;
+    }
+
+    GridSampleBackward::new(
+        write::<B, 4>(x_grad, shape_x, &device),
+        write::<B, 4>(grid_grad, shape_grid, &device),
+    )
+}
+
+/// Computes the derivative of [grid sample 2d](super::ModuleOps::grid_sample_2d) in the
+/// direction of the grid tangent, the input being constant.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn grid_sample_2d_grid_jvp<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    grid_tangent: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let device = B::float_device(&x);
+    let [batch_size, channels, height, width] = B::float_shape(&x).dims;
+    let [_, height_out, width_out, _] = B::float_shape(&grid).dims;
+    let sampling = Sampling::new(&read::<B>(grid), height, width, &options);
+    let grid_tangent = read::<B>(grid_tangent);
+    let x = read::<B>(x);
+
+    let positions_out = height_out * width_out;
+    let mut output = vec![0.0; batch_size * channels * positions_out];
+
+    for (k, output) in output.chunks_mut(positions_out).enumerate() {
+        let b = k / channels;
+        let x = &x[k * height * width..(k + 1) * height * width];
+
+        for (position, output) in output.iter_mut().enumerate() {
+            let position = b * positions_out + position;
+            let (x_grad, y_grad) = sampling.coordinates_grad[position];
+            let tangent_x = grid_tangent[2 * position] * x_grad;
+            let tangent_y = grid_tangent[2 * position + 1] * y_grad;
+
+            *output = sampling.taps[position]
+                .iter()
+                .map(|tap| x[tap.index] * (tap.weight_dx * tangent_x + tap.weight_dy * tangent_y))
+                .sum();
+        }
+    }
+
+    write::<B, 4>(
+        output,
+        Shape::new([batch_size, channels, height_out, width_out]),
+        &device,
+    )
+}
+
+/// Generates the grid of the positions of an output of the given size transformed by the affine
+/// matrices, as the product of the untransformed positions and the matrices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn affine_grid_2d<B: Backend>(
+    theta: FloatTensor<B, 3>,
+    size: [usize; 4],
+    align_corners: bool,
+) -> FloatTensor<B, 4> {
+    let device = B::float_device(&theta);
+    let [batch_size, _, height, width] = size;
+
+    let positions = |size: usize| -> Vec<f64> {
+        (0..size)
+            .map(|i| match align_corners {
+                true if size > 1 => 2.0 * i as f64 / (size - 1) as f64 - 1.0,
+                true => 0.0,
+                false => (2 * i + 1) as f64 / size as f64 - 1.0,
+            })
+            .collect()
+    };
+    let (xs, ys) = (positions(width), positions(height));
+
+    // The homogeneous coordinates `[x, y, 1]` of each output position.
+    let mut base = Vec::with_capacity(batch_size * height * width * 3);
+    for _ in 0..batch_size {
+        for y in ys.iter() {
+            for x in xs.iter() {
+                base.extend([*x, *y, 1.0]);
+            }
+        }
+    }
+
+    let base = write::<B, 3>(base, Shape::new([batch_size, height * width, 3]), &device);
+    let grid = B::float_matmul(base, B::float_swap_dims(theta, 1, 2));
+
+    B::float_reshape(grid, Shape::new([batch_size, height, width, 2]))
+}
+
+fn read<B: Backend>(tensor: FloatTensor<B, 4>) -> Vec<f64> {
+    B::float_into_data(tensor)
+        .read_sync()
+        .expect(READ_SYNC_ERROR)
+        .convert::<f64>()
+        .value
+}
+
+fn write<B: Backend, const D: usize>(
+    values: Vec<f64>,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> FloatTensor<B, D> {
+    B::float_from_data(Data::new(values, shape).convert(), device)
+}
@@ -0,0 +1,325 @@
+use super::{InterpolateMode, InterpolateOptions};
+use crate::{backend::Backend, ops::FloatTensor, Data, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// The input positions an output position is interpolated from, with their weights.
+pub type InterpolateTaps = Vec<(usize, f64)>;
+
+/// Computes how each position of an interpolated dimension is obtained from the input positions.
+///
+/// Interpolation is separable, so the interpolation of a tensor is the successive interpolation
+/// of each of its spatial dimensions.
+///
+/// # Arguments
+///
+/// * `input_size` - The size of the dimension before the interpolation.
+/// * `output_size` - The size of the dimension after the interpolation.
+/// * `options` - The interpolation options.
+///
+/// # Returns
+///
+/// For each output position, the input positions and their weights.
+pub fn interpolate_taps(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Vec<InterpolateTaps> {
+    match options.mode {
+        InterpolateMode::Nearest => nearest_taps(input_size, output_size),
+        InterpolateMode::Area => area_taps(input_size, output_size),
+        InterpolateMode::Linear | InterpolateMode::Bilinear | InterpolateMode::Trilinear => {
+            if options.antialias {
+                antialias_taps(input_size, output_size, options.align_corners, 1.0, |x| {
+                    f64::max(1.0 - x.abs(), 0.0)
+                })
+            } else {
+                linear_taps(input_size, output_size, options.align_corners)
+            }
+        }
+        InterpolateMode::Bicubic => {
+            if options.antialias {
+                antialias_taps(input_size, output_size, options.align_corners, 2.0, |x| {
+                    cubic_filter(x, -0.5)
+                })
+            } else {
+                cubic_taps(input_size, output_size, options.align_corners)
+            }
+        }
+    }
+}
+
+/// The distance between two consecutive output positions, in input positions.
+fn interpolate_scale(input_size: usize, output_size: usize, align_corners: bool) -> f64 {
+    if !align_corners {
+        input_size as f64 / output_size as f64
+    } else if output_size > 1 {
+        (input_size - 1) as f64 / (output_size - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+/// The input coordinate of an output position.
+fn source_index(index: usize, scale: f64, align_corners: bool) -> f64 {
+    if align_corners {
+        index as f64 * scale
+    } else {
+        (index as f64 + 0.5) * scale - 0.5
+    }
+}
+
+fn nearest_taps(input_size: usize, output_size: usize) -> Vec<InterpolateTaps> {
+    let scale = input_size as f64 / output_size as f64;
+
+    (0..output_size)
+        .map(|i| {
+            let index = (i as f64 * scale).floor() as usize;
+            vec![(usize::min(index, input_size - 1), 1.0)]
+        })
+        .collect()
+}
+
+fn area_taps(input_size: usize, output_size: usize) -> Vec<InterpolateTaps> {
+    (0..output_size)
+        .map(|i| {
+            let start = (i * input_size) / output_size;
+            let end = ((i + 1) * input_size).div_ceil(output_size);
+            let weight = 1.0 / (end - start) as f64;
+
+            (start..end).map(|index| (index, weight)).collect()
+        })
+        .collect()
+}
+
+fn linear_taps(input_size: usize, output_size: usize, align_corners: bool) -> Vec<InterpolateTaps> {
+    let scale = interpolate_scale(input_size, output_size, align_corners);
+
+    (0..output_size)
+        .map(|i| {
+            let source = f64::max(source_index(i, scale, align_corners), 0.0);
+            let index = usize::min(source.floor() as usize, input_size - 1);
+            let lambda = source - index as f64;
+
+            vec![
+                (index, 1.0 - lambda),
+                (usize::min(index + 1, input_size - 1), lambda),
+            ]
+        })
+        .collect()
+}
+
+fn cubic_taps(input_size: usize, output_size: usize, align_corners: bool) -> Vec<InterpolateTaps> {
+    let scale = interpolate_scale(input_size, output_size, align_corners);
+    let last = input_size as i64 - 1;
+
+    (0..output_size)
+        .map(|i| {
+            let source = source_index(i, scale, align_corners);
+            let index = source.floor();
+            let t = source - index;
+            let index = index as i64;
+
+            [-1.0, 0.0, 1.0, 2.0]
+                .into_iter()
+                .map(|offset| {
+                    let position = (index + offset as i64).clamp(0, last) as usize;
+                    (position, cubic_filter(t - offset, -0.75))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Interpolates with a filter stretched over the input positions covered by each output position
+/// when downsampling, which avoids aliasing.
+fn antialias_taps<F: Fn(f64) -> f64>(
+    input_size: usize,
+    output_size: usize,
+    align_corners: bool,
+    filter_support: f64,
+    filter: F,
+) -> Vec<InterpolateTaps> {
+    let scale = interpolate_scale(input_size, output_size, align_corners);
+    let (support, inv_scale) = if scale >= 1.0 {
+        (filter_support * scale, 1.0 / scale)
+    } else {
+        (filter_support, 1.0)
+    };
+
+    (0..output_size)
+        .map(|i| {
+            let center = scale * (i as f64 + 0.5);
+            let start = i64::max((center - support + 0.5) as i64, 0) as usize;
+            let end = usize::min((center + support + 0.5) as i64 as usize, input_size);
+
+            let mut taps: InterpolateTaps = (start..end)
+                .map(|index| (index, filter((index as f64 - center + 0.5) * inv_scale)))
+                .collect();
+
+            let total: f64 = taps.iter().map(|(_, weight)| weight).sum();
+            if total != 0.0 {
+                taps.iter_mut().for_each(|(_, weight)| *weight /= total);
+            }
+
+            taps
+        })
+        .collect()
+}
+
+/// The cubic convolution kernel of Keys, with the coefficient `a`.
+fn cubic_filter(x: f64, a: f64) -> f64 {
+    let x = x.abs();
+
+    if x < 1.0 {
+        ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a
+    } else {
+        0.0
+    }
+}
+
+/// The matrix of shape `[input_size, output_size]` multiplying a dimension on the right to
+/// interpolate it, or its transpose when `transpose` is set.
+fn taps_matrix<B: Backend>(
+    taps: &[InterpolateTaps],
+    input_size: usize,
+    transpose: bool,
+    device: &B::Device,
+) -> FloatTensor<B, 2> {
+    let output_size = taps.len();
+    let mut values = vec![0.0f64; input_size * output_size];
+
+    for (output, taps) in taps.iter().enumerate() {
+        for (input, weight) in taps {
+            match transpose {
+                false => values[input * output_size + output] += weight,
+                true => values[output * input_size + input] += weight,
+            }
+        }
+    }
+
+    let shape = match transpose {
+        false => Shape::new([input_size, output_size]),
+        true => Shape::new([output_size, input_size]),
+    };
+
+    B::float_from_data(Data::new(values, shape).convert(), device)
+}
+
+/// Applies one matrix per spatial dimension, each multiplying the dimension on the right.
+fn apply_matrices<B: Backend>(
+    x: FloatTensor<B, 4>,
+    matrix_height: FloatTensor<B, 2>,
+    matrix_width: FloatTensor<B, 2>,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::float_shape(&x).dims;
+    let [_, out_height] = B::float_shape(&matrix_height).dims;
+    let [_, out_width] = B::float_shape(&matrix_width).dims;
+
+    let x = B::float_reshape(x, Shape::new([batch_size * channels * height, width]));
+    let x = B::float_matmul(x, matrix_width);
+    let x = B::float_reshape(x, Shape::new([batch_size * channels, height, out_width]));
+    let x = B::float_swap_dims(x, 1, 2);
+    let x = B::float_reshape(x, Shape::new([batch_size * channels * out_width, height]));
+    let x = B::float_matmul(x, matrix_height);
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, out_width, out_height]));
+
+    B::float_swap_dims(x, 2, 3)
+}
+
+/// Interpolates the input with one matrix multiplication per spatial dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn interpolate_from_matmul<B: Backend>(
+    x: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 4> {
+    let [_, _, height, width] = B::float_shape(&x).dims;
+    let device = B::float_device(&x);
+
+    let taps_height = interpolate_taps(height, output_size[0], &options);
+    let taps_width = interpolate_taps(width, output_size[1], &options);
+
+    apply_matrices::<B>(
+        x,
+        taps_matrix::<B>(&taps_height, height, false, &device),
+        taps_matrix::<B>(&taps_width, width, false, &device),
+    )
+}
+
+/// Computes the gradient of [interpolate](super::ModuleOps::interpolate) with one matrix
+/// multiplication per spatial dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn interpolate_backward_from_matmul<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 4> {
+    let [_, _, height, width] = B::float_shape(&x).dims;
+    let device = B::float_device(&x);
+
+    let taps_height = interpolate_taps(height, output_size[0], &options);
+    let taps_width = interpolate_taps(width, output_size[1], &options);
+
+    apply_matrices::<B>(
+        grad,
+        taps_matrix::<B>(&taps_height, height, true, &device),
+        taps_matrix::<B>(&taps_width, width, true, &device),
+    )
+}
+
+pub(crate) fn interpolate1d_from_2d<B: Backend>(
+    x: FloatTensor<B, 3>,
+    output_size: usize,
+    options: InterpolateOptions,
+) -> FloatTensor<B, 3> {
+    let [batch_size, channels, length] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, 1, length]));
+    let x = B::interpolate(x, [1, output_size], options);
+
+    B::float_reshape(x, Shape::new([batch_size, channels, output_size]))
+}
+
+pub(crate) fn interpolate3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+    let [out_depth, out_height, out_width] = output_size;
+
+    // Interpolates the height and the width of each depth slice.
+    let x = B::float_reshape(x, Shape::new([batch_size, channels * depth, height, width]));
+    let x = B::interpolate(x, [out_height, out_width], options.clone());
+
+    // Interpolates the depth, leaving the flattened height and width untouched.
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size, channels, depth, out_height * out_width]),
+    );
+    let x = B::interpolate(x, [out_depth, out_height * out_width], options);
+
+    B::float_reshape(
+        x,
+        Shape::new([batch_size, channels, out_depth, out_height, out_width]),
+    )
+}
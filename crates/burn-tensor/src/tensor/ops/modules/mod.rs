@@ -7,6 +7,15 @@ pub(crate) mod unfold;
 /// Module with pooling operations.
 pub mod pool;
 
+/// Module with interpolation operations.
+pub mod interpolate;
+
+/// Module with grid sampling operations.
+pub mod grid_sample;
+
+/// Module with attention operations.
+pub mod attention;
+
 mod base;
 
 pub use base::*;
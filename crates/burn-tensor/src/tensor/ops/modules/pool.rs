@@ -1,10 +1,10 @@
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape,
+    ElementConversion, Shape,
 };
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B, 3>,
@@ -165,3 +165,299 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    // The average over a box is the average over the depth of the averages over the planes.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+    let [_, _, height_out, width_out] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+    let [_, _, depth_out, _] = B::float_shape(&x).dims;
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    grad: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&grad).dims;
+    let device = B::float_device(&x);
+
+    // Only the shape of the input is used by the backward pass of the pooling over the depth.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth_in, height_out * width_out]),
+        &device,
+    );
+    let grad_x = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad_x = B::avg_pool2d_backward(
+        x_depth,
+        grad_x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let grad_x = B::float_reshape(
+        grad_x,
+        Shape::from([batch_size, channels * depth_in, height_out, width_out]),
+    );
+    let grad_x = B::avg_pool2d_backward(
+        x,
+        grad_x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::float_reshape(
+        grad_x,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_size: [usize; 3],
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [height_out, width_out]);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [depth_out, height_out * width_out]);
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    grad: FloatTensor<B, 5>,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&grad).dims;
+    let device = B::float_device(&x);
+
+    // Only the shape of the input is used by the backward pass of the pooling over the depth.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth_in, height_out * width_out]),
+        &device,
+    );
+    let grad_x = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad_x = B::adaptive_avg_pool2d_backward(x_depth, grad_x);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let grad_x = B::float_reshape(
+        grad_x,
+        Shape::from([batch_size, channels * depth_in, height_out, width_out]),
+    );
+    let grad_x = B::adaptive_avg_pool2d_backward(x, grad_x);
+
+    B::float_reshape(
+        grad_x,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    )
+}
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    // The maximum over a box is the maximum over the depth of the maximums over the planes.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = B::float_shape(&x).dims;
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_1d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    // Pool over the width, then the height and finally the depth, keeping track of the position of
+    // the maximum in the input at each step.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth * height, width]),
+    );
+    let pooled = B::max_pool1d_with_indices(x, kernel_size[2], stride[2], padding[2], dilation[2]);
+    let [_, _, width_out] = B::float_shape(&pooled.output).dims;
+
+    let shape = [batch_size, channels * depth, height, width_out];
+    let x = float_move_to_last::<B>(pooled.output, shape);
+    let indices_w = int_move_to_last::<B>(pooled.indices, shape);
+    let pooled = B::max_pool1d_with_indices(x, kernel_size[1], stride[1], padding[1], dilation[1]);
+    let [_, _, height_out] = B::float_shape(&pooled.output).dims;
+
+    let indices_w = B::int_gather(2, indices_w, pooled.indices.clone());
+    let indices_hw = B::int_add(
+        B::int_mul_scalar(pooled.indices, (width as i64).elem()),
+        indices_w,
+    );
+
+    let shape = [batch_size, channels, depth, width_out * height_out];
+    let x = float_move_to_last::<B>(pooled.output, shape);
+    let indices_hw = int_move_to_last::<B>(indices_hw, shape);
+    let pooled = B::max_pool1d_with_indices(x, kernel_size[0], stride[0], padding[0], dilation[0]);
+    let [_, _, depth_out] = B::float_shape(&pooled.output).dims;
+
+    let indices_hw = B::int_gather(2, indices_hw, pooled.indices.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(pooled.indices, ((height * width) as i64).elem()),
+        indices_hw,
+    );
+
+    let shape = Shape::from([batch_size, channels, width_out, height_out, depth_out]);
+    let output = B::float_swap_dims(B::float_reshape(pooled.output, shape.clone()), 2, 4);
+    let indices = B::int_swap_dims(B::int_reshape(indices, shape), 2, 4);
+
+    MaxPool3dWithIndices::new(output, indices)
+}
+
+pub(crate) fn max_pool3d_with_indices_backward_from_scatter<B: Backend>(
+    x: FloatTensor<B, 5>,
+    _kernel_size: [usize; 3],
+    _stride: [usize; 3],
+    _padding: [usize; 3],
+    _dilation: [usize; 3],
+    output_grad: FloatTensor<B, 5>,
+    indices: IntTensor<B, 5>,
+) -> MaxPool3dBackward<B> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&output_grad).dims;
+    let shape_out = Shape::from([batch_size, channels, depth_out * height_out * width_out]);
+
+    let grad_x = B::float_zeros(
+        Shape::from([batch_size, channels, depth_in * height_in * width_in]),
+        &B::float_device(&x),
+    );
+    let grad_x = B::float_scatter(
+        2,
+        grad_x,
+        B::int_reshape(indices, shape_out.clone()),
+        B::float_reshape(output_grad, shape_out),
+    );
+
+    MaxPool3dBackward::new(B::float_reshape(
+        grad_x,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    ))
+}
+
+/// Reshape the tensor to `shape` and move its third dimension last, flattening the second and
+/// fourth dimensions together.
+fn float_move_to_last<B: Backend>(x: FloatTensor<B, 3>, shape: [usize; 4]) -> FloatTensor<B, 3> {
+    let [batch_size, dim_1, dim_2, dim_3] = shape;
+    let x = B::float_reshape(x, Shape::from(shape));
+    let x = B::float_swap_dims(x, 2, 3);
+
+    B::float_reshape(x, Shape::from([batch_size, dim_1 * dim_3, dim_2]))
+}
+
+/// Reshape the indices to `shape` and move their third dimension last, flattening the second and
+/// fourth dimensions together.
+fn int_move_to_last<B: Backend>(x: IntTensor<B, 3>, shape: [usize; 4]) -> IntTensor<B, 3> {
+    let [batch_size, dim_1, dim_2, dim_3] = shape;
+    let x = B::int_reshape(x, Shape::from(shape));
+    let x = B::int_swap_dims(x, 2, 3);
+
+    B::int_reshape(x, Shape::from([batch_size, dim_1 * dim_3, dim_2]))
+}
@@ -1,5 +1,14 @@
-use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float};
+use super::{
+    linalg, BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor,
+};
+use crate::{
+    argsort, atan2, cummax, cummax_with_indices, cummin, cummin_with_indices, cumprod, cumsum,
+    digamma, erfinv, fft, float_map, histc, ifft, irfft, lgamma, pad, rfft, searchsorted, sort,
+    sort_with_indices, unique,
+};
+use crate::{
+    backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float, PadMode,
+};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -1032,6 +1041,240 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the values rounded down to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the largest integers lower or equal to the values.
+    fn float_floor<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::floor)
+    }
+
+    /// Returns a new tensor with the values rounded up to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the smallest integers greater or equal to the values.
+    fn float_ceil<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::ceil)
+    }
+
+    /// Returns a new tensor with the values rounded to the nearest integer, with halfway values
+    /// rounded to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the rounded values.
+    fn float_round<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::rint)
+    }
+
+    /// Returns a new tensor with the values rounded toward zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to truncate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the integer parts of the values.
+    fn float_trunc<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let negative = B::float_lower_elem(tensor.clone(), 0.elem());
+        B::float_mask_where(
+            B::float_floor(tensor.clone()),
+            negative,
+            B::float_ceil(tensor),
+        )
+    }
+
+    /// Returns a new tensor with the signs of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `-1` for the negative values, `1` for the
+    /// positive values and `0` for zero.
+    fn float_sign<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let positive = B::float_greater_elem(tensor.clone(), 0.elem());
+        let negative = B::float_lower_elem(tensor.clone(), 0.elem());
+        let sign = B::float_zeros(B::float_shape(&tensor), &B::float_device(&tensor));
+        let sign = B::float_mask_fill(sign, positive, 1.elem());
+
+        B::float_mask_fill(sign, negative, (-1).elem())
+    }
+
+    /// Returns a new tensor with tangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the tangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with tangent values.
+    fn float_tan<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        B::float_div(B::float_sin(tensor.clone()), B::float_cos(tensor))
+    }
+
+    /// Returns a new tensor with arcsine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arcsine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arcsine values.
+    fn float_asin<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::asin)
+    }
+
+    /// Returns a new tensor with arccosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arccosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arccosine values.
+    fn float_acos<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::acos)
+    }
+
+    /// Returns a new tensor with arctangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arctangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arctangent values.
+    fn float_atan<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        float_map::<B, D>(tensor, libm::atan)
+    }
+
+    /// Element-wise four-quadrant arctangent of `lhs / rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The y coordinates.
+    /// * `rhs` - The x coordinates.
+    ///
+    /// # Returns
+    ///
+    /// The angles in radians between the positive x axis and the points, between `-π` and `π`.
+    fn float_atan2<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        atan2::<B, D>(lhs, rhs)
+    }
+
+    /// Returns a new tensor with hyperbolic sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic sine values.
+    fn float_sinh<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let difference = B::float_sub(
+            B::float_exp(tensor.clone()),
+            B::float_exp(B::float_neg(tensor)),
+        );
+        B::float_div_scalar(difference, 2.elem())
+    }
+
+    /// Returns a new tensor with hyperbolic cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic cosine values.
+    fn float_cosh<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let sum = B::float_add(
+            B::float_exp(tensor.clone()),
+            B::float_exp(B::float_neg(tensor)),
+        );
+        B::float_div_scalar(sum, 2.elem())
+    }
+
+    /// Returns a new tensor with the natural logarithm of the absolute value of the gamma function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma values.
+    fn float_lgamma<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        lgamma::<B, D>(tensor)
+    }
+
+    /// Returns a new tensor with the digamma function values, the derivative of the log-gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the digamma of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with digamma values.
+    fn float_digamma<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        digamma::<B, D>(tensor)
+    }
+
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of, with values between `-1`
+    ///   and `1`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        erfinv::<B, D>(tensor)
+    }
+
+    /// Returns a new tensor with the exponential values minus one.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to exponentiate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the values of `exp(x) - 1`.
+    fn float_expm1<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        B::float_sub_scalar(B::float_exp(tensor), 1.elem())
+    }
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1289,4 +1532,416 @@ pub trait FloatTensorOps<B: Backend> {
         let sum = B::float_sum_dim(B::bool_into_float(bool_tensor), dim);
         B::float_equal_elem(sum, (num_elems as f32).elem())
     }
+
+    /// Sort the elements of the input `tensor` by value in along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements are sorted by value.
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<B, D> {
+        sort::<B, D, Float>(tensor, dim, descending)
+    }
+
+    /// Sort the elements of the input `tensor` by value in along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and corresponding indices, where
+    /// the elements are sorted by value and the indices map back to the original input tensor.
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Float>(tensor, dim, descending)
+    }
+
+    /// Returns the indices that sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        argsort::<B, D, Float>(tensor, dim, descending)
+    }
+
+    /// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn float_cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn float_cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn float_cummax<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cummax::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running maximum along the given dimension. When the
+    /// maximum value occurs more than once, the index of the last occurrence is returned.
+    fn float_cummax_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        cummax_with_indices::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of all the
+    /// preceding elements (inclusive) along the given dimension.
+    fn float_cummin<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cummin::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and the corresponding indices, where each
+    /// index points to the position of the running minimum along the given dimension. When the
+    /// minimum value occurs more than once, the index of the last occurrence is returned.
+    fn float_cummin_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        cummin_with_indices::<B, D, Float>(tensor, dim)
+    }
+
+    /// Pads each dimension of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `pads` - The number of values added before and after each dimension.
+    /// * `mode` - How the added values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor, where each dimension grows by the sum of its padding.
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> FloatTensor<B, D> {
+        pad::<B, D, Float>(tensor, pads, mode)
+    }
+
+    /// Finds the unique elements of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// The unique elements sorted in ascending order, the index of each element of the input tensor
+    /// in the unique elements, and the number of occurrences of each unique element.
+    fn float_unique(
+        tensor: FloatTensor<B, 1>,
+    ) -> (FloatTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique::<B, Float>(tensor)
+    }
+
+    /// Finds the indices where the `values` would be inserted in the `sorted` tensor to keep it sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The tensor sorted in ascending order.
+    /// * `values` - The values to insert.
+    /// * `right` - Whether the index after the elements equal to a value is returned, instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the values, containing the insertion index of each value.
+    fn float_searchsorted<const D: usize>(
+        sorted: FloatTensor<B, 1>,
+        values: FloatTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        searchsorted::<B, D, Float>(sorted, values, right)
+    }
+
+    /// Computes the histogram of the input `tensor` with bins of equal width between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower end of the first bin.
+    /// * `max` - The upper end of the last bin, which includes it.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the number of elements in each bin, ignoring the elements outside of the range.
+    fn float_histc(
+        tensor: FloatTensor<B, 1>,
+        bins: usize,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> FloatTensor<B, 1> {
+        histc::<B>(tensor, bins, min, max)
+    }
+
+    /// Computes the discrete Fourier transform of a complex tensor along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the input tensor.
+    /// * `imag` - The imaginary part of the input tensor.
+    /// * `dim` - The axis along which to compute the transform.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the unnormalized transform.
+    fn float_fft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        fft::<B, D>(real, imag, dim)
+    }
+
+    /// Computes the inverse discrete Fourier transform of a complex tensor along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the input tensor.
+    /// * `imag` - The imaginary part of the input tensor.
+    /// * `dim` - The axis along which to compute the transform.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform, normalized by the size of the dimension.
+    fn float_ifft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        ifft::<B, D>(real, imag, dim)
+    }
+
+    /// Computes the discrete Fourier transform of a real tensor along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the transform.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the `n / 2 + 1` non-negative frequencies of the
+    /// unnormalized transform.
+    fn float_rfft<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        rfft::<B, D>(tensor, dim)
+    }
+
+    /// Computes the inverse of [float_rfft](FloatTensorOps::float_rfft) along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the non-negative frequencies.
+    /// * `imag` - The imaginary part of the non-negative frequencies.
+    /// * `dim` - The axis along which to compute the transform.
+    /// * `n` - The size of the output along the given dimension.
+    ///
+    /// # Returns
+    ///
+    /// The real signal, normalized by `n`.
+    fn float_irfft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        n: usize,
+    ) -> FloatTensor<B, D> {
+        irfft::<B, D>(real, imag, dim, n)
+    }
+
+    /// Solves the linear systems `a x = b` for batches of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The square matrices of shape `[..., n, n]`.
+    /// * `b` - The right-hand sides of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions of shape `[..., n, k]`.
+    fn float_solve<const D: usize>(
+        a: FloatTensor<B, D>,
+        b: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        linalg::solve::<B, D>(a, b)
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant for
+    /// batches of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The signs and the logarithms of the absolute determinants, both of shape `[..., 1, 1]`.
+    /// Singular matrices have a sign of zero and a logarithm of negative infinity.
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::slogdet::<B, D>(tensor)
+    }
+
+    /// Computes the Cholesky decomposition of batches of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`, where only the lower triangle is used.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factors `l` of shape `[..., n, n]`, where `tensor = l l^T`.
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        linalg::cholesky::<B, D>(tensor)
+    }
+
+    /// Computes the reduced QR decomposition of batches of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `q` of shape `[..., m, k]` with orthonormal columns and the upper triangular
+    /// matrices `r` of shape `[..., k, n]` with a non-negative diagonal, where `k = min(m, n)`.
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::qr::<B, D>(tensor)
+    }
+
+    /// Computes the reduced singular value decomposition of batches of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `u` of shape `[..., m, k]`, the singular values `s` of shape
+    /// `[..., 1, k]` in descending order and the right singular vectors `vh` of shape `[..., k, n]`,
+    /// where `k = min(m, n)`. The component of largest magnitude of each column of `u` is positive.
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::svd::<B, D>(tensor)
+    }
+
+    /// Computes the eigenvalues and eigenvectors of batches of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`, where only the lower triangle is used.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues of shape `[..., 1, n]` in ascending order and the eigenvectors of shape
+    /// `[..., n, n]` stored as columns. The component of largest magnitude of each eigenvector is
+    /// positive.
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        linalg::eigh::<B, D>(tensor)
+    }
 }
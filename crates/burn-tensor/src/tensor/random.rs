@@ -0,0 +1,188 @@
+use crate::{Data, Distribution, Element, Shape};
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use rand_distr::StandardNormal;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// A seedable stream of random numbers to sample random tensors from.
+///
+/// [Backend::seed](crate::backend::Backend::seed) seeds a stream shared by every random tensor of
+/// a backend, so the values sampled by a thread depend on what the other threads sampled before.
+/// A generator is instead an explicit value, which can be [forked](Generator::fork) into
+/// independent generators, for instance one per thread, to keep the sampled values reproducible.
+///
+/// The values are sampled on the host, so a generator produces the same values on every backend
+/// and device.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    rng: StdRng,
+}
+
+impl Generator {
+    /// Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a new generator, seeded by the next value of this one.
+    ///
+    /// The forks of generators created with the same seed are the same, while the values of a
+    /// fork are independent from the values of its parent.
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.rng.next_u64())
+    }
+
+    /// Samples data of the given shape where each element is sampled from the given distribution.
+    pub fn sample<E: Element, const D: usize>(
+        &mut self,
+        shape: Shape<D>,
+        distribution: Distribution,
+    ) -> Data<E, D> {
+        Data::random(shape, distribution, &mut self.rng)
+    }
+}
+
+/// Normal distribution restricted to an interval.
+#[derive(Debug, Clone, Copy)]
+pub struct TruncatedNormal {
+    mean: f64,
+    std: f64,
+    low: f64,
+    high: f64,
+}
+
+impl TruncatedNormal {
+    /// Creates a new truncated normal distribution with the given mean and standard deviation,
+    /// restricted to the given lower and upper bounds, which can be infinite.
+    ///
+    /// # Panics
+    ///
+    /// If the standard deviation isn't positive or if the lower bound is above the upper bound.
+    pub fn new(mean: f64, std: f64, low: f64, high: f64) -> Self {
+        if std <= 0.0 {
+            panic!("The standard deviation of a truncated normal distribution must be positive, got {std}");
+        }
+        if low > high {
+            panic!("The lower bound of a truncated normal distribution ({low}) must not be above its upper bound ({high})");
+        }
+
+        Self {
+            mean,
+            std,
+            low,
+            high,
+        }
+    }
+}
+
+impl rand::distributions::Distribution<f64> for TruncatedNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let low = (self.low - self.mean) / self.std;
+        let high = (self.high - self.mean) / self.std;
+
+        let sample = if low > 0.0 {
+            sample_tail(low, high, rng)
+        } else if high < 0.0 {
+            -sample_tail(-high, -low, rng)
+        } else if high - low >= 1.0 {
+            // The interval holds enough of the distribution for the samples to fall inside of it
+            // after a few attempts.
+            loop {
+                let sample: f64 = rng.sample(StandardNormal);
+
+                if low <= sample && sample <= high {
+                    break sample;
+                }
+            }
+        } else {
+            sample_uniform(low, high, 0.0, rng)
+        };
+
+        // Rounding errors could otherwise move the sample slightly outside of the bounds.
+        (self.mean + self.std * sample).clamp(self.low, self.high)
+    }
+}
+
+/// Samples the standard normal distribution restricted to `[low, high]`, where `0 < low`.
+fn sample_tail<R: Rng + ?Sized>(low: f64, high: f64, rng: &mut R) -> f64 {
+    if high - low <= f64::min(1.0, 1.0 / low) {
+        return sample_uniform(low, high, low, rng);
+    }
+
+    // Rejection sampling from a translated exponential distribution (Robert, 1995).
+    let rate = (low + (low * low + 4.0).sqrt()) / 2.0;
+
+    loop {
+        let sample = low - (1.0 - rng.gen::<f64>()).ln() / rate;
+
+        if sample <= high && rng.gen::<f64>() <= (-(sample - rate).powi(2) / 2.0).exp() {
+            break sample;
+        }
+    }
+}
+
+/// Samples the standard normal distribution restricted to `[low, high]` by rejection sampling from
+/// a uniform distribution, where `mode` is the most likely value of the interval.
+fn sample_uniform<R: Rng + ?Sized>(low: f64, high: f64, mode: f64, rng: &mut R) -> f64 {
+    loop {
+        let sample = rng.gen_range(low..=high);
+
+        if rng.gen::<f64>() <= ((mode * mode - sample * sample) / 2.0).exp() {
+            break sample;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fork_reproducible_generators() {
+        let shape = Shape::new([32]);
+        let mut generator_1 = Generator::new(42);
+        let mut generator_2 = Generator::new(42);
+
+        let mut fork_1 = generator_1.fork();
+        let mut fork_2 = generator_2.fork();
+
+        let data_1 = generator_1.sample::<f32, 1>(shape.clone(), Distribution::Default);
+        let data_2 = generator_2.sample::<f32, 1>(shape.clone(), Distribution::Default);
+        let fork_data_1 = fork_1.sample::<f32, 1>(shape.clone(), Distribution::Default);
+        let fork_data_2 = fork_2.sample::<f32, 1>(shape, Distribution::Default);
+
+        assert_eq!(data_1, data_2);
+        assert_eq!(fork_data_1, fork_data_2);
+        assert_ne!(data_1, fork_data_1);
+    }
+
+    #[test]
+    fn should_sample_truncated_normal_within_bounds() {
+        let mut generator = Generator::new(0);
+
+        for (low, high) in [
+            (-0.5, 0.5),
+            (-2.0, 2.0),
+            (3.0, 3.5),
+            (4.0, 100.0),
+            (-10.0, -6.0),
+        ] {
+            let data = generator.sample::<f64, 1>(
+                Shape::new([1000]),
+                Distribution::TruncatedNormal(1.0, 2.0, low, high),
+            );
+
+            for value in data.value {
+                assert!(
+                    low <= value && value <= high,
+                    "{value} not in [{low}, {high}]"
+                );
+            }
+        }
+    }
+}
@@ -22,18 +22,28 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
+        burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_avg_pool3d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_avg_pool3d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_linear_interpolate!();
+        burn_tensor::testgen_module_trilinear_interpolate!();
+        burn_tensor::testgen_module_area_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_attention!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -63,6 +73,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
+        burn_tensor::testgen_multinomial!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_neg!();
         burn_tensor::testgen_one_hot!();
@@ -85,8 +96,21 @@ macro_rules! testgen_all {
         burn_tensor::testgen_powf!();
         burn_tensor::testgen_any!();
         burn_tensor::testgen_all_op!();
+        burn_tensor::testgen_bitwise!();
         burn_tensor::testgen_bool!();
         burn_tensor::testgen_argwhere_nonzero!();
+        burn_tensor::testgen_sort_argsort!();
+        burn_tensor::testgen_counting!();
+        burn_tensor::testgen_round!();
+        burn_tensor::testgen_trig!();
+        burn_tensor::testgen_special!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_linalg!();
+        burn_tensor::testgen_sparse!();
+        burn_tensor::testgen_quantized!();
+        burn_tensor::testgen_pad!();
 
         // test stats
         burn_tensor::testgen_var!();
@@ -0,0 +1,122 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            depth: 4,
+            height: 4,
+            width: 6,
+            depth_out: 2,
+            height_out: 2,
+            width_out: 3,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[15.5, 17.5, 19.5], [27.5, 29.5, 31.5]],
+                [[63.5, 65.5, 67.5], [75.5, 77.5, 79.5]],
+            ],
+            [
+                [[111.5, 113.5, 115.5], [123.5, 125.5, 127.5]],
+                [[159.5, 161.5, 163.5], [171.5, 173.5, 175.5]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d_dyn_filter_size() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 3,
+            height: 5,
+            width: 7,
+            depth_out: 2,
+            height_out: 3,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [
+                [21.5, 23.0, 25.0, 26.5],
+                [32.0, 33.5, 35.5, 37.0],
+                [42.5, 44.0, 46.0, 47.5],
+            ],
+            [
+                [56.5, 58.0, 60.0, 61.5],
+                [67.0, 68.5, 70.5, 72.0],
+                [77.5, 79.0, 81.0, 82.5],
+            ],
+        ]]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d_bigger_output() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 2,
+            height: 2,
+            width: 3,
+            depth_out: 3,
+            height_out: 3,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [
+                [0.0, 0.5, 1.5, 2.0],
+                [1.5, 2.0, 3.0, 3.5],
+                [3.0, 3.5, 4.5, 5.0],
+            ],
+            [
+                [3.0, 3.5, 4.5, 5.0],
+                [4.5, 5.0, 6.0, 6.5],
+                [6.0, 6.5, 7.5, 8.0],
+            ],
+            [
+                [6.0, 6.5, 7.5, 8.0],
+                [7.5, 8.0, 9.0, 9.5],
+                [9.0, 9.5, 10.5, 11.0],
+            ],
+        ]]]));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        depth_out: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_avg_pool3d(x, [self.depth_out, self.height_out, self.width_out]);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
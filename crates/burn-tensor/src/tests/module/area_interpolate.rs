@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(module_area_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{interpolate, interpolate1d};
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_downsample_interpolation() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 5,
+            width: 7,
+            height_out: 2,
+            width_out: 3,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [8.0, 10.0, 12.0],
+            [22.0, 24.0, 26.0],
+        ]]]));
+    }
+
+    #[test]
+    fn test_upsample_interpolation() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 2,
+            width: 3,
+            height_out: 3,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.0, 0.5, 1.5, 2.0],
+            [1.5, 2.0, 3.0, 3.5],
+            [3.0, 3.5, 4.5, 5.0],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolation_1d() {
+        let x = TestTensor::from([[[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]]);
+
+        let output = interpolate1d(x, 3, InterpolateOptions::new(InterpolateMode::Area));
+
+        output
+            .into_data()
+            .assert_approx_eq(&TestTensor::from([[[1.0, 3.0, 5.0]]]).into_data(), 3);
+    }
+
+    struct InterpolateTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = interpolate(
+                x,
+                [self.height_out, self.width_out],
+                InterpolateOptions::new(InterpolateMode::Area),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
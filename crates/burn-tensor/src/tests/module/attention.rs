@@ -0,0 +1,249 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::{attention::AttentionDropout, AttentionOptions};
+    use burn_tensor::{backend::Backend, Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_attention() {
+        let test = AttentionTestCase::new();
+
+        test.assert_output(
+            None,
+            AttentionOptions::new(),
+            Data::from([[[[0.16563, 0.73213], [1.47284, 0.69046]]]]),
+        );
+    }
+
+    #[test]
+    fn test_attention_scale() {
+        let test = AttentionTestCase::new();
+
+        test.assert_output(
+            None,
+            AttentionOptions::new().with_scale(2.0),
+            Data::from([[[[-0.50055, 0.81783], [1.21877, 1.55419]]]]),
+        );
+    }
+
+    #[test]
+    fn test_attention_causal() {
+        let test = AttentionTestCase::new();
+
+        test.assert_output(
+            None,
+            AttentionOptions::new().with_causal(true),
+            Data::from([[[[-0.20901, 1.09325], [1.47284, 0.69046]]]]),
+        );
+    }
+
+    #[test]
+    fn test_attention_causal_more_queries_than_keys() {
+        let device = Default::default();
+        let query = TestTensor::from_floats([[[[1.0, 0.5], [-0.3, 2.0], [0.7, -1.1]]]], &device);
+        let key = TestTensor::from_floats([[[[0.2, 1.0], [1.5, -0.4]]]], &device);
+        let value = TestTensor::from_floats([[[[1.0, 2.0], [-1.0, 0.5]]]], &device);
+
+        let output = scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            None,
+            AttentionOptions::new().with_causal(true),
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.0, 2.0], [0.81028, 1.85771], [-0.69944, 0.72542]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_attention_mask_fully_masked_query() {
+        let test = AttentionTestCase::new();
+        let mask = Tensor::from_bool(
+            Data::from([[[[false, true, false], [true, true, true]]]]),
+            &Default::default(),
+        );
+
+        test.assert_output(
+            Some(mask),
+            AttentionOptions::new(),
+            Data::from([[[[1.50097, 0.99805], [0.0, 0.0]]]]),
+        );
+    }
+
+    #[test]
+    fn test_attention_mask_broadcast() {
+        let test = AttentionTestCase::new();
+        let query = test.query.repeat(0, 2).repeat(1, 3);
+        let key = test.key.repeat(0, 2).repeat(1, 3);
+        let value = test.value.repeat(0, 2).repeat(1, 3);
+        let mask = Tensor::from_bool(
+            Data::from([[[[false, true, false], [true, true, true]]]]),
+            &Default::default(),
+        );
+
+        let output =
+            scaled_dot_product_attention(query, key, value, Some(mask), AttentionOptions::new());
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::from_floats([[[[1.50097, 0.99805], [0.0, 0.0]]]], &Default::default())
+                .repeat(0, 2)
+                .repeat(1, 3)
+                .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_attention_dropout() {
+        let test = AttentionTestCase::new();
+        let options = AttentionOptions::new().with_dropout(0.5).with_seed(7);
+
+        let output = scaled_dot_product_attention(
+            test.query.clone(),
+            test.key.clone(),
+            test.value.clone(),
+            None,
+            options.clone(),
+        );
+
+        // The attention weights without dropout, multiplied by the dropout factors.
+        let weights = burn_tensor::activation::softmax(
+            test.query
+                .clone()
+                .matmul(test.key.clone().transpose())
+                .div_scalar(2.0_f32.sqrt()),
+            3,
+        );
+        let dropout = AttentionDropout::new(7, 0.5);
+        let factors = (0..2)
+            .flat_map(|i| (0..3).map(move |j| dropout.keep([0, 0, i, j])))
+            .map(|keep| match keep {
+                true => 2.0,
+                false => 0.0,
+            })
+            .collect::<Vec<f32>>();
+        let factors = TestTensor::from_data(
+            Data::new(factors, Shape::new([1, 1, 2, 3])).convert(),
+            &Default::default(),
+        );
+        let expected = (weights * factors).matmul(test.value.clone());
+
+        let expected = expected.into_data();
+        output.into_data().assert_approx_eq(&expected, 3);
+
+        // The same seed drops the same weights.
+        let output = scaled_dot_product_attention(test.query, test.key, test.value, None, options);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_attention_dropout_keeps_random_generator() {
+        let test = AttentionTestCase::new();
+        let device = Default::default();
+
+        TestBackend::seed(3);
+        let expected = TestTensor::<1>::random([32], Distribution::Default, &device).into_data();
+
+        TestBackend::seed(3);
+        scaled_dot_product_attention(
+            test.query,
+            test.key,
+            test.value,
+            None,
+            AttentionOptions::new().with_dropout(0.5).with_seed(7),
+        );
+        let output = TestTensor::<1>::random([32], Distribution::Default, &device).into_data();
+
+        output.assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_attention_dropout_keep_ratio() {
+        let dropout = AttentionDropout::new(42, 0.3);
+        let kept = (0..2)
+            .flat_map(|b| (0..4).flat_map(move |h| (0..50).map(move |i| [b, h, i, 40_000 + i])))
+            .flat_map(|[b, h, i, j]| (0..20).map(move |k| [b, h, i, j + k]))
+            .filter(|&position| dropout.keep(position))
+            .count();
+
+        // 8000 weights, of which about 70% are kept.
+        assert!((5400..5800).contains(&kept), "{kept} weights kept");
+    }
+
+    #[test]
+    fn test_attention_long_sequence_causal() {
+        // Long enough for the keys to be split in blocks by the backends computing the weights
+        // block by block.
+        let [seq_length_1, seq_length_2] = [5, 150];
+        let device = Default::default();
+        let tensor = |shape: [usize; 4], step: usize| {
+            let shape = Shape::new(shape);
+            let values = (0..shape.num_elements())
+                .map(|i| ((i * step) % 13) as f32 / 3.0 - 2.0)
+                .collect::<Vec<_>>();
+            TestTensor::from_data(Data::new(values, shape).convert(), &device)
+        };
+        let query = tensor([1, 2, seq_length_1, 3], 5);
+        let key = tensor([1, 2, seq_length_2, 3], 7);
+        let value = tensor([1, 2, seq_length_2, 2], 3);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            AttentionOptions::new().with_causal(true),
+        );
+
+        let offset = seq_length_2 - seq_length_1;
+        let causal = (0..seq_length_1)
+            .flat_map(|i| (0..seq_length_2).map(move |j| j > i + offset))
+            .collect::<Vec<_>>();
+        let causal = Tensor::<TestBackend, 4, burn_tensor::Bool>::from_bool(
+            Data::new(causal, Shape::new([1, 1, seq_length_1, seq_length_2])),
+            &device,
+        )
+        .repeat(1, 2);
+        let scores = query.matmul(key.transpose()).div_scalar(3.0_f32.sqrt());
+        let weights = burn_tensor::activation::softmax(scores.mask_fill(causal, -1.0e4), 3);
+        let expected = weights.matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    struct AttentionTestCase {
+        query: TestTensor<4>,
+        key: TestTensor<4>,
+        value: TestTensor<4>,
+    }
+
+    impl AttentionTestCase {
+        fn new() -> Self {
+            let device = Default::default();
+
+            Self {
+                query: TestTensor::from_floats([[[[1.0, 0.5], [-0.3, 2.0]]]], &device),
+                key: TestTensor::from_floats([[[[0.2, 1.0], [1.5, -0.4], [-1.0, 0.3]]]], &device),
+                value: TestTensor::from_floats([[[[1.0, 2.0], [-1.0, 0.5], [3.0, -2.0]]]], &device),
+            }
+        }
+
+        fn assert_output(
+            self,
+            mask: Option<Tensor<TestBackend, 4, burn_tensor::Bool>>,
+            options: AttentionOptions,
+            expected: Data<f32, 4>,
+        ) {
+            let output =
+                scaled_dot_product_attention(self.query, self.key, self.value, mask, options);
+
+            output.into_data().assert_approx_eq(&expected.convert(), 3);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[6.5, 7.5], [9.5, 10.5]],
+            [[15.5, 16.5], [18.5, 19.5]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[1.0, 1.6667], [2.75, 3.75], [5.25, 6.25], [4.3333, 5.0]],
+            [[22.0, 23.3333], [35.5, 37.5], [40.5, 42.5], [28.6667, 30.0]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[3.0, 5.0], [5.5, 7.5], [10.5, 12.5], [13.0, 15.0]],
+            [[33.0, 35.0], [35.5, 37.5], [40.5, 42.5], [43.0, 45.0]],
+        ]]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = avg_pool3d(
+                x,
+                [self.kernel_size_1, self.kernel_size_2, self.kernel_size_3],
+                [self.stride_1, self.stride_2, self.stride_3],
+                [self.padding_1, self.padding_2, self.padding_3],
+                self.count_include_pad,
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -85,6 +85,52 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_upsample_interpolation_without_align_corners() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 3,
+            width: 4,
+            height_out: 5,
+            width_out: 6,
+        };
+
+        test.assert_output_with_options(
+            TestTensor::from([[[
+                [-0.4708, 0.0222, 0.8290, 1.4030, 2.2098, 2.7028],
+                [1.1772, 1.6702, 2.4770, 3.0510, 3.8577, 4.3508],
+                [3.9132, 4.4062, 5.2130, 5.7870, 6.5938, 7.0868],
+                [6.6492, 7.1422, 7.9490, 8.5230, 9.3298, 9.8228],
+                [8.2972, 8.7903, 9.5970, 10.1710, 10.9777, 11.4708],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Bicubic).with_align_corners(false),
+        );
+    }
+
+    #[test]
+    fn test_downsample_interpolation_antialias() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 8,
+            width: 8,
+            height_out: 3,
+            width_out: 3,
+        };
+
+        test.assert_output_with_options(
+            TestTensor::from([[[
+                [8.0682, 10.6717, 13.2753],
+                [28.8965, 31.5000, 34.1035],
+                [49.7247, 52.3283, 54.9318],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Bicubic)
+                .with_align_corners(false)
+                .with_antialias(true),
+        );
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
@@ -96,6 +142,10 @@ mod tests {
 
     impl InterpolateTestCase {
         fn assert_output(self, y: TestTensor<4>) {
+            self.assert_output_with_options(y, InterpolateOptions::new(InterpolateMode::Bicubic));
+        }
+
+        fn assert_output_with_options(self, y: TestTensor<4>, options: InterpolateOptions) {
             let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
             let x = TestTensor::from(
                 TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
@@ -103,11 +153,7 @@ mod tests {
                     .into_data()
                     .convert(),
             );
-            let output = interpolate(
-                x,
-                [self.height_out, self.width_out],
-                InterpolateOptions::new(InterpolateMode::Bicubic),
-            );
+            let output = interpolate(x, [self.height_out, self.width_out], options);
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
         }
@@ -85,6 +85,52 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_upsample_interpolation_without_align_corners() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 3,
+            width: 4,
+            height_out: 5,
+            width_out: 6,
+        };
+
+        test.assert_output_with_options(
+            TestTensor::from([[[
+                [0.0000, 0.5000, 1.1667, 1.8333, 2.5000, 3.0000],
+                [1.6000, 2.1000, 2.7667, 3.4333, 4.1000, 4.6000],
+                [4.0000, 4.5000, 5.1667, 5.8333, 6.5000, 7.0000],
+                [6.4000, 6.9000, 7.5667, 8.2333, 8.9000, 9.4000],
+                [8.0000, 8.5000, 9.1667, 9.8333, 10.5000, 11.0000],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+        );
+    }
+
+    #[test]
+    fn test_downsample_interpolation_antialias() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 8,
+            width: 8,
+            height_out: 3,
+            width_out: 3,
+        };
+
+        test.assert_output_with_options(
+            TestTensor::from([[[
+                [9.9474, 12.3421, 14.7368],
+                [29.1053, 31.5000, 33.8947],
+                [48.2632, 50.6579, 53.0526],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Bilinear)
+                .with_align_corners(false)
+                .with_antialias(true),
+        );
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
@@ -96,6 +142,10 @@ mod tests {
 
     impl InterpolateTestCase {
         fn assert_output(self, y: TestTensor<4>) {
+            self.assert_output_with_options(y, InterpolateOptions::new(InterpolateMode::Bilinear));
+        }
+
+        fn assert_output_with_options(self, y: TestTensor<4>, options: InterpolateOptions) {
             let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
             let x = TestTensor::from(
                 TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
@@ -103,11 +153,7 @@ mod tests {
                     .into_data()
                     .convert(),
             );
-            let output = interpolate(
-                x,
-                [self.height_out, self.width_out],
-                InterpolateOptions::new(InterpolateMode::Bilinear),
-            );
+            let output = interpolate(x, [self.height_out, self.width_out], options);
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
         }
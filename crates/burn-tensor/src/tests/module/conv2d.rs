@@ -69,6 +69,45 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv2d_groups_multiple_channels_per_group() {
+        let test = Conv2dTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 6,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from([
+            [
+                [[268., 296.], [352., 380.]],
+                [[685., 777.], [961., 1053.]],
+                [[1102., 1258.], [1570., 1726.]],
+                [[5479., 5699.], [6139., 6359.]],
+                [[7048., 7332.], [7900., 8184.]],
+                [[8617., 8965.], [9661., 10009.]],
+            ],
+            [
+                [[1276., 1304.], [1360., 1388.]],
+                [[3997., 4089.], [4273., 4365.]],
+                [[6718., 6874.], [7186., 7342.]],
+                [[13399., 13619.], [14059., 14279.]],
+                [[17272., 17556.], [18124., 18408.]],
+                [[21145., 21493.], [22189., 22537.]],
+            ],
+        ]));
+    }
+
     #[test]
     fn test_conv2d_complex() {
         let test = Conv2dTestCase {
@@ -0,0 +1,195 @@
+#[burn_tensor_testgen::testgen(module_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv3d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv3d_simple() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [
+                    [10492., 15952., 10756.],
+                    [16404., 24873., 16728.],
+                    [11188., 16924., 11356.],
+                ],
+                [
+                    [8260., 12496., 8380.],
+                    [12732., 19203., 12840.],
+                    [8524., 12820., 8548.],
+                ],
+            ],
+            [
+                [
+                    [23885., 36689., 25013.],
+                    [38437., 58894., 40057.],
+                    [27173., 41549., 28205.],
+                ],
+                [
+                    [21653., 33233., 22637.],
+                    [34765., 53224., 36169.],
+                    [24509., 37445., 25397.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[[268., 296.], [352., 380.]], [[520., 548.], [604., 632.]]],
+            [
+                [[3169., 3261.], [3445., 3537.]],
+                [[3997., 4089.], [4273., 4365.]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 1,
+            depth: 3,
+            height: 5,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[[4564., 9164., 4588.]], [[12440., 24484., 12020.]]],
+            [[[10037., 20541., 10493.]], [[32025., 64517., 32469.]]],
+            [[[15510., 31918., 16398.]], [[51610., 104550., 52918.]]],
+        ]]));
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let device = Default::default();
+            let weight = TestTensor::from(
+                TestTensorInt::arange(0..shape_weight.num_elements() as i64, &device)
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from(
+                TestTensorInt::arange(0..self.channels_out as i64, &device)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv3d(
+                x,
+                weight,
+                Some(bias),
+                ConvOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
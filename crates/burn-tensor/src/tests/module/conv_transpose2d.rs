@@ -272,6 +272,59 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv_transpose2d_groups_batched() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from([
+            [
+                [[32., 76., 46.], [88., 208., 124.], [64., 148., 86.]],
+                [[49., 117., 71.], [137., 321., 189.], [97., 221., 127.]],
+                [
+                    [418., 894., 480.],
+                    [954., 2034., 1086.],
+                    [546., 1158., 616.],
+                ],
+                [
+                    [499., 1063., 569.],
+                    [1131., 2403., 1279.],
+                    [643., 1359., 721.],
+                ],
+            ],
+            [
+                [[160., 364., 206.], [408., 912., 508.], [256., 564., 310.]],
+                [[305., 661., 359.], [713., 1537., 829.], [417., 893., 479.]],
+                [
+                    [1058., 2206., 1152.],
+                    [2298., 4786., 2494.],
+                    [1250., 2598., 1352.],
+                ],
+                [
+                    [1267., 2631., 1369.],
+                    [2731., 5667., 2943.],
+                    [1475., 3055., 1585.],
+                ],
+            ],
+        ]));
+    }
+
     struct ConvTranspose2dTestCase {
         batch_size: usize,
         channels_in: usize,
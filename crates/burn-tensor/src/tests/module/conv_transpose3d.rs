@@ -0,0 +1,189 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::ConvTransposeOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            channels_out: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[96., 124.], [180., 208.]],
+            [[348., 376.], [432., 460.]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 1,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[0., 0., 1.], [0., 4., 6.], [4., 12., 9.]],
+                [[0., 4., 5.], [8., 28., 22.], [12., 32., 21.]],
+            ],
+            [
+                [[33., 77., 46.], [89., 205., 119.], [61., 137., 78.]],
+                [[49., 113., 66.], [129., 293., 167.], [85., 189., 106.]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_complex() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 2,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[352., 0., 776., 0., 428.], [392., 0., 864., 0., 476.]],
+            [[344., 0., 752., 0., 412.], [416., 0., 904., 0., 492.]],
+            [[560., 0., 1208., 0., 652.], [632., 0., 1360., 0., 732.]],
+        ]]]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let device = Default::default();
+            let weights = TestTensor::from(
+                TestTensorInt::arange(0..shape_weights.num_elements() as i64, &device)
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from(
+                TestTensorInt::arange(0..self.channels_out as i64, &device)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv_transpose3d(
+                x,
+                weights,
+                Some(bias),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
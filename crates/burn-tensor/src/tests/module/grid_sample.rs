@@ -0,0 +1,163 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_bilinear_zeros() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear),
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.0, 6.1, 2.1875],
+            [2.75, 0.0, 5.32],
+        ]]]));
+    }
+
+    #[test]
+    fn test_bilinear_zeros_align_corners() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear).with_align_corners(true),
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.0, 5.85, 3.25],
+            [11.0, 0.84, 6.55],
+        ]]]));
+    }
+
+    #[test]
+    fn test_bilinear_border() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear)
+                .with_padding_mode(GridSamplePaddingMode::Border),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 6.1, 2.5], [11.0, 3.0, 7.6]]]]));
+    }
+
+    #[test]
+    fn test_bilinear_reflection() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear)
+                .with_padding_mode(GridSamplePaddingMode::Reflection),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 6.1, 2.5], [11.0, 2.7, 7.6]]]]));
+    }
+
+    #[test]
+    fn test_bilinear_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Bilinear)
+                .with_padding_mode(GridSamplePaddingMode::Reflection)
+                .with_align_corners(true),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 5.85, 3.25], [11.0, 3.6, 6.55]]]]));
+    }
+
+    #[test]
+    fn test_nearest_zeros() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Nearest),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 5.0, 2.0], [0.0, 0.0, 8.0]]]]));
+    }
+
+    #[test]
+    fn test_nearest_border() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Nearest)
+                .with_padding_mode(GridSamplePaddingMode::Border),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 5.0, 2.0], [11.0, 3.0, 8.0]]]]));
+    }
+
+    #[test]
+    fn test_nearest_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(GridSampleMode::Nearest)
+                .with_padding_mode(GridSamplePaddingMode::Reflection)
+                .with_align_corners(true),
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 5.0, 2.0], [11.0, 2.0, 8.0]]]]));
+    }
+
+    #[test]
+    fn test_affine_grid() {
+        let theta = TestTensor::from([[[0.5, -0.2, 0.1], [0.3, 1.0, -0.4]]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], false);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::from([[
+                [[-0.1333, -1.1], [0.2, -0.9], [0.5333, -0.7]],
+                [[-0.3333, -0.1], [0.0, 0.1], [0.3333, 0.3]],
+            ]])
+            .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_align_corners() {
+        let theta = TestTensor::from([[[0.5, -0.2, 0.1], [0.3, 1.0, -0.4]]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], true);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::from([[
+                [[-0.2, -1.7], [0.3, -1.4], [0.8, -1.1]],
+                [[-0.6, 0.3], [-0.1, 0.6], [0.4, 0.9]],
+            ]])
+            .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_identity_affine_grid_sample() {
+        let x = TestTensor::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], false);
+        let output = grid_sample_2d(
+            x.clone(),
+            grid,
+            GridSampleOptions::new(GridSampleMode::Bilinear),
+        );
+
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    struct GridSampleTestCase {
+        options: GridSampleOptions,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, 3, 4]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let grid = TestTensor::from([[
+                [[-1.0, -1.0], [-0.3, 0.2], [0.5, -0.75]],
+                [[1.0, 1.0], [1.4, -1.3], [-0.9, 0.6]],
+            ]]);
+
+            let output = grid_sample_2d(x, grid, self.options);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(module_linear_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate1d;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_upsample_interpolation() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 2,
+            length: 4,
+            length_out: 7,
+        };
+
+        test.assert_output(
+            TestTensor::from([[
+                [0.0000, 0.5000, 1.0000, 1.5000, 2.0000, 2.5000, 3.0000],
+                [4.0000, 4.5000, 5.0000, 5.5000, 6.0000, 6.5000, 7.0000],
+            ]]),
+            InterpolateOptions::new(InterpolateMode::Linear),
+        );
+    }
+
+    #[test]
+    fn test_upsample_interpolation_without_align_corners() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 2,
+            length: 4,
+            length_out: 7,
+        };
+
+        test.assert_output(
+            TestTensor::from([[
+                [0.0000, 0.3571, 0.9286, 1.5000, 2.0714, 2.6429, 3.0000],
+                [4.0000, 4.3571, 4.9286, 5.5000, 6.0714, 6.6429, 7.0000],
+            ]]),
+            InterpolateOptions::new(InterpolateMode::Linear).with_align_corners(false),
+        );
+    }
+
+    #[test]
+    fn test_downsample_interpolation_without_align_corners() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            length: 9,
+            length_out: 4,
+        };
+
+        test.assert_output(
+            TestTensor::from([[[0.6250, 2.8750, 5.1250, 7.3750]]]),
+            InterpolateOptions::new(InterpolateMode::Linear).with_align_corners(false),
+        );
+    }
+
+    struct InterpolateTestCase {
+        batch_size: usize,
+        channels: usize,
+        length: usize,
+        length_out: usize,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, y: TestTensor<3>, options: InterpolateOptions) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.length]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = interpolate1d(x, self.length_out, options);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::{backend::Backend, Data, Tensor};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    fn input() -> TestTensor<5> {
+        TestTensor::from([[[
+            [
+                [0.0, 1.7, 3.4, 1.4],
+                [3.1, 1.1, 2.8, 0.8],
+                [2.5, 0.5, 2.2, 0.2],
+            ],
+            [
+                [1.9, 3.6, 1.6, 3.3],
+                [1.3, 3.0, 1.0, 2.7],
+                [0.7, 2.4, 0.4, 2.1],
+            ],
+            [
+                [0.1, 1.8, 3.5, 1.5],
+                [3.2, 1.2, 2.9, 0.9],
+                [2.6, 0.6, 2.3, 0.3],
+            ],
+        ]]])
+    }
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let y = TestTensor::from([[[
+            [[3.6, 3.6, 3.4], [3.1, 3.0, 2.8]],
+            [[3.6, 3.6, 3.5], [3.2, 3.0, 2.9]],
+        ]]]);
+
+        let output = max_pool3d(input(), [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let y = TestTensor::from([[[[[1.7, 3.4]], [[3.6, 3.4]], [[3.6, 3.5]], [[1.8, 3.5]]]]]);
+
+        let output = max_pool3d(input(), [2, 2, 3], [1, 2, 1], [1, 0, 1], [1, 1, 2]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices() {
+        let y = TestTensor::from([[[
+            [[0.0, 3.4, 1.4], [3.1, 2.8, 0.8]],
+            [[1.9, 3.6, 3.3], [3.2, 3.0, 2.7]],
+        ]]]);
+        let indices =
+            Data::<IntElem, 5>::from([[[[[0, 2, 3], [4, 6, 7]], [[12, 13, 15], [28, 17, 19]]]]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(input(), [2, 2, 2], [2, 2, 2], [1, 1, 1], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+}
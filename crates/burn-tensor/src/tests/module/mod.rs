@@ -1,15 +1,25 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
+mod area_interpolate;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod forward;
+mod grid_sample;
+mod linear_interpolate;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod nearest_interpolate;
+mod trilinear_interpolate;
 mod unfold4d;
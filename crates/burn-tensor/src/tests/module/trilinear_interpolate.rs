@@ -0,0 +1,89 @@
+#[burn_tensor_testgen::testgen(module_trilinear_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate3d;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_interpolation() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 2,
+            height: 3,
+            width: 3,
+            depth_out: 3,
+            height_out: 4,
+            width_out: 2,
+        };
+
+        test.assert_output(
+            TestTensor::from([[[
+                [[0.0, 2.0], [2.0, 4.0], [4.0, 6.0], [6.0, 8.0]],
+                [[4.5, 6.5], [6.5, 8.5], [8.5, 10.5], [10.5, 12.5]],
+                [[9.0, 11.0], [11.0, 13.0], [13.0, 15.0], [15.0, 17.0]],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Trilinear),
+        );
+    }
+
+    #[test]
+    fn test_interpolation_without_align_corners() {
+        let test = InterpolateTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 2,
+            height: 2,
+            width: 3,
+            depth_out: 3,
+            height_out: 3,
+            width_out: 2,
+        };
+
+        test.assert_output(
+            TestTensor::from([[[
+                [[0.25, 1.75], [1.75, 3.25], [3.25, 4.75]],
+                [[3.25, 4.75], [4.75, 6.25], [6.25, 7.75]],
+                [[6.25, 7.75], [7.75, 9.25], [9.25, 10.75]],
+            ]]]),
+            InterpolateOptions::new(InterpolateMode::Trilinear).with_align_corners(false),
+        );
+    }
+
+    struct InterpolateTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        depth_out: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, y: TestTensor<5>, options: InterpolateOptions) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = interpolate3d(
+                x,
+                [self.depth_out, self.height_out, self.width_out],
+                options,
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+#[burn_tensor_testgen::testgen(bitwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn test_bool_and() {
+        let tensor_1 = Tensor::<TestBackend, 2, Bool>::from([[true, true], [false, false]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, false]]);
+
+        let data_actual = tensor_1.bool_and(tensor_2).into_data();
+
+        let data_expected = Data::from([[true, false], [false, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_or() {
+        let tensor_1 = Tensor::<TestBackend, 2, Bool>::from([[true, true], [false, false]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, false]]);
+
+        let data_actual = tensor_1.bool_or(tensor_2).into_data();
+
+        let data_expected = Data::from([[true, true], [true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_xor() {
+        let tensor_1 = Tensor::<TestBackend, 2, Bool>::from([[true, true], [false, false]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, false]]);
+
+        let data_actual = tensor_1.bool_xor(tensor_2).into_data();
+
+        let data_expected = Data::from([[false, true], [true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_and_broadcast() {
+        // A causal mask combined with a padding mask.
+        let causal = Tensor::<TestBackend, 2, Bool>::from([
+            [true, false, false],
+            [true, true, false],
+            [true, true, true],
+        ]);
+        let padding = Tensor::<TestBackend, 2, Bool>::from([[true, true, false]]);
+
+        let data_actual = causal.bool_and(padding).into_data();
+
+        let data_expected = Data::from([
+            [true, false, false],
+            [true, true, false],
+            [true, true, false],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_and() {
+        let tensor_1 = TestTensorInt::from([[3, 5, 12], [-1, 0, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 4, 10], [9, 15, -8]]);
+
+        let data_actual = tensor_1.bitwise_and(tensor_2).into_data();
+
+        let data_expected = Data::from([[2, 4, 8], [9, 0, 0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_and_scalar() {
+        let tensor = TestTensorInt::from([[3, 5, 12], [-1, 0, 7]]);
+
+        let data_actual = tensor.bitwise_and_scalar(6).into_data();
+
+        let data_expected = Data::from([[2, 4, 4], [6, 0, 6]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_or() {
+        let tensor_1 = TestTensorInt::from([[3, 5, 12], [-2, 0, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 4, 10], [1, 15, 8]]);
+
+        let data_actual = tensor_1.bitwise_or(tensor_2).into_data();
+
+        let data_expected = Data::from([[7, 5, 14], [-1, 15, 15]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_or_scalar() {
+        let tensor = TestTensorInt::from([[3, 5, 12], [-2, 0, 7]]);
+
+        let data_actual = tensor.bitwise_or_scalar(1).into_data();
+
+        let data_expected = Data::from([[3, 5, 13], [-1, 1, 7]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_xor() {
+        let tensor_1 = TestTensorInt::from([[3, 5, 12], [-1, 0, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 4, 10], [1, 15, 7]]);
+
+        let data_actual = tensor_1.bitwise_xor(tensor_2).into_data();
+
+        let data_expected = Data::from([[5, 1, 6], [-2, 15, 0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_xor_scalar() {
+        let tensor = TestTensorInt::from([[3, 5, 12], [-1, 0, 7]]);
+
+        let data_actual = tensor.bitwise_xor_scalar(5).into_data();
+
+        let data_expected = Data::from([[6, 0, 9], [-6, 5, 2]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_xor_broadcast() {
+        let tensor_1 = TestTensorInt::from([[1], [2]]);
+        let tensor_2 = TestTensorInt::from([[3, 4, 5]]);
+
+        let data_actual = tensor_1.bitwise_xor(tensor_2).into_data();
+
+        let data_expected = Data::from([[2, 5, 4], [1, 6, 7]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_not() {
+        let tensor = TestTensorInt::from([[0, 1, -1], [5, -6, 127]]);
+
+        let data_actual = tensor.bitwise_not().into_data();
+
+        let data_expected = Data::from([[-1, -2, 0], [-6, 5, -128]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_left_shift() {
+        let tensor_1 = TestTensorInt::from([[1, 3, -2], [5, 0, 7]]);
+        let tensor_2 = TestTensorInt::from([[0, 2, 3], [1, 4, 10]]);
+
+        let data_actual = tensor_1.bitwise_left_shift(tensor_2).into_data();
+
+        let data_expected = Data::from([[1, 12, -16], [10, 0, 7168]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_left_shift_scalar() {
+        let tensor = TestTensorInt::from([[1, 3, -2], [5, 0, 7]]);
+
+        let data_actual = tensor.bitwise_left_shift_scalar(3).into_data();
+
+        let data_expected = Data::from([[8, 24, -16], [40, 0, 56]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_right_shift() {
+        let tensor_1 = TestTensorInt::from([[16, 7, -16], [5, 1024, -1]]);
+        let tensor_2 = TestTensorInt::from([[2, 1, 2], [0, 10, 5]]);
+
+        let data_actual = tensor_1.bitwise_right_shift(tensor_2).into_data();
+
+        let data_expected = Data::from([[4, 3, -4], [5, 1, -1]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bitwise_right_shift_scalar() {
+        let tensor = TestTensorInt::from([[16, 7, -16], [5, 1024, -1]]);
+
+        let data_actual = tensor.bitwise_right_shift_scalar(2).into_data();
+
+        let data_expected = Data::from([[4, 1, -4], [1, 256, -1]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
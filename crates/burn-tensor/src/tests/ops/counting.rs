@@ -0,0 +1,165 @@
+#[burn_tensor_testgen::testgen(counting)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, ElementConversion, Float, Int, Tensor};
+
+    #[test]
+    fn test_unique_int() {
+        let tensor = TestTensorInt::from([3, 1, 3, 2, 1]);
+
+        let (values, inverse, counts) = tensor.clone().unique_with_inverse_and_counts();
+
+        assert_eq!(values.into_data(), Data::from([1, 2, 3]));
+        assert_eq!(inverse.into_data(), Data::from([2, 0, 2, 1, 0]));
+        assert_eq!(counts.into_data(), Data::from([2, 1, 2]));
+        assert_eq!(tensor.unique().into_data(), Data::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_unique_float() {
+        let tensor = TestTensor::from([0.5, -1.0, 0.5, 2.0]);
+
+        let (values, counts) = tensor.unique_with_counts();
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, 0.5, 2.0]), 5);
+        assert_eq!(counts.into_data(), Data::from([1, 2, 1]));
+    }
+
+    #[test]
+    fn test_unique_inverse_should_select_the_input() {
+        let tensor = TestTensorInt::from([4, 4, 0, 7, 0, 4]);
+
+        let (values, inverse, _) = tensor.clone().unique_with_inverse_and_counts();
+
+        assert_eq!(values.select(0, inverse).into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn test_searchsorted_float() {
+        let sorted = TestTensor::from([1.0, 3.0, 5.0, 7.0]);
+        let values = Tensor::<TestBackend, 2>::from([[3.0, 6.0], [0.0, 9.0]]);
+
+        let left = sorted.clone().searchsorted(values.clone());
+        let right = sorted.searchsorted_right(values);
+
+        assert_eq!(left.into_data(), Data::from([[1, 3], [0, 4]]));
+        assert_eq!(right.into_data(), Data::from([[2, 3], [0, 4]]));
+    }
+
+    #[test]
+    fn test_searchsorted_int_with_duplicates() {
+        let sorted = TestTensorInt::from([1, 2, 2, 2, 5]);
+        let values = TestTensorInt::from([2, 3]);
+
+        let left = sorted.clone().searchsorted(values.clone());
+        let right = sorted.searchsorted_right(values);
+
+        assert_eq!(left.into_data(), Data::from([1, 4]));
+        assert_eq!(right.into_data(), Data::from([4, 4]));
+    }
+
+    #[test]
+    fn test_bincount() {
+        let tensor = TestTensorInt::from([1, 3, 1, 0]);
+
+        assert_eq!(
+            tensor.clone().bincount(0).into_data(),
+            Data::from([1, 2, 0, 1])
+        );
+        assert_eq!(
+            tensor.bincount(6).into_data(),
+            Data::from([1, 2, 0, 1, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_histc() {
+        let tensor = TestTensor::from([1.0, 2.0, 1.0, 4.0]);
+
+        let output = tensor.histc(4, 0.0, 4.0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 1.0, 1.0]), 5);
+    }
+
+    #[test]
+    fn test_histc_should_ignore_values_out_of_range() {
+        let tensor = TestTensor::from([-1.0, 0.5, 5.0, 0.0]);
+
+        let output = tensor.histc(2, 0.0, 1.0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 5);
+    }
+
+    #[test]
+    fn test_histc_should_use_the_range_of_the_values_when_min_equals_max() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let output = tensor.histc(2, 0.0, 0.0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0]), 5);
+    }
+
+    #[test]
+    fn test_counting_fallbacks_should_match_backend() {
+        let tensor = TestTensorInt::from([5, 2, 2, 9, 0, 5, 5]);
+
+        let (values, inverse, counts) =
+            burn_tensor::unique::<TestBackend, Int>(tensor.clone().into_primitive());
+        let (values_expected, inverse_expected, counts_expected) =
+            tensor.clone().unique_with_inverse_and_counts();
+        assert_eq!(
+            Tensor::<TestBackend, 1, Int>::from_primitive(values).into_data(),
+            values_expected.into_data()
+        );
+        assert_eq!(
+            Tensor::<TestBackend, 1, Int>::from_primitive(inverse).into_data(),
+            inverse_expected.into_data()
+        );
+        assert_eq!(
+            Tensor::<TestBackend, 1, Int>::from_primitive(counts).into_data(),
+            counts_expected.into_data()
+        );
+
+        let bincount = burn_tensor::bincount::<TestBackend>(tensor.clone().into_primitive(), 12);
+        assert_eq!(
+            Tensor::<TestBackend, 1, Int>::from_primitive(bincount).into_data(),
+            tensor.bincount(12).into_data()
+        );
+
+        let sorted = TestTensor::from([-2.0, 0.0, 0.0, 1.5, 3.0]);
+        let values = TestTensor::from([0.0, -3.0, 1.0, 3.0, 4.0]);
+        for right in [false, true] {
+            let indices = burn_tensor::searchsorted::<TestBackend, 1, Float>(
+                sorted.clone().into_primitive(),
+                values.clone().into_primitive(),
+                right,
+            );
+            let expected = match right {
+                true => sorted.clone().searchsorted_right(values.clone()),
+                false => sorted.clone().searchsorted(values.clone()),
+            };
+            assert_eq!(
+                Tensor::<TestBackend, 1, Int>::from_primitive(indices).into_data(),
+                expected.into_data()
+            );
+        }
+
+        let histc = burn_tensor::histc::<TestBackend>(
+            values.clone().into_primitive(),
+            3,
+            (-3.0).elem(),
+            3.0.elem(),
+        );
+        Tensor::<TestBackend, 1>::from_primitive(histc)
+            .into_data()
+            .assert_approx_eq(&values.histc(3, -3.0, 3.0).into_data(), 5);
+    }
+}
@@ -0,0 +1,136 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Float, Int, Tensor};
+
+    #[test]
+    fn test_cumsum_float() {
+        let tensor = TestTensor::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.clone().cumsum(0);
+        let expected = Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+
+        let output = tensor.cumsum(1);
+        let expected = Data::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::from([1, -2, 3, 4, -5, 6, 7]);
+
+        let output = tensor.cumsum(0);
+
+        assert_eq!(output.into_data(), Data::from([1, -1, 2, 6, 1, 7, 14]));
+    }
+
+    #[test]
+    fn test_cumprod_float() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 0.5, -2.0]]);
+
+        let output = tensor.clone().cumprod(0);
+        let expected = Data::from([[1.0, 2.0, 3.0], [4.0, 1.0, -6.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+
+        let output = tensor.cumprod(1);
+        let expected = Data::from([[1.0, 2.0, 6.0], [4.0, 2.0, -4.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::from([[1, 2, 3, 4], [-1, 2, -3, 4]]);
+
+        let output = tensor.cumprod(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1, 2, 6, 24], [-1, -2, 6, 24]])
+        );
+    }
+
+    #[test]
+    fn test_cummax_cummin_float() {
+        let tensor = TestTensor::from([[1.0, 3.0, 2.0, 5.0, 4.0], [0.0, -1.0, 2.0, -3.0, 1.0]]);
+
+        let output = tensor.clone().cummax(1);
+        let expected = Data::from([[1.0, 3.0, 3.0, 5.0, 5.0], [0.0, 0.0, 2.0, 2.0, 2.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+
+        let output = tensor.cummin(1);
+        let expected = Data::from([[1.0, 1.0, 1.0, 1.0, 1.0], [0.0, -1.0, -1.0, -3.0, -3.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_cummax_with_indices_int() {
+        let tensor = TestTensorInt::from([[1, 3, 3, 2, 4], [5, 1, 5, 6, 0]]);
+
+        let (values, indices) = tensor.cummax_with_indices(1);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[1, 3, 3, 3, 4], [5, 5, 5, 6, 6]])
+        );
+        // Ties select the last occurrence of the running maximum.
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[0, 1, 2, 2, 4], [0, 0, 2, 3, 3]])
+        );
+    }
+
+    #[test]
+    fn test_cummin_with_indices_float() {
+        let tensor = TestTensor::from([[3.0, 1.0], [2.0, 1.0], [4.0, 0.0], [2.0, 5.0]]);
+
+        let (values, indices) = tensor.cummin_with_indices(0);
+
+        let expected = Data::from([[3.0, 1.0], [2.0, 1.0], [2.0, 0.0], [2.0, 0.0]]);
+        values.into_data().assert_approx_eq(&expected, 5);
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[0, 0], [1, 1], [1, 2], [3, 2]])
+        );
+    }
+
+    #[test]
+    fn test_cumulative_fallbacks_should_match_backend() {
+        let tensor = TestTensor::from([[1.0, -3.0, 2.0, 5.0, 4.0, -1.0, 2.0]]);
+
+        let cumsum =
+            burn_tensor::cumsum::<TestBackend, 2, Float>(tensor.clone().into_primitive(), 1);
+        let cumsum = Tensor::<TestBackend, 2>::from_primitive(cumsum);
+        cumsum
+            .into_data()
+            .assert_approx_eq(&tensor.clone().cumsum(1).into_data(), 5);
+
+        let cumprod =
+            burn_tensor::cumprod::<TestBackend, 2, Float>(tensor.clone().into_primitive(), 1);
+        let cumprod = Tensor::<TestBackend, 2>::from_primitive(cumprod);
+        cumprod
+            .into_data()
+            .assert_approx_eq(&tensor.clone().cumprod(1).into_data(), 5);
+
+        let tensor = TestTensorInt::from([[1, 3, 3, 2, 4, 4, 0]]);
+        let (values, indices) = burn_tensor::cummax_with_indices::<TestBackend, 2, Int>(
+            tensor.clone().into_primitive(),
+            1,
+        );
+        let (values_expected, indices_expected) = tensor.clone().cummax_with_indices(1);
+        assert_eq!(
+            Tensor::<TestBackend, 2, Int>::from_primitive(values).into_data(),
+            values_expected.into_data()
+        );
+        assert_eq!(
+            Tensor::<TestBackend, 2, Int>::from_primitive(indices).into_data(),
+            indices_expected.into_data()
+        );
+
+        let cummin = burn_tensor::cummin::<TestBackend, 2, Int>(tensor.clone().into_primitive(), 1);
+        assert_eq!(
+            Tensor::<TestBackend, 2, Int>::from_primitive(cummin).into_data(),
+            tensor.cummin(1).into_data()
+        );
+    }
+}
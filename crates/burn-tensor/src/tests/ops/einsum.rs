@@ -0,0 +1,155 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, Data, Shape, Tensor};
+
+    #[test]
+    fn test_einsum_matmul() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 0.0], [2.0, -1.0], [0.0, 3.0]]);
+
+        let output =
+            einsum::<TestBackend, 2>("ij,jk->ik", vec![lhs.clone().into(), rhs.clone().into()]);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 7.0], [14.0, 13.0]]), 4);
+
+        let output = einsum::<TestBackend, 2>("ij, jk", vec![lhs.into(), rhs.into()]);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 7.0], [14.0, 13.0]]), 4);
+    }
+
+    #[test]
+    fn test_einsum_batched_attention_scores() {
+        let query = TestTensor::from([[[[1.0, 2.0], [0.0, 1.0]], [[2.0, -1.0], [1.0, 1.0]]]]);
+        let key = TestTensor::from([[
+            [[1.0, 0.0], [1.0, 1.0], [2.0, -1.0]],
+            [[0.0, 1.0], [3.0, 2.0], [-1.0, 1.0]],
+        ]]);
+
+        let output = einsum::<TestBackend, 4>("bhqd,bhkd->bhqk", vec![query.into(), key.into()]);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[
+                [[1.0, 3.0, 0.0], [0.0, 1.0, -1.0]],
+                [[-1.0, 4.0, -3.0], [1.0, 5.0, 0.0]],
+            ]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_einsum_single_operand() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let trace = einsum::<TestBackend, 1>("ii->", vec![tensor.clone().into()]);
+        trace.into_data().assert_approx_eq(&Data::from([15.0]), 4);
+
+        let diagonal = einsum::<TestBackend, 1>("ii->i", vec![tensor.clone().into()]);
+        diagonal
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 5.0, 9.0]), 4);
+
+        let tensor = tensor.narrow(0, 0, 2);
+        let transposed = einsum::<TestBackend, 2>("ij->ji", vec![tensor.clone().into()]);
+        transposed
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), 4);
+
+        let sum = einsum::<TestBackend, 1>("ij->j", vec![tensor.into()]);
+        sum.into_data()
+            .assert_approx_eq(&Data::from([5.0, 7.0, 9.0]), 4);
+    }
+
+    #[test]
+    fn test_einsum_outer_product_of_different_ranks() {
+        let lhs = TestTensor::from([1.0, -1.0, 2.0]);
+        let rhs = TestTensor::from([2.0, 3.0]);
+
+        let output = einsum::<TestBackend, 2>("i,j->ij", vec![lhs.into(), rhs.into()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 3.0], [-2.0, -3.0], [4.0, 6.0]]), 4);
+    }
+
+    #[test]
+    fn test_einsum_chain_of_three_operands() {
+        let a = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = TestTensor::from([[1.0, 0.0], [2.0, -1.0], [0.0, 3.0]]);
+        let c = TestTensor::from([[2.0, 1.0], [0.0, -1.0]]);
+
+        let output = einsum::<TestBackend, 2>("ij,jk,kl->il", vec![a.into(), b.into(), c.into()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0], [28.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn test_einsum_ellipsis() {
+        let lhs = Tensor::<TestBackend, 3>::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[0.0, 1.0, 0.0], [-1.0, 2.0, 1.0]],
+        ]);
+        let rhs = TestTensor::from([[1.0, 0.0], [2.0, -1.0], [0.0, 3.0]]);
+        let vectors = TestTensor::from([[1.0, 0.0, 2.0], [1.0, 1.0, -1.0]]);
+
+        let output =
+            einsum::<TestBackend, 3>("...ij,jk->...ik", vec![lhs.clone().into(), rhs.into()]);
+        output.into_data().assert_approx_eq(
+            &Data::from([[[5.0, 7.0], [14.0, 13.0]], [[2.0, -1.0], [3.0, 1.0]]]),
+            4,
+        );
+
+        let output = einsum::<TestBackend, 2>("...ij,...j->...i", vec![lhs.into(), vectors.into()]);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[7.0, 16.0], [1.0, 0.0]]), 4);
+    }
+
+    #[test]
+    fn test_einsum_interleaved_high_rank_intermediate() {
+        let device = Default::default();
+        let x = TestTensor::from([[1.0, -1.0], [2.0, 0.5]]).reshape([2, 2, 1, 1]);
+        let y = TestTensor::from([1.0, 2.0]).reshape([1, 2, 1, 1]);
+        let values: Vec<f32> = (0..64).map(|i| (i % 7) as f32 - 3.0).collect();
+        let z = TestTensor::<1>::from_data(Data::new(values, Shape::new([64])).convert(), &device)
+            .reshape([2, 1, 2, 1, 4, 4]);
+
+        // The outer product of the first two operands is the smallest intermediate tensor. Its 8
+        // dimensions are then reordered to move the 4 contracted ones last.
+        let output = einsum::<TestBackend, 6>(
+            "abcd,efgh,bdfhij->acegij",
+            vec![x.into(), y.into(), z.into()],
+        );
+
+        output.reshape([2, 4, 4]).into_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-12.0, 2.0, 2.0, 9.0],
+                    [9.0, -5.0, -5.0, -12.0],
+                    [2.0, 2.0, 9.0, 9.0],
+                    [-5.0, -5.0, -12.0, 2.0],
+                ],
+                [
+                    [-6.5, -6.0, 1.5, 5.5],
+                    [13.0, -7.5, 0.0, -6.5],
+                    [-6.0, 1.5, 5.5, 13.0],
+                    [-7.5, 0.0, -6.5, -6.0],
+                ],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_should_panic_when_label_sizes_differ() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 0.0], [2.0, -1.0]]);
+
+        einsum::<TestBackend, 2>("ij,jk->ik", vec![lhs.into(), rhs.into()]);
+    }
+}
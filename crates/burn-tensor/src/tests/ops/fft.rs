@@ -0,0 +1,208 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ComplexTensor, Data, Tensor};
+
+    #[test]
+    fn test_fft() {
+        let real = TestTensor::from([[1.0, 2.0, 0.0, -1.0], [2.0, 0.0, 1.0, 3.0]]);
+        let imag = TestTensor::from([[0.0, -1.0, 3.0, 1.0], [1.0, 0.0, -2.0, 0.0]]);
+
+        let (real, imag) = ComplexTensor::new(real, imag).fft(1).into_parts();
+
+        real.into_data().assert_approx_eq(
+            &Data::from([[2.0, -1.0, 0.0, 3.0], [6.0, 1.0, 0.0, 1.0]]),
+            4,
+        );
+        imag.into_data().assert_approx_eq(
+            &Data::from([[3.0, -6.0, 3.0, 0.0], [-1.0, 6.0, -1.0, 0.0]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_fft_dim_0_non_power_of_two() {
+        let real = TestTensor::from([[1.0, 2.0], [0.0, -1.0], [2.0, 1.0]]);
+        let imag = TestTensor::from([[0.0, -1.0], [3.0, 1.0], [0.0, 0.0]]);
+
+        let (real, imag) = ComplexTensor::new(real, imag).fft(0).into_parts();
+
+        real.into_data().assert_approx_eq(
+            &Data::from([[3.0, 2.0], [2.59808, 2.86603], [-2.59808, 1.13397]]),
+            4,
+        );
+        imag.into_data().assert_approx_eq(
+            &Data::from([[3.0, 0.0], [0.23205, 0.23205], [-3.23205, -3.23205]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_ifft() {
+        let real = TestTensor::from([1.0, 2.0, 3.0, 0.0, -1.0]);
+        let imag = TestTensor::from([1.0, 0.0, -1.0, 0.5, 0.0]);
+
+        let (real, imag) = ComplexTensor::new(real, imag).ifft(0).into_parts();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([1.0, -0.04727, -0.06171, 0.50892, -0.39994]), 4);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.1, 1.20421, -0.04886, 0.38706, -0.6424]), 4);
+    }
+
+    #[test]
+    fn test_ifft_should_invert_fft() {
+        let real = TestTensor::from([[1.0, -2.0, 0.5, 4.0, 3.0, 0.0, 1.0, 2.0]]);
+        let imag = TestTensor::from([[0.0, 1.0, -1.0, 2.0, 0.5, 0.0, 3.0, -2.0]]);
+        let tensor = ComplexTensor::new(real.clone(), imag.clone());
+
+        let (output_real, output_imag) = tensor.fft(1).ifft(1).into_parts();
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 4);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 4);
+    }
+
+    #[test]
+    fn test_rfft() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0, 4.0, 0.0], [1.0, -2.0, 0.5, 4.0, 3.0]]);
+
+        let (real, imag) = tensor.rfft(1).into_parts();
+
+        real.into_data().assert_approx_eq(
+            &Data::from([[10.0, -4.04508, 1.54508], [6.5, -2.33156, 1.58156]]),
+            4,
+        );
+        imag.into_data().assert_approx_eq(
+            &Data::from([[0.0, -1.31433, -2.12663], [0.0, 6.81253, -0.38977]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_irfft_should_invert_rfft() {
+        let even = TestTensor::from([[1.0, 2.0, 3.0, 4.0], [-1.0, 0.5, 2.0, 0.0]]);
+        let odd = TestTensor::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 2.0]]);
+
+        let output = even.clone().rfft(1).irfft(1, 4);
+        output.into_data().assert_approx_eq(&even.into_data(), 4);
+
+        let output = odd.clone().rfft(0).irfft(0, 2);
+        output
+            .into_data()
+            .assert_approx_eq(&odd.clone().into_data(), 4);
+
+        let output = odd.clone().rfft(1).irfft(1, 3);
+        output.into_data().assert_approx_eq(&odd.into_data(), 4);
+    }
+
+    #[test]
+    fn test_irfft_should_ignore_imaginary_part_of_real_frequencies() {
+        let real = TestTensor::from([10.0, -2.0, -2.0]);
+        let imag = TestTensor::from([5.0, 2.0, -3.0]);
+
+        let output = ComplexTensor::new(real, imag).irfft(0, 4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0, 3.0, 4.0]), 4);
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let lhs = ComplexTensor::new(TestTensor::from([1.0, 3.0]), TestTensor::from([2.0, -1.0]));
+        let rhs = ComplexTensor::new(TestTensor::from([0.0, 2.0]), TestTensor::from([1.0, 2.0]));
+
+        let (real, imag) = lhs.clone().mul(rhs.clone()).into_parts();
+        real.into_data()
+            .assert_approx_eq(&Data::from([-2.0, 8.0]), 4);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([1.0, 4.0]), 4);
+
+        let (real, imag) = lhs.clone().add(rhs.clone().conj()).into_parts();
+        real.into_data()
+            .assert_approx_eq(&Data::from([1.0, 5.0]), 4);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([1.0, -3.0]), 4);
+
+        let (real, imag) = lhs.sub(rhs).into_parts();
+        real.into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 4);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([1.0, -3.0]), 4);
+
+        let magnitude =
+            ComplexTensor::new(TestTensor::from([3.0, 0.0]), TestTensor::from([-4.0, 2.0])).abs();
+        magnitude
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0]), 4);
+    }
+
+    #[test]
+    fn test_fft_fallbacks_should_match_backend() {
+        let real = TestTensor::from([
+            [1.0, -2.0, 0.5, 4.0, 3.0, 0.0],
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        ]);
+        let imag = TestTensor::from([
+            [0.0, 1.0, -1.0, 2.0, 0.5, 0.0],
+            [3.0, -2.0, 0.0, 1.0, 0.0, 1.0],
+        ]);
+
+        let (expected_real, expected_imag) = ComplexTensor::new(real.clone(), imag.clone())
+            .fft(1)
+            .into_parts();
+        let (output_real, output_imag) = burn_tensor::fft::<TestBackend, 2>(
+            real.clone().into_primitive(),
+            imag.clone().into_primitive(),
+            1,
+        );
+        Tensor::<TestBackend, 2>::from_primitive(output_real)
+            .into_data()
+            .assert_approx_eq(&expected_real.into_data(), 4);
+        Tensor::<TestBackend, 2>::from_primitive(output_imag)
+            .into_data()
+            .assert_approx_eq(&expected_imag.into_data(), 4);
+
+        let (expected_real, expected_imag) = ComplexTensor::new(real.clone(), imag.clone())
+            .ifft(0)
+            .into_parts();
+        let (output_real, output_imag) = burn_tensor::ifft::<TestBackend, 2>(
+            real.clone().into_primitive(),
+            imag.clone().into_primitive(),
+            0,
+        );
+        Tensor::<TestBackend, 2>::from_primitive(output_real)
+            .into_data()
+            .assert_approx_eq(&expected_real.into_data(), 4);
+        Tensor::<TestBackend, 2>::from_primitive(output_imag)
+            .into_data()
+            .assert_approx_eq(&expected_imag.into_data(), 4);
+
+        let (expected_real, expected_imag) = real.clone().rfft(1).into_parts();
+        let (output_real, output_imag) =
+            burn_tensor::rfft::<TestBackend, 2>(real.clone().into_primitive(), 1);
+        Tensor::<TestBackend, 2>::from_primitive(output_real)
+            .into_data()
+            .assert_approx_eq(&expected_real.into_data(), 4);
+        Tensor::<TestBackend, 2>::from_primitive(output_imag)
+            .into_data()
+            .assert_approx_eq(&expected_imag.into_data(), 4);
+
+        let real = real.narrow(1, 0, 4);
+        let imag = imag.narrow(1, 0, 4);
+        let expected = ComplexTensor::new(real.clone(), imag.clone()).irfft(1, 7);
+        let output = burn_tensor::irfft::<TestBackend, 2>(
+            real.into_primitive(),
+            imag.into_primitive(),
+            1,
+            7,
+        );
+        Tensor::<TestBackend, 2>::from_primitive(output)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
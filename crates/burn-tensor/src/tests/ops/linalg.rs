@@ -0,0 +1,153 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn test_solve_batched() {
+        let a = Tensor::<TestBackend, 3>::from([
+            [[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]],
+            [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]],
+        ]);
+        let b = Tensor::<TestBackend, 3>::from([
+            [[1.0, 2.0], [0.0, 1.0], [3.0, -1.0]],
+            [[1.0, 0.0], [2.0, 1.0], [0.0, 2.0]],
+        ]);
+
+        let x = linalg::solve(a, b);
+
+        x.into_data().assert_approx_eq(
+            &Data::from([
+                [[0.77778, 0.94444], [-0.55556, 0.11111], [0.88889, -0.27778]],
+                [[0.6, 0.8], [1.4, 0.2], [-1.8, -0.4]],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_inv() {
+        let tensor = TestTensor::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+
+        let output = linalg::inv(tensor.clone());
+
+        output.clone().into_data().assert_approx_eq(
+            &Data::from([[-0.2, 0.4, 0.2], [0.2, 0.6, -0.2], [0.6, -1.2, 0.4]]),
+            4,
+        );
+        tensor
+            .matmul(output)
+            .into_data()
+            .assert_approx_eq(&TestTensor::diagonal(3, &Default::default()).into_data(), 4);
+    }
+
+    #[test]
+    fn test_det_and_slogdet() {
+        let tensor = Tensor::<TestBackend, 3>::from([
+            [[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]],
+            [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]],
+            [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]],
+        ]);
+
+        let det = linalg::det(tensor.clone());
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        det.into_data()
+            .assert_approx_eq(&Data::from([[[18.0]], [[-5.0]], [[0.0]]]), 3);
+        sign.into_data()
+            .assert_approx_eq(&Data::from([[[1.0]], [[-1.0]], [[0.0]]]), 4);
+
+        let logabsdet = logabsdet.into_data().value;
+        assert!((logabsdet[0] - 18.0f32.ln()).abs() < 1e-4);
+        assert!((logabsdet[1] - 5.0f32.ln()).abs() < 1e-4);
+        assert_eq!(logabsdet[2], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let tensor = TestTensor::from([[4.0, 2.0, -2.0], [2.0, 10.0, 2.0], [-2.0, 2.0, 6.0]]);
+
+        let output = linalg::cholesky(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 1.0, 2.0]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_qr() {
+        let tall = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let wide = TestTensor::from([[3.0, 1.0, 2.0], [4.0, -2.0, 0.0]]);
+
+        let (q, r) = linalg::qr(tall.clone());
+        q.clone().into_data().assert_approx_eq(
+            &Data::from([[0.16903, 0.89709], [0.50709, 0.27603], [0.84515, -0.34503]]),
+            4,
+        );
+        r.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.91608, 7.43736], [0.0, 0.82808]]), 4);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tall.into_data(), 4);
+
+        let (q, r) = linalg::qr(wide);
+        q.into_data()
+            .assert_approx_eq(&Data::from([[0.6, 0.8], [0.8, -0.6]]), 4);
+        r.into_data()
+            .assert_approx_eq(&Data::from([[5.0, -1.0, 1.2], [0.0, 2.0, 1.6]]), 4);
+    }
+
+    #[test]
+    fn test_svd() {
+        let tensor = TestTensor::from([[3.0, 0.0], [4.0, 5.0]]);
+        let wide = Tensor::<TestBackend, 3>::from([[[1.0, 2.0, 3.0], [-1.0, 0.0, 4.0]]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        u.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.31623, 0.94868], [0.94868, -0.31623]]), 4);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.7082, 2.23607]]), 4);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+
+        let (u, s, vh) = linalg::svd(wide.clone());
+        assert_eq!(u.dims(), [1, 2, 2]);
+        assert_eq!(s.dims(), [1, 1, 2]);
+        assert_eq!(vh.dims(), [1, 2, 3]);
+        vh.clone()
+            .matmul(vh.clone().swap_dims(1, 2))
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0, 0.0], [0.0, 1.0]]]), 4);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&wide.into_data(), 4);
+    }
+
+    #[test]
+    fn test_eigh() {
+        // Only the lower triangle is used.
+        let tensor = TestTensor::from([[2.0, 100.0], [1.0, 3.0]]);
+
+        let (values, vectors) = linalg::eigh(tensor);
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.38197, 3.61803]]), 4);
+        vectors
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.85065, 0.52573], [-0.52573, 0.85065]]), 4);
+        (vectors.clone() * values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 1.0], [1.0, 3.0]]), 4);
+    }
+}
@@ -7,6 +7,7 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod bitwise;
 mod bool;
 mod cast;
 mod cat;
@@ -14,15 +15,20 @@ mod chunk;
 mod clamp;
 mod close;
 mod cos;
+mod counting;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod fft;
 mod flatten;
 mod full;
 mod gather_scatter;
 mod init;
 mod iter_dim;
+mod linalg;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -30,18 +36,25 @@ mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
+mod multinomial;
 mod narrow;
 mod neg;
 mod one_hot;
+mod pad;
 mod powf;
 mod powf_scalar;
+mod quantized;
 mod random;
 mod recip;
 mod repeat;
 mod reshape;
+mod round;
 mod select;
 mod sin;
 mod slice;
+mod sort_argsort;
+mod sparse;
+mod special;
 mod sqrt;
 mod squeeze;
 mod stack;
@@ -49,3 +62,4 @@ mod sub;
 mod tanh;
 mod transpose;
 mod tri;
+mod trig;
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_sample_only_categories_with_weight() {
+        let weights = TestTensor::from([[0.0, 3.0, 0.0, 1.0], [0.0, 0.0, 2.0, 0.0]]);
+
+        let samples = weights.multinomial(50, true);
+
+        assert_eq!(samples.dims(), [2, 50]);
+        let data = samples.into_data();
+        for (i, value) in data.value.into_iter().enumerate() {
+            match i / 50 {
+                0 => assert!(value == 1 || value == 3, "Unexpected category {value}"),
+                _ => assert_eq!(value, 2),
+            }
+        }
+    }
+
+    #[test]
+    fn should_sample_with_weights_frequencies() {
+        let weights = TestTensor::from([1.0, 3.0]);
+
+        let samples = weights.multinomial(10000, true);
+        let frequency = samples.float().mean().into_scalar();
+
+        assert!(
+            (frequency - 0.75).abs() < 0.03,
+            "The frequency of the second category ({frequency}) is not close to 0.75"
+        );
+    }
+
+    #[test]
+    fn should_sample_without_replacement() {
+        let weights = TestTensor::from([[1.0, 2.0, 3.0, 4.0, 5.0], [5.0, 0.1, 1.0, 1.0, 0.5]]);
+
+        let samples = weights.multinomial(5, false).sort(1);
+
+        assert_eq!(
+            samples.into_data(),
+            Tensor::<TestBackend, 1, Int>::arange(0..5, &Default::default())
+                .reshape([1, 5])
+                .repeat(0, 2)
+                .into_data()
+        );
+    }
+
+    #[test]
+    fn should_sample_categories_with_weight_without_replacement() {
+        let weights = TestTensor::from([0.0, 2.0, 0.0, 1.0, 0.0]);
+
+        let samples = weights.multinomial(2, false).sort(0);
+
+        assert_eq!(samples.into_data(), Data::from([1, 3]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sampling_more_categories_than_available_without_replacement() {
+        let weights = TestTensor::from([1.0, 2.0]);
+
+        let _samples = weights.multinomial(3, false);
+    }
+}
@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode};
+
+    #[test]
+    fn should_pad_constant() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.pad([(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [0.5, 0.5, 0.5, 0.5],
+                [1.0, 2.0, 0.5, 0.5],
+                [3.0, 4.0, 0.5, 0.5]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_reflect() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.pad([(1, 1), (2, 1)], PadMode::Reflect);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_replicate() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.pad([(0, 1), (1, 2)], PadMode::Replicate);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [1.0, 1.0, 2.0, 3.0, 3.0, 3.0],
+                [4.0, 4.0, 5.0, 6.0, 6.0, 6.0],
+                [4.0, 4.0, 5.0, 6.0, 6.0, 6.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_circular() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.pad([(1, 0), (2, 2)], PadMode::Circular);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [5.0, 6.0, 4.0, 5.0, 6.0, 4.0, 5.0],
+                [2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0],
+                [5.0, 6.0, 4.0, 5.0, 6.0, 4.0, 5.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_3d_only_the_given_dims() {
+        let tensor = TestTensor::from([[[1.0, 2.0]], [[3.0, 4.0]]]);
+
+        let output = tensor.pad([(0, 0), (1, 1), (0, 0)], PadMode::Replicate);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]],
+                [[3.0, 4.0], [3.0, 4.0], [3.0, 4.0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_int_constant() {
+        let tensor = TestTensorInt::from([[1, 2]]);
+
+        let output = tensor.pad([(1, 1), (0, 1)], PadMode::Constant(7.0));
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[7, 7, 7], [1, 2, 7], [7, 7, 7]])
+        );
+    }
+}
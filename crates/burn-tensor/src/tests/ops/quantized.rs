@@ -0,0 +1,207 @@
+#[burn_tensor_testgen::testgen(quantized)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{conv2d, quantized_conv2d};
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Data, Int, QuantizationScheme, Tensor};
+
+    #[test]
+    fn should_quantize_per_tensor_rounding_half_to_even() {
+        let tensor = TestTensor::from([-1.0, 0.0, 0.5, 2.0, 1.25, 1.75, 100.0, -100.0]);
+        let scale = TestTensor::from([0.5]);
+        let zero_point = TestTensorInt::from([-10]);
+
+        let quantized = tensor.quantize(scale, zero_point, QuantizationScheme::PerTensor);
+
+        assert_eq!(
+            quantized.values().into_data(),
+            Data::from([-12, -10, -9, -6, -8, -6, 127, -128])
+        );
+        assert_eq!(
+            quantized.dequantize().into_data(),
+            Data::from([-1.0, 0.0, 0.5, 2.0, 1.0, 2.0, 68.5, -59.0])
+        );
+    }
+
+    #[test]
+    fn should_quantize_per_channel() {
+        let tensor = TestTensor::from([[1.0, -2.0], [3.0, 4.0]]);
+        let scale = TestTensor::from([0.5, 0.25]);
+        let zero_point = TestTensorInt::from([0, 10]);
+
+        let quantized = tensor.quantize(scale, zero_point, QuantizationScheme::PerChannel(1));
+
+        assert_eq!(
+            quantized.values().into_data(),
+            Data::from([[2, 2], [6, 26]])
+        );
+        assert_eq!(
+            quantized.dequantize().into_data(),
+            Data::from([[1.0, -2.0], [3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_quantize_dynamic_within_half_a_scale() {
+        let tensor = TestTensor::from([[-0.55, 0.0, 2.0, 0.3], [0.0, 0.0, 0.0, 0.0]]);
+
+        let quantized = tensor
+            .clone()
+            .quantize_dynamic(QuantizationScheme::PerChannel(0));
+
+        let values = quantized.values();
+        assert_eq!(values.clone().min().into_scalar(), -128);
+        assert_eq!(values.max().into_scalar(), 127);
+        assert_eq!(quantized.zero_point().into_data(), Data::from([-73, -128]));
+        quantized
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&tensor.into_data(), 0.006);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_number_of_scales_does_not_match_the_channels() {
+        let tensor = TestTensor::from([[1.0, -2.0], [3.0, 4.0]]);
+        let scale = TestTensor::from([0.5, 0.25, 1.0]);
+        let zero_point = TestTensorInt::from([0, 0, 0]);
+
+        let _quantized = tensor.quantize(scale, zero_point, QuantizationScheme::PerChannel(1));
+    }
+
+    #[test]
+    fn should_matmul_int_tensors() {
+        let lhs = TestTensorInt::from([[[1, 2], [3, 4]], [[1000, 2], [0, -1]]]);
+        let rhs = TestTensorInt::from([[[5, 6], [7, 8]], [[1000, 0], [3, 1]]]);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[19, 22], [43, 50]], [[1000006, 2], [-3, -1]]])
+        );
+    }
+
+    #[test]
+    fn should_matmul_quantized_tensors() {
+        let device = Default::default();
+        let lhs = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]).quantize(
+            TestTensor::from([0.5]),
+            TestTensorInt::from([1]),
+            QuantizationScheme::PerTensor,
+        );
+        let rhs = TestTensor::from([[1.0, 0.0], [-1.0, 2.0]]).quantize(
+            Tensor::from_floats([0.25, 0.5], &device),
+            Tensor::<TestBackend, 1, Int>::from_ints([0, -2], &device),
+            QuantizationScheme::PerChannel(1),
+        );
+
+        assert_eq!(rhs.values().into_data(), Data::from([[4, -2], [-4, 2]]));
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(output.into_data(), Data::from([[-1.0, 4.0], [-1.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_conv2d_quantized_tensors() {
+        let x = TestTensor::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+        let weight = TestTensor::from([[[[0.5, 0.5], [0.5, 0.5]]], [[[1.0, 0.0], [0.0, -1.0]]]]);
+        let bias = TestTensor::from([1.0, -1.0]);
+
+        let x = x.quantize(
+            TestTensor::from([1.0]),
+            TestTensorInt::from([0]),
+            QuantizationScheme::PerTensor,
+        );
+        let weight = weight.quantize(
+            TestTensor::from([0.5, 1.0]),
+            TestTensorInt::from([0, 0]),
+            QuantizationScheme::PerChannel(0),
+        );
+
+        let output = quantized_conv2d(
+            x,
+            weight,
+            Some(bias),
+            ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+        );
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[[7.0, 9.0], [13.0, 15.0]], [[-5.0, -5.0], [-5.0, -5.0]]]])
+        );
+    }
+
+    #[test]
+    fn should_conv2d_quantized_tensors_with_zero_points_and_padding() {
+        let x = TestTensor::from([[
+            [[1.0, -2.0, 3.0], [0.5, 1.5, -1.0], [2.0, 0.0, -0.5]],
+            [[-1.0, 0.5, 2.5], [1.0, -1.5, 0.0], [3.0, 2.0, 1.0]],
+        ]])
+        .quantize(
+            TestTensor::from([0.5]),
+            TestTensorInt::from([3]),
+            QuantizationScheme::PerTensor,
+        );
+        let weight = TestTensor::from([
+            [[[0.25, -0.5], [1.0, 0.0]], [[-0.25, 0.75], [0.5, -1.0]]],
+            [[[1.5, 0.25], [-0.75, 0.5]], [[0.0, 1.0], [-0.5, 0.25]]],
+        ])
+        .quantize(
+            TestTensor::from([0.25]),
+            TestTensorInt::from([-1]),
+            QuantizationScheme::PerTensor,
+        );
+        let options = ConvOptions::new([2, 1], [1, 1], [1, 1], 1);
+
+        let expected = conv2d(
+            x.clone().dequantize(),
+            weight.clone().dequantize(),
+            None,
+            options.clone(),
+        );
+        let output = quantized_conv2d(x, weight, None, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn should_match_conv2d_with_groups_and_asymmetric_weight_per_channel() {
+        let x = TestTensor::from([[
+            [[1.0, -0.5, 2.0], [0.0, 1.5, -1.0], [2.5, 0.5, 1.0]],
+            [[-1.0, 0.5, 2.5], [1.0, -1.5, 0.0], [3.0, 2.0, 1.0]],
+        ]])
+        .quantize(
+            TestTensor::from([0.5]),
+            TestTensorInt::from([3]),
+            QuantizationScheme::PerTensor,
+        );
+        let weight = TestTensor::from([
+            [[[0.25, -0.5], [1.0, 0.0]]],
+            [[[1.5, 0.25], [-0.75, 0.5]]],
+            [[[-0.25, 0.75], [0.5, -1.0]]],
+            [[[0.0, 1.0], [-0.5, 0.25]]],
+        ])
+        .quantize(
+            TestTensor::from([0.25, 0.25, 0.125, 0.25]),
+            TestTensorInt::from([-1, 2, 0, -3]),
+            QuantizationScheme::PerChannel(0),
+        );
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 2);
+
+        let expected = conv2d(
+            x.clone().dequantize(),
+            weight.clone().dequantize(),
+            None,
+            options.clone(),
+        );
+        let output = quantized_conv2d(x, weight, None, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
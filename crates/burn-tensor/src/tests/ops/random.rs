@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, Generator, Int, Tensor};
 
     #[test]
     fn rand_default() {
@@ -33,4 +33,109 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1.; 20].into());
     }
+
+    #[test]
+    fn rand_truncated_normal() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [1000],
+            Distribution::TruncatedNormal(0., 1., -0.5, 2.),
+            &Default::default(),
+        );
+
+        tensor.into_data().assert_within_range(-0.5..2.0001);
+    }
+
+    #[test]
+    fn rand_exponential() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [10000],
+            Distribution::Exponential(2.),
+            &Default::default(),
+        );
+
+        assert_mean(tensor.clone(), 0.5, 0.05);
+        tensor.into_data().assert_within_range(0.0..f32::INFINITY);
+    }
+
+    #[test]
+    fn rand_gamma() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [10000],
+            Distribution::Gamma(3., 2.),
+            &Default::default(),
+        );
+
+        assert_mean(tensor.clone(), 6.0, 0.3);
+        tensor.into_data().assert_within_range(0.0..f32::INFINITY);
+    }
+
+    #[test]
+    fn rand_beta() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [10000],
+            Distribution::Beta(2., 6.),
+            &Default::default(),
+        );
+
+        assert_mean(tensor.clone(), 0.25, 0.02);
+        tensor.into_data().assert_within_range(0.0..1.0001);
+    }
+
+    #[test]
+    fn rand_poisson() {
+        let tensor = Tensor::<TestBackend, 1>::random(
+            [10000],
+            Distribution::Poisson(4.),
+            &Default::default(),
+        );
+
+        assert_mean(tensor.clone(), 4.0, 0.2);
+        let data = tensor.into_data();
+        data.assert_within_range(0.0..f32::INFINITY);
+        for value in data.value {
+            assert_eq!(value, value.round());
+        }
+    }
+
+    #[test]
+    fn rand_with_generator() {
+        let device = Default::default();
+        let mut generator_1 = Generator::new(0);
+        let mut generator_2 = Generator::new(0);
+        let distribution = Distribution::Normal(0., 1.);
+
+        let tensor_1 = Tensor::<TestBackend, 2>::random_with_generator(
+            [4, 5],
+            distribution,
+            &mut generator_1.fork(),
+            &device,
+        );
+        let tensor_2 = Tensor::<TestBackend, 2>::random_with_generator(
+            [4, 5],
+            distribution,
+            &mut generator_2.fork(),
+            &device,
+        );
+
+        assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+    }
+
+    #[test]
+    fn randperm() {
+        let tensor = Tensor::<TestBackend, 1, Int>::randperm(20, &Default::default());
+
+        assert_eq!(
+            tensor.sort(0).into_data(),
+            Tensor::<TestBackend, 1, Int>::arange(0..20, &Default::default()).into_data()
+        );
+    }
+
+    fn assert_mean(tensor: Tensor<TestBackend, 1>, expected: f32, tolerance: f32) {
+        let mean = tensor.mean().into_scalar();
+
+        assert!(
+            (mean - expected).abs() < tolerance,
+            "The mean ({mean}) is not close to {expected}"
+        );
+    }
 }
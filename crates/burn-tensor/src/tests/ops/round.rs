@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(round)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_floor_ops() {
+        let tensor = TestTensor::from([-2.5, -0.5, 0.5, 1.5, 2.5]);
+
+        let data_actual = tensor.floor().into_data();
+
+        let data_expected = Data::from([-3.0, -1.0, 0.0, 1.0, 2.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_ceil_ops() {
+        let tensor = TestTensor::from([-2.5, -0.5, 0.5, 1.5, 2.5]);
+
+        let data_actual = tensor.ceil().into_data();
+
+        let data_expected = Data::from([-2.0, 0.0, 1.0, 2.0, 3.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_round_halfway_values_to_even() {
+        let tensor = TestTensor::from([-2.5, -0.5, 0.5, 1.5, 2.7]);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([-2.0, 0.0, 0.0, 2.0, 3.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_trunc_ops() {
+        let tensor = TestTensor::from([-2.5, -0.5, 0.5, 1.5, 2.7]);
+
+        let data_actual = tensor.trunc().into_data();
+
+        let data_expected = Data::from([-2.0, 0.0, 0.0, 1.0, 2.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_sign_ops() {
+        let data = Data::from([[-2.5, 0.0, 0.5], [-0.1, 3.0, -0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let data_actual = tensor.sign().into_data();
+
+        let data_expected = Data::from([[-1.0, 0.0, 1.0], [-1.0, 1.0, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_match_fallback_rounding() {
+        let data = Data::from([[-2.5, -1.2, 0.5], [1.5, 2.7, -0.6]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let trunc =
+            burn_tensor::float_map::<TestBackend, 2>(tensor.clone().into_primitive(), |x| {
+                x.trunc()
+            });
+        let round =
+            burn_tensor::float_map::<TestBackend, 2>(tensor.clone().into_primitive(), |x| {
+                x.round_ties_even()
+            });
+
+        Tensor::<TestBackend, 2>::from_primitive(trunc)
+            .into_data()
+            .assert_approx_eq(&tensor.clone().trunc().into_data(), 3);
+        Tensor::<TestBackend, 2>::from_primitive(round)
+            .into_data()
+            .assert_approx_eq(&tensor.round().into_data(), 3);
+    }
+}
@@ -0,0 +1,165 @@
+#[burn_tensor_testgen::testgen(sort_argsort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_sort_1d_int() {
+        let tensor = TestTensorInt::from([1, 4, 7, 2, 5, 6, 3, 0, 9, 8, 2, 8, -10, 42, 1000]);
+
+        let values = tensor.sort(0);
+        let values_expected = Data::from([-10, 0, 1, 2, 2, 3, 4, 5, 6, 7, 8, 8, 9, 42, 1000]);
+        assert_eq!(values_expected, values.into_data());
+    }
+
+    #[test]
+    fn test_argsort_1d_int() {
+        let tensor = TestTensorInt::from([1, 4, 7, 2, 5, 6, 3, 0, 9, 8, -10, 42, 1000]);
+
+        let indices = tensor.argsort(0);
+        let indices_expected = Data::from([10, 7, 0, 3, 6, 1, 4, 5, 2, 9, 8, 11, 12]);
+        assert_eq!(indices_expected, indices.into_data());
+    }
+
+    #[test]
+    fn test_sort_with_indices_descending_int() {
+        // 1D
+        let tensor = TestTensorInt::from([1, 4, 7, 2, 5, 6, 3, 0, 9, 8, -10, 42, 1000]);
+
+        let (values, indices) = tensor.sort_descending_with_indices(0);
+        let values_expected = Data::from([1000, 42, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, -10]);
+        assert_eq!(values_expected, values.into_data());
+
+        let indices_expected = Data::from([12, 11, 8, 9, 2, 5, 4, 1, 6, 3, 0, 7, 10]);
+        assert_eq!(indices_expected, indices.into_data());
+
+        // 2D
+        let tensor = TestTensorInt::from([[[1, 4, 7], [2, 5, 6]], [[3, 0, 9], [8, 2, 8]]]);
+
+        let (values, indices) = tensor.sort_descending_with_indices(1);
+        let values_expected = Data::from([[[2, 5, 7], [1, 4, 6]], [[8, 2, 9], [3, 0, 8]]]);
+        assert_eq!(values_expected, values.into_data());
+
+        let indices_expected = Data::from([[[1, 1, 0], [0, 0, 1]], [[1, 1, 0], [0, 0, 1]]]);
+        assert_eq!(indices_expected, indices.into_data());
+    }
+
+    #[test]
+    fn test_sort_float() {
+        let tensor = TestTensor::from([
+            [[-0.5, 1.2, -2.1], [0., 2.1, -0.2]],
+            [[1.1, 5.3, 0.9], [-2.1, 0.8, -3.1]],
+        ]);
+
+        // Sort along dim=0
+        let values = tensor.clone().sort(0);
+        let values_expected = Data::from([
+            [[-0.5, 1.2, -2.1], [-2.1, 0.8, -3.1]],
+            [[1.1, 5.3, 0.9], [0., 2.1, -0.2]],
+        ]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+
+        // Sort along dim=1
+        let values = tensor.clone().sort(1);
+        let values_expected = Data::from([
+            [[-0.5, 1.2, -2.1], [0., 2.1, -0.2]],
+            [[-2.1, 0.8, -3.1], [1.1, 5.3, 0.9]],
+        ]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+
+        // Sort along dim=2
+        let values = tensor.sort(2);
+        let values_expected = Data::from([
+            [[-2.1, -0.5, 1.2], [-0.2, 0., 2.1]],
+            [[0.9, 1.1, 5.3], [-3.1, -2.1, 0.8]],
+        ]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+    }
+
+    #[test]
+    fn test_sort_with_indices_float() {
+        let tensor = TestTensor::from([
+            [[-0.5, 1.2, -2.1], [0., 2.1, -0.2]],
+            [[1.1, 5.3, 0.9], [-2.1, 0.8, -3.1]],
+        ]);
+
+        // Sort along dim=0
+        let (values, indices) = tensor.clone().sort_with_indices(0);
+        let values_expected = Data::from([
+            [[-0.5, 1.2, -2.1], [-2.1, 0.8, -3.1]],
+            [[1.1, 5.3, 0.9], [0., 2.1, -0.2]],
+        ]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+
+        let indices_expected = Data::from([[[0, 0, 0], [1, 1, 1]], [[1, 1, 1], [0, 0, 0]]]);
+        assert_eq!(indices_expected, indices.into_data());
+
+        // Sort along dim=2
+        let (values, indices) = tensor.sort_with_indices(2);
+        let values_expected = Data::from([
+            [[-2.1, -0.5, 1.2], [-0.2, 0., 2.1]],
+            [[0.9, 1.1, 5.3], [-3.1, -2.1, 0.8]],
+        ]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+
+        let indices_expected = Data::from([[[2, 0, 1], [2, 0, 1]], [[2, 0, 1], [2, 0, 1]]]);
+        assert_eq!(indices_expected, indices.into_data());
+    }
+
+    #[test]
+    fn test_argsort_descending_float() {
+        let tensor = TestTensor::from([[-0.5, 1.2, -2.1, 0.], [2.1, -0.2, 1.1, 5.3]]);
+
+        let indices = tensor.argsort_descending(1);
+        let indices_expected = Data::from([[1, 3, 0, 2], [3, 0, 2, 1]]);
+        assert_eq!(indices_expected, indices.into_data());
+    }
+
+    #[test]
+    fn test_sort_with_indices_stable_on_ties() {
+        let tensor = TestTensorInt::from([3, 1, 3, 1, 2]);
+
+        let (values, indices) = tensor.clone().sort_with_indices(0);
+        assert_eq!(Data::from([1, 1, 2, 3, 3]), values.into_data());
+        assert_eq!(Data::from([1, 3, 4, 0, 2]), indices.into_data());
+
+        let indices = tensor.argsort_descending(0);
+        assert_eq!(Data::from([0, 2, 4, 1, 3]), indices.into_data());
+    }
+
+    #[test]
+    fn test_topk_1d() {
+        let tensor = TestTensorInt::from([1, 2, 3, 4, 5]);
+
+        let values = tensor.topk(3, 0);
+        let values_expected = Data::from([5, 4, 3]);
+        assert_eq!(values_expected, values.into_data());
+
+        let tensor = TestTensor::from([1., 2., 3., 4., 5.]);
+
+        let values = tensor.topk(3, 0);
+        let values_expected = Data::from([5., 4., 3.]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+    }
+
+    #[test]
+    fn test_topk_with_indices_3d() {
+        let tensor = TestTensor::from([[[1., 4., 7.], [2., 5., 6.]], [[3., 0., 9.], [8., 2., 7.]]]);
+
+        let (values, indices) = tensor.topk_with_indices(2, 2);
+
+        let values_expected = Data::from([[[7., 4.], [6., 5.]], [[9., 3.], [8., 7.]]]);
+        values_expected.assert_approx_eq(&values.into_data(), 5);
+
+        let indices_expected = Data::from([[[2, 1], [2, 1]], [[2, 0], [0, 2]]]);
+        assert_eq!(indices_expected, indices.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_should_panic_when_k_is_too_large() {
+        let tensor = TestTensor::from([1., 2., 3.]);
+
+        let _values = tensor.topk(4, 0);
+    }
+}
@@ -0,0 +1,152 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Shape, Sparse, SparseLayout, SparsePrimitive, Tensor};
+
+    fn sparse_matrix() -> Tensor<TestBackend, 2, Sparse> {
+        let device = Default::default();
+        let indices =
+            Tensor::<TestBackend, 2, Int>::from_ints([[2, 1], [0, 0], [0, 3], [2, 2]], &device);
+        let values = TestTensor::from([4.0, 1.0, 2.0, -3.0]);
+
+        Tensor::from_coo(indices, values, Shape::new([3, 4]))
+    }
+
+    #[test]
+    fn should_convert_coo_to_dense() {
+        let tensor = sparse_matrix();
+
+        assert_eq!(tensor.layout(), SparseLayout::Coo);
+        assert_eq!(tensor.dims(), [3, 4]);
+        assert_eq!(tensor.nnz(), 4);
+        tensor.to_dense().into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 0.0, 0.0, 2.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 4.0, -3.0, 0.0],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_sum_duplicated_coo_indices() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[1], [0], [1]], &device);
+        let values = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let tensor = Tensor::<TestBackend, 1, Sparse>::from_coo(indices, values, Shape::new([3]));
+
+        tensor
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, 4.0, 0.0]), 4);
+    }
+
+    #[test]
+    fn should_convert_dense_to_coo() {
+        let tensor = TestTensor::from([[[0.0, 1.5], [0.0, 0.0]], [[-2.0, 0.0], [0.0, 3.0]]]);
+
+        let sparse = tensor.clone().to_sparse_coo();
+
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(
+            sparse.indices().into_data(),
+            Data::from([[0, 0, 1], [1, 0, 0], [1, 1, 1]])
+        );
+        sparse
+            .values()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, -2.0, 3.0]), 4);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn should_convert_coo_to_csr() {
+        let tensor = sparse_matrix().into_csr();
+
+        assert_eq!(tensor.layout(), SparseLayout::Csr);
+        let indices = tensor.indices();
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[0, 0], [0, 3], [2, 1], [2, 2]])
+        );
+        tensor
+            .values()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0, 4.0, -3.0]), 4);
+        tensor
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&sparse_matrix().to_dense().into_data(), 4);
+    }
+
+    #[test]
+    fn should_create_csr() {
+        let device = Default::default();
+        let row_offsets = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 2, 3], &device);
+        let col_indices = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 1], &device);
+        let values = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let tensor = Tensor::from_csr(row_offsets, col_indices, values, Shape::new([3, 3]));
+
+        tensor.to_dense().into_data().assert_approx_eq(
+            &Data::from([[1.0, 0.0, 2.0], [0.0, 0.0, 0.0], [0.0, 3.0, 0.0]]),
+            4,
+        );
+
+        let tensor = TestTensor::from([[0.0, 0.0], [5.0, 0.0], [0.0, 6.0]]).to_sparse_csr();
+        let SparsePrimitive::Csr { row_offsets, .. } = tensor.into_primitive() else {
+            panic!("Expected a CSR tensor");
+        };
+        assert_eq!(row_offsets.into_data(), Data::from([0, 0, 1, 2]));
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let rhs = TestTensor::from([[1.0, 2.0], [0.0, -1.0], [3.0, 0.5], [2.0, 1.0]]);
+        let expected = Data::from([[5.0, 4.0], [0.0, 0.0], [-9.0, -5.5]]);
+
+        sparse_matrix()
+            .spmm(rhs.clone())
+            .into_data()
+            .assert_approx_eq(&expected, 4);
+        sparse_matrix()
+            .into_csr()
+            .spmm(rhs)
+            .into_data()
+            .assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_elementwise_ops() {
+        let dense = TestTensor::from([
+            [2.0, 5.0, 5.0, 0.5],
+            [5.0, 5.0, 5.0, 5.0],
+            [-1.0, 1.0, 2.0, 5.0],
+        ]);
+
+        let output = sparse_matrix()
+            .into_csr()
+            .map_values(|values| values.mul_scalar(2.0))
+            .mul_dense(dense);
+        output.to_dense().into_data().assert_approx_eq(
+            &Data::from([
+                [4.0, 0.0, 0.0, 2.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 8.0, -12.0, 0.0],
+            ]),
+            4,
+        );
+
+        let output = sparse_matrix().add_sparse(sparse_matrix().into_csr().map_values(|v| v.neg()));
+        assert_eq!(output.nnz(), 8);
+        output
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0; 4]; 3]), 4);
+    }
+}
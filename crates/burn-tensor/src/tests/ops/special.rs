@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(special)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_lgamma_ops() {
+        let tensor = TestTensor::from([[0.5, 1.0, 2.5], [10.0, -0.5, -2.5]]);
+
+        let data_actual = tensor.lgamma().into_data();
+
+        let data_expected = Data::from([[0.57236, 0.0, 0.28468], [12.80183, 1.26551, -0.05624]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_digamma_ops() {
+        let tensor = TestTensor::from([[-2.5, 0.1, 1.0], [2.5, 10.0, 100.0]]);
+
+        let data_actual = tensor.digamma().into_data();
+
+        let data_expected =
+            Data::from([[1.10316, -10.42375, -0.57722], [0.70316, 2.25175, 4.60016]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_erfinv_ops() {
+        let tensor = TestTensor::from([[-0.9, -0.5, 0.0], [0.3, 0.99, 0.999]]);
+
+        let data_actual = tensor.erfinv().into_data();
+
+        let data_expected = Data::from([[-1.16309, -0.47694, 0.0], [0.27246, 1.82139, 2.32675]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_erfinv_of_the_bounds() {
+        let tensor = TestTensor::from([-1.0, 1.0]);
+
+        let data_actual = tensor.erfinv().into_data();
+
+        assert_eq!(data_actual.value[0], f32::NEG_INFINITY);
+        assert_eq!(data_actual.value[1], f32::INFINITY);
+    }
+
+    #[test]
+    fn should_invert_erf() {
+        let tensor = TestTensor::from([[-2.0, -0.5, 0.1], [0.7, 1.2, 1.8]]);
+
+        let data_actual = tensor.clone().erf().erfinv().into_data();
+
+        data_actual.assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_expm1_ops() {
+        let tensor = TestTensor::from([[-0.9, -0.3, 0.0], [0.2, 0.5, 0.8]]);
+
+        let data_actual = tensor.expm1().into_data();
+
+        let data_expected = Data::from([[-0.59343, -0.25918, 0.0], [0.2214, 0.64872, 1.22554]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_match_fallback_lgamma() {
+        let tensor = TestTensor::from([[0.2, 1.0, 3.7], [25.0, -0.3, -4.5]]);
+
+        let fallback = burn_tensor::lgamma::<TestBackend, 2>(tensor.clone().into_primitive());
+
+        Tensor::<TestBackend, 2>::from_primitive(fallback)
+            .into_data()
+            .assert_approx_eq(&tensor.lgamma().into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_trigamma() {
+        let tensor = TestTensor::from([0.5, 2.0, -0.5, 3.0]);
+
+        let output = burn_tensor::trigamma::<TestBackend, 1>(tensor.into_primitive());
+
+        Tensor::<TestBackend, 1>::from_primitive(output)
+            .into_data()
+            .assert_approx_eq(&Data::from([4.93480, 0.64493, 8.93480, 0.39493]), 3);
+    }
+}
@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(trig)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, PI};
+
+    fn input() -> TestTensor<2> {
+        TestTensor::from([[-0.9, -0.3, 0.0], [0.2, 0.5, 0.8]])
+    }
+
+    #[test]
+    fn should_support_tan_ops() {
+        let data_actual = input().tan().into_data();
+
+        let data_expected = Data::from([[-1.26016, -0.30934, 0.0], [0.20271, 0.5463, 1.02964]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_asin_ops() {
+        let data_actual = input().asin().into_data();
+
+        let data_expected = Data::from([[-1.11977, -0.30469, 0.0], [0.20136, FRAC_PI_6, 0.9273]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_acos_ops() {
+        let data_actual = input().acos().into_data();
+
+        let data_expected =
+            Data::from([[2.69057, 1.87549, FRAC_PI_2], [1.36944, FRAC_PI_3, 0.6435]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_atan_ops() {
+        let data_actual = input().atan().into_data();
+
+        let data_expected = Data::from([[-0.73282, -0.29146, 0.0], [0.1974, 0.46365, 0.67474]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_sinh_ops() {
+        let data_actual = input().sinh().into_data();
+
+        let data_expected = Data::from([[-1.02652, -0.30452, 0.0], [0.20134, 0.5211, 0.88811]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_cosh_ops() {
+        let data_actual = input().cosh().into_data();
+
+        let data_expected = Data::from([[1.43309, 1.04534, 1.0], [1.02007, 1.12763, 1.33743]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_atan2_ops_in_all_quadrants() {
+        let y = TestTensor::from([[1.0, 1.0, -1.0], [-1.0, 0.0, 2.0]]);
+        let x = TestTensor::from([[1.0, -1.0, -1.0], [0.0, -1.0, 0.5]]);
+
+        let data_actual = y.atan2(x).into_data();
+
+        let data_expected = Data::from([
+            [FRAC_PI_4, 3.0 * FRAC_PI_4, -3.0 * FRAC_PI_4],
+            [-FRAC_PI_2, PI, 1.32582],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_atan2_ops_with_broadcast() {
+        let y = TestTensor::from([[1.0], [-1.0]]);
+        let x = TestTensor::from([[1.0, -1.0]]);
+
+        let data_actual = y.atan2(x).into_data();
+
+        let data_expected =
+            Data::from([[FRAC_PI_4, 3.0 * FRAC_PI_4], [-FRAC_PI_4, -3.0 * FRAC_PI_4]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_match_fallback_atan2() {
+        let y = TestTensor::from([[1.0, 1.0, -1.0], [-1.0, 0.0, 2.0]]);
+        let x = TestTensor::from([[1.0, -1.0, -1.0], [0.0, -1.0, 0.5]]);
+
+        let fallback = burn_tensor::atan2::<TestBackend, 2>(
+            y.clone().into_primitive(),
+            x.clone().into_primitive(),
+        );
+
+        Tensor::<TestBackend, 2>::from_primitive(fallback)
+            .into_data()
+            .assert_approx_eq(&y.atan2(x).into_data(), 3);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::tensor::BatchedTensor;
+use alloc::format;
+use alloc::string::String;
+use burn_tensor::{backend::Backend, Bool, Float, Int};
+use core::marker::PhantomData;
+
+/// Enable vectorized maps on a backend.
+///
+/// This works as a backend decorator: each tensor is either the same for every example of a
+/// batch, or carries one value per example, and every operation is lifted to apply to each
+/// example independently. Use [vmap](crate::vmap) to apply a function written for a single
+/// example to a batch.
+///
+/// The decorator composes with other decorators: `Autodiff<Vmap<B>>` differentiates each example
+/// independently, which computes per-example gradients.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Vmap<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Vmap<B> {
+    type Device = B::Device;
+
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type FullPrecisionBackend = Vmap<B::FullPrecisionBackend>;
+
+    type FloatTensorPrimitive<const D: usize> = BatchedTensor<B, Float, D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = BatchedTensor<B, Int, D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = BatchedTensor<B, Bool, D>;
+
+    fn ad_enabled() -> bool {
+        B::ad_enabled()
+    }
+
+    fn name() -> String {
+        format!("vmap<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device) {
+        B::sync(device);
+    }
+}
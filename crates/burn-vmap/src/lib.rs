@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Vmap
+//!
+//! This library is a part of the Burn project. It provides a backend decorator that lifts
+//! functions written for a single example to functions over a batch of examples, by adding a
+//! leading batch dimension to the tensors they receive.
+
+#[macro_use]
+extern crate derive_new;
+
+extern crate alloc;
+
+mod backend;
+mod ops;
+mod tensor;
+mod transform;
+
+pub use backend::*;
+pub use tensor::*;
+pub use transform::*;
+
+#[cfg(feature = "export_tests")]
+mod tests;
@@ -0,0 +1,5 @@
+use crate::Vmap;
+use burn_tensor::{backend::Backend, ops::ActivationOps};
+
+// The activations are composed of operations that are already batched.
+impl<B: Backend> ActivationOps<Vmap<B>> for Vmap<B> {}
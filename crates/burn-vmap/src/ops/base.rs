@@ -0,0 +1,142 @@
+use crate::tensor::{batch_size_of, BatchedTensor};
+use burn_tensor::{backend::Backend, Element, Int, Numeric, Shape, Tensor};
+
+pub(crate) fn gather<B: Backend, K: Numeric<B>, const D: usize>(
+    dim: usize,
+    tensor: BatchedTensor<B, K, D>,
+    indices: BatchedTensor<B, Int, D>,
+) -> BatchedTensor<B, K, D>
+where
+    K::Elem: Element,
+{
+    let Some(batch_size) = batch_size_of([tensor.batch_size, indices.batch_size]) else {
+        let indices = Tensor::from_primitive(indices.primitive);
+        return BatchedTensor::shared(K::gather(dim, tensor.primitive, indices));
+    };
+
+    let indices = indices.into_batched(batch_size);
+    let shape = indices.shape();
+    let output = K::gather(
+        2,
+        tensor.into_batched(batch_size).view(dim),
+        Tensor::from_primitive(indices.view(dim)),
+    );
+
+    BatchedTensor::from_view(output, dim, shape, Some(batch_size))
+}
+
+pub(crate) fn scatter<B: Backend, K: Numeric<B>, const D: usize>(
+    dim: usize,
+    tensor: BatchedTensor<B, K, D>,
+    indices: BatchedTensor<B, Int, D>,
+    value: BatchedTensor<B, K, D>,
+) -> BatchedTensor<B, K, D>
+where
+    K::Elem: Element,
+{
+    let Some(batch_size) = batch_size_of([tensor.batch_size, indices.batch_size, value.batch_size])
+    else {
+        let indices = Tensor::from_primitive(indices.primitive);
+        return BatchedTensor::shared(K::scatter(dim, tensor.primitive, indices, value.primitive));
+    };
+
+    let indices = Tensor::from_primitive(indices.into_batched(batch_size).view(dim));
+    let value = value.into_batched(batch_size).view(dim);
+
+    tensor
+        .into_batched(batch_size)
+        .map_dim(dim, |tensor| K::scatter(2, tensor, indices, value))
+}
+
+pub(crate) fn select<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: BatchedTensor<B, K, D>,
+    dim: usize,
+    indices: BatchedTensor<B, Int, 1>,
+) -> BatchedTensor<B, K, D>
+where
+    K::Elem: Element,
+{
+    let Some(batch_size) = indices.batch_size else {
+        let indices = Tensor::from_primitive(indices.primitive);
+        return tensor.map_dim(dim, |tensor| K::select(tensor, 2, indices));
+    };
+
+    // Each example selects different indices, which is a gather with the indices repeated.
+    tensor.into_batched(batch_size).map_dim(dim, |tensor| {
+        let [_, before, _, after] = K::shape(&tensor).dims;
+        let indices = select_indices::<B>(indices.primitive, batch_size, before, after);
+
+        K::gather(2, tensor, Tensor::from_primitive(indices))
+    })
+}
+
+pub(crate) fn select_assign<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: BatchedTensor<B, K, D>,
+    dim: usize,
+    indices: BatchedTensor<B, Int, 1>,
+    value: BatchedTensor<B, K, D>,
+) -> BatchedTensor<B, K, D>
+where
+    K::Elem: Element,
+{
+    let Some(batch_size) = batch_size_of([tensor.batch_size, indices.batch_size, value.batch_size])
+    else {
+        let indices = Tensor::from_primitive(indices.primitive);
+        return BatchedTensor::shared(K::select_assign(
+            tensor.primitive,
+            dim,
+            indices,
+            value.primitive,
+        ));
+    };
+
+    let value = value.into_batched(batch_size).view(dim);
+    tensor.into_batched(batch_size).map_dim(dim, |tensor| {
+        if indices.batch_size.is_none() {
+            let indices = Tensor::from_primitive(indices.primitive);
+            return K::select_assign(tensor, 2, indices, value);
+        }
+
+        // Each example assigns different indices, which is a scatter with the indices repeated.
+        let [_, before, _, after] = K::shape(&value).dims;
+        let indices = select_indices::<B>(indices.primitive, batch_size, before, after);
+
+        K::scatter(2, tensor, Tensor::from_primitive(indices), value)
+    })
+}
+
+pub(crate) fn sum<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: BatchedTensor<B, K, D>,
+) -> BatchedTensor<B, K, 1>
+where
+    K::Elem: Element,
+{
+    if tensor.batch_size.is_none() {
+        return BatchedTensor::shared(K::sum(tensor.primitive));
+    }
+
+    let shape = Shape::new([tensor.shape().num_elements()]);
+    tensor
+        .reshape(shape)
+        .map_dim(0, |tensor| K::sum_dim(tensor, 2))
+}
+
+/// Returns the indices of a gather along the dimension `2` of views of shape
+/// `[batch_size, before, size, after]`, selecting the given indices of each example.
+fn select_indices<B: Backend>(
+    indices: B::IntTensorPrimitive<1>,
+    batch_size: usize,
+    before: usize,
+    after: usize,
+) -> B::IntTensorPrimitive<4> {
+    let [size] = B::int_shape(&indices).dims;
+    let mut indices = B::int_reshape(indices, Shape::new([batch_size, 1, size / batch_size, 1]));
+    if before != 1 {
+        indices = B::int_repeat(indices, 1, before);
+    }
+    if after != 1 {
+        indices = B::int_repeat(indices, 3, after);
+    }
+
+    indices
+}
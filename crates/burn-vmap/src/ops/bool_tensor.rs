@@ -0,0 +1,140 @@
+use crate::{
+    tensor::{align, BatchedTensor},
+    Vmap,
+};
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Data, Device, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Vmap<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        BatchedTensor::shared(B::bool_empty(shape, device))
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        assert!(
+            tensor.batch_size.is_none(),
+            "The data of a batched tensor can't be read inside vmap"
+        );
+        B::bool_into_data(tensor.primitive)
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        BatchedTensor::shared(B::bool_from_data(data, device))
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::bool_into_int)
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::bool_into_float)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(&tensor.primitive)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        tensor.map(|tensor| B::bool_to_device(tensor, device))
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        tensor.reshape(shape)
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        tensor.slice(ranges)
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        tensor.slice_assign(ranges, value)
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        tensor.repeat(dim, times)
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        BatchedTensor::cat(tensors, dim)
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::bool_equal(lhs, rhs), batch_size)
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        tensor.map(B::bool_not)
+    }
+
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::bool_and(lhs, rhs), batch_size)
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::bool_or(lhs, rhs), batch_size)
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::bool_xor(lhs, rhs), batch_size)
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        tensor.swap_dims(dim1, dim2)
+    }
+}
@@ -0,0 +1,472 @@
+use super::base::{gather, scatter, select, select_assign, sum};
+use crate::{
+    tensor::{align, align3, BatchedTensor},
+    Vmap,
+};
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Data, Device, Distribution, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Vmap<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::shared(B::int_empty(shape, device))
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<B>, D>> {
+        assert!(
+            tensor.batch_size.is_none(),
+            "The data of a batched tensor can't be read inside vmap"
+        );
+        B::int_into_data(tensor.primitive)
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<B>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        BatchedTensor::shared(B::int_from_data(data, device))
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(&tensor.primitive)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(|tensor| B::int_to_device(tensor, device))
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        tensor.reshape(shape)
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        tensor.slice(ranges)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        tensor.slice_assign(ranges, value)
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::int_into_float)
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (tensor, mask, value, batch_size) = align3(tensor, mask, value);
+
+        BatchedTensor::new(B::int_mask_where(tensor, mask, value), batch_size)
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        let (tensor, mask, batch_size) = align(tensor, mask);
+
+        BatchedTensor::new(B::int_mask_fill(tensor, mask, value), batch_size)
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        gather(dim, tensor, indices)
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        scatter(dim, tensor, indices, value)
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        select(tensor, dim, indices)
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        select_assign(tensor, dim, indices, value)
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        tensor.repeat(dim, times)
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        BatchedTensor::cat(tensors, dim)
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_equal(lhs, rhs), batch_size)
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_equal_elem(lhs, rhs))
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_greater(lhs, rhs), batch_size)
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_greater_elem(lhs, rhs))
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_greater_equal(lhs, rhs), batch_size)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_greater_equal_elem(lhs, rhs))
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_lower(lhs, rhs), batch_size)
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_lower_elem(lhs, rhs))
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_lower_equal(lhs, rhs), batch_size)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_lower_equal_elem(lhs, rhs))
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_add(lhs, rhs), batch_size)
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_add_scalar(lhs, rhs))
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_sub(lhs, rhs), batch_size)
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_sub_scalar(lhs, rhs))
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_mul(lhs, rhs), batch_size)
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_mul_scalar(lhs, rhs))
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_div(lhs, rhs), batch_size)
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_div_scalar(lhs, rhs))
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::shared(B::int_zeros(shape, device))
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::shared(B::int_ones(shape, device))
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        sum(tensor)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_sum_dim(tensor, 2))
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_mean_dim(tensor, 2))
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_argmax(tensor, 2))
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_argmin(tensor, 2))
+    }
+
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_cumsum(tensor, 2))
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_cumprod(tensor, 2))
+    }
+
+    fn int_cummax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_cummax(tensor, 2))
+    }
+
+    fn int_cummin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_cummin(tensor, 2))
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| B::int_cummax_with_indices(tensor, 2))
+    }
+
+    fn int_cummin_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| B::int_cummin_with_indices(tensor, 2))
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::int_abs)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        tensor.swap_dims(dim1, dim2)
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        BatchedTensor::shared(B::int_random(shape, distribution, device))
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_sort(tensor, 2, descending))
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| {
+            B::int_sort_with_indices(tensor, 2, descending)
+        })
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::int_argsort(tensor, 2, descending))
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_bitwise_and(lhs, rhs), batch_size)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_bitwise_and_scalar(lhs, rhs))
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_bitwise_or(lhs, rhs), batch_size)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_bitwise_or_scalar(lhs, rhs))
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_bitwise_xor(lhs, rhs), batch_size)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_bitwise_xor_scalar(lhs, rhs))
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::int_bitwise_not)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_bitwise_left_shift(lhs, rhs), batch_size)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_bitwise_left_shift_scalar(lhs, rhs))
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::int_bitwise_right_shift(lhs, rhs), batch_size)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_bitwise_right_shift_scalar(lhs, rhs))
+    }
+}
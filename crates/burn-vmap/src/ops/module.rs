@@ -0,0 +1,226 @@
+use crate::{
+    tensor::{align, align3, batch_size_of, BatchedTensor},
+    Vmap,
+};
+use burn_tensor::{backend::Backend, ops::*, Shape};
+
+impl<B: Backend> ModuleOps<Self> for Vmap<B> {
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let batch_size = batch_size_of([
+            weight.batch_size,
+            bias.as_ref().and_then(|bias| bias.batch_size),
+        ]);
+        let Some(batch_size) = batch_size else {
+            let bias = bias.map(|bias| bias.primitive);
+            return x.map(|x| B::conv2d(x, weight.primitive, bias, options));
+        };
+
+        // Each example has its own weights, which is a grouped convolution with one group of
+        // channels per example.
+        let [channels_out, ..] = weight.shape().dims;
+        let x = channels_first::<B>(x, batch_size);
+        let weight = weight.expand(batch_size);
+        let bias = bias.map(|bias| bias.expand_to(batch_size, channels_out));
+        let options = ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups * batch_size,
+        );
+
+        channels_last::<B>(B::conv2d(x, weight, bias, options), batch_size)
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let batch_size = batch_size_of([
+            weight.batch_size,
+            bias.as_ref().and_then(|bias| bias.batch_size),
+        ]);
+        let Some(batch_size) = batch_size else {
+            let bias = bias.map(|bias| bias.primitive);
+            return x.map(|x| B::conv_transpose2d(x, weight.primitive, bias, options));
+        };
+
+        let [_, channels_out_per_group, ..] = weight.shape().dims;
+        let channels_out = channels_out_per_group * options.groups;
+        let x = channels_first::<B>(x, batch_size);
+        let weight = weight.expand(batch_size);
+        let bias = bias.map(|bias| bias.expand_to(batch_size, channels_out));
+        let options = ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            options.padding_out,
+            options.dilation,
+            options.groups * batch_size,
+        );
+
+        channels_last::<B>(B::conv_transpose2d(x, weight, bias, options), batch_size)
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad))
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let (x, grad, batch_size) = align(x, grad);
+        let x_grad =
+            B::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad);
+
+        BatchedTensor::new(x_grad, batch_size)
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        let (x, grad, batch_size) = align(x, grad);
+
+        BatchedTensor::new(B::adaptive_avg_pool2d_backward(x, grad), batch_size)
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::max_pool2d(x, kernel_size, stride, padding, dilation))
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let output =
+            B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(
+            BatchedTensor::new(output.output, x.batch_size),
+            BatchedTensor::new(output.indices, x.batch_size),
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let (x, output_grad, indices, batch_size) = align3(x, output_grad, indices);
+        let backward = B::max_pool2d_with_indices_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        );
+
+        MaxPool2dBackward::new(BatchedTensor::new(backward.x_grad, batch_size))
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::interpolate(x, output_size, options))
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let (x, grad, batch_size) = align(x, grad);
+        let x_grad = B::interpolate_backward(x, grad, output_size, options);
+
+        BatchedTensor::new(x_grad, batch_size)
+    }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self, 4>,
+        grid: FloatTensor<Self, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self, 4> {
+        let (x, grid, batch_size) = align(x, grid);
+        let output = B::grid_sample_2d(x, grid, options);
+
+        BatchedTensor::new(output, batch_size)
+    }
+
+    fn grid_sample_2d_backward(
+        x: FloatTensor<Self, 4>,
+        grid: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<Self> {
+        let (x, grid, grad, batch_size) = align3(x, grid, grad);
+        let backward = B::grid_sample_2d_backward(x, grid, grad, options);
+
+        GridSampleBackward::new(
+            BatchedTensor::new(backward.x_grad, batch_size),
+            BatchedTensor::new(backward.grid_grad, batch_size),
+        )
+    }
+}
+
+/// Moves the examples of a batch of images into the channels, from `[batch_size * n, c, h, w]`
+/// to `[n, batch_size * c, h, w]`.
+fn channels_first<B: Backend>(x: FloatTensor<Vmap<B>, 4>, batch_size: usize) -> FloatTensor<B, 4> {
+    let [n, c, h, w] = x.shape().dims;
+    let x = B::float_reshape(x.expand(batch_size), Shape::new([batch_size, n, c, h, w]));
+    let x = B::float_swap_dims(x, 0, 1);
+
+    B::float_reshape(x, Shape::new([n, batch_size * c, h, w]))
+}
+
+/// Moves the examples out of the channels, from `[n, batch_size * c, h, w]` to
+/// `[batch_size * n, c, h, w]`.
+fn channels_last<B: Backend>(x: FloatTensor<B, 4>, batch_size: usize) -> FloatTensor<Vmap<B>, 4> {
+    let [n, channels, h, w] = B::float_shape(&x).dims;
+    let x = B::float_reshape(x, Shape::new([n, batch_size, channels / batch_size, h, w]));
+    let x = B::float_swap_dims(x, 0, 1);
+    let x = B::float_reshape(x, Shape::new([batch_size * n, channels / batch_size, h, w]));
+
+    BatchedTensor::batched(x, batch_size)
+}
@@ -0,0 +1,680 @@
+use super::base::{gather, scatter, select, select_assign, sum};
+use crate::{
+    tensor::{align, align3, batch_size_of, BatchedTensor},
+    Vmap,
+};
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
+    Data, Device, Distribution, Float, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for Vmap<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<B>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::shared(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::shared(B::float_random(shape, distribution, device))
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::shared(B::float_zeros(shape, device))
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::shared(B::float_ones(shape, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<B>, D>> {
+        assert!(
+            tensor.batch_size.is_none(),
+            "The data of a batched tensor can't be read inside vmap"
+        );
+        B::float_into_data(tensor.primitive)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primitive)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::float_into_int)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::shared(B::float_empty(shape, device))
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.repeat(dim, times)
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_add(lhs, rhs), batch_size)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_add_scalar(lhs, rhs))
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_sub(lhs, rhs), batch_size)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_sub_scalar(lhs, rhs))
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_mul(lhs, rhs), batch_size)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_mul_scalar(lhs, rhs))
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_div(lhs, rhs), batch_size)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_div_scalar(lhs, rhs))
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size_of([lhs.batch_size, rhs.batch_size]) else {
+            return BatchedTensor::shared(B::float_matmul(lhs.primitive, rhs.primitive));
+        };
+
+        // With batch dimensions, the examples are stacked along the first one.
+        if D > 2 {
+            let (lhs, rhs, batch_size) = align(lhs, rhs);
+            return BatchedTensor::new(B::float_matmul(lhs, rhs), batch_size);
+        }
+
+        // Stacked rows multiplied by a shared matrix are the rows of the stacked products.
+        if rhs.batch_size.is_none() {
+            return lhs.map(|lhs| B::float_matmul(lhs, rhs.primitive));
+        }
+
+        let lhs = lhs.into_batched(batch_size);
+        let shape = lhs.shape();
+        let output = B::float_matmul(lhs.view_matrices(), rhs.view_matrices());
+
+        BatchedTensor::from_view_matrices(output, shape, Some(batch_size))
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_recip)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.swap_dims(dim1, dim2)
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        tensor.reshape(shape)
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        gather(dim, tensor, indices)
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        scatter(dim, tensor, indices, value)
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        select(tensor, dim, indices)
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        select_assign(tensor, dim, indices, value)
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        tensor.slice(ranges)
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        tensor.slice_assign(ranges, value)
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (tensor, mask, value, batch_size) = align3(tensor, mask, value);
+
+        BatchedTensor::new(B::float_mask_where(tensor, mask, value), batch_size)
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        let (tensor, mask, batch_size) = align(tensor, mask);
+
+        BatchedTensor::new(B::float_mask_fill(tensor, mask, value), batch_size)
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_equal(lhs, rhs), batch_size)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_equal_elem(lhs, rhs))
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_greater(lhs, rhs), batch_size)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_greater_elem(lhs, rhs))
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_greater_equal(lhs, rhs), batch_size)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_greater_equal_elem(lhs, rhs))
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_lower(lhs, rhs), batch_size)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_lower_elem(lhs, rhs))
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_lower_equal(lhs, rhs), batch_size)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_lower_equal_elem(lhs, rhs))
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_detach)
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_set_require_grad(tensor, require_grad))
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
+        B::float_is_require_grad(&tensor.primitive)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        sum(tensor)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_sum_dim(tensor, 2))
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_mean_dim(tensor, 2))
+    }
+
+    fn float_to_full_precision<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
+        BatchedTensor::new(
+            B::float_to_full_precision(&tensor.primitive),
+            tensor.batch_size,
+        )
+    }
+
+    fn float_from_full_precision<const D: usize>(
+        tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::new(
+            B::float_from_full_precision(tensor.primitive),
+            tensor.batch_size,
+        )
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_exp)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_log)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_log1p)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (lhs, rhs, batch_size) = align(lhs, rhs);
+
+        BatchedTensor::new(B::float_powf(lhs, rhs), batch_size)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_powf_scalar(tensor, value))
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_sqrt)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_abs)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_cos)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_sin)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_tanh)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_erf)
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::cat(tensors, dim)
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_argmax(tensor, 2))
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_argmin(tensor, 2))
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_sort(tensor, 2, descending))
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| {
+            B::float_sort_with_indices(tensor, 2, descending)
+        })
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_argsort(tensor, 2, descending))
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_cumsum(tensor, 2))
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_cumprod(tensor, 2))
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_cummax(tensor, 2))
+    }
+
+    fn float_cummax_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| B::float_cummax_with_indices(tensor, 2))
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        tensor.map_dim(dim, |tensor| B::float_cummin(tensor, 2))
+    }
+
+    fn float_cummin_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        tensor.map_dim_with_indices(dim, |tensor| B::float_cummin_with_indices(tensor, 2))
+    }
+
+    fn float_fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        complex_map_dim::<B, D, _>(real, imag, dim, |real, imag| B::float_fft(real, imag, 2))
+    }
+
+    fn float_ifft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        complex_map_dim::<B, D, _>(real, imag, dim, |real, imag| B::float_ifft(real, imag, 2))
+    }
+
+    fn float_rfft<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let (real, imag) = B::float_rfft(tensor.view(dim), 2);
+
+        (
+            BatchedTensor::from_view(real, dim, shape.clone(), batch_size),
+            BatchedTensor::from_view(imag, dim, shape, batch_size),
+        )
+    }
+
+    fn float_irfft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        n: usize,
+    ) -> FloatTensor<Self, D> {
+        let (real, imag, batch_size) = align(real, imag);
+        let real = BatchedTensor::<B, Float, D>::new(real, batch_size);
+        let imag = BatchedTensor::<B, Float, D>::new(imag, batch_size);
+
+        real.map_dim(dim, |real| B::float_irfft(real, imag.view(dim), 2, n))
+    }
+
+    fn float_solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size_of([a.batch_size, b.batch_size]) else {
+            return BatchedTensor::shared(B::float_solve(a.primitive, b.primitive));
+        };
+
+        let b = b.into_batched(batch_size);
+        let shape = b.shape();
+        let output = B::float_solve(
+            a.into_batched(batch_size).view_matrices(),
+            b.view_matrices(),
+        );
+
+        BatchedTensor::from_view_matrices(output, shape, Some(batch_size))
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let (sign, logabsdet) = B::float_slogdet(tensor.view_matrices());
+
+        (
+            BatchedTensor::from_view_matrices(sign, shape.clone(), batch_size),
+            BatchedTensor::from_view_matrices(logabsdet, shape, batch_size),
+        )
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let output = B::float_cholesky(tensor.view_matrices());
+
+        BatchedTensor::from_view_matrices(output, shape, batch_size)
+    }
+
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let (q, r) = B::float_qr(tensor.view_matrices());
+
+        (
+            BatchedTensor::from_view_matrices(q, shape.clone(), batch_size),
+            BatchedTensor::from_view_matrices(r, shape, batch_size),
+        )
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let (u, s, vh) = B::float_svd(tensor.view_matrices());
+
+        (
+            BatchedTensor::from_view_matrices(u, shape.clone(), batch_size),
+            BatchedTensor::from_view_matrices(s, shape.clone(), batch_size),
+            BatchedTensor::from_view_matrices(vh, shape, batch_size),
+        )
+    }
+
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let shape = tensor.shape();
+        let batch_size = tensor.batch_size;
+        let (values, vectors) = B::float_eigh(tensor.view_matrices());
+
+        (
+            BatchedTensor::from_view_matrices(values, shape.clone(), batch_size),
+            BatchedTensor::from_view_matrices(vectors, shape, batch_size),
+        )
+    }
+}
+
+/// Applies an operation along a dimension of complex examples, see
+/// [map_dim](BatchedTensor::map_dim).
+fn complex_map_dim<B: Backend, const D: usize, F>(
+    real: BatchedTensor<B, Float, D>,
+    imag: BatchedTensor<B, Float, D>,
+    dim: usize,
+    func: F,
+) -> (BatchedTensor<B, Float, D>, BatchedTensor<B, Float, D>)
+where
+    F: FnOnce(
+        B::FloatTensorPrimitive<4>,
+        B::FloatTensorPrimitive<4>,
+    ) -> (B::FloatTensorPrimitive<4>, B::FloatTensorPrimitive<4>),
+{
+    let (real, imag, batch_size) = align(real, imag);
+    let real = BatchedTensor::<B, Float, D>::new(real, batch_size);
+    let imag = BatchedTensor::<B, Float, D>::new(imag, batch_size);
+    let shape = real.shape();
+    let (real, imag) = func(real.view(dim), imag.view(dim));
+
+    (
+        BatchedTensor::from_view(real, dim, shape.clone(), batch_size),
+        BatchedTensor::from_view(imag, dim, shape, batch_size),
+    )
+}
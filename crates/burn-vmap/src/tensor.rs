@@ -0,0 +1,384 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, BasicOps, Int, Shape, TensorKind};
+use core::ops::Range;
+
+/// A tensor holding either one value per example of a batch, or a value shared by all examples.
+///
+/// The examples of a batched tensor are stacked along its first dimension: examples of shape
+/// `[d0, d1, ...]` are stored in a primitive of shape `[batch_size * d0, d1, ...]`, which keeps
+/// the rank of the primitive equal to the rank of an example.
+#[derive(new)]
+pub struct BatchedTensor<B: Backend, K: TensorKind<B>, const D: usize> {
+    /// The values, with the examples stacked along the first dimension when batched.
+    pub primitive: K::Primitive<D>,
+    /// The number of examples, `None` when the tensor is shared by all examples.
+    pub batch_size: Option<usize>,
+}
+
+impl<B: Backend, K: TensorKind<B>, const D: usize> Clone for BatchedTensor<B, K, D> {
+    fn clone(&self) -> Self {
+        Self::new(self.primitive.clone(), self.batch_size)
+    }
+}
+
+impl<B: Backend, K: TensorKind<B>, const D: usize> core::fmt::Debug for BatchedTensor<B, K, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BatchedTensor")
+            .field("primitive", &self.primitive)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl<B: Backend, K: BasicOps<B>, const D: usize> BatchedTensor<B, K, D> {
+    /// Create a tensor shared by all examples.
+    pub fn shared(primitive: K::Primitive<D>) -> Self {
+        Self::new(primitive, None)
+    }
+
+    /// Create a tensor from examples stacked along the first dimension of the primitive.
+    pub fn batched(primitive: K::Primitive<D>, batch_size: usize) -> Self {
+        Self::new(primitive, Some(batch_size))
+    }
+
+    /// Returns the shape of a single example.
+    pub fn shape(&self) -> Shape<D> {
+        let mut shape = K::shape(&self.primitive);
+        if let Some(batch_size) = self.batch_size {
+            shape.dims[0] /= batch_size;
+        }
+
+        shape
+    }
+
+    /// Applies an operation that acts on each element independently.
+    pub(crate) fn map<K2: BasicOps<B>, F>(self, func: F) -> BatchedTensor<B, K2, D>
+    where
+        F: FnOnce(K::Primitive<D>) -> K2::Primitive<D>,
+    {
+        BatchedTensor::new(func(self.primitive), self.batch_size)
+    }
+
+    /// Applies an operation along a dimension of each example.
+    ///
+    /// The operation receives a view of shape `[batch_size, before, size, after]`, where `size` is
+    /// the size of the dimension, and must act on the dimension `2` of the view.
+    pub(crate) fn map_dim<K2: BasicOps<B>, F>(self, dim: usize, func: F) -> BatchedTensor<B, K2, D>
+    where
+        F: FnOnce(K::Primitive<4>) -> K2::Primitive<4>,
+    {
+        let shape = self.shape();
+        let batch_size = self.batch_size;
+
+        BatchedTensor::from_view(func(self.view(dim)), dim, shape, batch_size)
+    }
+
+    /// Applies an operation along a dimension of each example that also returns indices along
+    /// that dimension, see [map_dim](Self::map_dim).
+    pub(crate) fn map_dim_with_indices<F>(
+        self,
+        dim: usize,
+        func: F,
+    ) -> (Self, BatchedTensor<B, Int, D>)
+    where
+        F: FnOnce(K::Primitive<4>) -> (K::Primitive<4>, B::IntTensorPrimitive<4>),
+    {
+        let shape = self.shape();
+        let batch_size = self.batch_size;
+        let (output, indices) = func(self.view(dim));
+
+        (
+            Self::from_view(output, dim, shape.clone(), batch_size),
+            BatchedTensor::from_view(indices, dim, shape, batch_size),
+        )
+    }
+
+    /// Returns a view of shape `[batch_size, before, size, after]` around a dimension of the
+    /// examples, with a batch size of one when the tensor is shared.
+    pub(crate) fn view(self, dim: usize) -> K::Primitive<4> {
+        let shape = self.shape();
+        let before = shape.dims[..dim].iter().product();
+        let after = shape.dims[dim + 1..].iter().product();
+        let batch_size = self.batch_size.unwrap_or(1);
+
+        K::reshape(
+            self.primitive,
+            Shape::new([batch_size, before, shape.dims[dim], after]),
+        )
+    }
+
+    /// Creates a tensor from a view created by [view](Self::view), where the size of the
+    /// dimension may have changed.
+    pub(crate) fn from_view(
+        view: K::Primitive<4>,
+        dim: usize,
+        mut shape: Shape<D>,
+        batch_size: Option<usize>,
+    ) -> Self {
+        shape.dims[dim] = K::shape(&view).dims[2];
+
+        Self::new(K::reshape(view, folded(shape, batch_size)), batch_size)
+    }
+
+    /// Returns a view of shape `[batch_size * batch, rows, cols]` of the matrices of the
+    /// examples, where `batch` is the number of matrices in an example.
+    pub(crate) fn view_matrices(self) -> K::Primitive<3> {
+        let shape = self.shape();
+        let batch = shape.dims[..D - 2].iter().product::<usize>();
+
+        K::reshape(
+            self.primitive,
+            Shape::new([
+                self.batch_size.unwrap_or(1) * batch,
+                shape.dims[D - 2],
+                shape.dims[D - 1],
+            ]),
+        )
+    }
+
+    /// Creates a tensor from a view created by [view_matrices](Self::view_matrices), where the
+    /// sizes of the matrices may have changed.
+    pub(crate) fn from_view_matrices(
+        view: K::Primitive<3>,
+        mut shape: Shape<D>,
+        batch_size: Option<usize>,
+    ) -> Self {
+        let [_, rows, cols] = K::shape(&view).dims;
+        shape.dims[D - 2] = rows;
+        shape.dims[D - 1] = cols;
+
+        Self::new(K::reshape(view, folded(shape, batch_size)), batch_size)
+    }
+
+    /// Returns the tensor with one value per example.
+    pub(crate) fn into_batched(self, batch_size: usize) -> Self {
+        Self::batched(self.expand(batch_size), batch_size)
+    }
+
+    /// Returns the primitive with one value per example.
+    pub(crate) fn expand(self, batch_size: usize) -> K::Primitive<D> {
+        let size = self.shape().dims[0];
+        self.expand_to(batch_size, size)
+    }
+
+    /// Returns the primitive with one value per example, broadcasting the first dimension of the
+    /// examples to the given size.
+    pub(crate) fn expand_to(self, batch_size: usize, size: usize) -> K::Primitive<D> {
+        let mut shape = self.shape();
+        let batch_size_input = self.batch_size.unwrap_or(1);
+        if batch_size_input == batch_size && shape.dims[0] == size {
+            return self.primitive;
+        }
+
+        let after = shape.dims[1..].iter().product();
+        let mut tensor = K::reshape(
+            self.primitive,
+            Shape::new([batch_size_input, shape.dims[0], after]),
+        );
+        if batch_size_input != batch_size {
+            tensor = K::repeat(tensor, 0, batch_size);
+        }
+        if shape.dims[0] != size {
+            tensor = K::repeat(tensor, 1, size);
+        }
+        shape.dims[0] = size;
+
+        K::reshape(tensor, folded(shape, Some(batch_size)))
+    }
+
+    pub(crate) fn reshape<const D2: usize>(self, shape: Shape<D2>) -> BatchedTensor<B, K, D2> {
+        // The examples are contiguous, so reshaping the stacked examples reshapes each of them.
+        BatchedTensor::new(
+            K::reshape(self.primitive, folded(shape, self.batch_size)),
+            self.batch_size,
+        )
+    }
+
+    pub(crate) fn swap_dims(self, dim1: usize, dim2: usize) -> Self {
+        if dim1 == dim2 {
+            return self;
+        }
+
+        let (dim1, dim2) = (usize::min(dim1, dim2), usize::max(dim1, dim2));
+        let mut shape = self.shape();
+        let batch_size = self.batch_size;
+        let view = Shape::new([
+            batch_size.unwrap_or(1),
+            shape.dims[..dim1].iter().product(),
+            shape.dims[dim1],
+            shape.dims[dim1 + 1..dim2].iter().product(),
+            shape.dims[dim2],
+            shape.dims[dim2 + 1..].iter().product(),
+        ]);
+        let tensor = K::swap_dims(K::reshape(self.primitive, view), 2, 4);
+        shape.dims.swap(dim1, dim2);
+
+        Self::new(K::reshape(tensor, folded(shape, batch_size)), batch_size)
+    }
+
+    pub(crate) fn repeat(self, dim: usize, times: usize) -> Self {
+        self.map_dim(dim, |tensor| K::repeat(tensor, 2, times))
+    }
+
+    pub(crate) fn slice<const D2: usize>(self, ranges: [Range<usize>; D2]) -> Self {
+        let Some(batch_size) = self.batch_size else {
+            return Self::shared(K::slice(self.primitive, ranges));
+        };
+
+        // The other dimensions are sliced on the stacked examples, then the first dimension is
+        // sliced on each example.
+        let mut ranges_stacked = ranges.clone();
+        ranges_stacked[0] = 0..batch_size * self.shape().dims[0];
+        let tensor = Self::batched(K::slice(self.primitive, ranges_stacked), batch_size);
+
+        tensor.map_dim(0, |tensor| {
+            let [batch_size, before, _, after] = K::shape(&tensor).dims;
+            K::slice(
+                tensor,
+                [0..batch_size, 0..before, ranges[0].clone(), 0..after],
+            )
+        })
+    }
+
+    pub(crate) fn slice_assign<const D2: usize>(
+        self,
+        ranges: [Range<usize>; D2],
+        value: Self,
+    ) -> Self {
+        let Some(batch_size) = batch_size_of([self.batch_size, value.batch_size]) else {
+            return Self::shared(K::slice_assign(self.primitive, ranges, value.primitive));
+        };
+
+        let shape = self.shape();
+        let after = shape.dims[1..].iter().product();
+        let range = ranges[0].clone();
+        let view = K::reshape(
+            self.expand(batch_size),
+            Shape::new([batch_size, shape.dims[0], after]),
+        );
+        let ranges_view = [0..batch_size, range.clone(), 0..after];
+
+        // The examples are sliced along their first dimension, then the other dimensions are
+        // assigned on the stacked slices.
+        let mut shape_slice = shape.clone();
+        shape_slice.dims[0] = range.len();
+        let slice = K::reshape(
+            K::slice(view.clone(), ranges_view.clone()),
+            folded(shape_slice, Some(batch_size)),
+        );
+        let mut ranges_stacked = ranges;
+        ranges_stacked[0] = 0..batch_size * range.len();
+        let slice = K::slice_assign(slice, ranges_stacked, value.expand(batch_size));
+        let slice = K::reshape(slice, Shape::new([batch_size, range.len(), after]));
+        let view = K::slice_assign(view, ranges_view, slice);
+
+        Self::batched(
+            K::reshape(view, folded(shape, Some(batch_size))),
+            batch_size,
+        )
+    }
+
+    pub(crate) fn cat(tensors: Vec<Self>, dim: usize) -> Self {
+        let Some(batch_size) = batch_size_of(tensors.iter().map(|tensor| tensor.batch_size)) else {
+            let tensors = tensors.into_iter().map(|tensor| tensor.primitive).collect();
+            return Self::shared(K::cat(tensors, dim));
+        };
+
+        let shape = tensors[0].shape();
+        let views = tensors
+            .into_iter()
+            .map(|tensor| Self::batched(tensor.expand(batch_size), batch_size).view(dim))
+            .collect();
+
+        Self::from_view(K::cat(views, 2), dim, shape, Some(batch_size))
+    }
+}
+
+/// Expands two tensors to the same number of examples, broadcasting the first dimension of the
+/// examples.
+pub(crate) fn align<B, K1, K2, const D: usize>(
+    lhs: BatchedTensor<B, K1, D>,
+    rhs: BatchedTensor<B, K2, D>,
+) -> (K1::Primitive<D>, K2::Primitive<D>, Option<usize>)
+where
+    B: Backend,
+    K1: BasicOps<B>,
+    K2: BasicOps<B>,
+{
+    let Some(batch_size) = batch_size_of([lhs.batch_size, rhs.batch_size]) else {
+        return (lhs.primitive, rhs.primitive, None);
+    };
+    let size = usize::max(lhs.shape().dims[0], rhs.shape().dims[0]);
+
+    (
+        lhs.expand_to(batch_size, size),
+        rhs.expand_to(batch_size, size),
+        Some(batch_size),
+    )
+}
+
+/// Expands three tensors to the same number of examples, broadcasting the first dimension of the
+/// examples.
+pub(crate) fn align3<B, K1, K2, K3, const D: usize>(
+    first: BatchedTensor<B, K1, D>,
+    second: BatchedTensor<B, K2, D>,
+    third: BatchedTensor<B, K3, D>,
+) -> (
+    K1::Primitive<D>,
+    K2::Primitive<D>,
+    K3::Primitive<D>,
+    Option<usize>,
+)
+where
+    B: Backend,
+    K1: BasicOps<B>,
+    K2: BasicOps<B>,
+    K3: BasicOps<B>,
+{
+    let Some(batch_size) = batch_size_of([first.batch_size, second.batch_size, third.batch_size])
+    else {
+        return (first.primitive, second.primitive, third.primitive, None);
+    };
+    let size = [first.shape(), second.shape(), third.shape()]
+        .iter()
+        .map(|shape| shape.dims[0])
+        .max()
+        .unwrap();
+
+    (
+        first.expand_to(batch_size, size),
+        second.expand_to(batch_size, size),
+        third.expand_to(batch_size, size),
+        Some(batch_size),
+    )
+}
+
+/// Returns the number of examples of tensors used together, `None` if they are all shared.
+///
+/// # Panics
+///
+/// If the tensors have different numbers of examples.
+pub(crate) fn batch_size_of<I>(batch_sizes: I) -> Option<usize>
+where
+    I: IntoIterator<Item = Option<usize>>,
+{
+    batch_sizes
+        .into_iter()
+        .flatten()
+        .reduce(|batch_size, other| {
+            assert_eq!(
+                batch_size, other,
+                "Tensors with different batch sizes can't be used together"
+            );
+            batch_size
+        })
+}
+
+/// Returns the shape of the primitive holding examples of the given shape.
+pub(crate) fn folded<const D: usize>(mut shape: Shape<D>, batch_size: Option<usize>) -> Shape<D> {
+    if let Some(batch_size) = batch_size {
+        shape.dims[0] *= batch_size;
+    }
+
+    shape
+}
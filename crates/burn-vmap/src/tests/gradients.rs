@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(vmap_gradients)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+    use burn_vmap::{shared, vmap};
+
+    type TestVmapAutodiffBackend = burn_autodiff::Autodiff<TestVmapBackend>;
+
+    #[test]
+    fn should_compute_per_sample_gradients() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [-1.0, 0.5], [3.0, 0.0]], &device);
+        let y = TestTensor::from_data([[1.0], [0.0], [2.0]], &device);
+        let weight = TestTensor::from_data([[0.5], [-1.0]], &device);
+
+        // The loss of each example is differentiated with respect to weights shared by all the
+        // examples, which gives one gradient per example.
+        let grads: TestTensor<3> = vmap(
+            |[x, y]: [TestVmapTensor<1>; 2]| {
+                let weight =
+                    Tensor::<TestVmapAutodiffBackend, 2>::from_inner(shared(weight.clone()))
+                        .require_grad();
+                let x = Tensor::<TestVmapAutodiffBackend, 1>::from_inner(x).unsqueeze::<2>();
+                let y = Tensor::<TestVmapAutodiffBackend, 1>::from_inner(y).unsqueeze::<2>();
+                let loss = (x.matmul(weight.clone()) - y).powf_scalar(2.0).sum();
+
+                weight.grad(&loss.backward()).unwrap()
+            },
+            [x.clone(), y.clone()],
+        );
+
+        // The gradient of (x w - y)^2 with respect to w is 2 (x w - y) x^T.
+        let residual = x.clone().matmul(weight) - y;
+        let expected = (x * residual * 2.0).unsqueeze_dim::<3>(2);
+
+        grads.into_data().assert_approx_eq(&expected.into_data(), 4);
+    }
+}
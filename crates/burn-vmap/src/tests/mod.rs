@@ -0,0 +1,17 @@
+#![allow(missing_docs)]
+
+mod gradients;
+mod module;
+mod ops;
+
+#[macro_export]
+macro_rules! testgen_all {
+    () => {
+        type TestVmapBackend = burn_vmap::Vmap<TestBackend>;
+        type TestVmapTensor<const D: usize> = burn_tensor::Tensor<TestVmapBackend, D>;
+
+        burn_vmap::testgen_vmap_ops!();
+        burn_vmap::testgen_vmap_module!();
+        burn_vmap::testgen_vmap_gradients!();
+    };
+}
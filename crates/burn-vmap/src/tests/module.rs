@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(vmap_module)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        module::{conv2d, conv_transpose2d},
+        ops::{ConvOptions, ConvTransposeOptions},
+        Tensor,
+    };
+    use burn_vmap::{shared, vmap};
+
+    #[test]
+    fn should_map_conv2d_with_shared_weights() {
+        let device = Default::default();
+        let x: TestTensor<5> = Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..72, &device)
+            .float()
+            .reshape([2, 1, 4, 3, 3]);
+        let weight = TestTensor::<4>::ones([2, 4, 2, 2], &device);
+        let options = ConvOptions::new([1, 1], [1, 0], [1, 1], 1);
+
+        let output: TestTensor<5> = vmap(
+            |[x]: [TestVmapTensor<4>; 1]| conv2d(x, shared(weight.clone()), None, options.clone()),
+            [x.clone()],
+        );
+
+        let expected = conv2d(x.reshape([2, 4, 3, 3]), weight, None, options);
+        assert_eq!(
+            output.into_data(),
+            expected.unsqueeze_dim::<5>(1).into_data()
+        );
+    }
+
+    #[test]
+    fn should_map_conv2d_with_weights_per_example() {
+        let device = Default::default();
+        let x: TestTensor<5> = Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..96, &device)
+            .float()
+            .reshape([2, 2, 2, 4, 3]);
+        let weight: TestTensor<5> =
+            Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..32, &device)
+                .float()
+                .reshape([2, 4, 1, 2, 2]);
+        let bias =
+            TestTensor::<2>::from_data([[1.0, 2.0, 3.0, 4.0], [-1.0, -2.0, -3.0, -4.0]], &device);
+        let options = ConvOptions::new([1, 1], [0, 1], [1, 1], 2);
+
+        // The inputs of vmap share their rank, so the bias is passed with padded dimensions.
+        let output: TestTensor<5> = vmap(
+            |[x, weight, bias]: [TestVmapTensor<4>; 3]| {
+                conv2d(x, weight, Some(bias.reshape([4])), options.clone())
+            },
+            [
+                x.clone(),
+                weight.clone(),
+                bias.clone().reshape([2, 4, 1, 1, 1]),
+            ],
+        );
+
+        for example in 0..2 {
+            let x = x.clone().slice([example..example + 1]).squeeze(0);
+            let weight = weight.clone().slice([example..example + 1]).squeeze(0);
+            let bias = bias.clone().slice([example..example + 1]).squeeze(0);
+            let expected = conv2d(x, weight, Some(bias), options.clone());
+            let output = output.clone().slice([example..example + 1]).squeeze::<4>(0);
+
+            assert_eq!(output.into_data(), expected.into_data());
+        }
+    }
+
+    #[test]
+    fn should_map_conv_transpose2d_with_weights_per_example() {
+        let device = Default::default();
+        let x: TestTensor<5> = Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..36, &device)
+            .float()
+            .reshape([2, 1, 2, 3, 3]);
+        let weight: TestTensor<5> =
+            Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..48, &device)
+                .float()
+                .reshape([2, 2, 3, 2, 2]);
+        let options = ConvTransposeOptions::new([2, 1], [0, 1], [1, 0], [1, 1], 1);
+
+        let output: TestTensor<5> = vmap(
+            |[x, weight]: [TestVmapTensor<4>; 2]| {
+                conv_transpose2d(x, weight, None, options.clone())
+            },
+            [x.clone(), weight.clone()],
+        );
+
+        for example in 0..2 {
+            let x = x.clone().slice([example..example + 1]).squeeze(0);
+            let weight = weight.clone().slice([example..example + 1]).squeeze(0);
+            let expected = conv_transpose2d(x, weight, None, options.clone());
+            let output = output.clone().slice([example..example + 1]).squeeze::<4>(0);
+
+            assert_eq!(output.into_data(), expected.into_data());
+        }
+    }
+}
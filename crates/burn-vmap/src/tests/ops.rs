@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(vmap_ops)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+    use burn_vmap::{shared, vmap};
+
+    #[test]
+    fn should_map_elementwise_ops() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let y = TestTensor::from_data([[0.5, 1.0], [2.0, 0.0], [-1.0, 1.0]], &device);
+
+        let output: TestTensor<2> = vmap(
+            |[x, y]: [TestVmapTensor<1>; 2]| x.clone().exp() * y + x,
+            [x.clone(), y.clone()],
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&(x.clone().exp() * y + x).into_data(), 3);
+    }
+
+    #[test]
+    fn should_broadcast_shared_tensors() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let bias = TestTensor::from_data([10.0, 20.0], &device);
+
+        let output: TestTensor<2> =
+            vmap(|[x]: [TestVmapTensor<1>; 1]| x + shared(bias.clone()), [x]);
+
+        assert_eq!(output.into_data(), Data::from([[11.0, 22.0], [13.0, 24.0]]));
+    }
+
+    #[test]
+    fn should_map_matmul_with_shared_and_batched_rhs() {
+        let device = Default::default();
+        let x = TestTensor::from_data(
+            [[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]],
+            &device,
+        );
+        let w = TestTensor::from_data([[2.0, 0.0], [1.0, 3.0]], &device);
+
+        let output: TestTensor<3> = vmap(
+            |[x]: [TestVmapTensor<2>; 1]| x.matmul(shared(w.clone())),
+            [x.clone()],
+        );
+        assert_eq!(
+            output.into_data(),
+            x.clone().matmul(w.unsqueeze()).into_data()
+        );
+
+        let output: TestTensor<3> = vmap(
+            |[lhs, rhs]: [TestVmapTensor<2>; 2]| lhs.matmul(rhs),
+            [x.clone(), x.clone().swap_dims(1, 2)],
+        );
+        assert_eq!(
+            output.into_data(),
+            x.clone().matmul(x.swap_dims(1, 2)).into_data()
+        );
+    }
+
+    #[test]
+    fn should_map_reductions() {
+        let device = Default::default();
+        let x = TestTensor::from_data(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]],
+            ],
+            &device,
+        );
+
+        let sum_dim: TestTensor<3> = vmap(|[x]: [TestVmapTensor<2>; 1]| x.sum_dim(1), [x.clone()]);
+        let sum: TestTensor<2> = vmap(|[x]: [TestVmapTensor<2>; 1]| x.sum(), [x.clone()]);
+        let argmax = vmap::<TestBackend, _, 2, 3, 2, 3, 1>(|[x]| x.argmax(0).float(), [x.clone()]);
+
+        assert_eq!(sum_dim.into_data(), x.clone().sum_dim(2).into_data());
+        assert_eq!(sum.into_data(), Data::from([[21.0], [21.0]]));
+        assert_eq!(argmax.into_data(), x.argmax(1).float().into_data());
+    }
+
+    #[test]
+    fn should_map_indexing_ops() {
+        let device = Default::default();
+        let x = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = Tensor::<TestBackend, 2, Int>::from_data([[2, 0], [1, 1]], &device);
+
+        let output: TestTensor<2> = vmap(
+            |[x, indices]: [TestVmapTensor<1>; 2]| x.gather(0, indices.int()),
+            [x.clone(), indices.clone().float()],
+        );
+        assert_eq!(output.into_data(), Data::from([[3.0, 1.0], [5.0, 5.0]]));
+
+        let output: TestTensor<2> = vmap(
+            |[x, indices]: [TestVmapTensor<1>; 2]| x.select(0, indices.int()),
+            [x.clone(), indices.float()],
+        );
+        assert_eq!(output.into_data(), Data::from([[3.0, 1.0], [5.0, 5.0]]));
+
+        let output: TestTensor<2> = vmap(
+            |[x]: [TestVmapTensor<1>; 1]| {
+                let shared_indices = shared(TestTensor::from_data([1.0, 0.0], &x.device()));
+                x.select(0, shared_indices.int())
+            },
+            [x],
+        );
+        assert_eq!(output.into_data(), Data::from([[2.0, 1.0], [5.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_map_slice_and_cat() {
+        let device = Default::default();
+        let x = TestTensor::from_data(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+            ],
+            &device,
+        );
+
+        let output: TestTensor<3> = vmap(
+            |[x]: [TestVmapTensor<2>; 1]| {
+                let first = x.clone().slice([0..2, 1..3]);
+                let last = x.slice([1..2, 0..3]).transpose().slice([0..2, 0..1]);
+
+                Tensor::cat(vec![first, last], 1)
+            },
+            [x],
+        );
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[2.0, 3.0, 4.0], [5.0, 6.0, 5.0]],
+                [[8.0, 9.0, 10.0], [11.0, 12.0, 11.0]]
+            ])
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{tensor::BatchedTensor, Vmap};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Create a tensor shared by all examples of a batch.
+pub fn shared<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<Vmap<B>, D> {
+    Tensor::from_primitive(BatchedTensor::shared(tensor.into_primitive()))
+}
+
+/// Splits a tensor along its first dimension into a batch of examples.
+///
+/// # Panics
+///
+/// If the rank of the examples `D` isn't one less than the rank `DB` of the tensor.
+pub fn batch<B: Backend, const D: usize, const DB: usize>(
+    tensor: Tensor<B, DB>,
+) -> Tensor<Vmap<B>, D> {
+    assert_eq!(
+        DB,
+        D + 1,
+        "The batched tensor must have one more dimension than the examples"
+    );
+    let dims = tensor.dims();
+    let mut shape = [0; D];
+    shape.copy_from_slice(&dims[1..]);
+    shape[0] *= dims[0];
+
+    let tensor = tensor.reshape(shape);
+
+    Tensor::from_primitive(BatchedTensor::batched(tensor.into_primitive(), dims[0]))
+}
+
+/// Stacks a batch of examples along a new first dimension.
+///
+/// Tensors shared by all examples are repeated for each of the `batch_size` examples.
+///
+/// # Panics
+///
+/// If the rank `DB` of the output isn't one more than the rank of the examples `D`, or if the
+/// tensor holds a different number of examples.
+pub fn unbatch<B: Backend, const D: usize, const DB: usize>(
+    tensor: Tensor<Vmap<B>, D>,
+    batch_size: usize,
+) -> Tensor<B, DB> {
+    assert_eq!(
+        DB,
+        D + 1,
+        "The batched tensor must have one more dimension than the examples"
+    );
+    let tensor = tensor.into_primitive();
+    let shape = tensor.shape();
+    let mut dims = [batch_size; DB];
+    dims[1..].copy_from_slice(&shape.dims);
+
+    Tensor::<B, D>::from_primitive(tensor.expand(batch_size)).reshape(dims)
+}
+
+/// Maps a function written for a single example over a batch of examples.
+///
+/// The inputs are split along their first dimension into examples, the function is evaluated
+/// once on all the examples and its outputs are stacked along a new first dimension. Tensors
+/// created inside the function, or captured with [shared], are the same for every example.
+///
+/// # Arguments
+///
+/// * `func` - The function to apply to each example.
+/// * `inputs` - The batched inputs of the function, with the examples on the first dimension.
+///
+/// # Returns
+///
+/// The outputs of the function, with the examples on the first dimension.
+///
+/// # Panics
+///
+/// If the ranks don't differ by one, or if the inputs have different numbers of examples.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// // Computes the dot product of each row of `x` with the corresponding row of `y`.
+/// let output: Tensor<B, 2> = vmap(
+///     |[x, y]: [Tensor<Vmap<B>, 1>; 2]| (x * y).sum(),
+///     [x, y],
+/// );
+/// ```
+pub fn vmap<
+    B,
+    F,
+    const D: usize,
+    const DB: usize,
+    const D2: usize,
+    const D2B: usize,
+    const N: usize,
+>(
+    func: F,
+    inputs: [Tensor<B, DB>; N],
+) -> Tensor<B, D2B>
+where
+    B: Backend,
+    F: FnOnce([Tensor<Vmap<B>, D>; N]) -> Tensor<Vmap<B>, D2>,
+{
+    let batch_size = inputs
+        .iter()
+        .map(|input| input.dims()[0])
+        .reduce(|batch_size, other| {
+            assert_eq!(
+                batch_size, other,
+                "The inputs of vmap must have the same batch size"
+            );
+            batch_size
+        })
+        .expect("vmap requires at least one input");
+
+    unbatch(func(inputs.map(batch)), batch_size)
+}
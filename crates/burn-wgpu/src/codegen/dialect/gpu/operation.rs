@@ -51,6 +51,12 @@ pub enum Operator {
     And(BinaryOperator),
     Or(BinaryOperator),
     Not(UnaryOperator),
+    BitwiseAnd(BinaryOperator),
+    BitwiseOr(BinaryOperator),
+    BitwiseXor(BinaryOperator),
+    BitwiseNot(UnaryOperator),
+    ShiftLeft(BinaryOperator),
+    ShiftRight(BinaryOperator),
     Max(BinaryOperator),
     Min(BinaryOperator),
 }
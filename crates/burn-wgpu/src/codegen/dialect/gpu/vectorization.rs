@@ -74,6 +74,12 @@ impl Operator {
             Operator::And(op) => Operator::And(op.vectorize(vectorization)),
             Operator::Or(op) => Operator::Or(op.vectorize(vectorization)),
             Operator::Not(op) => Operator::Not(op.vectorize(vectorization)),
+            Operator::BitwiseAnd(op) => Operator::BitwiseAnd(op.vectorize(vectorization)),
+            Operator::BitwiseOr(op) => Operator::BitwiseOr(op.vectorize(vectorization)),
+            Operator::BitwiseXor(op) => Operator::BitwiseXor(op.vectorize(vectorization)),
+            Operator::BitwiseNot(op) => Operator::BitwiseNot(op.vectorize(vectorization)),
+            Operator::ShiftLeft(op) => Operator::ShiftLeft(op.vectorize(vectorization)),
+            Operator::ShiftRight(op) => Operator::ShiftRight(op.vectorize(vectorization)),
         }
     }
 }
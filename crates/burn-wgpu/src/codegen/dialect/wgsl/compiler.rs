@@ -511,6 +511,35 @@ impl<F: FloatElement, I: IntElement> Compiler<F, I> {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
             },
+            gpu::Operator::BitwiseAnd(op) => wgsl::Instruction::BitwiseAnd {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Operator::BitwiseOr(op) => wgsl::Instruction::BitwiseOr {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Operator::BitwiseXor(op) => wgsl::Instruction::BitwiseXor {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Operator::ShiftLeft(op) => wgsl::Instruction::ShiftLeft {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Operator::ShiftRight(op) => wgsl::Instruction::ShiftRight {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Operator::BitwiseNot(op) => wgsl::Instruction::BitwiseNot {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
         }
     }
 
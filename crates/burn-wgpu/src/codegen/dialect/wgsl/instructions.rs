@@ -1,4 +1,4 @@
-use super::base::{Item, Variable};
+use super::base::{Elem, Item, Variable};
 use std::fmt::Display;
 
 /// All instructions that can be used in a WGSL compute shader.
@@ -182,6 +182,35 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    BitwiseAnd {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    BitwiseOr {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    BitwiseXor {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShiftLeft {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShiftRight {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    BitwiseNot {
+        input: Variable,
+        out: Variable,
+    },
     Loop {
         instructions: Vec<Instruction>,
     },
@@ -210,6 +239,29 @@ impl Display for Instruction {
                 f.write_fmt(format_args!("{out} = {lhs} || {rhs};\n"))
             }
             Instruction::Not { input, out } => f.write_fmt(format_args!("{out} = !{input};\n")),
+            Instruction::BitwiseAnd { lhs, rhs, out } => {
+                let (lhs, rhs) = (splat(lhs, out), splat(rhs, out));
+                f.write_fmt(format_args!("{out} = {lhs} & {rhs};\n"))
+            }
+            Instruction::BitwiseOr { lhs, rhs, out } => {
+                let (lhs, rhs) = (splat(lhs, out), splat(rhs, out));
+                f.write_fmt(format_args!("{out} = {lhs} | {rhs};\n"))
+            }
+            Instruction::BitwiseXor { lhs, rhs, out } => {
+                let (lhs, rhs) = (splat(lhs, out), splat(rhs, out));
+                f.write_fmt(format_args!("{out} = {lhs} ^ {rhs};\n"))
+            }
+            Instruction::BitwiseNot { input, out } => {
+                f.write_fmt(format_args!("{out} = ~{input};\n"))
+            }
+            Instruction::ShiftLeft { lhs, rhs, out } => {
+                let (lhs, rhs) = (splat(lhs, out), shift_amount(rhs, out));
+                f.write_fmt(format_args!("{out} = {lhs} << {rhs};\n"))
+            }
+            Instruction::ShiftRight { lhs, rhs, out } => {
+                let (lhs, rhs) = (splat(lhs, out), shift_amount(rhs, out));
+                f.write_fmt(format_args!("{out} = {lhs} >> {rhs};\n"))
+            }
             Instruction::Index { lhs, rhs, out } => {
                 let item = out.item();
                 f.write_fmt(format_args!("{out} = {item}({lhs}[{rhs}]);\n"))
@@ -483,3 +535,25 @@ fn comparison(
         },
     }
 }
+
+/// Bitwise operators don't mix scalars and vectors, so the scalar operands are converted to the
+/// item of the output.
+fn splat(var: &Variable, out: &Variable) -> String {
+    let item = out.item();
+    format!("{item}({var})")
+}
+
+/// The shift amount must be unsigned, with the same number of components as the shifted value.
+fn shift_amount(var: &Variable, out: &Variable) -> String {
+    let item = match out.item() {
+        Item::Vec4(_) => Item::Vec4(Elem::U32),
+        Item::Vec3(_) => Item::Vec3(Elem::U32),
+        Item::Vec2(_) => Item::Vec2(Elem::U32),
+        Item::Scalar(_) => Item::Scalar(Elem::U32),
+    };
+
+    match var.item() {
+        Item::Scalar(_) => format!("{item}(u32({var}))"),
+        _ => format!("{item}({var})"),
+    }
+}
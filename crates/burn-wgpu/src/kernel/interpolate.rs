@@ -16,6 +16,19 @@ kernel_wgsl!(Nearest, "../template/interpolate/nearest.wgsl");
 kernel_wgsl!(Bilinear, "../template/interpolate/bilinear.wgsl");
 kernel_wgsl!(Bicubic, "../template/interpolate/bicubic.wgsl");
 
+/// Whether the interpolation has a kernel, otherwise it is computed with matrix multiplications.
+///
+/// The bilinear and bicubic kernels align the corners and divide by the output size minus one.
+pub(crate) fn is_supported(output_size: [usize; 2], options: &InterpolateOptions) -> bool {
+    match options.mode {
+        InterpolateMode::Nearest => true,
+        InterpolateMode::Bilinear | InterpolateMode::Bicubic => {
+            options.align_corners && !options.antialias && output_size.iter().all(|size| *size > 1)
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn interpolate<R: Runtime, E: JitElement + Element>(
     input: JitTensor<R, E, 4>,
     output_size: [usize; 2],
@@ -51,6 +64,7 @@ pub(crate) fn interpolate<R: Runtime, E: JitElement + Element>(
             output.shape.num_elements(),
             WORKGROUP_DEFAULT,
         ))),
+        mode => panic!("Interpolate mode {mode:?} has no kernel"),
     };
 
     input
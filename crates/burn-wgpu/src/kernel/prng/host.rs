@@ -0,0 +1,22 @@
+use burn_common::rand::get_seeded_rng;
+use burn_tensor::{Data, Distribution, Shape};
+
+use crate::{element::JitElement, ops::from_data, tensor::JitTensor, Runtime, SEED};
+
+/// Pseudo-random generator for the distributions without a kernel, sampled on the host from the
+/// same seeded stream as the kernels.
+pub fn random_host<R: Runtime, E: JitElement, const D: usize>(
+    shape: Shape<D>,
+    device: &R::Device,
+    distribution: Distribution,
+) -> JitTensor<R, E, D> {
+    let mut seed = SEED.lock().unwrap();
+    let mut rng = match seed.as_ref() {
+        Some(rng_seeded) => rng_seeded.clone(),
+        None => get_seeded_rng(),
+    };
+    let data = Data::random(shape, distribution, &mut rng);
+    *seed = Some(rng);
+
+    from_data(data, device)
+}
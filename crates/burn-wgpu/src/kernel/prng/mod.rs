@@ -1,8 +1,10 @@
 mod base;
 mod bernoulli;
+mod host;
 mod normal;
 mod uniform;
 
 pub use bernoulli::*;
+pub use host::*;
 pub use normal::*;
 pub use uniform::*;
@@ -1,3 +1,4 @@
+use super::numeric;
 use crate::{kernel, JitBackend, Runtime};
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntTensor};
 use burn_tensor::Reader;
@@ -89,6 +90,27 @@ impl<R: Runtime> BoolTensorOps<Self> for JitBackend<R> {
         kernel::equal_elem(tensor, 0)
     }
 
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::bitwise_and(lhs, rhs)
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::bitwise_or(lhs, rhs)
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::bitwise_xor(lhs, rhs)
+    }
+
     fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
         kernel::bool_cast(tensor)
     }
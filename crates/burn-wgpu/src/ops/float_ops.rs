@@ -6,7 +6,7 @@ use crate::kernel::matmul::init_matmul_output;
 use crate::kernel::matmul::matmul_autotune;
 #[cfg(not(feature = "autotune"))]
 use crate::kernel::matmul::vec4::matmul_tiling_2d_vec4;
-use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
+use crate::kernel::prng::{random_bernoulli, random_host, random_normal, random_uniform};
 use crate::kernel::{self, reduce};
 use crate::tensor::JitTensor;
 use crate::Runtime;
@@ -40,6 +40,7 @@ impl<R: Runtime> FloatTensorOps<Self> for JitBackend<R> {
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem())
             }
+            _ => random_host(shape, device, distribution),
         }
     }
 
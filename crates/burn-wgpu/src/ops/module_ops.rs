@@ -1,7 +1,7 @@
 use crate::{kernel, JitBackend, Runtime};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
-    ModuleOps,
+    interpolate, ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward,
+    MaxPool2dWithIndices, ModuleOps,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -107,6 +107,10 @@ impl<R: Runtime> ModuleOps<Self> for JitBackend<R> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self, 4> {
-        kernel::interpolate::interpolate(x, output_size, options)
+        if kernel::interpolate::is_supported(output_size, &options) {
+            kernel::interpolate::interpolate(x, output_size, options)
+        } else {
+            interpolate::interpolate_from_matmul::<Self>(x, output_size, options)
+        }
     }
 }
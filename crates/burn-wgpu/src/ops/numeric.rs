@@ -220,3 +220,177 @@ pub fn pow<R: Runtime, E: JitElement, const D: usize>(
         elem: E
     )
 }
+
+pub fn bitwise_and<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseAnd(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_array(1, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_and_scalar<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseAnd(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_scalar(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_or<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseOr(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_array(1, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_or_scalar<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseOr(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_scalar(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_xor<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseXor(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_array(1, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_xor_scalar<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseXor(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_scalar(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn left_shift<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::ShiftLeft(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_array(1, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn left_shift_scalar<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::ShiftLeft(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_scalar(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn right_shift<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::ShiftRight(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_array(1, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn right_shift_scalar<R: Runtime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::ShiftRight(BinaryOperator {
+            lhs: scope.read_array(0, elem),
+            rhs: scope.read_scalar(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_not<R: Runtime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    unary!(
+        operation: |scope: &mut Scope, elem: Elem| Operator::BitwiseNot(UnaryOperator {
+            input: scope.read_array(0, elem),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: tensor,
+        elem: E
+    )
+}